@@ -1,3 +1,5 @@
+// the error types of the routes, which no route returns yet
+#[allow(dead_code)]
 mod error;
 
 #[tokio::main]
@@ -0,0 +1,56 @@
+//! Keeps configured request builders in a struct and builds them in helper functions, which needs the services and
+//! the builders to be nameable from outside the crate.
+//!
+//! Run with `TEST_API_KEY=... cargo run --example stored_requests`.
+
+use youtube_data::error::Result;
+use youtube_data::search::{Order, SearchList, VideoSearchResult};
+use youtube_data::videos::{Chart, Part, Rating, VideoFilter, VideoList, VideoRate};
+use youtube_data::{ListApi, SearchService, VideosService, YouTube};
+
+/// The requests of a dashboard, configured once and sent on every refresh.
+struct Dashboard<'a> {
+    latest: SearchList<'a, VideoSearchResult>,
+    popular: VideoList<'a>,
+}
+
+fn latest_uploads<'a>(
+    search: SearchService<'a>,
+    channel_id: &str,
+) -> SearchList<'a, VideoSearchResult> {
+    let mut list = search.videos("");
+    list.channel_id(channel_id)
+        .order(Order::Date)
+        .max_results(5);
+    list
+}
+
+fn popular_in<'a>(videos: VideosService<'a>, region_code: &str) -> VideoList<'a> {
+    let mut list = videos.list([Part::Snippet], VideoFilter::Chart(Chart::MostPopular));
+    list.region_code(region_code).max_results(5);
+    list
+}
+
+/// A rating that is only sent once the user confirms it.
+#[allow(dead_code)]
+fn like<'a>(videos: VideosService<'a>, id: &str) -> VideoRate<'a> {
+    videos.rate(id, Rating::Like)
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let api_key = std::env::var("TEST_API_KEY").expect("TEST_API_KEY is not set");
+    let youtube = YouTube::builder(api_key).build()?;
+    let dashboard = Dashboard {
+        latest: latest_uploads(youtube.search(), "UC_x5XG1OV2P6uZZ5FSM9Ttw"),
+        popular: popular_in(youtube.videos(), "KR"),
+    };
+
+    for result in dashboard.latest.request().await?.items {
+        println!("{}", result.id);
+    }
+    for video in dashboard.popular.request().await?.items {
+        println!("{}", video.id);
+    }
+    Ok(())
+}
@@ -0,0 +1,481 @@
+//! A client for the `reports.query` method of the [YouTube Analytics API](https://developers.google.com/youtube/analytics).
+//! Enabled with the `analytics` feature.
+
+use crate::{
+    auth::{Authorizer, OAuthTokenAuth},
+    error::{Error, Result},
+    json, RequestBase, YouTube, YouTubeDataApi,
+};
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// The base path for the YouTube Analytics API.
+const ANALYTICS_BASE_PATH: &str = "https://youtubeanalytics.googleapis.com/v2";
+
+#[derive(Clone, Copy)]
+pub struct AnalyticsService<'a> {
+    youtube: &'a YouTube,
+}
+
+impl<'a> AnalyticsService<'a> {
+    pub(crate) fn new(youtube: &'a YouTube) -> Self {
+        Self { youtube }
+    }
+
+    pub fn query(
+        self,
+        ids: ReportIds,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+        metrics: Vec<Metric>,
+    ) -> ReportQuery<'a> {
+        ReportQuery::new(self, ids, start_date, end_date, metrics)
+    }
+}
+
+/// Parameters for the `query` method of the `reports` api. details:
+/// [link](https://developers.google.com/youtube/analytics/reference/reports/query)
+pub struct ReportQuery<'a> {
+    service: AnalyticsService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    ids: ReportIds,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+    metrics: Vec<Metric>,
+
+    // optional parameters
+    currency: Option<String>,
+    dimensions: Option<Vec<Dimension>>,
+    filters: Vec<Filter>,
+    include_historical_channel_data: Option<bool>,
+    max_results: Option<u32>,
+    sort: Vec<String>,
+    start_index: Option<u32>,
+}
+
+impl RequestBase for ReportQuery<'_> {
+    fn api_path(&self) -> &str {
+        "reports"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for ReportQuery<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> ReportQuery<'a> {
+    pub(crate) fn new(
+        service: AnalyticsService<'a>,
+        ids: ReportIds,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+        metrics: Vec<Metric>,
+    ) -> Self {
+        Self {
+            service,
+            authorizer: None,
+            ids,
+            start_date,
+            end_date,
+            metrics,
+            currency: None,
+            dimensions: None,
+            filters: vec![],
+            include_historical_channel_data: None,
+            max_results: None,
+            sort: vec![],
+            start_index: None,
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+
+    /// The currency of the revenue metrics, as an [ISO 4217](https://en.wikipedia.org/wiki/ISO_4217) code. The
+    /// default value is `USD`.
+    pub fn currency(&mut self, currency: impl Into<String>) -> &mut Self {
+        self.currency = Some(currency.into());
+        self
+    }
+
+    pub fn dimensions(&mut self, dimensions: Vec<Dimension>) -> &mut Self {
+        self.dimensions = Some(dimensions);
+        self
+    }
+
+    /// Restricts the report to rows whose `dimension` matches one of `values`. Calling this method again adds another
+    /// filter, and all filters must match.
+    pub fn filter(&mut self, dimension: Dimension, values: Vec<&str>) -> &mut Self {
+        self.filters.push(Filter {
+            dimension,
+            values: values.into_iter().map(String::from).collect(),
+        });
+        self
+    }
+
+    /// Whether to include the data of the time before the channel was linked to a content owner.
+    pub fn include_historical_channel_data(
+        &mut self,
+        include_historical_channel_data: bool,
+    ) -> &mut Self {
+        self.include_historical_channel_data = Some(include_historical_channel_data);
+        self
+    }
+
+    pub fn max_results(&mut self, max_results: u32) -> &mut Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    /// Sorts the rows by a metric or dimension. Calling this method again adds a secondary sort key.
+    pub fn sort(&mut self, key: impl std::fmt::Display, descending: bool) -> &mut Self {
+        let key = if descending {
+            format!("-{}", key)
+        } else {
+            key.to_string()
+        };
+        self.sort.push(key);
+        self
+    }
+
+    /// The 1-based index of the first row to retrieve.
+    pub fn start_index(&mut self, start_index: u32) -> &mut Self {
+        let start_index = start_index.max(1);
+        self.start_index = Some(start_index);
+        self
+    }
+
+    pub async fn request(&self) -> Result<ResultTable> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        // required parameters
+        self.insert_query_parameter(&mut params, "ids", Some(&self.ids));
+        if self.start_date > self.end_date {
+            return Err(Error::invalid_parameter(
+                "The `start_date` must not be later than the `end_date`",
+            ));
+        }
+        self.insert_query_parameter(
+            &mut params,
+            "startDate",
+            Some(self.start_date.format("%Y-%m-%d")),
+        );
+        self.insert_query_parameter(
+            &mut params,
+            "endDate",
+            Some(self.end_date.format("%Y-%m-%d")),
+        );
+        if self.metrics.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required parameter `metrics` is empty",
+            ));
+        }
+        self.insert_query_parameters(&mut params, "metrics", Some(&self.metrics));
+
+        // optional parameters
+        self.insert_query_parameter(&mut params, "currency", self.currency.as_deref());
+        self.insert_query_parameters(&mut params, "dimensions", self.dimensions.as_ref());
+        if !self.filters.is_empty() {
+            let filters = self
+                .filters
+                .iter()
+                .map(Filter::to_string)
+                .collect::<Vec<String>>()
+                .join(";");
+            self.insert_query_parameter(&mut params, "filters", Some(filters));
+        }
+        self.insert_query_parameter(
+            &mut params,
+            "includeHistoricalChannelData",
+            self.include_historical_channel_data,
+        );
+        self.insert_query_parameter(&mut params, "maxResults", self.max_results);
+        self.insert_query_parameters(&mut params, "sort", Some(&self.sort));
+        self.insert_query_parameter(&mut params, "startIndex", self.start_index);
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .get(self.url(ANALYTICS_BASE_PATH))
+                .query(&params),
+        )?;
+        let response = self.send(youtube, request).await?;
+        json(response).await
+    }
+}
+
+/// The channel or content owner for which the report is retrieved.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReportIds {
+    /// The channel of the authorized user.
+    MyChannel,
+
+    /// The channel with the given channel ID.
+    Channel(String),
+
+    /// The content owner with the given name.
+    ContentOwner(String),
+}
+
+impl std::fmt::Display for ReportIds {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReportIds::MyChannel => write!(f, "channel==MINE"),
+            ReportIds::Channel(id) => write!(f, "channel=={}", id),
+            ReportIds::ContentOwner(name) => write!(f, "contentOwner=={}", name),
+        }
+    }
+}
+
+struct Filter {
+    dimension: Dimension,
+    values: Vec<String>,
+}
+
+impl std::fmt::Display for Filter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}=={}", self.dimension, self.values.join(","))
+    }
+}
+
+/// The metrics of a report. details:
+/// [link](https://developers.google.com/youtube/analytics/metrics)
+#[derive(Debug, Clone, PartialEq)]
+pub enum Metric {
+    AnnotationClickThroughRate,
+    AverageViewDuration,
+    AverageViewPercentage,
+    CardClickRate,
+    Comments,
+    CpmEstimated,
+    Dislikes,
+    EstimatedAdRevenue,
+    EstimatedMinutesWatched,
+    EstimatedRedPartnerRevenue,
+    EstimatedRevenue,
+    Likes,
+    PlaylistStarts,
+    Shares,
+    SubscribersGained,
+    SubscribersLost,
+    VideosAddedToPlaylists,
+    VideosRemovedFromPlaylists,
+    ViewerPercentage,
+    Views,
+}
+
+impl std::fmt::Display for Metric {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            Metric::AnnotationClickThroughRate => "annotationClickThroughRate",
+            Metric::AverageViewDuration => "averageViewDuration",
+            Metric::AverageViewPercentage => "averageViewPercentage",
+            Metric::CardClickRate => "cardClickRate",
+            Metric::Comments => "comments",
+            Metric::CpmEstimated => "cpm",
+            Metric::Dislikes => "dislikes",
+            Metric::EstimatedAdRevenue => "estimatedAdRevenue",
+            Metric::EstimatedMinutesWatched => "estimatedMinutesWatched",
+            Metric::EstimatedRedPartnerRevenue => "estimatedRedPartnerRevenue",
+            Metric::EstimatedRevenue => "estimatedRevenue",
+            Metric::Likes => "likes",
+            Metric::PlaylistStarts => "playlistStarts",
+            Metric::Shares => "shares",
+            Metric::SubscribersGained => "subscribersGained",
+            Metric::SubscribersLost => "subscribersLost",
+            Metric::VideosAddedToPlaylists => "videosAddedToPlaylists",
+            Metric::VideosRemovedFromPlaylists => "videosRemovedFromPlaylists",
+            Metric::ViewerPercentage => "viewerPercentage",
+            Metric::Views => "views",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+/// The dimensions of a report. details:
+/// [link](https://developers.google.com/youtube/analytics/dimensions)
+#[derive(Debug, Clone, PartialEq)]
+pub enum Dimension {
+    AgeGroup,
+    Channel,
+    City,
+    Country,
+    CreatorContentType,
+    Day,
+    DeviceType,
+    Gender,
+    InsightPlaybackLocationType,
+    InsightTrafficSourceDetail,
+    InsightTrafficSourceType,
+    LiveOrOnDemand,
+    Month,
+    OperatingSystem,
+    Playlist,
+    Province,
+    SharingService,
+    SubscribedStatus,
+    Video,
+    YoutubeProduct,
+}
+
+impl std::fmt::Display for Dimension {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            Dimension::AgeGroup => "ageGroup",
+            Dimension::Channel => "channel",
+            Dimension::City => "city",
+            Dimension::Country => "country",
+            Dimension::CreatorContentType => "creatorContentType",
+            Dimension::Day => "day",
+            Dimension::DeviceType => "deviceType",
+            Dimension::Gender => "gender",
+            Dimension::InsightPlaybackLocationType => "insightPlaybackLocationType",
+            Dimension::InsightTrafficSourceDetail => "insightTrafficSourceDetail",
+            Dimension::InsightTrafficSourceType => "insightTrafficSourceType",
+            Dimension::LiveOrOnDemand => "liveOrOnDemand",
+            Dimension::Month => "month",
+            Dimension::OperatingSystem => "operatingSystem",
+            Dimension::Playlist => "playlist",
+            Dimension::Province => "province",
+            Dimension::SharingService => "sharingService",
+            Dimension::SubscribedStatus => "subscribedStatus",
+            Dimension::Video => "video",
+            Dimension::YoutubeProduct => "youtubeProduct",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+/// The response of `reports.query`. Each row holds one value per column, in the order of `column_headers`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResultTable {
+    /// Identifies the API resource's type. The value will be `youtubeAnalytics#resultTable`.
+    #[serde(default)]
+    pub kind: String,
+
+    /// The name and type of each column, dimensions first and metrics after, in the requested order.
+    #[serde(default)]
+    pub column_headers: Vec<ColumnHeader>,
+
+    /// The rows of the report. The list is empty when there is no data for the query.
+    #[serde(default)]
+    pub rows: Vec<Vec<serde_json::Value>>,
+}
+
+impl ResultTable {
+    /// The index of the column with the given name, e.g. `Metric::Views.to_string()`.
+    pub fn column_index(&self, name: &str) -> Option<usize> {
+        self.column_headers
+            .iter()
+            .position(|header| header.name == name)
+    }
+
+    /// The values of the column with the given name, one per row.
+    pub fn column(&self, name: &str) -> Option<Vec<&serde_json::Value>> {
+        let index = self.column_index(name)?;
+        Some(self.rows.iter().filter_map(|row| row.get(index)).collect())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ColumnHeader {
+    /// The name of the dimension or metric.
+    #[serde(default)]
+    pub name: String,
+
+    /// The type of the column, `DIMENSION` or `METRIC`.
+    #[serde(default)]
+    pub column_type: String,
+
+    /// The type of the data in the column, e.g. `STRING`, `INTEGER` or `FLOAT`.
+    #[serde(default)]
+    pub data_type: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_youtube_without_user_agent;
+
+    #[tokio::test]
+    async fn test_query_without_auth() {
+        let without_auth = get_youtube_without_user_agent()
+            .analytics()
+            .query(
+                ReportIds::MyChannel,
+                NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 1, 31).unwrap(),
+                vec![Metric::Views, Metric::EstimatedMinutesWatched],
+            )
+            .dimensions(vec![Dimension::Day])
+            .filter(Dimension::Country, vec!["US", "KR"])
+            .sort(Dimension::Day, false)
+            .request()
+            .await;
+        assert!(without_auth.is_err());
+        let err = without_auth.unwrap_err();
+        assert_eq!(
+            "builder error: \"The request to `reports` requires OAuth 2.0 authorization\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_query_with_start_after_end() {
+        let start_after_end = get_youtube_without_user_agent()
+            .analytics()
+            .query(
+                ReportIds::Channel("UC_x5XG1OV2P6uZZ5FSM9Ttw".to_string()),
+                NaiveDate::from_ymd_opt(2023, 2, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+                vec![Metric::Views],
+            )
+            .request()
+            .await;
+        assert!(start_after_end.is_err());
+        let err = start_after_end.unwrap_err();
+        assert_eq!(
+            "builder error: \"The `start_date` must not be later than the `end_date`\"",
+            format!("{}", err)
+        );
+    }
+
+    #[test]
+    fn test_deserialize_result_table() {
+        let table: ResultTable = serde_json::from_str(
+            r#"{
+                "kind": "youtubeAnalytics#resultTable",
+                "columnHeaders": [
+                    {"name": "day", "columnType": "DIMENSION", "dataType": "STRING"},
+                    {"name": "views", "columnType": "METRIC", "dataType": "INTEGER"}
+                ],
+                "rows": [["2023-01-01", 120], ["2023-01-02", 80]]
+            }"#,
+        )
+        .unwrap();
+        let views = table.column(&Metric::Views.to_string()).unwrap();
+        assert_eq!(views.iter().filter_map(|v| v.as_u64()).sum::<u64>(), 200);
+        assert!(table.column("likes").is_none());
+    }
+}
@@ -0,0 +1,212 @@
+//! The credentials attached to every request. The api key and the OAuth 2.0 access token of [YouTube] are
+//! [Authorizer]s; implement the trait to hand the requests to another token broker (e.g. `yup-oauth2`).
+//!
+//! [YouTube]: crate::YouTube
+
+use crate::error::{Error, Result};
+
+use async_trait::async_trait;
+use reqwest::header::{HeaderValue, AUTHORIZATION};
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+/// Attaches credentials to a request right before it is sent.
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait Authorizer: Send + Sync {
+    /// Adds the credentials (a query parameter, a header, ...) to the request.
+    async fn authorize(&self, request: &mut reqwest::Request) -> Result<()>;
+
+    /// Whether the credentials act on behalf of a user. The apis and parameters that require OAuth 2.0
+    /// authorization fail with a builder error unless an authorizer does.
+    fn authorizes_user(&self) -> bool;
+}
+
+/// How the keys of an [ApiKeyAuth] pool are picked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyRotation {
+    /// Every request uses the next key, spreading the load evenly over the keys.
+    RoundRobin,
+
+    /// Every request uses the same key until its quota is exceeded, then the next one.
+    OnQuotaExceeded,
+}
+
+/// Sends the api key of a Google Cloud project as the `key` query parameter. A pool of keys of several projects
+/// spreads the quota consumption over the projects; with either [KeyRotation], a request rejected with
+/// `quotaExceeded` is retried with the next key until every key was tried.
+#[derive(Debug, Clone)]
+pub struct ApiKeyAuth {
+    api_keys: Vec<String>,
+    rotation: KeyRotation,
+    /// The key used by the next request, shared by the clones of the handle.
+    next: Arc<AtomicUsize>,
+}
+
+impl ApiKeyAuth {
+    pub fn new(api_key: impl Into<String>) -> ApiKeyAuth {
+        ApiKeyAuth::pool([api_key], KeyRotation::OnQuotaExceeded)
+    }
+
+    pub fn pool<K>(api_keys: impl IntoIterator<Item = K>, rotation: KeyRotation) -> ApiKeyAuth
+    where
+        K: Into<String>,
+    {
+        ApiKeyAuth {
+            api_keys: api_keys
+                .into_iter()
+                .map(Into::into)
+                .filter(|api_key| !api_key.is_empty())
+                .collect(),
+            rotation,
+            next: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Whether there is another key to retry with.
+    pub(crate) fn is_pool(&self) -> bool {
+        self.api_keys.len() > 1
+    }
+
+    /// The index of the key used by a new request.
+    pub(crate) fn select(&self) -> Option<usize> {
+        if self.api_keys.is_empty() {
+            return None;
+        }
+        let next = match self.rotation {
+            KeyRotation::RoundRobin => self.next.fetch_add(1, Ordering::Relaxed),
+            KeyRotation::OnQuotaExceeded => self.next.load(Ordering::Relaxed),
+        };
+        Some(next % self.api_keys.len())
+    }
+
+    /// The key to retry with after the quota of the key `exhausted` was exceeded, or `None` when every key was tried
+    /// since the key `first` of the request.
+    pub(crate) fn rotate(&self, exhausted: usize, first: usize) -> Option<usize> {
+        let next = (exhausted + 1) % self.api_keys.len();
+        if self.rotation == KeyRotation::OnQuotaExceeded {
+            // another request may have rotated already
+            let _ =
+                self.next
+                    .compare_exchange(exhausted, next, Ordering::Relaxed, Ordering::Relaxed);
+        }
+        (next != first).then_some(next)
+    }
+
+    /// Sets the `key` query parameter to the key at `index`, replacing the key of a previous attempt.
+    pub(crate) fn apply(&self, request: &mut reqwest::Request, index: usize) {
+        let params = request
+            .url()
+            .query_pairs()
+            .filter(|(name, _)| name != "key")
+            .map(|(name, value)| (name.into_owned(), value.into_owned()))
+            .collect::<Vec<(String, String)>>();
+        request
+            .url_mut()
+            .query_pairs_mut()
+            .clear()
+            .extend_pairs(params)
+            .append_pair("key", &self.api_keys[index]);
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl Authorizer for ApiKeyAuth {
+    async fn authorize(&self, request: &mut reqwest::Request) -> Result<()> {
+        if let Some(index) = self.select() {
+            self.apply(request, index);
+        }
+        Ok(())
+    }
+
+    fn authorizes_user(&self) -> bool {
+        false
+    }
+}
+
+/// Sends an OAuth 2.0 access token as a bearer token.
+#[derive(Debug, Clone)]
+pub struct OAuthTokenAuth {
+    access_token: String,
+}
+
+impl OAuthTokenAuth {
+    pub fn new(access_token: impl Into<String>) -> OAuthTokenAuth {
+        OAuthTokenAuth {
+            access_token: access_token.into(),
+        }
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl Authorizer for OAuthTokenAuth {
+    async fn authorize(&self, request: &mut reqwest::Request) -> Result<()> {
+        let mut value =
+            HeaderValue::from_str(&format!("Bearer {}", self.access_token)).map_err(|_| {
+                Error::invalid_parameter(
+                    "The access token contains characters that are not allowed in a header",
+                )
+            })?;
+        value.set_sensitive(true);
+        request.headers_mut().insert(AUTHORIZATION, value);
+        Ok(())
+    }
+
+    fn authorizes_user(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_authorize() {
+        let mut request = reqwest::Client::new()
+            .get("https://www.googleapis.com/youtube/v3/channels?part=id")
+            .build()
+            .unwrap();
+        ApiKeyAuth::new("api-key")
+            .authorize(&mut request)
+            .await
+            .unwrap();
+        OAuthTokenAuth::new("access-token")
+            .authorize(&mut request)
+            .await
+            .unwrap();
+        assert_eq!(request.url().query(), Some("part=id&key=api-key"));
+        assert_eq!(
+            request.headers().get(AUTHORIZATION).unwrap(),
+            "Bearer access-token"
+        );
+    }
+
+    #[test]
+    fn test_key_pool() {
+        let round_robin = ApiKeyAuth::pool(["a", "b", "c"], KeyRotation::RoundRobin);
+        assert_eq!(round_robin.select(), Some(0));
+        assert_eq!(round_robin.select(), Some(1));
+        assert_eq!(round_robin.rotate(1, 1), Some(2));
+        assert_eq!(round_robin.select(), Some(2));
+
+        let on_quota_exceeded = ApiKeyAuth::pool(["a", "b"], KeyRotation::OnQuotaExceeded);
+        assert_eq!(on_quota_exceeded.select(), Some(0));
+        assert_eq!(on_quota_exceeded.select(), Some(0));
+        assert_eq!(on_quota_exceeded.rotate(0, 0), Some(1));
+        assert_eq!(on_quota_exceeded.select(), Some(1));
+        assert_eq!(on_quota_exceeded.rotate(1, 0), None);
+        assert_eq!(on_quota_exceeded.select(), Some(0));
+
+        let mut request = reqwest::Client::new()
+            .get("https://www.googleapis.com/youtube/v3/channels?key=a&part=id")
+            .build()
+            .unwrap();
+        on_quota_exceeded.apply(&mut request, 1);
+        assert_eq!(request.url().query(), Some("part=id&key=b"));
+    }
+}
@@ -0,0 +1,517 @@
+//! Sends many requests in one HTTP round trip through the
+//! [batch endpoint](https://developers.google.com/youtube/v3/guides/implementation/batch) of the api.
+//!
+//! The requests are built with the services of [Batch::youtube] as usual and run together by [Batch::run]: every
+//! request is collected instead of being sent, the collected requests are sent as the parts of one
+//! `multipart/mixed` request (at most 50 per batch), and the parts of the response are handed back to the
+//! requests, which then handle them like any other response. Media uploads can not be batched.
+//!
+//! [Batch::run] takes requests of one type, e.g. several `channels().list(...)`; the requests of different
+//! operations are [added](BatchRequests::add) to [Batch::requests], which hands back one typed result per request.
+
+use crate::{
+    error::{Error, ErrorKind, Result},
+    multipart_boundary,
+    transport::HttpTransport,
+    YouTube,
+};
+
+use async_trait::async_trait;
+use futures_util::future::{join, join_all, BoxFuture};
+use reqwest::{header::CONTENT_TYPE, Method, Url};
+use std::future::Future;
+use std::mem;
+use std::sync::{Arc, Mutex};
+use tokio::sync::{oneshot, Notify};
+
+/// The most requests the batch endpoint accepts in one batch.
+const MAX_BATCH_SIZE: usize = 50;
+
+type Reply = oneshot::Sender<Result<reqwest::Response>>;
+
+/// Collects the requests built with [Batch::youtube] and sends them together, see the [module](self) documentation.
+pub struct Batch {
+    youtube: YouTube,
+    /// The transport of the original handle, which sends the batches.
+    transport: Arc<dyn HttpTransport>,
+    batch_url: Url,
+    state: Arc<BatchState>,
+    /// Only one run at a time, the requests of two runs would be mixed up.
+    running: tokio::sync::Mutex<()>,
+}
+
+#[derive(Default)]
+struct BatchState {
+    pending: Mutex<Pending>,
+    changed: Notify,
+}
+
+#[derive(Default)]
+struct Pending {
+    /// The requests waiting for the next batch.
+    waiting: Vec<(reqwest::Request, Reply)>,
+    /// The number of requests of the run that completed.
+    finished: usize,
+}
+
+impl BatchState {
+    fn update(&self, update: impl FnOnce(&mut Pending)) {
+        update(&mut self.pending.lock().unwrap());
+        self.changed.notify_one();
+    }
+}
+
+/// Collects the requests of the batch handle instead of sending them.
+struct BatchTransport {
+    state: Arc<BatchState>,
+}
+
+#[async_trait]
+impl HttpTransport for BatchTransport {
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+        let (reply, response) = oneshot::channel();
+        self.state
+            .update(|pending| pending.waiting.push((request, reply)));
+        response
+            .await
+            .unwrap_or_else(|_| Err(batch_error("The batch was dropped before it was sent")))
+    }
+}
+
+impl Batch {
+    pub(crate) fn new(youtube: &YouTube) -> Result<Batch> {
+        // the batch endpoint of `https://www.googleapis.com/youtube/v3` is `https://www.googleapis.com/batch/youtube/v3`
+        let mut batch_url = Url::parse(&youtube.inner().base_path)
+            .map_err(|_| Error::invalid_parameter("The base path is not a valid URL"))?;
+        batch_url.set_path(&format!("/batch{}", batch_url.path()));
+
+        let state = Arc::new(BatchState::default());
+        let mut batched = youtube.clone();
+        batched.config_mut().transport = Arc::new(BatchTransport {
+            state: state.clone(),
+        });
+        Ok(Batch {
+            youtube: batched,
+            transport: youtube.inner().transport.clone(),
+            batch_url,
+            state,
+            running: tokio::sync::Mutex::new(()),
+        })
+    }
+
+    /// The handle whose requests are collected into the batch.
+    pub fn youtube(&self) -> &YouTube {
+        &self.youtube
+    }
+
+    /// Runs the requests, e.g. the `request()` futures of several `channels().list(...)` builders, and returns their
+    /// results in the same order. A request that fails before it is sent, e.g. with a builder error, does not affect
+    /// the others.
+    pub async fn run<F, T>(&self, requests: impl IntoIterator<Item = F>) -> Vec<Result<T>>
+    where
+        F: Future<Output = Result<T>>,
+    {
+        self.run_all(requests).await
+    }
+
+    /// Starts a run of requests of different operations, see [BatchRequests].
+    pub fn requests(&self) -> BatchRequests<'_> {
+        BatchRequests {
+            batch: self,
+            requests: Vec::new(),
+        }
+    }
+
+    async fn run_all<F: Future>(&self, requests: impl IntoIterator<Item = F>) -> Vec<F::Output> {
+        let _running = self.running.lock().await;
+        self.state.update(|pending| pending.finished = 0);
+
+        let requests = requests
+            .into_iter()
+            .map(|request| async {
+                let result = request.await;
+                self.state.update(|pending| pending.finished += 1);
+                result
+            })
+            .collect::<Vec<_>>();
+        let count = requests.len();
+        let (results, ()) = join(join_all(requests), self.flush(count)).await;
+        results
+    }
+
+    /// Sends the collected requests whenever every request of the run is either waiting for its response or
+    /// completed, until all of them completed. Retries and multi-step requests are sent in later batches.
+    async fn flush(&self, count: usize) {
+        loop {
+            let round = {
+                let mut pending = self.state.pending.lock().unwrap();
+                if pending.finished >= count {
+                    return;
+                }
+                if pending.waiting.is_empty() || pending.waiting.len() + pending.finished < count {
+                    None
+                } else {
+                    Some(mem::take(&mut pending.waiting))
+                }
+            };
+            match round {
+                Some(mut round) => {
+                    while !round.is_empty() {
+                        let rest = round.split_off(round.len().min(MAX_BATCH_SIZE));
+                        self.send(round).await;
+                        round = rest;
+                    }
+                }
+                None => self.state.changed.notified().await,
+            }
+        }
+    }
+
+    /// Sends one batch and hands the parts of the response to the requests.
+    async fn send(&self, batch: Vec<(reqwest::Request, Reply)>) {
+        let contents = batch
+            .iter()
+            .filter_map(|(request, _)| request.body().and_then(|body| body.as_bytes()))
+            .collect::<Vec<&[u8]>>();
+        let boundary = multipart_boundary("batch", &contents);
+        let mut body = Vec::new();
+        let mut replies = Vec::new();
+        for (request, reply) in batch {
+            match encode_part(&mut body, &boundary, replies.len(), &request) {
+                Ok(()) => replies.push(reply),
+                Err(e) => {
+                    let _ = reply.send(Err(e));
+                }
+            }
+        }
+        if replies.is_empty() {
+            return;
+        }
+        body.extend_from_slice(format!("--{}--\r\n", boundary).as_bytes());
+
+        let mut request = reqwest::Request::new(Method::POST, self.batch_url.clone());
+        request.headers_mut().insert(
+            CONTENT_TYPE,
+            format!("multipart/mixed; boundary={}", boundary)
+                .parse()
+                .expect("the boundary is a valid header value"),
+        );
+        *request.body_mut() = Some(body.into());
+
+        let parts = match self.transport.execute(request).await {
+            Ok(response) => decode_response(response, replies.len()).await,
+            Err(e) => Err(e),
+        };
+        match parts {
+            Ok(parts) => {
+                for (reply, part) in replies.into_iter().zip(parts) {
+                    let _ = reply.send(part);
+                }
+            }
+            Err(e) => {
+                let message = e.to_string();
+                for reply in replies {
+                    let _ = reply.send(Err(batch_error(message.clone())));
+                }
+            }
+        }
+    }
+}
+
+/// The requests of one run of a [Batch], which may be of different operations, e.g. a `videos().rate(...)` next to
+/// a `channels().list(...)`:
+///
+/// ```ignore
+/// let mut requests = batch.requests();
+/// let channels = requests.add(channels.request());
+/// let rating = requests.add(rate.request());
+/// requests.run().await;
+/// let channels = channels.into_result()?;
+/// ```
+pub struct BatchRequests<'a> {
+    batch: &'a Batch,
+    requests: Vec<BoxFuture<'a, ()>>,
+}
+
+impl<'a> BatchRequests<'a> {
+    /// Adds a request to the run, e.g. the `request()` future of a builder of [Batch::youtube]. Its result is taken
+    /// from the returned [BatchResult] after [run](BatchRequests::run).
+    pub fn add<F, T>(&mut self, request: F) -> BatchResult<T>
+    where
+        F: Future<Output = Result<T>> + Send + 'a,
+        T: Send + 'a,
+    {
+        let (reply, result) = oneshot::channel();
+        self.requests.push(Box::pin(async move {
+            let _ = reply.send(request.await);
+        }));
+        BatchResult(result)
+    }
+
+    /// Runs the requests, as [Batch::run] does.
+    pub async fn run(self) {
+        self.batch.run_all(self.requests).await;
+    }
+}
+
+/// The result of a request [added](BatchRequests::add) to a run.
+pub struct BatchResult<T>(oneshot::Receiver<Result<T>>);
+
+impl<T> BatchResult<T> {
+    /// The result of the request, or an error when the run was not completed.
+    pub fn into_result(mut self) -> Result<T> {
+        self.0
+            .try_recv()
+            .unwrap_or_else(|_| Err(batch_error("The batch was not run")))
+    }
+}
+
+fn batch_error(message: impl Into<String>) -> Error {
+    Error::new(ErrorKind::ServerError, Some(message.into()), None::<Url>)
+}
+
+/// Appends a request as the `application/http` part `item<index>` of a batch.
+fn encode_part(
+    body: &mut Vec<u8>,
+    boundary: &str,
+    index: usize,
+    request: &reqwest::Request,
+) -> Result<()> {
+    let content = match request.body() {
+        Some(content) => Some(content.as_bytes().ok_or_else(|| {
+            Error::invalid_parameter("Requests with a streamed body can not be batched")
+        })?),
+        None => None,
+    };
+    let url = request.url();
+    let target = match url.query() {
+        Some(query) => format!("{}?{}", url.path(), query),
+        None => url.path().to_string(),
+    };
+
+    let mut part = format!(
+        "--{}\r\nContent-Type: application/http\r\nContent-ID: <item{}>\r\n\r\n{} {} HTTP/1.1\r\n",
+        boundary,
+        index,
+        request.method(),
+        target
+    );
+    for (name, value) in request.headers() {
+        if let Ok(value) = value.to_str() {
+            part.push_str(&format!("{}: {}\r\n", name, value));
+        }
+    }
+    if let Some(content) = content {
+        part.push_str(&format!("Content-Length: {}\r\n", content.len()));
+    }
+    part.push_str("\r\n");
+    body.extend_from_slice(part.as_bytes());
+    if let Some(content) = content {
+        body.extend_from_slice(content);
+    }
+    body.extend_from_slice(b"\r\n");
+    Ok(())
+}
+
+/// Splits the response of a batch into the responses of its `count` requests. When the batch itself failed, every
+/// request gets the response of the batch.
+async fn decode_response(
+    response: reqwest::Response,
+    count: usize,
+) -> Result<Vec<Result<reqwest::Response>>> {
+    let status = response.status().as_u16();
+    let boundary = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|content_type| content_type.to_str().ok())
+        .and_then(|content_type| content_type.split("boundary=").nth(1))
+        .map(|boundary| boundary.trim_matches('"').to_string());
+    let body = response.text().await?;
+
+    let boundary = match boundary {
+        Some(boundary) if status < 300 => boundary,
+        _ => {
+            return Ok((0..count)
+                .map(|_| http_response(status, Vec::new(), body.clone()))
+                .collect());
+        }
+    };
+
+    let mut parts = (0..count).map(|_| None).collect::<Vec<_>>();
+    for part in body.split(&format!("--{}", boundary)).skip(1) {
+        let Some((headers, http)) = part.split_once("\r\n\r\n") else {
+            continue;
+        };
+        let index = headers
+            .lines()
+            .filter_map(|line| line.split_once(':'))
+            .find(|(name, _)| name.trim().eq_ignore_ascii_case("content-id"))
+            .and_then(|(_, id)| {
+                id.trim()
+                    .trim_start_matches("<response-item")
+                    .trim_end_matches('>')
+                    .parse::<usize>()
+                    .ok()
+            });
+        if let Some(slot) = index.and_then(|index| parts.get_mut(index)) {
+            *slot = Some(decode_part(http));
+        }
+    }
+    Ok(parts
+        .into_iter()
+        .enumerate()
+        .map(|(index, part)| {
+            part.unwrap_or_else(|| {
+                Err(batch_error(format!(
+                    "The batch response has no part for request {}",
+                    index
+                )))
+            })
+        })
+        .collect())
+}
+
+/// Parses the HTTP response embedded in a part.
+fn decode_part(http: &str) -> Result<reqwest::Response> {
+    let (head, body) = http.split_once("\r\n\r\n").unwrap_or((http, ""));
+    let mut lines = head.lines();
+    let status = lines
+        .next()
+        .and_then(|status_line| status_line.split_whitespace().nth(1))
+        .and_then(|status| status.parse::<u16>().ok())
+        .ok_or_else(|| batch_error("A part of the batch response has no status line"))?;
+    let headers = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+        .collect();
+    http_response(status, headers, body.trim_end_matches("\r\n").to_string())
+}
+
+fn http_response(
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: String,
+) -> Result<reqwest::Response> {
+    let mut response = http02::Response::builder().status(status);
+    for (name, value) in headers {
+        response = response.header(name, value);
+    }
+    let response = response
+        .body(body)
+        .map_err(|e| batch_error(format!("A part of the batch response is not valid: {}", e)))?;
+    Ok(reqwest::Response::from(response))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::ListApi;
+
+    /// Answers a batch by echoing the request line of every part as `{"items":[{"id":"<method> <target>"}]}`.
+    pub(crate) struct EchoBatchTransport;
+
+    #[async_trait]
+    impl HttpTransport for EchoBatchTransport {
+        async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+            let content_type = request.headers()[CONTENT_TYPE]
+                .to_str()
+                .unwrap()
+                .to_string();
+            let boundary = content_type.split("boundary=").nth(1).unwrap();
+            let body =
+                String::from_utf8(request.body().unwrap().as_bytes().unwrap().to_vec()).unwrap();
+
+            let mut response = String::new();
+            for part in body.split(&format!("--{}", boundary)).skip(1) {
+                let Some((headers, http)) = part.split_once("\r\n\r\n") else {
+                    continue;
+                };
+                let id = headers
+                    .lines()
+                    .find_map(|line| line.strip_prefix("Content-ID: <item"))
+                    .unwrap()
+                    .trim_end_matches('>');
+                let request_line = http.lines().next().unwrap().trim_end_matches(" HTTP/1.1");
+                response.push_str(&format!(
+                    "--response_boundary\r\nContent-Type: application/http\r\nContent-ID: <response-item{}>\r\n\r\nHTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\r\n{{\"items\":[{{\"id\":\"{}\"}}]}}\r\n",
+                    id, request_line
+                ));
+            }
+            response.push_str("--response_boundary--\r\n");
+            let response = http02::Response::builder()
+                .status(200)
+                .header(CONTENT_TYPE, "multipart/mixed; boundary=response_boundary")
+                .body(response)
+                .unwrap();
+            Ok(reqwest::Response::from(response))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_requests_of_different_operations() {
+        let batch = crate::get_youtube_with_transport(EchoBatchTransport)
+            .batch()
+            .unwrap();
+        let mut categories = batch
+            .youtube()
+            .video_categories()
+            .list(vec![crate::video_categories::Part::Snippet]);
+        categories.id(vec!["10"]);
+        let channels = batch.youtube().channels().list(
+            vec![crate::channels::Part::Snippet],
+            crate::channels::ChannelFilter::id(["channel-id"]),
+        );
+        let videos = batch.youtube().videos().list(
+            vec![crate::videos::Part::Id],
+            crate::videos::VideoFilter::id(Vec::<String>::new()),
+        );
+
+        let mut requests = batch.requests();
+        let categories = requests.add(categories.request());
+        let channels = requests.add(channels.request());
+        let videos = requests.add(videos.request());
+        requests.run().await;
+        let categories = categories.into_result().unwrap();
+        assert!(categories.items[0]
+            .id
+            .starts_with("GET /youtube/v3/videoCategories?"));
+        let channels = channels.into_result().unwrap();
+        assert!(channels.items[0]
+            .id
+            .starts_with("GET /youtube/v3/channels?"));
+        assert!(channels.items[0].id.contains("id=channel-id"));
+        assert!(videos.into_result().is_err());
+    }
+
+    #[test]
+    fn test_result_without_run() {
+        let (_, result) = oneshot::channel::<Result<()>>();
+        let err = BatchResult(result).into_result().unwrap_err();
+        assert_eq!(err.to_string(), "server error: The batch was not run");
+    }
+
+    #[test]
+    fn test_encode_part() {
+        let request = reqwest::Client::new()
+            .post("https://www.googleapis.com/youtube/v3/videos/rate?id=video-id&rating=like")
+            .body("{}")
+            .build()
+            .unwrap();
+        let mut body = Vec::new();
+        encode_part(&mut body, "b", 3, &request).unwrap();
+        assert_eq!(
+            String::from_utf8(body).unwrap(),
+            "--b\r\nContent-Type: application/http\r\nContent-ID: <item3>\r\n\r\nPOST /youtube/v3/videos/rate?id=video-id&rating=like HTTP/1.1\r\nContent-Length: 2\r\n\r\n{}\r\n"
+        );
+    }
+
+    #[test]
+    fn test_decode_part() {
+        let response = decode_part(
+            "HTTP/1.1 404 Not Found\r\nContent-Type: application/json\r\n\r\n{\"error\":{}}\r\n",
+        )
+        .unwrap();
+        assert_eq!(response.status(), 404);
+        assert_eq!(response.headers()[CONTENT_TYPE], "application/json");
+    }
+}
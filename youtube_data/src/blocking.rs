@@ -0,0 +1,440 @@
+//! A synchronous [YouTube] handle for scripts and build tools that do not run an async runtime. Enabled with the
+//! `blocking` feature.
+//!
+//! The services of the handle return the request builders of the async api wrapped in a [BlockingRequest], whose
+//! `request` and `collect_all` send the request and wait for its response, e.g.
+//! `youtube.videos().list(vec![Part::Snippet], VideoFilter::id(ids)).request()?`; the parameters are set with the
+//! setters of the async builder. The operations that are not wrapped, e.g. the uploads, are run with
+//! [YouTube::block_on] on the handle of [YouTube::as_async].
+//!
+//! The methods must not be called from within an async runtime.
+
+use crate::{
+    captions::{self, CaptionList, CaptionsService},
+    channel_sections::{self, ChannelSectionList, ChannelSectionsService},
+    channels::{self, ChannelFilter, ChannelList, ChannelListResource, ChannelsService},
+    client::YouTubeBuilder,
+    comments::{self, CommentList, CommentsService},
+    error::{Error, Result},
+    i18n_regions::RegionCode,
+    playlist_items::{self, PlaylistItemList, PlaylistItemsService},
+    playlists::{self, PlaylistList, PlaylistListResource, PlaylistsService},
+    search::{
+        self, ChannelSearchResult, PlaylistSearchResult, SearchList, SearchService,
+        VideoSearchResult,
+    },
+    subscriptions::{self, SubscriptionList, SubscriptionsService},
+    video_categories::{self, VideoCategoriesService, VideoCategoryList},
+    videos::{self, VideoFilter, VideoList, VideoListResource, VideosService},
+    Collected, ListApi, PagedListApi,
+};
+
+use std::future::Future;
+use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+/// A [YouTube](crate::YouTube) handle whose requests are sent synchronously.
+#[derive(Clone)]
+pub struct YouTube {
+    youtube: crate::YouTube,
+    runtime: Arc<Runtime>,
+}
+
+impl YouTube {
+    /// Starts the configuration of a handle that authenticates its requests with `api_key`, see
+    /// [YouTube::builder](crate::YouTube::builder).
+    pub fn builder(api_key: impl Into<String>) -> BlockingYouTubeBuilder {
+        BlockingYouTubeBuilder(crate::YouTube::builder(api_key))
+    }
+
+    /// Wraps an async handle, e.g. one that already carries an access token.
+    pub fn new(youtube: crate::YouTube) -> Result<YouTube> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| {
+                Error::invalid_parameter(format!("The runtime could not be started: {}", e))
+            })?;
+        Ok(YouTube {
+            youtube,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    pub fn captions(&self) -> BlockingService<'_, CaptionsService<'_>> {
+        self.service(self.youtube.captions())
+    }
+
+    pub fn channel_sections(&self) -> BlockingService<'_, ChannelSectionsService<'_>> {
+        self.service(self.youtube.channel_sections())
+    }
+
+    pub fn channels(&self) -> BlockingService<'_, ChannelsService<'_>> {
+        self.service(self.youtube.channels())
+    }
+
+    pub fn comments(&self) -> BlockingService<'_, CommentsService<'_>> {
+        self.service(self.youtube.comments())
+    }
+
+    pub fn playlist_items(&self) -> BlockingService<'_, PlaylistItemsService<'_>> {
+        self.service(self.youtube.playlist_items())
+    }
+
+    pub fn playlists(&self) -> BlockingService<'_, PlaylistsService<'_>> {
+        self.service(self.youtube.playlists())
+    }
+
+    pub fn search(&self) -> BlockingService<'_, SearchService<'_>> {
+        self.service(self.youtube.search())
+    }
+
+    pub fn subscriptions(&self) -> BlockingService<'_, SubscriptionsService<'_>> {
+        self.service(self.youtube.subscriptions())
+    }
+
+    pub fn video_categories(&self) -> BlockingService<'_, VideoCategoriesService<'_>> {
+        self.service(self.youtube.video_categories())
+    }
+
+    pub fn videos(&self) -> BlockingService<'_, VideosService<'_>> {
+        self.service(self.youtube.videos())
+    }
+
+    /// Runs a request, or any other future, to completion and returns its result.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// The async handle, e.g. to build the requests that the blocking services do not wrap for
+    /// [block_on](YouTube::block_on).
+    pub fn as_async(&self) -> &crate::YouTube {
+        &self.youtube
+    }
+
+    /// The async handle, e.g. to pass it to code that is async.
+    pub fn into_async(self) -> crate::YouTube {
+        self.youtube
+    }
+
+    fn service<'a, S>(&'a self, service: S) -> BlockingService<'a, S> {
+        BlockingService {
+            service,
+            runtime: &self.runtime,
+        }
+    }
+}
+
+/// Builds a blocking [YouTube] handle; the setters are the ones of [YouTubeBuilder].
+pub struct BlockingYouTubeBuilder(YouTubeBuilder);
+
+impl BlockingYouTubeBuilder {
+    /// Applies the configuration of the async builder, e.g.
+    /// `.configure(|builder| builder.user_agent("my-tool").retry(RetryPolicy::exponential(3)))`.
+    pub fn configure(self, configure: impl FnOnce(YouTubeBuilder) -> YouTubeBuilder) -> Self {
+        BlockingYouTubeBuilder(configure(self.0))
+    }
+
+    pub fn build(self) -> Result<YouTube> {
+        YouTube::new(self.0.build()?)
+    }
+}
+
+/// A service of the blocking [YouTube] handle, with the methods of the async service `S` that read resources.
+#[derive(Clone, Copy)]
+pub struct BlockingService<'a, S> {
+    service: S,
+    runtime: &'a Runtime,
+}
+
+impl<'a, S> BlockingService<'a, S> {
+    fn request<B>(&self, builder: B) -> BlockingRequest<'a, B> {
+        BlockingRequest {
+            builder,
+            runtime: self.runtime,
+        }
+    }
+}
+
+/// A request builder of the async api whose requests are sent synchronously. It dereferences to the builder for the
+/// setters, e.g. `list.max_results(50);`.
+pub struct BlockingRequest<'a, B> {
+    builder: B,
+    runtime: &'a Runtime,
+}
+
+impl<B> BlockingRequest<'_, B> {
+    /// Sends the request, see [ListApi::request].
+    pub fn request<T>(&self) -> Result<T>
+    where
+        B: ListApi<T>,
+        T: serde::Serialize,
+    {
+        self.runtime.block_on(self.builder.request())
+    }
+
+    /// Requests pages until `max_items` resources are collected, see [PagedListApi::collect_all].
+    pub fn collect_all<T>(&self, max_items: usize) -> Result<Collected<T>>
+    where
+        B: PagedListApi<T> + Sync,
+        T: serde::Serialize + Send + 'static,
+    {
+        self.runtime.block_on(self.builder.collect_all(max_items))
+    }
+
+    /// The async builder, e.g. to pass it to code that is async.
+    pub fn into_async(self) -> B {
+        self.builder
+    }
+}
+
+impl<B> Deref for BlockingRequest<'_, B> {
+    type Target = B;
+
+    fn deref(&self) -> &B {
+        &self.builder
+    }
+}
+
+impl<B> DerefMut for BlockingRequest<'_, B> {
+    fn deref_mut(&mut self) -> &mut B {
+        &mut self.builder
+    }
+}
+
+impl<'a> BlockingService<'a, CaptionsService<'a>> {
+    pub fn list(
+        self,
+        part: impl IntoIterator<Item = captions::Part>,
+        video_id: impl Into<String>,
+    ) -> BlockingRequest<'a, CaptionList<'a>> {
+        self.request(self.service.list(part, video_id))
+    }
+}
+
+impl<'a> BlockingService<'a, ChannelSectionsService<'a>> {
+    pub fn list(
+        self,
+        part: impl IntoIterator<Item = channel_sections::Part>,
+    ) -> BlockingRequest<'a, ChannelSectionList<'a>> {
+        self.request(self.service.list(part))
+    }
+}
+
+impl<'a> BlockingService<'a, ChannelsService<'a>> {
+    pub fn list(
+        self,
+        part: impl IntoIterator<Item = channels::Part>,
+        filter: ChannelFilter,
+    ) -> BlockingRequest<'a, ChannelList<'a>> {
+        self.request(self.service.list(part, filter))
+    }
+
+    /// See [ChannelsService::get].
+    pub fn get(self, id: impl Into<String>) -> Result<Option<ChannelListResource>> {
+        self.runtime.block_on(self.service.get(id))
+    }
+
+    /// See [ChannelsService::by_handle].
+    pub fn by_handle(self, handle: impl Into<String>) -> Result<Option<ChannelListResource>> {
+        self.runtime.block_on(self.service.by_handle(handle))
+    }
+
+    /// See [ChannelsService::resolve].
+    pub fn resolve(self, channel: &str) -> Result<Option<ChannelListResource>> {
+        self.runtime.block_on(self.service.resolve(channel))
+    }
+
+    /// See [ChannelsService::uploads].
+    pub fn uploads(
+        self,
+        channel_id: impl Into<String>,
+    ) -> Result<Option<BlockingRequest<'a, PlaylistItemList<'a>>>> {
+        let uploads = self.runtime.block_on(self.service.uploads(channel_id))?;
+        Ok(uploads.map(|uploads| self.request(uploads)))
+    }
+}
+
+impl<'a> BlockingService<'a, CommentsService<'a>> {
+    pub fn list(
+        self,
+        part: impl IntoIterator<Item = comments::Part>,
+    ) -> BlockingRequest<'a, CommentList<'a>> {
+        self.request(self.service.list(part))
+    }
+}
+
+impl<'a> BlockingService<'a, PlaylistItemsService<'a>> {
+    pub fn list(
+        self,
+        part: impl IntoIterator<Item = playlist_items::Part>,
+    ) -> BlockingRequest<'a, PlaylistItemList<'a>> {
+        self.request(self.service.list(part))
+    }
+}
+
+impl<'a> BlockingService<'a, PlaylistsService<'a>> {
+    pub fn list(
+        self,
+        part: impl IntoIterator<Item = playlists::Part>,
+    ) -> BlockingRequest<'a, PlaylistList<'a>> {
+        self.request(self.service.list(part))
+    }
+
+    /// See [PlaylistsService::get].
+    pub fn get(self, id: impl Into<String>) -> Result<Option<PlaylistListResource>> {
+        self.runtime.block_on(self.service.get(id))
+    }
+}
+
+impl<'a> BlockingService<'a, SearchService<'a>> {
+    pub fn list(
+        self,
+        part: impl IntoIterator<Item = search::Part>,
+    ) -> BlockingRequest<'a, SearchList<'a>> {
+        self.request(self.service.list(part))
+    }
+
+    /// See [SearchService::videos].
+    pub fn videos(
+        self,
+        q: impl Into<String>,
+    ) -> BlockingRequest<'a, SearchList<'a, VideoSearchResult>> {
+        self.request(self.service.videos(q))
+    }
+
+    /// See [SearchService::channels].
+    pub fn channels(
+        self,
+        q: impl Into<String>,
+    ) -> BlockingRequest<'a, SearchList<'a, ChannelSearchResult>> {
+        self.request(self.service.channels(q))
+    }
+
+    /// See [SearchService::playlists].
+    pub fn playlists(
+        self,
+        q: impl Into<String>,
+    ) -> BlockingRequest<'a, SearchList<'a, PlaylistSearchResult>> {
+        self.request(self.service.playlists(q))
+    }
+}
+
+impl<'a> BlockingService<'a, SubscriptionsService<'a>> {
+    pub fn list(
+        self,
+        part: impl IntoIterator<Item = subscriptions::Part>,
+    ) -> BlockingRequest<'a, SubscriptionList<'a>> {
+        self.request(self.service.list(part))
+    }
+}
+
+impl<'a> BlockingService<'a, VideoCategoriesService<'a>> {
+    pub fn list(
+        self,
+        part: impl IntoIterator<Item = video_categories::Part>,
+    ) -> BlockingRequest<'a, VideoCategoryList<'a>> {
+        self.request(self.service.list(part))
+    }
+}
+
+impl<'a> BlockingService<'a, VideosService<'a>> {
+    pub fn list(
+        self,
+        part: impl IntoIterator<Item = videos::Part>,
+        filter: VideoFilter,
+    ) -> BlockingRequest<'a, VideoList<'a>> {
+        self.request(self.service.list(part, filter))
+    }
+
+    /// See [VideosService::get].
+    pub fn get(self, id: impl Into<String>) -> Result<Option<VideoListResource>> {
+        self.runtime.block_on(self.service.get(id))
+    }
+
+    /// See [VideosService::most_popular].
+    pub fn most_popular(
+        self,
+        region_code: RegionCode,
+        video_category_id: Option<String>,
+    ) -> BlockingRequest<'a, VideoList<'a>> {
+        self.request(self.service.most_popular(region_code, video_category_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_youtube_with_transport;
+    use crate::transport::MockTransport;
+
+    fn get_blocking_youtube(transport: Arc<MockTransport>) -> YouTube {
+        YouTube::new(get_youtube_with_transport(transport)).unwrap()
+    }
+
+    #[test]
+    fn test_list_request() {
+        let transport = Arc::new(MockTransport::new().respond(
+            reqwest::Method::GET,
+            "videos",
+            200,
+            r#"{"items": [{"id": "video-id", "snippet": {"title": "A video"}}]}"#,
+        ));
+        let youtube = get_blocking_youtube(transport.clone());
+        let mut list = youtube
+            .videos()
+            .list(vec![videos::Part::Snippet], VideoFilter::id(["video-id"]));
+        list.hl("ko");
+        let response = list.request().unwrap();
+        assert_eq!(response.items[0].id, "video-id");
+        assert_eq!(response.items[0].snippet.as_ref().unwrap().title, "A video");
+        let query = transport.requests()[0].1.query().unwrap().to_string();
+        assert!(query.contains("id=video-id"));
+        assert!(query.contains("hl=ko"));
+    }
+
+    #[test]
+    fn test_get() {
+        let transport = Arc::new(MockTransport::new().respond(
+            reqwest::Method::GET,
+            "channels",
+            200,
+            r#"{"items": [{"id": "channel-id", "snippet": {"title": "A channel"}}]}"#,
+        ));
+        let youtube = get_blocking_youtube(transport);
+        let channel = youtube.channels().get("channel-id").unwrap().unwrap();
+        assert_eq!(channel.id, "channel-id");
+        assert_eq!(channel.snippet.unwrap().title, "A channel");
+    }
+
+    #[test]
+    fn test_collect_all() {
+        let transport = Arc::new(MockTransport::new().respond(
+            reqwest::Method::GET,
+            "playlistItems",
+            200,
+            r#"{"items": [{"id": "item-0"}, {"id": "item-1"}]}"#,
+        ));
+        let youtube = get_blocking_youtube(transport.clone());
+        let mut list = youtube
+            .playlist_items()
+            .list(vec![playlist_items::Part::Id]);
+        list.playlist_id("playlist-id");
+        let collected = list.collect_all(10).unwrap();
+        assert_eq!(
+            collected
+                .items
+                .iter()
+                .map(|item| item.id.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["item-0", "item-1"]
+        );
+        assert_eq!(collected.requests, 1);
+        assert!(transport.requests()[0]
+            .1
+            .query()
+            .unwrap()
+            .contains("playlistId=playlist-id"));
+    }
+}
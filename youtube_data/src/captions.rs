@@ -0,0 +1,970 @@
+use crate::{
+    auth::{Authorizer, OAuthTokenAuth},
+    error::{Error, Result},
+    json, list_json, multipart_related_body, ListApi, RequestBase, YouTube, YouTubeDataApi,
+};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use youtube_data_derive::QueryBuilder;
+
+#[derive(Clone, Copy)]
+pub struct CaptionsService<'a> {
+    youtube: &'a YouTube,
+}
+
+impl<'a> CaptionsService<'a> {
+    pub(crate) fn new(youtube: &'a YouTube) -> Self {
+        Self { youtube }
+    }
+
+    pub fn list(
+        self,
+        part: impl IntoIterator<Item = Part>,
+        video_id: impl Into<String>,
+    ) -> CaptionList<'a> {
+        CaptionList::new(self, part, video_id)
+    }
+
+    pub fn download(self, id: impl Into<String>) -> CaptionDownload<'a> {
+        CaptionDownload::new(self, id)
+    }
+
+    pub fn insert(
+        self,
+        video_id: impl Into<String>,
+        language: impl Into<String>,
+        name: impl Into<String>,
+        track: Vec<u8>,
+    ) -> CaptionInsert<'a> {
+        CaptionInsert::new(self, video_id, language, name, track)
+    }
+
+    pub fn update(self, id: impl Into<String>) -> CaptionUpdate<'a> {
+        CaptionUpdate::new(self, id)
+    }
+
+    pub fn delete(self, id: impl Into<String>) -> CaptionDelete<'a> {
+        CaptionDelete::new(self, id)
+    }
+}
+
+/// Parameters for the `list` method of the `captions` api. details:
+/// [link](https://developers.google.com/youtube/v3/docs/captions/list)
+#[derive(QueryBuilder)]
+pub struct CaptionList<'a> {
+    service: CaptionsService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    #[query(unique)]
+    part: Vec<Part>,
+    video_id: String,
+
+    // optional parameters
+    /// Selects the fields of the response, e.g. `items(id,snippet/title)`, so only the needed part of every
+    /// resource is returned. details: [link](https://developers.google.com/youtube/v3/getting-started#fields)
+    #[query]
+    fields: Option<String>,
+    /// Fails the request with a timeout error when it does not complete in time, instead of the timeout of the
+    /// `YouTube` handle.
+    #[setter]
+    timeout: Option<Duration>,
+    #[query]
+    id: Option<Vec<String>>,
+    #[setter]
+    on_behalf_of_content_owner: Option<String>,
+}
+
+impl RequestBase for CaptionList<'_> {
+    fn api_path(&self) -> &str {
+        "captions"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for CaptionList<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+
+    fn request_timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl ListApi<CaptionListResponse> for CaptionList<'_> {
+    async fn request(&self) -> Result<CaptionListResponse> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        if self.video_id.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required parameter `video_id` is empty",
+            ));
+        }
+        self.insert_query_parameter(&mut params, "videoId", Some(&self.video_id));
+        self.insert_query(youtube, &mut params)?;
+        self.insert_content_owner_parameters(
+            youtube,
+            &mut params,
+            self.on_behalf_of_content_owner.as_deref(),
+            None,
+        )?;
+
+        let request = self.optional_bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .get(self.url(&youtube.inner().base_path))
+                .query(&params),
+        )?;
+        let response = self.send(youtube, request).await?;
+        list_json(response).await
+    }
+}
+
+impl<'a> CaptionList<'a> {
+    pub(crate) fn new(
+        service: CaptionsService<'a>,
+        part: impl IntoIterator<Item = Part>,
+        video_id: impl Into<String>,
+    ) -> Self {
+        let part = crate::dedupe(part);
+        let part = if part.is_empty() {
+            vec![Part::Snippet]
+        } else {
+            part
+        };
+        Self {
+            service,
+            authorizer: None,
+            part,
+            video_id: video_id.into(),
+            fields: None,
+            timeout: None,
+            id: None,
+            on_behalf_of_content_owner: None,
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+}
+
+/// Parameters for the `download` method of the `captions` api. details:
+/// [link](https://developers.google.com/youtube/v3/docs/captions/download)
+pub struct CaptionDownload<'a> {
+    service: CaptionsService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    id: String,
+
+    // optional parameters
+    tfmt: Option<CaptionFormat>,
+    tlang: Option<String>,
+
+    /// The caption track id is part of the api path (`captions/{id}`).
+    api_path: String,
+}
+
+impl RequestBase for CaptionDownload<'_> {
+    fn api_path(&self) -> &str {
+        &self.api_path
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for CaptionDownload<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> CaptionDownload<'a> {
+    pub(crate) fn new(service: CaptionsService<'a>, id: impl Into<String>) -> Self {
+        let id: String = id.into();
+        Self {
+            service,
+            authorizer: None,
+            api_path: format!("captions/{}", id),
+            id,
+            tfmt: None,
+            tlang: None,
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+
+    pub fn tfmt(&mut self, tfmt: CaptionFormat) -> &mut Self {
+        self.tfmt = Some(tfmt);
+        self
+    }
+
+    pub fn tlang(&mut self, tlang: impl Into<String>) -> &mut Self {
+        self.tlang = Some(tlang.into());
+        self
+    }
+
+    /// Downloads the caption track. The track is returned in its original format unless `tfmt` is specified,
+    /// and in its original language unless `tlang` is specified.
+    pub async fn request(&self) -> Result<String> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        // required parameters
+        if self.id.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required parameter `id` is empty",
+            ));
+        }
+
+        // optional parameters
+        self.insert_query_parameter(&mut params, "tfmt", self.tfmt.as_ref());
+        self.insert_query_parameter(&mut params, "tlang", self.tlang.as_deref());
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .get(self.url(&youtube.inner().base_path))
+                .query(&params),
+        )?;
+        let response = self.send(youtube, request).await?;
+        Ok(response.text().await?)
+    }
+}
+
+/// Parameters for the `insert` method of the `captions` api. details:
+/// [link](https://developers.google.com/youtube/v3/docs/captions/insert)
+pub struct CaptionInsert<'a> {
+    service: CaptionsService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // optional parameters
+    on_behalf_of_content_owner: Option<String>,
+    sync: Option<bool>,
+
+    // request body
+    video_id: String,
+    language: String,
+    name: String,
+    is_draft: Option<bool>,
+
+    // media
+    track: Vec<u8>,
+}
+
+impl RequestBase for CaptionInsert<'_> {
+    fn api_path(&self) -> &str {
+        "captions"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for CaptionInsert<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> CaptionInsert<'a> {
+    pub(crate) fn new(
+        service: CaptionsService<'a>,
+        video_id: impl Into<String>,
+        language: impl Into<String>,
+        name: impl Into<String>,
+        track: Vec<u8>,
+    ) -> Self {
+        Self {
+            service,
+            authorizer: None,
+            on_behalf_of_content_owner: None,
+            sync: None,
+            video_id: video_id.into(),
+            language: language.into(),
+            name: name.into(),
+            is_draft: None,
+            track,
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+
+    pub fn on_behalf_of_content_owner(
+        &mut self,
+        on_behalf_of_content_owner: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+        self
+    }
+
+    /// Indicates whether YouTube should automatically synchronize the caption file with the audio track of the video.
+    /// Set it to `true` to upload a transcript without timing information.
+    pub fn sync(&mut self, sync: bool) -> &mut Self {
+        self.sync = Some(sync);
+        self
+    }
+
+    pub fn is_draft(&mut self, is_draft: bool) -> &mut Self {
+        self.is_draft = Some(is_draft);
+        self
+    }
+
+    /// Uploads a caption track and returns the created caption resource.
+    pub async fn request(&self) -> Result<CaptionListResource> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        self.insert_query_parameter(&mut params, "uploadType", Some("multipart"));
+
+        // required parameters
+        self.insert_query_parameter(&mut params, "part", Some(Part::Snippet));
+
+        // optional parameters
+        self.insert_content_owner_parameters(
+            youtube,
+            &mut params,
+            self.on_behalf_of_content_owner.as_deref(),
+            None,
+        )?;
+        self.insert_query_parameter(&mut params, "sync", self.sync);
+
+        // request body
+        for (name, value) in [
+            ("snippet.video_id", &self.video_id),
+            ("snippet.language", &self.language),
+            ("snippet.name", &self.name),
+        ] {
+            if value.is_empty() {
+                return Err(Error::missing_required_parameter(format!(
+                    "Required property `{}` is empty",
+                    name
+                )));
+            }
+        }
+        if self.track.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required media body `track` is empty",
+            ));
+        }
+        let metadata = CaptionBody {
+            id: None,
+            snippet: CaptionBodySnippet {
+                video_id: Some(&self.video_id),
+                language: Some(&self.language),
+                name: Some(&self.name),
+                is_draft: self.is_draft,
+            },
+        };
+        let (content_type, body) =
+            multipart_related_body(&metadata, &self.track, "application/octet-stream")?;
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .post(self.url(&youtube.inner().upload_base_path))
+                .header(reqwest::header::CONTENT_TYPE, content_type)
+                .query(&params)
+                .body(body),
+        )?;
+        let response = self.send(youtube, request).await?;
+        json(response).await
+    }
+}
+
+/// Parameters for the `update` method of the `captions` api. details:
+/// [link](https://developers.google.com/youtube/v3/docs/captions/update)
+pub struct CaptionUpdate<'a> {
+    service: CaptionsService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // optional parameters
+    on_behalf_of_content_owner: Option<String>,
+    sync: Option<bool>,
+
+    // request body
+    id: String,
+    is_draft: Option<bool>,
+
+    // media
+    track: Option<Vec<u8>>,
+}
+
+impl RequestBase for CaptionUpdate<'_> {
+    fn api_path(&self) -> &str {
+        "captions"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for CaptionUpdate<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> CaptionUpdate<'a> {
+    pub(crate) fn new(service: CaptionsService<'a>, id: impl Into<String>) -> Self {
+        Self {
+            service,
+            authorizer: None,
+            on_behalf_of_content_owner: None,
+            sync: None,
+            id: id.into(),
+            is_draft: None,
+            track: None,
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+
+    pub fn on_behalf_of_content_owner(
+        &mut self,
+        on_behalf_of_content_owner: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+        self
+    }
+
+    /// Indicates whether YouTube should automatically synchronize the new caption file with the audio track of the
+    /// video. Only used together with `track`.
+    pub fn sync(&mut self, sync: bool) -> &mut Self {
+        self.sync = Some(sync);
+        self
+    }
+
+    pub fn is_draft(&mut self, is_draft: bool) -> &mut Self {
+        self.is_draft = Some(is_draft);
+        self
+    }
+
+    /// Replaces the content of the caption track.
+    pub fn track(&mut self, track: Vec<u8>) -> &mut Self {
+        self.track = Some(track);
+        self
+    }
+
+    /// Updates the draft status of a caption track and, if `track` is set, replaces its content. Returns the updated
+    /// caption resource.
+    pub async fn request(&self) -> Result<CaptionListResource> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        // required parameters
+        self.insert_query_parameter(&mut params, "part", Some(Part::Snippet));
+
+        // optional parameters
+        self.insert_content_owner_parameters(
+            youtube,
+            &mut params,
+            self.on_behalf_of_content_owner.as_deref(),
+            None,
+        )?;
+        if self.sync.is_some() && self.track.is_none() {
+            return Err(Error::incompatible_parameters(
+                "The `sync` parameter can only be used when `track` is specified",
+            ));
+        }
+        self.insert_query_parameter(&mut params, "sync", self.sync);
+
+        // request body
+        if self.id.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required property `id` is empty",
+            ));
+        }
+        let metadata = CaptionBody {
+            id: Some(&self.id),
+            snippet: CaptionBodySnippet {
+                video_id: None,
+                language: None,
+                name: None,
+                is_draft: self.is_draft,
+            },
+        };
+
+        let request = match &self.track {
+            Some(track) => {
+                if track.is_empty() {
+                    return Err(Error::missing_required_parameter(
+                        "Required media body `track` is empty",
+                    ));
+                }
+                self.insert_query_parameter(&mut params, "uploadType", Some("multipart"));
+                let (content_type, body) =
+                    multipart_related_body(&metadata, track, "application/octet-stream")?;
+                youtube
+                    .inner()
+                    .client
+                    .put(self.url(&youtube.inner().upload_base_path))
+                    .header(reqwest::header::CONTENT_TYPE, content_type)
+                    .query(&params)
+                    .body(body)
+            }
+            None => youtube
+                .inner()
+                .client
+                .put(self.url(&youtube.inner().base_path))
+                .query(&params)
+                .json(&metadata),
+        };
+        let request = self.bearer_auth(youtube, request)?;
+        let response = self.send(youtube, request).await?;
+        json(response).await
+    }
+}
+
+/// Parameters for the `delete` method of the `captions` api. details:
+/// [link](https://developers.google.com/youtube/v3/docs/captions/delete)
+pub struct CaptionDelete<'a> {
+    service: CaptionsService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    id: String,
+
+    // optional parameters
+    on_behalf_of_content_owner: Option<String>,
+}
+
+impl RequestBase for CaptionDelete<'_> {
+    fn api_path(&self) -> &str {
+        "captions"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for CaptionDelete<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> CaptionDelete<'a> {
+    pub(crate) fn new(service: CaptionsService<'a>, id: impl Into<String>) -> Self {
+        Self {
+            service,
+            authorizer: None,
+            id: id.into(),
+            on_behalf_of_content_owner: None,
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+
+    pub fn on_behalf_of_content_owner(
+        &mut self,
+        on_behalf_of_content_owner: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+        self
+    }
+
+    /// Deletes a caption track. The api responds with an empty body on success.
+    pub async fn request(&self) -> Result<()> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        // required parameters
+        if self.id.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required parameter `id` is empty",
+            ));
+        }
+        self.insert_query_parameter(&mut params, "id", Some(&self.id));
+
+        // optional parameters
+        self.insert_content_owner_parameters(
+            youtube,
+            &mut params,
+            self.on_behalf_of_content_owner.as_deref(),
+            None,
+        )?;
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .delete(self.url(&youtube.inner().base_path))
+                .query(&params),
+        )?;
+        self.send_without_content(youtube, request).await
+    }
+}
+
+/// The request body of `captions.insert` and `captions.update`.
+#[derive(Serialize)]
+struct CaptionBody<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<&'a str>,
+
+    snippet: CaptionBodySnippet<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CaptionBodySnippet<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    video_id: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_draft: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    Id,
+    Snippet,
+}
+
+impl Part {
+    /// Every part of the resource, to request all of its properties.
+    pub fn all_public() -> Vec<Part> {
+        vec![Part::Id, Part::Snippet]
+    }
+}
+
+impl std::fmt::Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            Part::Id => "id",
+            Part::Snippet => "snippet",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+/// The tfmt parameter specifies that the caption track should be returned in a specific format.
+pub enum CaptionFormat {
+    /// SubViewer subtitle.
+    Sbv,
+
+    /// Scenarist Closed Caption format.
+    Scc,
+
+    /// SubRip subtitle.
+    Srt,
+
+    /// Timed Text Markup Language caption.
+    Ttml,
+
+    /// Web Video Text Tracks caption.
+    Vtt,
+}
+
+impl std::fmt::Display for CaptionFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            CaptionFormat::Sbv => "sbv",
+            CaptionFormat::Scc => "scc",
+            CaptionFormat::Srt => "srt",
+            CaptionFormat::Ttml => "ttml",
+            CaptionFormat::Vtt => "vtt",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+/// The `captions.list` response is not paginated, so it does not share the [ListResponse](crate::ListResponse)
+/// layout.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CaptionListResponse {
+    /// Identifies the API resource's type. The value will be `youtube#captionListResponse`.
+    #[serde(default)]
+    pub kind: String,
+
+    /// The ETag of the response.
+    #[serde(default)]
+    pub etag: String,
+
+    /// A list of captions that match the request criteria.
+    #[serde(default)]
+    pub items: Vec<CaptionListResource>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CaptionListResource {
+    /// Identifies the API resource's type. The value will be `youtube#caption`.
+    #[serde(default)]
+    pub kind: String,
+
+    /// The ETag of the response.
+    #[serde(default)]
+    pub etag: String,
+
+    /// The ID that YouTube uses to uniquely identify the caption track.
+    #[serde(default)]
+    pub id: String,
+
+    pub snippet: Option<CaptionSnippet>,
+
+    /// The fields of the resource that this version of the crate does not know, e.g. added to the api after its
+    /// release. They are serialized back with the resource.
+    #[serde(flatten, deserialize_with = "crate::serde_helpers::extra_fields")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// The snippet object contains basic details about the caption.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptionSnippet {
+    /// The ID that YouTube uses to uniquely identify the video associated with the caption track.
+    #[serde(default)]
+    pub video_id: String,
+
+    /// The date and time when the caption track was last updated. The value is specified in
+    /// [ISO 8601](https://www.w3.org/TR/NOTE-datetime) format.
+    pub last_updated: Option<DateTime<Utc>>,
+
+    /// The caption track's type.
+    ///
+    /// Valid values for this property are:
+    ///
+    /// * asr – A caption track generated using automatic speech recognition.
+    /// * forced – A caption track that plays when no other track is selected in the player.
+    /// * standard – A regular caption track. This is the default value.
+    #[serde(default)]
+    pub track_kind: String,
+
+    /// The language of the caption track. The property value is a
+    /// [BCP-47](https://www.rfc-editor.org/rfc/bcp/bcp47.txt) language tag.
+    #[serde(default)]
+    pub language: String,
+
+    /// The name of the caption track. The name is intended to be visible to the user as an option during playback.
+    #[serde(default)]
+    pub name: String,
+
+    /// The type of audio track associated with the caption track.
+    ///
+    /// Valid values for this property are:
+    ///
+    /// * commentary
+    /// * descriptive
+    /// * primary
+    /// * unknown
+    pub audio_track_type: Option<String>,
+
+    /// Indicates whether the track contains closed captions for the deaf and hard of hearing.
+    #[serde(rename = "isCC")]
+    pub is_cc: Option<bool>,
+
+    /// Indicates whether the caption track uses large text for the vision-impaired.
+    pub is_large: Option<bool>,
+
+    /// Indicates whether caption track is formatted for "easy reader," meaning it is at a third-grade level
+    /// for language learners.
+    pub is_easy_reader: Option<bool>,
+
+    /// Indicates whether the caption track is a draft. If the value is true, then the track is not publicly visible.
+    pub is_draft: Option<bool>,
+
+    /// Indicates whether YouTube synchronized the caption track to the audio track in the video.
+    pub is_auto_synced: Option<bool>,
+
+    /// The caption track's status.
+    ///
+    /// Valid values for this property are:
+    ///
+    /// * failed
+    /// * serving
+    /// * syncing
+    #[serde(default)]
+    pub status: String,
+
+    /// The reason that YouTube failed to process the caption track. This property is only present if the state
+    /// property's value is failed.
+    ///
+    /// Valid values for this property are:
+    ///
+    /// * processingFailed
+    /// * unknownFormat
+    /// * unsupportedFormat
+    pub failure_reason: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::MockTransport;
+    use crate::{get_youtube_with_transport, get_youtube_without_user_agent};
+
+    #[tokio::test]
+    async fn test_get_by_video_id() {
+        let transport = Arc::new(MockTransport::new().respond(
+            reqwest::Method::GET,
+            "captions",
+            200,
+            r#"{
+                "kind": "youtube#captionListResponse",
+                "items": [{
+                    "kind": "youtube#caption",
+                    "id": "AUieDaZJxYug0L5YNAw_31GbXz73b0CPXCDFlsPNSNe7KQvuv1g",
+                    "snippet": {
+                        "videoId": "Ks-_Mh1QhMc",
+                        "lastUpdated": "2012-10-01T20:35:08.537Z",
+                        "trackKind": "standard",
+                        "language": "en",
+                        "name": "English"
+                    }
+                }]
+            }"#,
+        ));
+        let response = get_youtube_with_transport(transport.clone())
+            .captions()
+            .list(vec![Part::Snippet], "Ks-_Mh1QhMc")
+            .request()
+            .await
+            .unwrap();
+        assert_eq!(response.items.len(), 1);
+        let snippet = response.items[0].snippet.as_ref().unwrap();
+        assert_eq!(snippet.video_id, "Ks-_Mh1QhMc");
+        assert_eq!(snippet.track_kind, "standard");
+        assert_eq!(snippet.language, "en");
+        assert_eq!(snippet.name, "English");
+        assert!(snippet.last_updated.is_some());
+        let query = transport.requests()[0].1.query().unwrap().to_string();
+        assert!(query.contains("part=snippet"));
+        assert!(query.contains("videoId=Ks-_Mh1QhMc"));
+    }
+
+    #[tokio::test]
+    async fn test_request_with_empty_video_id() {
+        let empty_video_id = get_youtube_without_user_agent()
+            .captions()
+            .list(vec![], "")
+            .request()
+            .await;
+        assert!(empty_video_id.is_err());
+        let err = empty_video_id.unwrap_err();
+        assert_eq!(
+            "builder error: \"Required parameter `video_id` is empty\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_download_without_auth() {
+        let without_auth = get_youtube_without_user_agent()
+            .captions()
+            .download("AUieDaZJxYug0L5YNAw_31GbXz73b0CPXCDFlsPNSNe7KQvuv1g")
+            .tfmt(CaptionFormat::Srt)
+            .request()
+            .await;
+        assert!(without_auth.is_err());
+        let err = without_auth.unwrap_err();
+        assert_eq!(
+            "builder error: \"The request to `captions/AUieDaZJxYug0L5YNAw_31GbXz73b0CPXCDFlsPNSNe7KQvuv1g` requires OAuth 2.0 authorization\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_insert_without_auth() {
+        let without_auth = get_youtube_without_user_agent()
+            .captions()
+            .insert(
+                "Ks-_Mh1QhMc",
+                "en",
+                "English",
+                b"1\n00:00:00,000 --> 00:00:01,000\nHello\n".to_vec(),
+            )
+            .request()
+            .await;
+        assert!(without_auth.is_err());
+        let err = without_auth.unwrap_err();
+        assert_eq!(
+            "builder error: \"The request to `captions` requires OAuth 2.0 authorization\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_sync_without_track() {
+        let sync_without_track = get_youtube_without_user_agent()
+            .captions()
+            .update("AUieDaZJxYug0L5YNAw_31GbXz73b0CPXCDFlsPNSNe7KQvuv1g")
+            .sync(true)
+            .request()
+            .await;
+        assert!(sync_without_track.is_err());
+        let err = sync_without_track.unwrap_err();
+        assert_eq!(
+            "builder error: \"The `sync` parameter can only be used when `track` is specified\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_without_auth() {
+        let without_auth = get_youtube_without_user_agent()
+            .captions()
+            .delete("AUieDaZJxYug0L5YNAw_31GbXz73b0CPXCDFlsPNSNe7KQvuv1g")
+            .request()
+            .await;
+        assert!(without_auth.is_err());
+        let err = without_auth.unwrap_err();
+        assert_eq!(
+            "builder error: \"The request to `captions` requires OAuth 2.0 authorization\"",
+            format!("{}", err)
+        );
+    }
+}
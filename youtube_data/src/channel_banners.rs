@@ -0,0 +1,330 @@
+use crate::{
+    auth::{Authorizer, OAuthTokenAuth},
+    channels::{ChannelListResource, ChannelsService},
+    error::{Error, Result},
+    json, media_body, ImageMimeType, RequestBase, UploadProgress, YouTube, YouTubeDataApi,
+};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// The maximum file size of a channel banner image (6MB).
+const MAX_BANNER_SIZE: usize = 6 * 1024 * 1024;
+
+#[derive(Clone, Copy)]
+pub struct ChannelBannersService<'a> {
+    youtube: &'a YouTube,
+}
+
+impl<'a> ChannelBannersService<'a> {
+    pub(crate) fn new(youtube: &'a YouTube) -> Self {
+        Self { youtube }
+    }
+
+    pub fn insert(self, image: Vec<u8>, mime_type: ImageMimeType) -> ChannelBannerInsert<'a> {
+        ChannelBannerInsert::new(self, image, mime_type)
+    }
+
+    /// Uploads a banner image and applies it to the channel, see [ChannelBannerSet].
+    pub fn set(
+        self,
+        channel_id: impl Into<String>,
+        image: Vec<u8>,
+        mime_type: ImageMimeType,
+    ) -> ChannelBannerSet<'a> {
+        ChannelBannerSet::new(self, channel_id, image, mime_type)
+    }
+}
+
+/// Parameters for the `insert` method of the `channelBanners` api. details:
+/// [link](https://developers.google.com/youtube/v3/docs/channelBanners/insert)
+pub struct ChannelBannerInsert<'a> {
+    service: ChannelBannersService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // optional parameters
+    on_behalf_of_content_owner: Option<String>,
+
+    // media
+    image: Vec<u8>,
+    mime_type: ImageMimeType,
+    on_progress: Option<UploadProgress>,
+}
+
+impl RequestBase for ChannelBannerInsert<'_> {
+    fn api_path(&self) -> &str {
+        "channelBanners/insert"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for ChannelBannerInsert<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> ChannelBannerInsert<'a> {
+    pub(crate) fn new(
+        service: ChannelBannersService<'a>,
+        image: Vec<u8>,
+        mime_type: ImageMimeType,
+    ) -> Self {
+        Self {
+            service,
+            authorizer: None,
+            on_behalf_of_content_owner: None,
+            image,
+            mime_type,
+            on_progress: None,
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+
+    pub fn on_behalf_of_content_owner(
+        &mut self,
+        on_behalf_of_content_owner: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+        self
+    }
+
+    /// Reports the number of bytes sent and the size of the image while the image is uploaded.
+    pub fn on_progress(
+        &mut self,
+        on_progress: impl Fn(u64, u64) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.on_progress = Some(Arc::new(on_progress));
+        self
+    }
+
+    /// Uploads a channel banner image to YouTube. The returned url is used to set the banner of a channel with
+    /// `channels().update_banner`.
+    pub async fn request(&self) -> Result<ChannelBannerResource> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        self.insert_query_parameter(&mut params, "uploadType", Some("media"));
+
+        // optional parameters
+        self.insert_content_owner_parameters(
+            youtube,
+            &mut params,
+            self.on_behalf_of_content_owner.as_deref(),
+            None,
+        )?;
+
+        // media
+        if self.image.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required media body `image` is empty",
+            ));
+        }
+        if self.image.len() > MAX_BANNER_SIZE {
+            return Err(Error::invalid_parameter(
+                "The banner image exceeds the maximum file size of 6MB",
+            ));
+        }
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .post(self.url(&youtube.inner().upload_base_path))
+                .header(reqwest::header::CONTENT_TYPE, self.mime_type.to_string())
+                .header(reqwest::header::CONTENT_LENGTH, self.image.len())
+                .query(&params)
+                .body(media_body(self.image.clone(), self.on_progress.clone())),
+        )?;
+        let response = self.send(youtube, request).await?;
+        json(response).await
+    }
+}
+
+/// Uploads a banner image with `channelBanners.insert` and applies the returned url to the channel with
+/// `channels.update`, reporting each step through [ChannelBannerSet::on_progress].
+pub struct ChannelBannerSet<'a> {
+    service: ChannelBannersService<'a>,
+    access_token: Option<String>,
+
+    // required parameters
+    channel_id: String,
+
+    // optional parameters
+    on_behalf_of_content_owner: Option<String>,
+
+    // media
+    image: Vec<u8>,
+    mime_type: ImageMimeType,
+    on_progress: Option<Arc<dyn Fn(ChannelBannerProgress) + Send + Sync>>,
+}
+
+impl<'a> ChannelBannerSet<'a> {
+    pub(crate) fn new(
+        service: ChannelBannersService<'a>,
+        channel_id: impl Into<String>,
+        image: Vec<u8>,
+        mime_type: ImageMimeType,
+    ) -> Self {
+        Self {
+            service,
+            access_token: None,
+            channel_id: channel_id.into(),
+            on_behalf_of_content_owner: None,
+            image,
+            mime_type,
+            on_progress: None,
+        }
+    }
+
+    /// Runs both steps with the access token of another user instead of the credentials of the `YouTube` handle.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.access_token = Some(access_token.into());
+        self
+    }
+
+    pub fn on_behalf_of_content_owner(
+        &mut self,
+        on_behalf_of_content_owner: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+        self
+    }
+
+    pub fn on_progress(
+        &mut self,
+        on_progress: impl Fn(ChannelBannerProgress) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.on_progress = Some(Arc::new(on_progress));
+        self
+    }
+
+    /// Runs both steps and returns the updated channel.
+    pub async fn request(&self) -> Result<ChannelListResource> {
+        if self.channel_id.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required parameter `channel_id` is empty",
+            ));
+        }
+
+        // upload
+        let mut insert = ChannelBannerInsert {
+            service: self.service,
+            authorizer: None,
+            on_behalf_of_content_owner: self.on_behalf_of_content_owner.clone(),
+            image: self.image.clone(),
+            mime_type: self.mime_type,
+            on_progress: None,
+        };
+        if let Some(access_token) = &self.access_token {
+            insert.as_user(access_token.clone());
+        }
+        if let Some(on_progress) = self.on_progress.clone() {
+            insert.on_progress(move |sent, total| {
+                on_progress(ChannelBannerProgress::Uploading { sent, total })
+            });
+        }
+        let banner = insert.request().await?;
+        self.report(ChannelBannerProgress::Uploaded {
+            url: banner.url.clone(),
+        });
+
+        // apply
+        let channels = ChannelsService::new(self.service.youtube);
+        let mut update = channels.update_banner(self.channel_id.as_str(), banner.url.as_str());
+        if let Some(on_behalf_of_content_owner) = &self.on_behalf_of_content_owner {
+            update.on_behalf_of_content_owner(on_behalf_of_content_owner);
+        }
+        if let Some(access_token) = &self.access_token {
+            update.as_user(access_token.clone());
+        }
+        let channel = update.request().await?;
+        self.report(ChannelBannerProgress::Applied);
+
+        Ok(channel)
+    }
+
+    fn report(&self, progress: ChannelBannerProgress) {
+        if let Some(on_progress) = &self.on_progress {
+            on_progress(progress);
+        }
+    }
+}
+
+/// The steps of [ChannelBannerSet], in the order they are reported.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChannelBannerProgress {
+    /// The banner image is being uploaded; `sent` out of `total` bytes have been sent.
+    Uploading { sent: u64, total: u64 },
+
+    /// The banner image was uploaded to `url`.
+    Uploaded { url: String },
+
+    /// The banner was applied to the channel.
+    Applied,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChannelBannerResource {
+    /// Identifies the API resource's type. The value will be `youtube#channelBannerResource`.
+    #[serde(default)]
+    pub kind: String,
+
+    /// The ETag of the response.
+    #[serde(default)]
+    pub etag: String,
+
+    /// The banner image's URL. After calling the `channelBanners.insert` method, extract this value from the API
+    /// response and set it as the value of the channel's `brandingSettings.image.bannerExternalUrl` property.
+    #[serde(default)]
+    pub url: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_youtube_without_user_agent;
+
+    #[tokio::test]
+    async fn test_insert_without_auth() {
+        let without_auth = get_youtube_without_user_agent()
+            .channel_banners()
+            .insert(vec![0x89, 0x50, 0x4e, 0x47], ImageMimeType::Png)
+            .request()
+            .await;
+        assert!(without_auth.is_err());
+        let err = without_auth.unwrap_err();
+        assert_eq!(
+            "builder error: \"The request to `channelBanners/insert` requires OAuth 2.0 authorization\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_with_empty_channel_id() {
+        let empty_channel_id = get_youtube_without_user_agent()
+            .channel_banners()
+            .set("", vec![0x89, 0x50, 0x4e, 0x47], ImageMimeType::Png)
+            .on_progress(|_| panic!("nothing should be uploaded"))
+            .request()
+            .await;
+        assert!(empty_channel_id.is_err());
+        let err = empty_channel_id.unwrap_err();
+        assert_eq!(
+            "builder error: \"Required parameter `channel_id` is empty\"",
+            format!("{}", err)
+        );
+    }
+}
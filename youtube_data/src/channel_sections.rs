@@ -0,0 +1,898 @@
+use crate::{
+    auth::{Authorizer, OAuthTokenAuth},
+    error::{Error, Result},
+    json, list_json, ListApi, RequestBase, YouTube, YouTubeDataApi,
+};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use youtube_data_derive::QueryBuilder;
+
+#[derive(Clone, Copy)]
+pub struct ChannelSectionsService<'a> {
+    youtube: &'a YouTube,
+}
+
+impl<'a> ChannelSectionsService<'a> {
+    pub(crate) fn new(youtube: &'a YouTube) -> Self {
+        Self { youtube }
+    }
+
+    pub fn list(self, part: impl IntoIterator<Item = Part>) -> ChannelSectionList<'a> {
+        ChannelSectionList::new(self, part)
+    }
+
+    pub fn insert(self, section_type: ChannelSectionType) -> ChannelSectionInsert<'a> {
+        ChannelSectionInsert::new(self, section_type)
+    }
+
+    pub fn update(
+        self,
+        id: impl Into<String>,
+        section_type: ChannelSectionType,
+    ) -> ChannelSectionUpdate<'a> {
+        ChannelSectionUpdate::new(self, id, section_type)
+    }
+
+    pub fn delete(self, id: impl Into<String>) -> ChannelSectionDelete<'a> {
+        ChannelSectionDelete::new(self, id)
+    }
+}
+
+/// Parameters for the `list` method of the `channelSections` api. details:
+/// [link](https://developers.google.com/youtube/v3/docs/channelSections/list)
+#[derive(QueryBuilder)]
+pub struct ChannelSectionList<'a> {
+    service: ChannelSectionsService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    #[query(unique)]
+    part: Vec<Part>,
+
+    // filters (specify exactly one of the following parameters)
+    #[query(filter)]
+    channel_id: Option<String>,
+    #[query(filter)]
+    id: Option<Vec<String>>,
+    #[query(filter, authorized)]
+    mine: Option<bool>,
+
+    // optional parameters
+    /// Selects the fields of the response, e.g. `items(id,snippet/title)`, so only the needed part of every
+    /// resource is returned. details: [link](https://developers.google.com/youtube/v3/getting-started#fields)
+    #[query]
+    fields: Option<String>,
+    /// Fails the request with a timeout error when it does not complete in time, instead of the timeout of the
+    /// `YouTube` handle.
+    #[setter]
+    timeout: Option<Duration>,
+    #[setter]
+    on_behalf_of_content_owner: Option<String>,
+}
+
+impl RequestBase for ChannelSectionList<'_> {
+    fn api_path(&self) -> &str {
+        "channelSections"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for ChannelSectionList<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+
+    fn request_timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl ListApi<ChannelSectionListResponse> for ChannelSectionList<'_> {
+    async fn request(&self) -> Result<ChannelSectionListResponse> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        self.insert_query(youtube, &mut params)?;
+        self.insert_content_owner_parameters(
+            youtube,
+            &mut params,
+            self.on_behalf_of_content_owner.as_deref(),
+            None,
+        )?;
+
+        let request = self.optional_bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .get(self.url(&youtube.inner().base_path))
+                .query(&params),
+        )?;
+        let response = self.send(youtube, request).await?;
+        list_json(response).await
+    }
+}
+
+impl<'a> ChannelSectionList<'a> {
+    pub(crate) fn new(
+        service: ChannelSectionsService<'a>,
+        part: impl IntoIterator<Item = Part>,
+    ) -> Self {
+        let part = crate::dedupe(part);
+        let part = if part.is_empty() {
+            vec![Part::Id]
+        } else {
+            part
+        };
+        Self {
+            service,
+            authorizer: None,
+            part,
+            channel_id: None,
+            id: None,
+            mine: None,
+            fields: None,
+            timeout: None,
+            on_behalf_of_content_owner: None,
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+}
+
+/// Parameters for the `insert` method of the `channelSections` api. details:
+/// [link](https://developers.google.com/youtube/v3/docs/channelSections/insert)
+pub struct ChannelSectionInsert<'a> {
+    service: ChannelSectionsService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // optional parameters
+    on_behalf_of_content_owner: Option<String>,
+    on_behalf_of_content_owner_channel: Option<String>,
+
+    // request body
+    body: ChannelSectionBody,
+}
+
+impl RequestBase for ChannelSectionInsert<'_> {
+    fn api_path(&self) -> &str {
+        "channelSections"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for ChannelSectionInsert<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> ChannelSectionInsert<'a> {
+    pub(crate) fn new(
+        service: ChannelSectionsService<'a>,
+        section_type: ChannelSectionType,
+    ) -> Self {
+        Self {
+            service,
+            authorizer: None,
+            on_behalf_of_content_owner: None,
+            on_behalf_of_content_owner_channel: None,
+            body: ChannelSectionBody::new(None, section_type),
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+
+    pub fn title(&mut self, title: impl Into<String>) -> &mut Self {
+        self.body.snippet.title = Some(title.into());
+        self
+    }
+
+    pub fn position(&mut self, position: u32) -> &mut Self {
+        self.body.snippet.position = Some(position);
+        self
+    }
+
+    pub fn playlists(
+        &mut self,
+        playlists: impl IntoIterator<Item = impl Into<String>>,
+    ) -> &mut Self {
+        self.body.content_details().playlists =
+            Some(playlists.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn channels(&mut self, channels: impl IntoIterator<Item = impl Into<String>>) -> &mut Self {
+        self.body.content_details().channels = Some(channels.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn on_behalf_of_content_owner(
+        &mut self,
+        on_behalf_of_content_owner: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+        self
+    }
+
+    pub fn on_behalf_of_content_owner_channel(
+        &mut self,
+        on_behalf_of_content_owner_channel: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner_channel = Some(on_behalf_of_content_owner_channel.into());
+        self
+    }
+
+    /// Adds a channel section to the authenticated user's channel and returns the created section.
+    pub async fn request(&self) -> Result<ChannelSectionListResource> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        // required parameters
+        self.insert_query_parameters(&mut params, "part", Some(&self.body.part()));
+
+        // optional parameters
+        self.insert_content_owner_parameters(
+            youtube,
+            &mut params,
+            self.on_behalf_of_content_owner.as_deref(),
+            self.on_behalf_of_content_owner_channel.as_deref(),
+        )?;
+
+        // request body
+        self.body.validate()?;
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .post(self.url(&youtube.inner().base_path))
+                .query(&params)
+                .json(&self.body),
+        )?;
+        let response = self.send(youtube, request).await?;
+        json(response).await
+    }
+}
+
+/// Parameters for the `update` method of the `channelSections` api. details:
+/// [link](https://developers.google.com/youtube/v3/docs/channelSections/update)
+///
+/// The update replaces the whole section, so every property that should be kept has to be set again.
+pub struct ChannelSectionUpdate<'a> {
+    service: ChannelSectionsService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // optional parameters
+    on_behalf_of_content_owner: Option<String>,
+
+    // request body
+    body: ChannelSectionBody,
+}
+
+impl RequestBase for ChannelSectionUpdate<'_> {
+    fn api_path(&self) -> &str {
+        "channelSections"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for ChannelSectionUpdate<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> ChannelSectionUpdate<'a> {
+    pub(crate) fn new(
+        service: ChannelSectionsService<'a>,
+        id: impl Into<String>,
+        section_type: ChannelSectionType,
+    ) -> Self {
+        Self {
+            service,
+            authorizer: None,
+            on_behalf_of_content_owner: None,
+            body: ChannelSectionBody::new(Some(id.into()), section_type),
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+
+    pub fn title(&mut self, title: impl Into<String>) -> &mut Self {
+        self.body.snippet.title = Some(title.into());
+        self
+    }
+
+    /// Moves the section to `position`, which is how sections are reordered on the channel page.
+    pub fn position(&mut self, position: u32) -> &mut Self {
+        self.body.snippet.position = Some(position);
+        self
+    }
+
+    pub fn playlists(
+        &mut self,
+        playlists: impl IntoIterator<Item = impl Into<String>>,
+    ) -> &mut Self {
+        self.body.content_details().playlists =
+            Some(playlists.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn channels(&mut self, channels: impl IntoIterator<Item = impl Into<String>>) -> &mut Self {
+        self.body.content_details().channels = Some(channels.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn on_behalf_of_content_owner(
+        &mut self,
+        on_behalf_of_content_owner: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+        self
+    }
+
+    /// Updates a channel section and returns the updated section.
+    pub async fn request(&self) -> Result<ChannelSectionListResource> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        // required parameters
+        self.insert_query_parameters(&mut params, "part", Some(&self.body.part()));
+
+        // optional parameters
+        self.insert_content_owner_parameters(
+            youtube,
+            &mut params,
+            self.on_behalf_of_content_owner.as_deref(),
+            None,
+        )?;
+
+        // request body
+        if self.body.id.as_deref().is_none_or(str::is_empty) {
+            return Err(Error::missing_required_parameter(
+                "Required property `id` is empty",
+            ));
+        }
+        self.body.validate()?;
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .put(self.url(&youtube.inner().base_path))
+                .query(&params)
+                .json(&self.body),
+        )?;
+        let response = self.send(youtube, request).await?;
+        json(response).await
+    }
+}
+
+/// Parameters for the `delete` method of the `channelSections` api. details:
+/// [link](https://developers.google.com/youtube/v3/docs/channelSections/delete)
+pub struct ChannelSectionDelete<'a> {
+    service: ChannelSectionsService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    id: String,
+
+    // optional parameters
+    on_behalf_of_content_owner: Option<String>,
+}
+
+impl RequestBase for ChannelSectionDelete<'_> {
+    fn api_path(&self) -> &str {
+        "channelSections"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for ChannelSectionDelete<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> ChannelSectionDelete<'a> {
+    pub(crate) fn new(service: ChannelSectionsService<'a>, id: impl Into<String>) -> Self {
+        Self {
+            service,
+            authorizer: None,
+            id: id.into(),
+            on_behalf_of_content_owner: None,
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+
+    pub fn on_behalf_of_content_owner(
+        &mut self,
+        on_behalf_of_content_owner: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+        self
+    }
+
+    /// Deletes a channel section. The api responds with an empty body on success.
+    pub async fn request(&self) -> Result<()> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        // required parameters
+        if self.id.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required parameter `id` is empty",
+            ));
+        }
+        self.insert_query_parameter(&mut params, "id", Some(&self.id));
+
+        // optional parameters
+        self.insert_content_owner_parameters(
+            youtube,
+            &mut params,
+            self.on_behalf_of_content_owner.as_deref(),
+            None,
+        )?;
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .delete(self.url(&youtube.inner().base_path))
+                .query(&params),
+        )?;
+        self.send_without_content(youtube, request).await
+    }
+}
+
+/// The request body of `channelSections.insert` and `channelSections.update`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ChannelSectionBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+
+    snippet: ChannelSectionBodySnippet,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_details: Option<ChannelSectionBodyContentDetails>,
+}
+
+#[derive(Serialize)]
+struct ChannelSectionBodySnippet {
+    #[serde(rename = "type")]
+    section_type: ChannelSectionType,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    position: Option<u32>,
+}
+
+#[derive(Default, Serialize)]
+struct ChannelSectionBodyContentDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    playlists: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    channels: Option<Vec<String>>,
+}
+
+impl ChannelSectionBody {
+    fn new(id: Option<String>, section_type: ChannelSectionType) -> Self {
+        Self {
+            id,
+            snippet: ChannelSectionBodySnippet {
+                section_type,
+                title: None,
+                position: None,
+            },
+            content_details: None,
+        }
+    }
+
+    fn content_details(&mut self) -> &mut ChannelSectionBodyContentDetails {
+        self.content_details.get_or_insert_with(Default::default)
+    }
+
+    /// The parts written by the request. `contentDetails` is only sent when playlists or channels are set.
+    fn part(&self) -> Vec<Part> {
+        let mut part = vec![Part::Snippet];
+        if self.content_details.is_some() {
+            part.push(Part::ContentDetails);
+        }
+        part
+    }
+
+    fn validate(&self) -> Result<()> {
+        let section_type = &self.snippet.section_type;
+        let content_details = self.content_details.as_ref();
+        let playlists = content_details.and_then(|c| c.playlists.as_ref());
+        let channels = content_details.and_then(|c| c.channels.as_ref());
+
+        let multiple = matches!(
+            section_type,
+            ChannelSectionType::MultipleChannels | ChannelSectionType::MultiplePlaylists
+        );
+        if multiple && self.snippet.title.as_deref().is_none_or(str::is_empty) {
+            return Err(Error::missing_required_parameter(format!(
+                "Required property `snippet.title` is empty for a `{}` section",
+                section_type
+            )));
+        }
+        if self.snippet.title.is_some() && !multiple {
+            return Err(Error::invalid_parameter(format!(
+                "The title of a `{}` section can not be set",
+                section_type
+            )));
+        }
+
+        match section_type {
+            ChannelSectionType::SinglePlaylist | ChannelSectionType::MultiplePlaylists => {
+                if playlists.is_none_or(Vec::is_empty) {
+                    return Err(Error::missing_required_parameter(format!(
+                        "Required property `content_details.playlists` is empty for a `{}` section",
+                        section_type
+                    )));
+                }
+                if *section_type == ChannelSectionType::SinglePlaylist
+                    && playlists.map_or(0, Vec::len) > 1
+                {
+                    return Err(Error::invalid_parameter(
+                        "A `singlePlaylist` section can only contain one playlist",
+                    ));
+                }
+            }
+            ChannelSectionType::MultipleChannels if channels.is_none_or(Vec::is_empty) => {
+                return Err(Error::missing_required_parameter(format!(
+                    "Required property `content_details.channels` is empty for a `{}` section",
+                    section_type
+                )));
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    ContentDetails,
+    Id,
+    Snippet,
+}
+
+impl Part {
+    /// Every part of the resource, to request all of its properties.
+    pub fn all_public() -> Vec<Part> {
+        vec![Part::ContentDetails, Part::Id, Part::Snippet]
+    }
+}
+
+impl std::fmt::Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            Part::ContentDetails => "contentDetails",
+            Part::Id => "id",
+            Part::Snippet => "snippet",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+/// The type of a channel section.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ChannelSectionType {
+    AllPlaylists,
+    CompletedEvents,
+    LiveEvents,
+    MultipleChannels,
+    MultiplePlaylists,
+    PopularUploads,
+    RecentUploads,
+    SinglePlaylist,
+    Subscriptions,
+    UpcomingEvents,
+}
+
+impl std::fmt::Display for ChannelSectionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            ChannelSectionType::AllPlaylists => "allPlaylists",
+            ChannelSectionType::CompletedEvents => "completedEvents",
+            ChannelSectionType::LiveEvents => "liveEvents",
+            ChannelSectionType::MultipleChannels => "multipleChannels",
+            ChannelSectionType::MultiplePlaylists => "multiplePlaylists",
+            ChannelSectionType::PopularUploads => "popularUploads",
+            ChannelSectionType::RecentUploads => "recentUploads",
+            ChannelSectionType::SinglePlaylist => "singlePlaylist",
+            ChannelSectionType::Subscriptions => "subscriptions",
+            ChannelSectionType::UpcomingEvents => "upcomingEvents",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+/// The `channelSections.list` response is not paginated, so it does not share the [ListResponse](crate::ListResponse)
+/// layout.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChannelSectionListResponse {
+    /// Identifies the API resource's type. The value will be `youtube#channelSectionListResponse`.
+    #[serde(default)]
+    pub kind: String,
+
+    /// The ETag of the response.
+    #[serde(default)]
+    pub etag: String,
+
+    /// A list of ChannelSections that match the request criteria.
+    #[serde(default)]
+    pub items: Vec<ChannelSectionListResource>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelSectionListResource {
+    /// Identifies the API resource's type. The value will be `youtube#channelSection`.
+    #[serde(default)]
+    pub kind: String,
+
+    /// The ETag of the response.
+    #[serde(default)]
+    pub etag: String,
+
+    /// The ID that YouTube uses to uniquely identify the channel section.
+    #[serde(default)]
+    pub id: String,
+
+    pub snippet: Option<ChannelSectionSnippet>,
+
+    pub content_details: Option<ChannelSectionContentDetails>,
+
+    /// The fields of the resource that this version of the crate does not know, e.g. added to the api after its
+    /// release. They are serialized back with the resource.
+    #[serde(flatten, deserialize_with = "crate::serde_helpers::extra_fields")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// The snippet object contains basic details about the channel section, such as its type and title.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelSectionSnippet {
+    /// The channel section's type.
+    ///
+    /// Valid values for this property are:
+    ///
+    /// * allPlaylists
+    /// * completedEvents
+    /// * liveEvents
+    /// * multipleChannels
+    /// * multiplePlaylists
+    /// * popularUploads
+    /// * recentUploads
+    /// * singlePlaylist
+    /// * subscriptions
+    /// * upcomingEvents
+    #[serde(rename = "type", default)]
+    pub section_type: String,
+
+    /// The ID that YouTube uses to uniquely identify the channel that published the channel section.
+    #[serde(default)]
+    pub channel_id: String,
+
+    /// The section's title. You can only set the title of a channel section that has a `snippet.type` value of
+    /// either `multiplePlaylists` or `multipleChannels`.
+    pub title: Option<String>,
+
+    /// The section's position on the channel page. This property uses a 0-based index. A value of 0 identifies the
+    /// first section that appears on the channel, a value of 1 identifies the second section, and so forth.
+    #[serde(default)]
+    pub position: u32,
+}
+
+/// The contentDetails object contains details about the channel section's content, such as a list of playlists or
+/// channels featured in the section.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChannelSectionContentDetails {
+    /// A list of one or more playlist IDs that are featured in a channel section. You must specify a list of playlist
+    /// IDs if the channelSection resource's `snippet.type` property is either `singlePlaylist` or `multiplePlaylists`.
+    pub playlists: Option<Vec<String>>,
+
+    /// A list of one or more channel IDs that are featured in a channel section. You must specify a list of channel
+    /// IDs if the channelSection resource's `snippet.type` property is `multipleChannels`.
+    pub channels: Option<Vec<String>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::MockTransport;
+    use crate::{get_youtube_with_transport, get_youtube_without_user_agent};
+
+    #[tokio::test]
+    async fn test_get_by_channel_id() {
+        let transport = Arc::new(MockTransport::new().respond(
+            reqwest::Method::GET,
+            "channelSections",
+            200,
+            r#"{
+                "kind": "youtube#channelSectionListResponse",
+                "items": [
+                    {
+                        "kind": "youtube#channelSection",
+                        "id": "UC_x5XG1OV2P6uZZ5FSM9Ttw.e-Fk7vMPqLE",
+                        "snippet": {
+                            "type": "singlePlaylist",
+                            "channelId": "UC_x5XG1OV2P6uZZ5FSM9Ttw",
+                            "position": 0
+                        },
+                        "contentDetails": { "playlists": ["PLOU2XLYxmsIIM9h1Ybw2DuRw6o2fkNMeR"] }
+                    },
+                    {
+                        "kind": "youtube#channelSection",
+                        "id": "UC_x5XG1OV2P6uZZ5FSM9Ttw.B8DTd9ZXJqM",
+                        "snippet": {
+                            "type": "multipleChannels",
+                            "channelId": "UC_x5XG1OV2P6uZZ5FSM9Ttw",
+                            "title": "Other channels",
+                            "position": 1
+                        },
+                        "contentDetails": { "channels": ["UCVHFbqXqoYvEWM1Ddxl0QDg"] }
+                    }
+                ]
+            }"#,
+        ));
+        let response = get_youtube_with_transport(transport.clone())
+            .channel_sections()
+            .list(vec![Part::Snippet, Part::ContentDetails])
+            .channel_id("UC_x5XG1OV2P6uZZ5FSM9Ttw")
+            .request()
+            .await
+            .unwrap();
+        assert_eq!(response.items.len(), 2);
+        for item in &response.items {
+            assert_eq!(
+                item.snippet.as_ref().unwrap().channel_id,
+                "UC_x5XG1OV2P6uZZ5FSM9Ttw"
+            );
+        }
+        let playlist = &response.items[0];
+        assert_eq!(
+            playlist.snippet.as_ref().unwrap().section_type,
+            "singlePlaylist"
+        );
+        assert_eq!(
+            playlist.content_details.as_ref().unwrap().playlists,
+            Some(vec!["PLOU2XLYxmsIIM9h1Ybw2DuRw6o2fkNMeR".to_string()])
+        );
+        let channels = response.items[1].snippet.as_ref().unwrap();
+        assert_eq!(channels.title.as_deref(), Some("Other channels"));
+        assert_eq!(channels.position, 1);
+        let query = transport.requests()[0].1.query().unwrap().to_string();
+        assert!(query.contains("channelId=UC_x5XG1OV2P6uZZ5FSM9Ttw"));
+    }
+
+    #[tokio::test]
+    async fn test_request_without_filters() {
+        let without_filters = get_youtube_without_user_agent()
+            .channel_sections()
+            .list(vec![])
+            .request()
+            .await;
+        assert!(without_filters.is_err());
+        let err = without_filters.unwrap_err();
+        assert_eq!(
+            "builder error: \"No filter selected. Expected one of: channel_id, id, mine\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_insert_without_auth() {
+        let without_auth = get_youtube_without_user_agent()
+            .channel_sections()
+            .insert(ChannelSectionType::PopularUploads)
+            .position(0)
+            .request()
+            .await;
+        assert!(without_auth.is_err());
+        let err = without_auth.unwrap_err();
+        assert_eq!(
+            "builder error: \"The request to `channelSections` requires OAuth 2.0 authorization\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_insert_multiple_playlists_without_title() {
+        let without_title = get_youtube_without_user_agent()
+            .channel_sections()
+            .insert(ChannelSectionType::MultiplePlaylists)
+            .playlists(vec!["PLOU2XLYxmsIIM9h1Ybw2DuRw6o2fkNMeR"])
+            .request()
+            .await;
+        assert!(without_title.is_err());
+        let err = without_title.unwrap_err();
+        assert_eq!(
+            "builder error: \"Required property `snippet.title` is empty for a `multiplePlaylists` section\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_with_empty_id() {
+        let empty_id = get_youtube_without_user_agent()
+            .channel_sections()
+            .update("", ChannelSectionType::RecentUploads)
+            .position(1)
+            .request()
+            .await;
+        assert!(empty_id.is_err());
+        let err = empty_id.unwrap_err();
+        assert_eq!(
+            "builder error: \"Required property `id` is empty\"",
+            format!("{}", err)
+        );
+    }
+
+    #[test]
+    fn test_serialize_channel_section_body() {
+        let mut body = ChannelSectionBody::new(
+            Some("UC_x5XG1OV2P6uZZ5FSM9Ttw.LeAltgu_pbM".to_string()),
+            ChannelSectionType::SinglePlaylist,
+        );
+        body.snippet.position = Some(2);
+        body.content_details().playlists =
+            Some(vec!["PLOU2XLYxmsIIM9h1Ybw2DuRw6o2fkNMeR".to_string()]);
+        assert_eq!(
+            r#"{"id":"UC_x5XG1OV2P6uZZ5FSM9Ttw.LeAltgu_pbM","snippet":{"type":"singlePlaylist","position":2},"contentDetails":{"playlists":["PLOU2XLYxmsIIM9h1Ybw2DuRw6o2fkNMeR"]}}"#,
+            serde_json::to_string(&body).unwrap()
+        );
+    }
+}
@@ -1,7 +1,15 @@
 use crate::{
+    auth::{Authorizer, OAuthTokenAuth},
+    cursor::PageCursor,
     error::{Error, Result},
-    ListApi, ListResponse, Localization, RequestBase, Thumbnail, ThumbnailKind, YouTube,
-    YouTubeDataApi,
+    json, list_json,
+    oauth::Scope,
+    playlist_items::{self, PlaylistItemList, PlaylistItemsService},
+    search::{self, SearchService},
+    serde_helpers::{option_string_or_number, string_or_number},
+    url::{is_channel_id, YouTubeLink},
+    ListApi, ListResponse, Localization, PagedListApi, PrivacyStatus, RequestBase, Thumbnails,
+    YouTube, YouTubeDataApi,
 };
 
 use async_trait::async_trait;
@@ -9,41 +17,182 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt::Display;
+use std::sync::Arc;
+use std::time::Duration;
+use youtube_data_derive::QueryBuilder;
 
 pub type ChannelListResponse = ListResponse<ChannelListResource>;
 
-#[derive(Clone)]
-pub(crate) struct ChannelsService {
-    youtube: Box<YouTube>,
+#[derive(Clone, Copy)]
+pub struct ChannelsService<'a> {
+    youtube: &'a YouTube,
 }
 
-impl ChannelsService {
-    pub fn new(youtube: Box<YouTube>) -> Self {
+impl<'a> ChannelsService<'a> {
+    pub(crate) fn new(youtube: &'a YouTube) -> Self {
         Self { youtube }
     }
 
-    pub fn list(&self, part: Vec<Part>) -> ChannelList {
-        ChannelList::new(&self, part)
+    pub fn list(
+        self,
+        part: impl IntoIterator<Item = Part>,
+        filter: ChannelFilter,
+    ) -> ChannelList<'a> {
+        ChannelList::new(self, part, filter)
     }
+
+    /// Fetches one channel with its `snippet`, `statistics` and `contentDetails` parts, or `None` when no channel
+    /// has the id.
+    pub async fn get(self, id: impl Into<String>) -> Result<Option<ChannelListResource>> {
+        first(&self.list(SUMMARY_PARTS, ChannelFilter::id([id]))).await
+    }
+
+    /// Fetches the channel of a handle, with or without its leading `@`, like [get](Self::get).
+    pub async fn by_handle(self, handle: impl Into<String>) -> Result<Option<ChannelListResource>> {
+        first(&self.list(SUMMARY_PARTS, ChannelFilter::ForHandle(handle.into()))).await
+    }
+
+    /// Fetches the channel of a legacy YouTube username, like [get](Self::get).
+    pub async fn by_username(
+        self,
+        username: impl Into<String>,
+    ) -> Result<Option<ChannelListResource>> {
+        first(&self.list(SUMMARY_PARTS, ChannelFilter::ForUsername(username.into()))).await
+    }
+
+    /// Fetches the channel of a link, like [get](Self::get): `/channel/UC...` links by id, `/@handle` links by handle
+    /// and `/user/name` links by username. The api has no filter for the custom URLs of `/c/name` links, which are
+    /// looked up as a handle and then as the first channel of a search for the name, which costs 100 quota units.
+    pub async fn by_url(self, url: &str) -> Result<Option<ChannelListResource>> {
+        match YouTubeLink::parse(url)? {
+            YouTubeLink::Channel(id) => self.get(id).await,
+            YouTubeLink::Handle(handle) => self.by_handle(handle).await,
+            YouTubeLink::User(username) => self.by_username(username).await,
+            YouTubeLink::Custom(name) => match self.by_handle(name.as_str()).await? {
+                Some(channel) => Ok(Some(channel)),
+                None => self.search_first(name).await,
+            },
+            link => Err(Error::invalid_parameter(format!(
+                "`{}` is not a link to a channel",
+                link
+            ))),
+        }
+    }
+
+    /// Fetches a channel from whatever identifies it, like [get](Self::get): a link (see [by_url](Self::by_url)), a
+    /// channel id, a handle starting with `@`, or else a name, looked up as a legacy username, then as a handle and
+    /// last as the first channel of a search for the name, which costs 100 quota units.
+    pub async fn resolve(self, channel: &str) -> Result<Option<ChannelListResource>> {
+        let channel = channel.trim();
+        if channel.contains('/') {
+            return self.by_url(channel).await;
+        }
+        if channel.starts_with('@') {
+            return self.by_handle(channel).await;
+        }
+        if is_channel_id(channel) {
+            return self.get(channel).await;
+        }
+        if let Some(found) = self.by_username(channel).await? {
+            return Ok(Some(found));
+        }
+        if let Some(found) = self.by_handle(channel).await? {
+            return Ok(Some(found));
+        }
+        self.search_first(channel.to_string()).await
+    }
+
+    /// The first channel of a search for `query`.
+    async fn search_first(self, query: String) -> Result<Option<ChannelListResource>> {
+        let response = SearchService::new(self.youtube)
+            .channels(query)
+            .part([search::Part::Id])
+            .max_results(1)
+            .request()
+            .await?;
+        match response.items.into_iter().next() {
+            Some(channel) => self.get(channel.id).await,
+            None => Ok(None),
+        }
+    }
+
+    /// The items of the `uploads` playlist of the channel, the videos it uploaded, most recent first: requests the
+    /// playlist from the `contentDetails` of the channel and returns the list of its items with their `snippet` and
+    /// `contentDetails`, whose `video_id` identifies the video, 50 per page. Its [items](PagedListApi::items),
+    /// [poll](PagedListApi::poll) and [cursor](PagedListApi::cursor) follow the pages. `None` when the channel does
+    /// not exist.
+    pub async fn uploads(
+        self,
+        channel_id: impl Into<String>,
+    ) -> Result<Option<PlaylistItemList<'a>>> {
+        let Some(uploads) = self.uploads_playlist(channel_id.into()).await? else {
+            return Ok(None);
+        };
+        let mut list = PlaylistItemsService::new(self.youtube).list([
+            playlist_items::Part::Snippet,
+            playlist_items::Part::ContentDetails,
+        ]);
+        list.playlist_id(uploads).max_results(50);
+        Ok(Some(list))
+    }
+
+    /// The id of the `uploads` playlist of the channel, `None` when the channel does not exist.
+    async fn uploads_playlist(self, channel_id: String) -> Result<Option<String>> {
+        let channel =
+            first(&self.list([Part::ContentDetails], ChannelFilter::id([channel_id]))).await?;
+        Ok(channel
+            .and_then(|channel| channel.content_details)
+            .and_then(|content_details| content_details.related_playlists.uploads))
+    }
+
+    pub fn update_banner(
+        self,
+        id: impl Into<String>,
+        banner_url: impl Into<String>,
+    ) -> ChannelUpdateBanner<'a> {
+        ChannelUpdateBanner::new(self, id, banner_url)
+    }
+}
+
+/// The parts returned by [ChannelsService::get] and the lookups by handle, username and link.
+const SUMMARY_PARTS: [Part; 3] = [Part::Snippet, Part::Statistics, Part::ContentDetails];
+
+/// The first channel of the response to `list`.
+async fn first(list: &ChannelList<'_>) -> Result<Option<ChannelListResource>> {
+    Ok(list.request().await?.items.into_iter().next())
 }
 
-struct ChannelList<'a> {
-    service: &'a ChannelsService,
+/// Parameters for the `list` method of the `channels` api. details:
+/// [link](https://developers.google.com/youtube/v3/docs/channels/list)
+#[derive(QueryBuilder)]
+pub struct ChannelList<'a> {
+    service: ChannelsService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
 
     // required parameters
+    #[query(unique)]
     part: Vec<Part>,
 
-    // filters (spcify exactly one of the following parameters)
-    for_username: Option<&'a str>,
-    id: Option<&'a str>,
-    managed_by_me: Option<bool>,
-    mine: Option<bool>,
+    // the filter, of which the api requires exactly one
+    filter: ChannelFilter,
 
     // optional parameters
-    hl: Option<&'a str>,
+    /// Selects the fields of the response, e.g. `items(id,snippet/title)`, so only the needed part of every
+    /// resource is returned. details: [link](https://developers.google.com/youtube/v3/getting-started#fields)
+    #[query]
+    fields: Option<String>,
+    /// Fails the request with a timeout error when it does not complete in time, instead of the timeout of the
+    /// `YouTube` handle.
+    #[setter]
+    timeout: Option<Duration>,
+    #[query(default)]
+    hl: Option<String>,
+    #[query(max = 50)]
     max_results: Option<u32>,
-    on_behalf_of_content_owner: Option<&'a str>,
-    page_token: Option<&'a str>,
+    #[setter]
+    on_behalf_of_content_owner: Option<String>,
+    #[setter]
+    page_token: Option<String>,
 }
 
 impl RequestBase for ChannelList<'_> {
@@ -52,119 +201,124 @@ impl RequestBase for ChannelList<'_> {
     }
 }
 
-#[async_trait]
-impl YouTubeDataApi for ChannelList<'_> {}
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for ChannelList<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
 
-#[async_trait]
+    fn request_timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl ListApi<ChannelListResponse> for ChannelList<'_> {
     async fn request(&self) -> Result<ChannelListResponse> {
+        self.request_page(None).await
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl PagedListApi<ChannelListResource> for ChannelList<'_> {
+    async fn request_page(&self, page_token: Option<&str>) -> Result<ChannelListResponse> {
+        let youtube = &self.service.youtube;
+        let params = self.params(page_token)?;
+
+        let request = self.optional_bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .get(self.url(&youtube.inner().base_path))
+                .query(&params),
+        )?;
+        let response = self.send(youtube, request).await?;
+        list_json(response).await
+    }
+
+    fn cursor(&self, page_token: Option<&str>) -> Result<PageCursor> {
+        Ok(PageCursor::new(self.api_path(), self.params(page_token)?))
+    }
+}
+
+impl ChannelList<'_> {
+    /// The query parameters of the page of `page_token`, or of the `page_token` of the builder for `None`.
+    fn params(&self, page_token: Option<&str>) -> Result<HashMap<String, String>> {
         let youtube = &self.service.youtube;
+        let page_token = page_token.or(self.page_token.as_deref());
 
         // createquery parameter map
         let mut params = HashMap::<String, String>::new();
 
-        // key
-        self.insert_query_parameter(&mut params, "key", Some(&youtube.api_key));
-
-        // required parameters
-        self.insert_query_parameters(&mut params, "part", Some(&self.part));
-
-        // filter
-        let filters = vec![
-            self.for_username.is_some(),
-            self.id.is_some(),
-            self.managed_by_me.is_some(),
-            self.mine.is_some(),
-        ]
-        .into_iter()
-        .filter(|&v| v);
-        let count = filters.clone().count();
-
-        // filter must be specified exactly one
-        if count == 1 {
-            if let Some(for_username) = self.for_username {
-                self.insert_query_parameter(&mut params, "forUsername", Some(for_username));
+        self.insert_query(youtube, &mut params)?;
+        match &self.filter {
+            ChannelFilter::ForHandle(handle) => {
+                self.insert_query_parameter(&mut params, "forHandle", Some(handle));
             }
-            if let Some(id) = self.id {
-                self.insert_query_parameter(&mut params, "id", Some(id));
+            ChannelFilter::ForUsername(username) => {
+                self.insert_query_parameter(&mut params, "forUsername", Some(username));
             }
-            // TODO: check if the user is authenticated
-            if let Some(_managed_by_me) = self.managed_by_me {
-                return Err(Error::authorization_required(
-                    "The request uses the `managed_by_me` parameter but is not properly authorized",
-                ));
-
-                // self.insert_query_parameter(
-                //     &mut query_parameters,
-                //     "managedByMe",
-                //     Some(managed_by_me),
-                // );
+            ChannelFilter::Id(id) => {
+                if id.is_empty() {
+                    return Err(Error::missing_required_parameter(
+                        "The `id` filter requires at least one id",
+                    ));
+                }
+                self.insert_query_parameters(&mut params, "id", Some(id));
             }
-            if let Some(_mine) = self.mine {
-                return Err(Error::authorization_required(
-                    "The request uses the `mine` parameter but is not properly authorized",
-                ));
-                // self.insert_query_parameter(&mut query_parameters, "mine", Some(mine));
+            ChannelFilter::ManagedByMe => {
+                self.require_authorization(youtube, "managed_by_me")?;
+                self.insert_query_parameter(&mut params, "managedByMe", Some(true));
             }
-        } else {
-            return if count > 1 {
-                let imcompatible_params = filters
-                    .into_iter()
-                    .enumerate()
-                    .filter(|(_, v)| *v)
-                    .map(|(i, _)| match i {
-                        0 => "for_username",
-                        1 => "id",
-                        2 => "managed_by_me",
-                        3 => "mine",
-                        _ => "",
-                    })
-                    .collect::<Vec<&str>>()
-                    .join(", ");
-                Err(Error::incompatible_parameters(format!(
-                    "Incompatible parameters specified in the request: {}",
-                    imcompatible_params,
-                )))
-            } else {
-                Err(Error::missing_required_parameter(
-                    "No filter selected. Expected one of: for_username, id, managed_by_me, mine",
-                ))
-            };
-        }
-
-        // optional parameters
-        self.insert_query_parameter(&mut params, "hl", self.hl);
-        self.insert_query_parameter(&mut params, "maxResults", self.max_results);
-        self.insert_query_parameter(&mut params, "pageToken", self.page_token);
-
-        // TODO: check if the user is authenticated
-        if let Some(on_behalf_of_content_owner) = self.on_behalf_of_content_owner {
-            if !on_behalf_of_content_owner.is_empty() {
-                return Err(Error::authorization_required(
-                    "The request uses the `on_behalf_of_content_owner` parameter but is not properly authorized",
-                ));
-                // self.insert_query_parameter(
-                //     &mut query_parameters,
-                //     "onBehalfOfContentOwner",
-                //     Some(on_behalf_of_content_owner),
-                // );
+            ChannelFilter::Mine => {
+                self.require_authorization(youtube, "mine")?;
+                self.insert_query_parameter(&mut params, "mine", Some(true));
             }
         }
+        self.require_part_authorization(
+            youtube,
+            self.part.iter().filter(|part| part.is_owner_only()),
+        )?;
+        // the scopes of the handle do not describe the token of another user
+        let scopes = youtube
+            .inner()
+            .scopes
+            .as_ref()
+            .filter(|_| self.authorizer.is_none());
+        if self
+            .part
+            .iter()
+            .any(|part| matches!(part, Part::AuditDetails))
+            && scopes.is_some_and(|scopes| !scopes.contains(&Scope::YoutubePartnerChannelAudit))
+        {
+            return Err(Error::authorization_required(format!(
+                "The `auditDetails` part requires the scope: {}",
+                Scope::YoutubePartnerChannelAudit
+            )));
+        }
+        self.insert_query_parameter(&mut params, "pageToken", page_token);
+        self.insert_content_owner_parameters(
+            youtube,
+            &mut params,
+            self.on_behalf_of_content_owner.as_deref(),
+            None,
+        )?;
 
-        let response = self
-            .send(
-                youtube
-                    .client
-                    .get(self.url(&youtube.base_path))
-                    .query(&params),
-            )
-            .await?;
-        Ok(response.json().await?)
+        Ok(params)
     }
 }
 
 impl<'a> ChannelList<'a> {
-    pub fn new(service: &'a ChannelsService, part: Vec<Part>) -> Self {
+    pub(crate) fn new(
+        service: ChannelsService<'a>,
+        part: impl IntoIterator<Item = Part>,
+        filter: ChannelFilter,
+    ) -> Self {
+        let part = crate::dedupe(part);
         let part = if part.is_empty() {
             vec![Part::Id]
         } else {
@@ -172,11 +326,11 @@ impl<'a> ChannelList<'a> {
         };
         Self {
             service,
+            authorizer: None,
             part,
-            for_username: None,
-            id: None,
-            managed_by_me: None,
-            mine: None,
+            filter,
+            fields: None,
+            timeout: None,
             hl: None,
             max_results: None,
             on_behalf_of_content_owner: None,
@@ -184,54 +338,171 @@ impl<'a> ChannelList<'a> {
         }
     }
 
-    pub fn part(&mut self, part: Vec<Part>) -> &mut Self {
-        self.part = part;
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
         self
     }
+}
 
-    pub fn for_username(&mut self, for_username: &'a str) -> &mut Self {
-        self.for_username = Some(for_username);
-        self
-    }
+/// Parameters for the `update` method of the `channels` api, limited to the banner image of the
+/// `brandingSettings` part. details: [link](https://developers.google.com/youtube/v3/docs/channels/update)
+pub struct ChannelUpdateBanner<'a> {
+    service: ChannelsService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
 
-    pub fn id(&mut self, id: &'a str) -> &mut Self {
-        self.id = Some(id);
-        self
+    // required parameters
+    id: String,
+    banner_url: String,
+
+    // optional parameters
+    on_behalf_of_content_owner: Option<String>,
+}
+
+impl RequestBase for ChannelUpdateBanner<'_> {
+    fn api_path(&self) -> &str {
+        "channels"
     }
+}
 
-    pub fn managed_by_me(&mut self, managed_by_me: bool) -> &mut Self {
-        self.managed_by_me = Some(managed_by_me);
-        self
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for ChannelUpdateBanner<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
     }
+}
 
-    pub fn mine(&mut self, mine: bool) -> &mut Self {
-        self.mine = Some(mine);
-        self
+impl<'a> ChannelUpdateBanner<'a> {
+    pub(crate) fn new(
+        service: ChannelsService<'a>,
+        id: impl Into<String>,
+        banner_url: impl Into<String>,
+    ) -> Self {
+        Self {
+            service,
+            authorizer: None,
+            id: id.into(),
+            banner_url: banner_url.into(),
+            on_behalf_of_content_owner: None,
+        }
     }
 
-    pub fn hl(&mut self, hl: &'a str) -> &mut Self {
-        self.hl = Some(hl);
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
         self
     }
 
-    pub fn max_results(&mut self, max_results: u32) -> &mut Self {
-        let max_results = if max_results > 50 { 50 } else { max_results };
-        self.max_results = Some(max_results);
+    pub fn on_behalf_of_content_owner(
+        &mut self,
+        on_behalf_of_content_owner: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
         self
     }
 
-    pub fn on_behalf_of_content_owner(&mut self, on_behalf_of_content_owner: &'a str) -> &mut Self {
-        self.on_behalf_of_content_owner = Some(on_behalf_of_content_owner);
-        self
+    /// Sets the banner image of the channel to an url returned by `channel_banners().insert`.
+    ///
+    /// `channels.update` overwrites every property of the updated part, so the current `brandingSettings` of the
+    /// channel are fetched first and sent back with only the banner url changed.
+    pub async fn request(&self) -> Result<ChannelListResource> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        // required parameters
+        self.insert_query_parameter(&mut params, "part", Some(Part::BrandingSettings));
+        if self.id.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required parameter `id` is empty",
+            ));
+        }
+        if self.banner_url.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required property `branding_settings.image.banner_external_url` is empty",
+            ));
+        }
+
+        // optional parameters
+        self.insert_content_owner_parameters(
+            youtube,
+            &mut params,
+            self.on_behalf_of_content_owner.as_deref(),
+            None,
+        )?;
+
+        // current branding settings
+        let mut list_params = params.clone();
+        self.insert_query_parameter(&mut list_params, "id", Some(&self.id));
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .get(self.url(&youtube.inner().base_path))
+                .query(&list_params),
+        )?;
+        let mut list = json::<serde_json::Value>(self.send(youtube, request).await?).await?;
+        let branding_settings = match list["items"][0].get_mut("brandingSettings") {
+            Some(branding_settings) => branding_settings.take(),
+            None => {
+                return Err(Error::invalid_parameter(format!(
+                    "The channel `{}` was not found",
+                    self.id
+                )))
+            }
+        };
+
+        let mut body = serde_json::json!({
+            "id": self.id,
+            "brandingSettings": branding_settings,
+        });
+        body["brandingSettings"]["image"]["bannerExternalUrl"] = self.banner_url.as_str().into();
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .put(self.url(&youtube.inner().base_path))
+                .query(&params)
+                .json(&body),
+        )?;
+        let response = self.send(youtube, request).await?;
+        json(response).await
     }
+}
 
-    pub fn page_token(&mut self, page_token: &'a str) -> &mut Self {
-        self.page_token = Some(page_token);
-        self
+/// The filter of a [ChannelList], which selects the channels of the response.
+pub enum ChannelFilter {
+    /// The channel of a handle, with or without the leading `@`, e.g. `@YouTube` or `YouTube`.
+    ForHandle(String),
+
+    /// The channel of a legacy YouTube username.
+    ForUsername(String),
+
+    /// The channels of the ids.
+    Id(Vec<String>),
+
+    /// The channels managed by the content owner of `on_behalf_of_content_owner`.
+    ManagedByMe,
+
+    /// The channel of the authorized user.
+    Mine,
+}
+
+impl ChannelFilter {
+    /// The channels of the ids, see [ChannelFilter::Id].
+    pub fn id(id: impl IntoIterator<Item = impl Into<String>>) -> ChannelFilter {
+        ChannelFilter::Id(id.into_iter().map(Into::into).collect())
     }
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum Part {
     AuditDetails,
     BrandingSettings,
@@ -245,6 +516,28 @@ pub enum Part {
     TopicDetails,
 }
 
+impl Part {
+    /// The parts that are returned without being the owner of the channel, to request everything about a channel.
+    pub fn all_public() -> Vec<Part> {
+        vec![
+            Part::BrandingSettings,
+            Part::ContentDetails,
+            Part::Id,
+            Part::Localizations,
+            Part::Snippet,
+            Part::Statistics,
+            Part::Status,
+            Part::TopicDetails,
+        ]
+    }
+
+    /// Whether the part is only returned to the owner of the channel, or to its content owner, so it requires an
+    /// authorized request.
+    pub fn is_owner_only(&self) -> bool {
+        matches!(self, Part::AuditDetails | Part::ContentOwnerDetails)
+    }
+}
+
 impl Display for Part {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let str = match self {
@@ -264,37 +557,64 @@ impl Display for Part {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ChannelListResource {
     /// Identifies the API resource's type. The value will be `youtube#channel`.
+    #[serde(default)]
     pub kind: String,
 
     /// The ETag of the response.
+    #[serde(default)]
     pub etag: String,
 
     /// The ID that YouTube uses to uniquely identify the channel.
+    #[serde(default)]
     pub id: String,
 
     pub snippet: Option<ChannelSnippet>,
+
+    pub content_details: Option<ChannelContentDetails>,
+
+    pub statistics: Option<ChannelStatistics>,
+
+    pub status: Option<ChannelStatus>,
+
+    pub topic_details: Option<ChannelTopicDetails>,
+
+    pub branding_settings: Option<ChannelBrandingSettings>,
+
+    pub audit_details: Option<ChannelAuditDetails>,
+
+    pub content_owner_details: Option<ChannelContentOwnerDetails>,
+
+    /// The translations of the channel's metadata, keyed by the language of the translation, e.g. `de_DE`.
+    pub localizations: Option<HashMap<String, Localization>>,
+
+    /// The fields of the resource that this version of the crate does not know, e.g. added to the api after its
+    /// release. They are serialized back with the resource.
+    #[serde(flatten, deserialize_with = "crate::serde_helpers::extra_fields")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ChannelSnippet {
     /// The channel's title.
+    #[serde(default)]
     pub title: String,
 
     /// The channel's description. The property's value has a maximum length of 1000 characters.
+    #[serde(default)]
     pub description: String,
 
     /// The channel's custom URL. The [YouTube Help Center](https://support.google.com/youtube/answer/2657968)
     /// explains eligibility requirements for getting a custom URL as well as how to set up the URL.
-    #[serde(alias = "customUrl")]
     pub custom_url: Option<String>,
 
     /// The date and time that the channel was created. The value is specified in [ISO 8601](https://www.w3.org/TR/NOTE-datetime)
     /// format.
-    #[serde(alias = "publishedAt")]
-    pub published_at: DateTime<Utc>,
+    pub published_at: Option<DateTime<Utc>>,
 
     /// A map of thumbnail images associated with the search result. For each object in the map, the key is the name of the
     /// thumbnail image, and the value is an object that contains other information about the thumbnail.
@@ -306,20 +626,20 @@ pub struct ChannelSnippet {
     /// Channel thumbnail URLs are available only in the https domain, which is how the URLs appear in API responses.
     /// You might see broken images in your application if it tries to load YouTube images from the http domain.
     /// Thumbnail images might be empty for newly created channels and might take up to one day to populate.
-    pub thumbnails: HashMap<ThumbnailKind, Thumbnail>,
+    #[serde(default)]
+    pub thumbnails: Thumbnails,
 
     /// The language of the text in the channel resource's `snippet.title` and `snippet.description` properties.
-    #[serde(alias = "defaultLanguage")]
     pub default_language: Option<String>,
 
     /// The snippet.localized object contains a localized title and description for the channel or it contains
     /// the channel's title and description in the [default_language][default_language] for the channel's metadata.
     ///
     /// - Localized text is returned in the resource snippet if the [channels.list][channels.list] request used the hl parameter to
-    /// specify a language for which localized text should be returned, the hl parameter value identifies a
-    /// [YouTube application language][YouTube application language], and localized text is available in that language.
+    ///   specify a language for which localized text should be returned, the hl parameter value identifies a
+    ///   [YouTube application language][YouTube application language], and localized text is available in that language.
     /// - Metadata for the default language is returned if an hl parameter value is not specified or a value is
-    /// specified but localized metadata is not available for the specified language.
+    ///   specified but localized metadata is not available for the specified language.
     ///
     /// The property contains a read-only value. Use the [localizations][localizations] object to add, update, or delete localized metadata.
     ///
@@ -335,107 +655,763 @@ pub struct ChannelSnippet {
     pub country: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct ChannelContentDetails {}
+/// The contentDetails object encapsulates information about the channel's content.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelContentDetails {
+    /// The playlists associated with the channel, such as the channel's uploaded videos or liked videos.
+    #[serde(default)]
+    pub related_playlists: RelatedPlaylists,
+}
+
+/// The IDs of the playlists associated with a channel. Use [playlist_items](crate::YouTube::playlist_items) to
+/// retrieve the videos of a playlist, e.g. `uploads` to enumerate the videos uploaded by the channel.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RelatedPlaylists {
+    /// The ID of the playlist that contains the channel's liked videos. Only returned for the channel of the
+    /// authenticated user, as the liked videos of other channels are private.
+    pub likes: Option<String>,
+
+    /// The ID of the playlist that contains the channel's uploaded videos.
+    pub uploads: Option<String>,
+}
+
+/// The statistics object encapsulates statistics for the channel.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelStatistics {
+    /// The number of times the channel has been viewed.
+    #[serde(default, deserialize_with = "string_or_number")]
+    pub view_count: u64,
+
+    /// The number of subscribers that the channel has, rounded down to three significant figures. `None` when the
+    /// channel hides its subscriber count.
+    #[serde(default, deserialize_with = "option_string_or_number")]
+    pub subscriber_count: Option<u64>,
+
+    /// Indicates whether the channel's subscriber count is publicly visible.
+    #[serde(default)]
+    pub hidden_subscriber_count: bool,
+
+    /// The number of public videos uploaded to the channel. Note that the value reflects the count of the channel's
+    /// public videos only, even to owners.
+    #[serde(default, deserialize_with = "string_or_number")]
+    pub video_count: u64,
+}
+
+/// The topicDetails object encapsulates information about topics associated with the channel.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelTopicDetails {
+    /// A list of topic IDs associated with the channel. The IDs are
+    /// [Freebase topic IDs](https://developers.google.com/youtube/v3/docs/channels#topicDetails.topicIds).
+    #[serde(default)]
+    pub topic_ids: Vec<String>,
+
+    /// A list of Wikipedia URLs that describe the channel's content.
+    #[serde(default)]
+    pub topic_categories: Vec<String>,
+}
+
+/// The status object encapsulates information about the privacy status of the channel.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelStatus {
+    /// Privacy status of the channel.
+    pub privacy_status: Option<PrivacyStatus>,
+
+    /// Indicates whether the channel data identifies a user that is already linked to either a YouTube username or a
+    /// Google+ account. A user that has one of these links already has a public YouTube identity, which is a
+    /// prerequisite for several actions, such as uploading videos.
+    #[serde(default)]
+    pub is_linked: bool,
+
+    /// Indicates whether the channel is eligible to upload videos that are more than 15 minutes long. This property is
+    /// only returned if the channel owner authorized the API request.
+    ///
+    /// Valid values for this property are:
+    ///
+    /// * allowed
+    /// * disallowed
+    /// * eligible
+    pub long_uploads_status: Option<String>,
+
+    /// This value indicates whether the channel is designated as child-directed, and it contains the current "made for
+    /// kids" status of the channel.
+    #[serde(default)]
+    pub made_for_kids: bool,
+
+    /// In a channels.update request, this property allows the channel owner to designate the channel as
+    /// child-directed. The property value is only returned if the channel owner authorized the API request.
+    pub self_declared_made_for_kids: Option<bool>,
+}
+
+/// The brandingSettings object encapsulates information about the branding of the channel.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChannelBrandingSettings {
+    /// The branding properties of the channel page.
+    pub channel: Option<ChannelBrandingChannel>,
+
+    /// The branding properties of the watch pages of the channel's videos.
+    pub watch: Option<ChannelBrandingWatch>,
+
+    /// The banner images of the channel page.
+    pub image: Option<ChannelBrandingImage>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelBrandingChannel {
+    /// The channel's title. The title has a maximum length of 30 characters.
+    pub title: Option<String>,
+
+    /// The channel description, which appears in the channel information box on your channel page. The property's
+    /// value has a maximum length of 1000 characters.
+    pub description: Option<String>,
+
+    /// Keywords associated with your channel. The value is a space-separated list of strings. Channel keywords might
+    /// be truncated if they exceed the maximum allowed length of 500 characters or if they contained unescaped
+    /// quotation marks (`"`).
+    pub keywords: Option<String>,
+
+    /// The ID for a Google Analytics account that you want to use to track and measure traffic to your channel.
+    pub tracking_analytics_account_id: Option<String>,
+
+    /// The video that should play in the featured video module in the channel page's browse view for unsubscribed
+    /// viewers.
+    pub unsubscribed_trailer: Option<String>,
+
+    /// The language of the text in the channel resource's `snippet.title` and `snippet.description` properties.
+    pub default_language: Option<String>,
+
+    /// The country with which the channel is associated.
+    pub country: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelBrandingWatch {
+    /// The text color for the video watch page's branded area.
+    pub text_color: Option<String>,
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct ChannelStatistics {}
+    /// The background color for the video watch page's branded area.
+    pub background_color: Option<String>,
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct ChannelTopicDetails {}
+    /// The ID of a public playlist that should be featured on the watch pages of the channel's videos.
+    pub featured_playlist_id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelBrandingImage {
+    /// The URL of the banner image shown on the channel page, on desktop, mobile and TV.
+    pub banner_external_url: Option<String>,
+}
+
+/// The auditDetails object encapsulates channel data that a multichannel network (MCN) would evaluate while
+/// determining whether to accept or reject a particular channel. Only returned to requests authorized with the
+/// `https://www.googleapis.com/auth/youtubepartner-channel-audit` scope.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelAuditDetails {
+    /// This field indicates whether there are any issues with the channel. Currently, this field represents the result
+    /// of the logical and operation over the community guidelines, copyright strikes and content ID claims good
+    /// standings.
+    #[serde(default)]
+    pub overall_good_standing: bool,
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct ChannelStatus {}
+    /// Indicates whether the channel respects YouTube's community guidelines.
+    #[serde(default)]
+    pub community_guidelines_good_standing: bool,
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct ChannelBrandingSettings {}
+    /// Indicates whether the channel has any copyright strikes.
+    #[serde(default)]
+    pub copyright_strikes_good_standing: bool,
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct ChannelAuditDetails {}
+    /// Indicates whether the channel has any unresolved claims.
+    #[serde(default)]
+    pub content_id_claims_good_standing: bool,
+}
+
+/// The contentOwnerDetails object encapsulates channel data that is only visible to the YouTube Partner that has
+/// linked the channel to their Content Manager.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelContentOwnerDetails {
+    /// The ID of the content owner linked to the channel.
+    pub content_owner: Option<String>,
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct ChannelContentOwnerDetails {}
+    /// The date and time of when the channel was linked to the content owner. The value is specified in ISO 8601
+    /// format.
+    pub time_linked: Option<DateTime<Utc>>,
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::get_youtube_without_user_agent;
+    use crate::transport::{json_response, HttpTransport, MockTransport};
+    use crate::{get_youtube_with_transport, get_youtube_without_user_agent};
+    use futures_util::TryStreamExt;
+    use std::sync::Mutex;
+
+    /// Finds the channel `UC_x5XG1OV2P6uZZ5FSM9Ttw` only by id and by a search, recording the query of every
+    /// request.
+    #[derive(Default)]
+    struct SearchOnlyChannel(Mutex<Vec<String>>);
+
+    #[async_trait]
+    impl HttpTransport for SearchOnlyChannel {
+        async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+            let query = request.url().query().unwrap_or_default().to_string();
+            self.0.lock().unwrap().push(query.clone());
+            let body = if request.url().path().ends_with("/search") {
+                r#"{"items": [{"id": {"kind": "youtube#channel", "channelId": "UC_x5XG1OV2P6uZZ5FSM9Ttw"}}]}"#
+            } else if query.contains("id=UC_x5XG1OV2P6uZZ5FSM9Ttw") {
+                r#"{"items": [{"id": "UC_x5XG1OV2P6uZZ5FSM9Ttw"}]}"#
+            } else {
+                r#"{"items": []}"#
+            };
+            json_response(200, body)
+        }
+    }
+
+    /// Serves the channel `UCBR8-60-B28hp2BmDPdntcQ` and two pages of its uploads playlist.
+    struct Uploads;
+
+    #[async_trait]
+    impl HttpTransport for Uploads {
+        async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+            let query = request.url().query().unwrap_or_default();
+            let body = if request.url().path().ends_with("/channels") {
+                if query.contains("id=UCBR8-60-B28hp2BmDPdntcQ") {
+                    r#"{"items": [{"id": "UCBR8-60-B28hp2BmDPdntcQ", "contentDetails": {
+                        "relatedPlaylists": {"uploads": "UUBR8-60-B28hp2BmDPdntcQ"}
+                    }}]}"#
+                } else {
+                    r#"{"items": []}"#
+                }
+            } else {
+                assert!(query.contains("playlistId=UUBR8-60-B28hp2BmDPdntcQ"));
+                if query.contains("pageToken=page-1") {
+                    r#"{"items": [{"id": "item-2", "contentDetails": {"videoId": "video-2"}}]}"#
+                } else {
+                    r#"{"nextPageToken": "page-1", "items": [
+                        {"id": "item-1", "contentDetails": {"videoId": "video-1"}}
+                    ]}"#
+                }
+            };
+            json_response(200, body)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_uploads() {
+        let youtube = get_youtube_with_transport(Uploads);
+        let uploads = youtube
+            .channels()
+            .uploads("UCBR8-60-B28hp2BmDPdntcQ")
+            .await
+            .unwrap()
+            .unwrap();
+        let video_ids = uploads
+            .items(None)
+            .map_ok(|item| item.content_details.unwrap().video_id)
+            .try_collect::<Vec<String>>()
+            .await
+            .unwrap();
+        assert_eq!(video_ids, vec!["video-1", "video-2"]);
+
+        let missing = youtube.channels().uploads("UC_missing").await.unwrap();
+        assert!(missing.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_resolve() {
+        let transport = Arc::new(SearchOnlyChannel::default());
+        let youtube = get_youtube_with_transport(transport.clone());
+        let channel = youtube
+            .channels()
+            .resolve("GoogleDevelopers")
+            .await
+            .unwrap();
+        assert_eq!(channel.unwrap().id, "UC_x5XG1OV2P6uZZ5FSM9Ttw");
+        let queries = transport
+            .0
+            .lock()
+            .unwrap()
+            .drain(..)
+            .collect::<Vec<String>>();
+        assert_eq!(queries.len(), 4);
+        assert!(queries[0].contains("forUsername=GoogleDevelopers"));
+        assert!(queries[1].contains("forHandle=GoogleDevelopers"));
+        assert!(queries[2].contains("q=GoogleDevelopers"));
+        assert!(queries[2].contains("type=channel"));
+        assert!(queries[3].contains("id=UC_x5XG1OV2P6uZZ5FSM9Ttw"));
+
+        let channels = youtube.channels();
+        assert!(channels.resolve("@missing").await.unwrap().is_none());
+        assert!(channels
+            .resolve("https://www.youtube.com/channel/UC_x5XG1OV2P6uZZ5FSM9Ttw")
+            .await
+            .unwrap()
+            .is_some());
+        assert!(channels
+            .resolve("UC_x5XG1OV2P6uZZ5FSM9Ttw")
+            .await
+            .unwrap()
+            .is_some());
+        assert!(channels
+            .by_url("youtube.com/user/missing")
+            .await
+            .unwrap()
+            .is_none());
+        let queries = transport
+            .0
+            .lock()
+            .unwrap()
+            .drain(..)
+            .collect::<Vec<String>>();
+        assert_eq!(queries.len(), 4);
+        assert!(queries[0].contains("forHandle=%40missing"));
+        assert!(queries[3].contains("forUsername=missing"));
+
+        let err = channels
+            .by_url("https://youtu.be/Ks-_Mh1QhMc")
+            .await
+            .unwrap_err();
+        assert_eq!(
+            "builder error: \"`https://www.youtube.com/watch?v=Ks-_Mh1QhMc` is not a link to a channel\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get() {
+        let transport = Arc::new(MockTransport::new().respond(
+            reqwest::Method::GET,
+            "channels",
+            200,
+            r#"{"items": [{"id": "UCa9Y57gfeY0Zro_noHRVrnw"}]}"#,
+        ));
+        let youtube = get_youtube_with_transport(transport.clone());
+        let channel = youtube
+            .channels()
+            .get("UCa9Y57gfeY0Zro_noHRVrnw")
+            .await
+            .unwrap();
+        assert_eq!(channel.unwrap().id, "UCa9Y57gfeY0Zro_noHRVrnw");
+        let requests = transport.requests();
+        let query = requests[0].1.query().unwrap();
+        assert!(query.contains("part=snippet%2Cstatistics%2CcontentDetails"));
+        assert!(query.contains("id=UCa9Y57gfeY0Zro_noHRVrnw"));
+    }
 
     #[tokio::test]
     async fn test_get_by_id() {
-        let response = get_youtube_without_user_agent()
+        let transport = Arc::new(MockTransport::new().respond(
+            reqwest::Method::GET,
+            "channels",
+            200,
+            r#"{
+                "kind": "youtube#channelListResponse",
+                "pageInfo": { "totalResults": 1, "resultsPerPage": 5 },
+                "items": [{
+                    "kind": "youtube#channel",
+                    "id": "UCa9Y57gfeY0Zro_noHRVrnw",
+                    "snippet": {
+                        "title": "A channel",
+                        "description": "The description of the channel",
+                        "customUrl": "@achannel",
+                        "publishedAt": "2006-09-13T22:43:12Z",
+                        "thumbnails": {
+                            "high": { "url": "https://yt3.ggpht.com/high.jpg", "width": 800, "height": 800 }
+                        }
+                    },
+                    "statistics": {
+                        "viewCount": "8016593",
+                        "subscriberCount": "65300",
+                        "hiddenSubscriberCount": false,
+                        "videoCount": "42"
+                    }
+                }]
+            }"#,
+        ));
+        let response = get_youtube_with_transport(transport.clone())
             .channels()
-            .list(vec![Part::Snippet])
-            .id("UCa9Y57gfeY0Zro_noHRVrnw")
+            .list(
+                vec![Part::Snippet, Part::Statistics],
+                ChannelFilter::id(["UCa9Y57gfeY0Zro_noHRVrnw"]),
+            )
             .request()
-            .await;
-        assert_eq!(true, response.is_ok());
-        assert_eq!("UCa9Y57gfeY0Zro_noHRVrnw", response.unwrap().items[0].id);
+            .await
+            .unwrap();
+        let channel = &response.items[0];
+        assert_eq!(channel.id, "UCa9Y57gfeY0Zro_noHRVrnw");
+        let snippet = channel.snippet.as_ref().unwrap();
+        assert_eq!(snippet.title, "A channel");
+        assert_eq!(snippet.custom_url.as_deref(), Some("@achannel"));
+        assert_eq!(
+            snippet.thumbnails.high.as_ref().unwrap().url,
+            "https://yt3.ggpht.com/high.jpg"
+        );
+        let statistics = channel.statistics.as_ref().unwrap();
+        assert_eq!(statistics.view_count, 8016593);
+        assert_eq!(statistics.subscriber_count, Some(65300));
+        assert_eq!(statistics.video_count, 42);
+        assert!(transport.requests()[0]
+            .1
+            .query()
+            .unwrap()
+            .contains("id=UCa9Y57gfeY0Zro_noHRVrnw"));
+    }
+
+    #[tokio::test]
+    async fn test_get_by_handle() {
+        let transport = Arc::new(MockTransport::new().respond(
+            reqwest::Method::GET,
+            "channels",
+            200,
+            r#"{
+                "kind": "youtube#channelListResponse",
+                "items": [{
+                    "kind": "youtube#channel",
+                    "id": "UCBR8-60-B28hp2BmDPdntcQ",
+                    "snippet": { "title": "YouTube", "description": "", "customUrl": "@youtube" }
+                }]
+            }"#,
+        ));
+        let response = get_youtube_with_transport(transport.clone())
+            .channels()
+            .list(
+                vec![Part::Snippet],
+                ChannelFilter::ForHandle("@YouTube".to_string()),
+            )
+            .request()
+            .await
+            .unwrap();
+        assert_eq!(response.items[0].id, "UCBR8-60-B28hp2BmDPdntcQ");
+        assert_eq!(
+            response.items[0]
+                .snippet
+                .as_ref()
+                .unwrap()
+                .custom_url
+                .as_deref(),
+            Some("@youtube")
+        );
+        assert!(transport.requests()[0]
+            .1
+            .query()
+            .unwrap()
+            .contains("forHandle=%40YouTube"));
     }
 
     #[tokio::test]
     async fn test_request_with_invalid_id() {
-        let invalid_id = get_youtube_without_user_agent()
+        let transport = Arc::new(MockTransport::new().respond(
+            reqwest::Method::GET,
+            "channels",
+            400,
+            r#"{
+                "error": {
+                    "code": 400,
+                    "message": "Request contains an invalid argument.",
+                    "errors": [
+                        { "message": "Request contains an invalid argument.", "domain": "global", "reason": "badRequest" }
+                    ],
+                    "status": "INVALID_ARGUMENT"
+                }
+            }"#,
+        ));
+        let invalid_id = get_youtube_with_transport(transport)
             .channels()
-            .list(vec![Part::Snippet])
-            .id("UC_x5XG1OV2P6uZZ5FSM9Ttw日本語한국어English")
+            .list(
+                vec![Part::Snippet],
+                ChannelFilter::id(["UC_x5XG1OV2P6uZZ5FSM9Ttw日本語한국어English"]),
+            )
             .request()
             .await;
-        assert_eq!(true, invalid_id.is_err());
         let err = invalid_id.unwrap_err();
         let assert_message = concat!("client error for url (\"/youtube/v3/channels?id=UC_x5XG1OV2P6uZZ5FSM9Ttw日本語한국어English&key=[API_KEY]&part=snippet\"): ",
         "400 Bad Request status: \"INVALID_ARGUMENT\" ",
-        "message: \"Request contains an invalid argument.\" ", 
+        "message: \"Request contains an invalid argument.\" ",
         "[message: \"Request contains an invalid argument.\", domain: \"global\", reason: \"badRequest\"]");
         assert_eq!(assert_message, format!("{}", err));
     }
 
     #[tokio::test]
-    async fn test_request_without_filters() {
-        let without_filters = get_youtube_without_user_agent()
+    async fn test_request_without_ids() {
+        let without_ids = get_youtube_without_user_agent()
             .channels()
-            .list(vec![])
+            .list(vec![], ChannelFilter::id(Vec::<String>::new()))
             .request()
             .await;
-        assert_eq!(true, without_filters.is_err());
-        let err = without_filters.unwrap_err();
+        assert!(without_ids.is_err());
+        let err = without_ids.unwrap_err();
         assert_eq!(
-            "builder error: \"No filter selected. Expected one of: for_username, id, managed_by_me, mine\"",
+            "builder error: \"The `id` filter requires at least one id\"",
             format!("{}", err)
         );
     }
 
+    /// test use filters that require authentication wihtout authentication
     #[tokio::test]
-    async fn test_request_multiple_filters() {
-        let multiple_filters = get_youtube_without_user_agent()
+    async fn test_request_without_auth() {
+        let without_auth = get_youtube_without_user_agent()
             .channels()
-            .list(vec![])
-            .id("something")
-            .for_username("something")
+            .list(vec![], ChannelFilter::Mine)
             .request()
             .await;
-        assert_eq!(true, multiple_filters.is_err());
-        let err = multiple_filters.unwrap_err();
+        assert!(without_auth.is_err());
+        let err = without_auth.unwrap_err();
         assert_eq!(
-            "builder error: \"Incompatible parameters specified in the request: for_username, id\"",
+            "builder error: \"The request uses the `mine` parameter but is not properly authorized\"",
             format!("{}", err)
         );
     }
 
-    /// test use filters that require authentication wihtout authentication
     #[tokio::test]
-    async fn test_request_without_auth() {
-        let without_auth = get_youtube_without_user_agent()
+    async fn test_request_owner_parts() {
+        let youtube = get_youtube_without_user_agent();
+        let without_auth = youtube
             .channels()
-            .list(vec![])
-            .mine(true)
+            .list(
+                vec![Part::Snippet, Part::ContentOwnerDetails],
+                ChannelFilter::id(["UC_x5XG1OV2P6uZZ5FSM9Ttw"]),
+            )
             .request()
             .await;
-        assert_eq!(true, without_auth.is_err());
-        let err = without_auth.unwrap_err();
         assert_eq!(
-            "builder error: \"The request uses the `mine` parameter but is not properly authorized\"",
-            format!("{}", err)
+            "builder error: \"The request uses the parts only returned to the owner of the resource but is not \
+             properly authorized: contentOwnerDetails\"",
+            format!("{}", without_auth.unwrap_err())
+        );
+
+        let without_audit_scope = youtube
+            .with_access_token("access-token")
+            .with_scopes(&[Scope::YoutubeReadonly])
+            .channels()
+            .list(vec![Part::AuditDetails], ChannelFilter::Mine)
+            .request()
+            .await;
+        assert_eq!(
+            "builder error: \"The `auditDetails` part requires the scope: \
+             https://www.googleapis.com/auth/youtubepartner-channel-audit\"",
+            format!("{}", without_audit_scope.unwrap_err())
+        );
+    }
+
+    #[test]
+    fn test_deserialize_content_details() {
+        let channel: ChannelListResource = serde_json::from_str(
+            r#"{
+                "kind": "youtube#channel",
+                "etag": "etag",
+                "id": "UCBR8-60-B28hp2BmDPdntcQ",
+                "contentDetails": {
+                    "relatedPlaylists": {
+                        "likes": "",
+                        "uploads": "UUBR8-60-B28hp2BmDPdntcQ"
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+        let related_playlists = channel.content_details.unwrap().related_playlists;
+        assert_eq!(
+            related_playlists.uploads.as_deref(),
+            Some("UUBR8-60-B28hp2BmDPdntcQ")
+        );
+        assert_eq!(related_playlists.likes.as_deref(), Some(""));
+    }
+
+    #[test]
+    fn test_deserialize_branding_settings() {
+        let channel: ChannelListResource = serde_json::from_str(
+            r#"{
+                "kind": "youtube#channel",
+                "etag": "etag",
+                "id": "UCBR8-60-B28hp2BmDPdntcQ",
+                "brandingSettings": {
+                    "channel": {
+                        "title": "YouTube",
+                        "description": "The official YouTube channel.",
+                        "keywords": "youtube \"creator news\"",
+                        "unsubscribedTrailer": "nFvYtoBSKp8",
+                        "country": "US"
+                    },
+                    "image": {
+                        "bannerExternalUrl": "https://yt3.googleusercontent.com/banner"
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+        let branding_settings = channel.branding_settings.unwrap();
+        let branding_channel = branding_settings.channel.unwrap();
+        assert_eq!(branding_channel.title.as_deref(), Some("YouTube"));
+        assert_eq!(
+            branding_channel.keywords.as_deref(),
+            Some("youtube \"creator news\"")
+        );
+        assert_eq!(
+            branding_channel.unsubscribed_trailer.as_deref(),
+            Some("nFvYtoBSKp8")
+        );
+        assert_eq!(branding_channel.default_language, None);
+        assert!(branding_settings.watch.is_none());
+        assert_eq!(
+            branding_settings
+                .image
+                .unwrap()
+                .banner_external_url
+                .as_deref(),
+            Some("https://yt3.googleusercontent.com/banner")
+        );
+    }
+
+    /// the api sends the counts as strings
+    #[test]
+    fn test_deserialize_statistics() {
+        let channel: ChannelListResource = serde_json::from_str(
+            r#"{
+                "id": "UCBR8-60-B28hp2BmDPdntcQ",
+                "statistics": {
+                    "viewCount": "3497851284",
+                    "subscriberCount": "41200000",
+                    "hiddenSubscriberCount": false,
+                    "videoCount": "1191"
+                }
+            }"#,
+        )
+        .unwrap();
+        let statistics = channel.statistics.unwrap();
+        assert_eq!(statistics.view_count, 3_497_851_284);
+        assert_eq!(statistics.subscriber_count, Some(41_200_000));
+        assert_eq!(statistics.video_count, 1191);
+
+        let hidden: ChannelStatistics = serde_json::from_str(
+            r#"{ "viewCount": "0", "hiddenSubscriberCount": true, "videoCount": "0" }"#,
+        )
+        .unwrap();
+        assert!(hidden.hidden_subscriber_count);
+        assert_eq!(hidden.subscriber_count, None);
+    }
+
+    #[test]
+    fn test_deserialize_owner_parts() {
+        let channel: ChannelListResource = serde_json::from_str(
+            r#"{
+                "kind": "youtube#channel",
+                "etag": "etag",
+                "id": "UCBR8-60-B28hp2BmDPdntcQ",
+                "status": {
+                    "privacyStatus": "public",
+                    "isLinked": true,
+                    "longUploadsStatus": "allowed",
+                    "madeForKids": false,
+                    "selfDeclaredMadeForKids": false
+                },
+                "topicDetails": {
+                    "topicIds": ["/m/02jjt"],
+                    "topicCategories": ["https://en.wikipedia.org/wiki/Entertainment"]
+                },
+                "auditDetails": {
+                    "overallGoodStanding": true,
+                    "communityGuidelinesGoodStanding": true,
+                    "copyrightStrikesGoodStanding": false,
+                    "contentIdClaimsGoodStanding": true
+                },
+                "contentOwnerDetails": {
+                    "contentOwner": "content-owner-id",
+                    "timeLinked": "2015-03-02T20:14:35Z"
+                }
+            }"#,
+        )
+        .unwrap();
+        let status = channel.status.unwrap();
+        assert_eq!(status.privacy_status, Some(PrivacyStatus::Public));
+        assert!(status.is_linked);
+        assert_eq!(status.long_uploads_status.as_deref(), Some("allowed"));
+        assert_eq!(status.self_declared_made_for_kids, Some(false));
+
+        let topic_details = channel.topic_details.unwrap();
+        assert_eq!(topic_details.topic_ids, vec!["/m/02jjt"]);
+        assert_eq!(
+            topic_details.topic_categories,
+            vec!["https://en.wikipedia.org/wiki/Entertainment"]
+        );
+
+        let audit_details = channel.audit_details.unwrap();
+        assert!(audit_details.overall_good_standing);
+        assert!(!audit_details.copyright_strikes_good_standing);
+
+        let content_owner_details = channel.content_owner_details.unwrap();
+        assert_eq!(
+            content_owner_details.content_owner.as_deref(),
+            Some("content-owner-id")
+        );
+        assert_eq!(
+            content_owner_details.time_linked.unwrap().to_rfc3339(),
+            "2015-03-02T20:14:35+00:00"
+        );
+    }
+
+    #[test]
+    fn test_deserialize_empty_owner_parts() {
+        let channel: ChannelListResource = serde_json::from_str(
+            r#"{
+                "id": "UCBR8-60-B28hp2BmDPdntcQ",
+                "status": {},
+                "topicDetails": {},
+                "contentOwnerDetails": {}
+            }"#,
+        )
+        .unwrap();
+        assert!(!channel.status.unwrap().is_linked);
+        assert!(channel.topic_details.unwrap().topic_ids.is_empty());
+        assert!(channel.audit_details.is_none());
+        assert!(channel.content_owner_details.unwrap().time_linked.is_none());
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn test_deserialize_extra_fields() {
+        let json = serde_json::json!({
+            "kind": "youtube#channel",
+            "etag": "etag",
+            "id": "UCBR8-60-B28hp2BmDPdntcQ",
+            "snippet": { "title": "YouTube" },
+            "shortsDetails": { "shortsCount": "12" }
+        });
+        let channel: ChannelListResource = serde_json::from_value(json).unwrap();
+        assert_eq!(channel.snippet.as_ref().unwrap().title, "YouTube");
+        assert_eq!(channel.extra.len(), 1);
+        assert_eq!(channel.extra["shortsDetails"]["shortsCount"], "12");
+
+        let serialized = serde_json::to_value(&channel).unwrap();
+        assert_eq!(serialized["shortsDetails"]["shortsCount"], "12");
+        assert!(serialized.get("extra").is_none());
+    }
+
+    #[test]
+    fn test_deserialize_localizations() {
+        let channel: ChannelListResource = serde_json::from_str(
+            r#"{
+                "id": "UCBR8-60-B28hp2BmDPdntcQ",
+                "localizations": {
+                    "de_DE": { "title": "YouTube", "description": "Der offizielle YouTube-Kanal." },
+                    "ko": { "title": "YouTube" }
+                }
+            }"#,
+        )
+        .unwrap();
+        let localizations = channel.localizations.unwrap();
+        assert_eq!(localizations.len(), 2);
+        assert_eq!(
+            localizations["de_DE"].description,
+            "Der offizielle YouTube-Kanal."
         );
+        assert_eq!(localizations["ko"].description, "");
     }
 }
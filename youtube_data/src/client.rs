@@ -0,0 +1,704 @@
+//! Configuration of the [YouTube] handle: base URLs, the HTTP client, timeouts, default query parameters, the
+//! retry and rate limit policies applied to every request and the metrics recorder.
+
+use crate::{
+    auth::{ApiKeyAuth, KeyRotation},
+    error::{Error, Result},
+    inner::YouTubeInner,
+    metrics::{MetricsRecorder, NoopRecorder},
+    search::SafeSearch,
+    timer::{self, Instant},
+    transport::{HttpTransport, ReqwestTransport},
+    YouTube,
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::transport::{CaptureSink, CapturingTransport};
+#[cfg(not(target_arch = "wasm32"))]
+use reqwest::Proxy;
+use reqwest::{header::HeaderMap, Client, StatusCode};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+pub use tokio_util::sync::CancellationToken;
+
+/// The base path of the YouTube Data API.
+pub(crate) const BASE_PATH: &str = "https://www.googleapis.com/youtube/v3";
+
+/// The base path of the media upload endpoints of the YouTube Data API.
+pub(crate) const UPLOAD_BASE_PATH: &str = "https://www.googleapis.com/upload/youtube/v3";
+
+/// Builds a [YouTube] handle, see [YouTube::builder].
+pub struct YouTubeBuilder {
+    api_keys: Vec<String>,
+    key_rotation: KeyRotation,
+    base_path: String,
+    fallback_base_paths: Vec<String>,
+    upload_base_path: String,
+    client: Option<Client>,
+    transport: Option<Arc<dyn HttpTransport>>,
+    user_agent: Option<String>,
+    timeout: Option<Duration>,
+    #[cfg(not(target_arch = "wasm32"))]
+    connect_timeout: Option<Duration>,
+    #[cfg(not(target_arch = "wasm32"))]
+    proxies: Vec<Proxy>,
+    #[cfg(not(target_arch = "wasm32"))]
+    no_proxy: bool,
+    #[cfg(all(
+        not(target_arch = "wasm32"),
+        any(feature = "native-tls", feature = "rustls-tls")
+    ))]
+    tls_backend: Option<TlsBackend>,
+    default_params: Vec<(String, String)>,
+    region_code: Option<String>,
+    hl: Option<String>,
+    safe_search: Option<SafeSearch>,
+    out_of_range: OutOfRange,
+    retry: RetryPolicy,
+    rate_limit: Option<RateLimit>,
+    metrics: Arc<dyn MetricsRecorder>,
+    cancellation: Option<CancellationToken>,
+    #[cfg(not(target_arch = "wasm32"))]
+    capture: Option<Arc<dyn CaptureSink>>,
+}
+
+impl YouTubeBuilder {
+    pub(crate) fn new(api_key: impl Into<String>) -> YouTubeBuilder {
+        YouTubeBuilder {
+            api_keys: vec![api_key.into()],
+            key_rotation: KeyRotation::OnQuotaExceeded,
+            base_path: BASE_PATH.to_string(),
+            fallback_base_paths: Vec::new(),
+            upload_base_path: UPLOAD_BASE_PATH.to_string(),
+            client: None,
+            transport: None,
+            user_agent: None,
+            timeout: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            connect_timeout: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            proxies: Vec::new(),
+            #[cfg(not(target_arch = "wasm32"))]
+            no_proxy: false,
+            #[cfg(all(
+                not(target_arch = "wasm32"),
+                any(feature = "native-tls", feature = "rustls-tls")
+            ))]
+            tls_backend: None,
+            default_params: Vec::new(),
+            region_code: None,
+            hl: None,
+            safe_search: None,
+            out_of_range: OutOfRange::Clamp,
+            retry: RetryPolicy::none(),
+            rate_limit: None,
+            metrics: Arc::new(NoopRecorder),
+            cancellation: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            capture: None,
+        }
+    }
+
+    /// Replaces the api key with a pool of keys, e.g. of several Google Cloud projects, so one handle spreads its
+    /// quota consumption over the projects. See [KeyRotation].
+    pub fn api_keys<K>(
+        mut self,
+        api_keys: impl IntoIterator<Item = K>,
+        rotation: KeyRotation,
+    ) -> YouTubeBuilder
+    where
+        K: Into<String>,
+    {
+        self.api_keys = api_keys.into_iter().map(Into::into).collect();
+        self.key_rotation = rotation;
+        self
+    }
+
+    /// Sends the requests to another base path, e.g. a mock server or a caching proxy. Defaults to
+    /// `https://www.googleapis.com/youtube/v3`.
+    pub fn base_path(mut self, base_path: impl Into<String>) -> YouTubeBuilder {
+        self.base_path = base_path.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// A base path the requests are sent to when the base path, and the fallback base paths added before, can not be
+    /// connected to, e.g. `https://www.googleapis.com/youtube/v3` behind a caching proxy. Media uploads are not
+    /// failed over.
+    pub fn fallback_base_path(mut self, base_path: impl Into<String>) -> YouTubeBuilder {
+        self.fallback_base_paths
+            .push(base_path.into().trim_end_matches('/').to_string());
+        self
+    }
+
+    /// Sends the media uploads to another base path. Defaults to `https://www.googleapis.com/upload/youtube/v3`.
+    pub fn upload_base_path(mut self, upload_base_path: impl Into<String>) -> YouTubeBuilder {
+        self.upload_base_path = upload_base_path.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Uses an already configured HTTP client, e.g. to share its connection pool with the rest of the application.
+    /// `connect_timeout`, the proxies and the TLS backend only apply to the client built by this builder.
+    pub fn client(mut self, client: Client) -> YouTubeBuilder {
+        self.client = Some(client);
+        self
+    }
+
+    /// Executes the requests with another transport than the HTTP client, e.g. a [MockTransport] in tests. The
+    /// requests are still built by the HTTP client.
+    ///
+    /// [MockTransport]: crate::transport::MockTransport
+    pub fn transport(mut self, transport: impl HttpTransport + 'static) -> YouTubeBuilder {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// The `User-Agent` header sent with every request.
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> YouTubeBuilder {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// The time limit of a request, from sending it until the whole response body was received.
+    pub fn timeout(mut self, timeout: Duration) -> YouTubeBuilder {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// The time limit of establishing a connection. Not available on wasm32, where the browser connects.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> YouTubeBuilder {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    /// Sends the requests through a proxy, e.g. `Proxy::https("http://proxy.corp:3128")`. Can be called several
+    /// times; the first proxy that matches a request is used. The `HTTP_PROXY`/`HTTPS_PROXY` environment variables
+    /// are used when no proxy is configured. Not available on wasm32.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn proxy(mut self, proxy: Proxy) -> YouTubeBuilder {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Ignores the proxies of the environment variables.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn no_proxy(mut self) -> YouTubeBuilder {
+        self.no_proxy = true;
+        self
+    }
+
+    /// Selects the TLS implementation when both the `native-tls` and `rustls-tls` features are enabled. Defaults to
+    /// native TLS. Not available on wasm32.
+    #[cfg(all(
+        not(target_arch = "wasm32"),
+        any(feature = "native-tls", feature = "rustls-tls")
+    ))]
+    pub fn tls_backend(mut self, tls_backend: TlsBackend) -> YouTubeBuilder {
+        self.tls_backend = Some(tls_backend);
+        self
+    }
+
+    /// A query parameter sent with every request that does not set it already, e.g. `quotaUser`.
+    pub fn default_param(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<String>,
+    ) -> YouTubeBuilder {
+        self.default_params.push((key.into(), value.into()));
+        self
+    }
+
+    /// The `regionCode` of the requests that do not set their own, e.g. `KR`: the searches and the video charts.
+    pub fn default_region_code(mut self, region_code: impl Into<String>) -> YouTubeBuilder {
+        self.region_code = Some(region_code.into());
+        self
+    }
+
+    /// The language of the localized texts of the requests that do not set their own `hl`, e.g. `ko`: the videos,
+    /// channels, video categories, i18n regions and languages, abuse report reasons, live chat messages and Super
+    /// Chat events.
+    pub fn default_hl(mut self, hl: impl Into<String>) -> YouTubeBuilder {
+        self.hl = Some(hl.into());
+        self
+    }
+
+    /// The `safeSearch` of the searches that do not set their own.
+    pub fn default_safe_search(mut self, safe_search: SafeSearch) -> YouTubeBuilder {
+        self.safe_search = Some(safe_search);
+        self
+    }
+
+    /// What the requests do with the values out of the range accepted by the api, e.g. a `max_results` above 50.
+    /// Defaults to [OutOfRange::Clamp].
+    pub fn out_of_range(mut self, out_of_range: OutOfRange) -> YouTubeBuilder {
+        self.out_of_range = out_of_range;
+        self
+    }
+
+    /// Retries the requests that failed with a rate limit or server error. Requests are not retried by default.
+    pub fn retry(mut self, retry: RetryPolicy) -> YouTubeBuilder {
+        self.retry = retry;
+        self
+    }
+
+    /// Limits the rate at which requests are sent. The limit is shared by every clone of the handle.
+    pub fn rate_limit(mut self, rate_limit: RateLimit) -> YouTubeBuilder {
+        self.rate_limit = Some(rate_limit);
+        self
+    }
+
+    /// Reports the endpoint, duration, status and estimated quota cost of every request to `metrics`.
+    pub fn metrics(mut self, metrics: impl MetricsRecorder + 'static) -> YouTubeBuilder {
+        self.metrics = Arc::new(metrics);
+        self
+    }
+
+    /// Aborts the requests of the handle once `cancellation` is cancelled, see [YouTube::with_cancellation].
+    pub fn cancellation(mut self, cancellation: CancellationToken) -> YouTubeBuilder {
+        self.cancellation = Some(cancellation);
+        self
+    }
+
+    /// Passes the raw bodies of every request and response to `sink`, with the api key replaced, e.g. to collect the
+    /// responses a user's deserialization errors come from. Meant for debugging: every response body is copied. Not
+    /// available on wasm32.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn capture(mut self, sink: impl CaptureSink + 'static) -> YouTubeBuilder {
+        self.capture = Some(Arc::new(sink));
+        self
+    }
+
+    pub fn build(self) -> Result<YouTube> {
+        let client = match self.client {
+            Some(client) => client,
+            None => {
+                #[cfg(not(target_arch = "wasm32"))]
+                let mut builder = Client::builder();
+                #[cfg(target_arch = "wasm32")]
+                let builder = Client::builder();
+                #[cfg(not(target_arch = "wasm32"))]
+                if let Some(connect_timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(connect_timeout);
+                }
+                #[cfg(not(target_arch = "wasm32"))]
+                if self.no_proxy {
+                    builder = builder.no_proxy();
+                }
+                #[cfg(not(target_arch = "wasm32"))]
+                for proxy in self.proxies {
+                    builder = builder.proxy(proxy);
+                }
+                #[cfg(all(
+                    not(target_arch = "wasm32"),
+                    any(feature = "native-tls", feature = "rustls-tls")
+                ))]
+                if let Some(tls_backend) = self.tls_backend {
+                    builder = match tls_backend {
+                        #[cfg(feature = "native-tls")]
+                        TlsBackend::NativeTls => builder.use_native_tls(),
+                        #[cfg(feature = "rustls-tls")]
+                        TlsBackend::Rustls => builder.use_rustls_tls(),
+                    };
+                }
+                builder.build()?
+            }
+        };
+        for base_path in std::iter::once(&self.base_path).chain(&self.fallback_base_paths) {
+            if reqwest::Url::parse(base_path).is_err() {
+                return Err(Error::invalid_parameter(format!(
+                    "The base path `{}` is not a valid URL",
+                    base_path
+                )));
+            }
+        }
+
+        #[cfg_attr(target_arch = "wasm32", allow(unused_mut))]
+        let mut transport = self
+            .transport
+            .unwrap_or_else(|| Arc::new(ReqwestTransport::new(client.clone())));
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(sink) = self.capture {
+            transport = Arc::new(CapturingTransport::new(transport, sink));
+        }
+
+        Ok(YouTube {
+            inner: Arc::new(YouTubeInner {
+                api_key: ApiKeyAuth::pool(self.api_keys, self.key_rotation),
+                transport,
+                client,
+                base_path: self.base_path,
+                fallback_base_paths: self.fallback_base_paths,
+                upload_base_path: self.upload_base_path,
+                user_agent: self.user_agent,
+                timeout: self.timeout,
+                default_params: self.default_params,
+                region_code: self.region_code,
+                hl: self.hl,
+                safe_search: self.safe_search,
+                out_of_range: self.out_of_range,
+                retry: self.retry,
+                rate_limit: self.rate_limit.map(Arc::new),
+                metrics: self.metrics,
+                cancellation: self.cancellation,
+                authorizer: None,
+                scopes: None,
+            }),
+        })
+    }
+}
+
+/// The TLS implementations, enabled by the `native-tls` (default) and `rustls-tls` features.
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    any(feature = "native-tls", feature = "rustls-tls")
+))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TlsBackend {
+    /// The platform library: OpenSSL, Secure Transport or SChannel.
+    #[cfg(feature = "native-tls")]
+    NativeTls,
+
+    /// rustls, which needs no system library.
+    #[cfg(feature = "rustls-tls")]
+    Rustls,
+}
+
+/// What a request does with a value out of the range accepted by the api, e.g. a `max_results` above 50 or a
+/// `max_height` below 72.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutOfRange {
+    /// Sends the nearest value in the range.
+    Clamp,
+
+    /// Fails the request with a builder error.
+    Error,
+
+    /// Sends the nearest value in the range and logs a warning with the `tracing` feature.
+    Warn,
+}
+
+impl OutOfRange {
+    /// The value sent for the `value` of the parameter `name`, which the api accepts from `min` to `max`.
+    pub(crate) fn apply(
+        self,
+        name: &str,
+        value: u32,
+        min: Option<u32>,
+        max: Option<u32>,
+    ) -> Result<u32> {
+        let clamped = value
+            .max(min.unwrap_or(u32::MIN))
+            .min(max.unwrap_or(u32::MAX));
+        if clamped == value {
+            return Ok(value);
+        }
+        match self {
+            OutOfRange::Clamp => Ok(clamped),
+            OutOfRange::Error => {
+                let range = match (min, max) {
+                    (Some(min), Some(max)) => format!("from {} to {}", min, max),
+                    (Some(min), None) => format!("at least {}", min),
+                    (None, _) => format!("at most {}", clamped),
+                };
+                Err(Error::invalid_parameter(format!(
+                    "Request contains an invalid argument: `{}` must be {}, not {}",
+                    name, range, value
+                )))
+            }
+            OutOfRange::Warn => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(
+                    parameter = name,
+                    value,
+                    sent = clamped,
+                    "parameter out of range"
+                );
+                Ok(clamped)
+            }
+        }
+    }
+}
+
+/// How requests that failed with `429 Too Many Requests` or a `5xx` server error are retried. The delay doubles
+/// after every attempt, unless the response sets a `Retry-After` header.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// Does not retry requests.
+    pub fn none() -> RetryPolicy {
+        RetryPolicy {
+            max_retries: 0,
+            initial_backoff: Duration::ZERO,
+            max_backoff: Duration::ZERO,
+        }
+    }
+
+    /// Retries a request up to `max_retries` times, waiting 1s, 2s, 4s, ... up to 32s in between.
+    pub fn exponential(max_retries: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_retries,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(32),
+        }
+    }
+
+    /// The delay before the first retry.
+    pub fn initial_backoff(mut self, initial_backoff: Duration) -> RetryPolicy {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// The longest delay between two attempts.
+    pub fn max_backoff(mut self, max_backoff: Duration) -> RetryPolicy {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// The delay before retrying a request that was attempted `attempt + 1` times, or `None` if it is not retried.
+    pub(crate) fn backoff(
+        &self,
+        attempt: u32,
+        status: StatusCode,
+        headers: &HeaderMap,
+    ) -> Option<Duration> {
+        if attempt >= self.max_retries
+            || !(status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error())
+        {
+            return None;
+        }
+        let retry_after = retry_after(headers);
+        let exponential = self
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt));
+        Some(retry_after.unwrap_or(exponential).min(self.max_backoff))
+    }
+}
+
+/// The delay of the `Retry-After` header, when given in seconds.
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
+/// Spaces requests evenly so that at most the configured number is sent per second or minute.
+#[derive(Debug)]
+pub struct RateLimit {
+    interval: Duration,
+    next: Mutex<Option<Instant>>,
+}
+
+impl RateLimit {
+    pub fn per_second(requests: u32) -> RateLimit {
+        RateLimit::with_interval(Duration::from_secs(1) / requests.max(1))
+    }
+
+    pub fn per_minute(requests: u32) -> RateLimit {
+        RateLimit::with_interval(Duration::from_secs(60) / requests.max(1))
+    }
+
+    fn with_interval(interval: Duration) -> RateLimit {
+        RateLimit {
+            interval,
+            next: Mutex::new(None),
+        }
+    }
+
+    /// Waits until the next request may be sent.
+    pub(crate) async fn acquire(&self) {
+        let mut next = self.next.lock().await;
+        let now = Instant::now();
+        let at = match *next {
+            Some(at) if at > now => {
+                timer::sleep(at - now).await;
+                at
+            }
+            _ => now,
+        };
+        *next = Some(at + self.interval);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_with_invalid_base_path() {
+        let invalid = YouTube::builder("api-key").base_path("localhost").build();
+        assert!(invalid.is_err());
+        let err = invalid.err().unwrap();
+        assert_eq!(
+            "builder error: \"The base path `localhost` is not a valid URL\"",
+            format!("{}", err)
+        );
+    }
+
+    #[test]
+    fn test_build_with_proxy() {
+        let with_proxy = YouTube::builder("api-key")
+            .proxy(Proxy::https("http://127.0.0.1:3128").unwrap())
+            .no_proxy()
+            .build();
+        assert!(with_proxy.is_ok());
+    }
+
+    #[test]
+    fn test_retry_backoff() {
+        let none = HeaderMap::new();
+        let mut retry_after = HeaderMap::new();
+        retry_after.insert(reqwest::header::RETRY_AFTER, "10".parse().unwrap());
+
+        let retry = RetryPolicy::exponential(3);
+        let unavailable = StatusCode::SERVICE_UNAVAILABLE;
+        assert_eq!(
+            retry.backoff(2, unavailable, &none),
+            Some(Duration::from_secs(4))
+        );
+        assert_eq!(
+            retry.backoff(0, StatusCode::TOO_MANY_REQUESTS, &retry_after),
+            Some(Duration::from_secs(10))
+        );
+        assert_eq!(retry.backoff(3, unavailable, &none), None);
+        assert_eq!(retry.backoff(0, StatusCode::BAD_REQUEST, &none), None);
+        assert_eq!(RetryPolicy::none().backoff(0, unavailable, &none), None);
+    }
+
+    #[tokio::test]
+    async fn test_default_parameters() {
+        use crate::transport::MockTransport;
+        use crate::videos::{self, VideoFilter};
+        use crate::{i18n_regions::RegionCode, search::SafeSearch, ListApi};
+
+        let transport = Arc::new(
+            MockTransport::new()
+                .respond(reqwest::Method::GET, "search", 200, "{}")
+                .respond(reqwest::Method::GET, "videos", 200, "{}"),
+        );
+        let youtube = YouTube::builder("api-key")
+            .transport(transport.clone())
+            .default_region_code("KR")
+            .default_hl("ko")
+            .default_safe_search(SafeSearch::Strict)
+            .build()
+            .unwrap();
+        youtube.search().videos("rust").request().await.unwrap();
+        youtube
+            .search()
+            .videos("rust")
+            .region_code("JP")
+            .request()
+            .await
+            .unwrap();
+        youtube
+            .videos()
+            .most_popular(RegionCode::new("US").unwrap(), None)
+            .request()
+            .await
+            .unwrap();
+        youtube
+            .videos()
+            .list(vec![videos::Part::Id], VideoFilter::id(["Ks-_Mh1QhMc"]))
+            .request()
+            .await
+            .unwrap();
+
+        let queries = transport
+            .requests()
+            .into_iter()
+            .map(|(_, url)| url.query().unwrap().to_string())
+            .collect::<Vec<String>>();
+        assert!(queries[0].contains("regionCode=KR"));
+        assert!(queries[0].contains("safeSearch=strict"));
+        assert!(!queries[0].contains("hl="));
+        assert!(queries[1].contains("regionCode=JP"));
+        assert!(queries[2].contains("regionCode=US"));
+        assert!(queries[2].contains("hl=ko"));
+        // the default region code only applies to the charts
+        assert!(!queries[3].contains("regionCode"));
+        assert!(queries[3].contains("hl=ko"));
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        assert_eq!(
+            OutOfRange::Error
+                .apply("max_results", 50, None, Some(50))
+                .unwrap(),
+            50
+        );
+        assert_eq!(
+            OutOfRange::Clamp
+                .apply("max_results", 100, None, Some(50))
+                .unwrap(),
+            50
+        );
+        assert_eq!(
+            OutOfRange::Warn
+                .apply("max_height", 10, Some(72), Some(4320))
+                .unwrap(),
+            72
+        );
+        let err = OutOfRange::Error
+            .apply("max_height", 10, Some(72), Some(4320))
+            .unwrap_err();
+        assert_eq!(
+            "builder error: \"Request contains an invalid argument: `max_height` must be from 72 to 4320, not 10\"",
+            format!("{}", err)
+        );
+        let err = OutOfRange::Error
+            .apply("max_results", 100, None, Some(50))
+            .unwrap_err();
+        assert_eq!(
+            "builder error: \"Request contains an invalid argument: `max_results` must be at most 50, not 100\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_request_out_of_range() {
+        use crate::transport::MockTransport;
+        use crate::{search::Part, ListApi};
+
+        let transport =
+            Arc::new(MockTransport::new().respond(reqwest::Method::GET, "search", 200, "{}"));
+        let youtube = YouTube::builder("api-key")
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+        let mut list = youtube.search().list([Part::Id]);
+        list.max_results(100);
+        list.request().await.unwrap();
+        let requests = transport.requests();
+        assert!(requests[0].1.query().unwrap().contains("maxResults=50"));
+
+        let youtube = YouTube::builder("api-key")
+            .transport(transport.clone())
+            .out_of_range(OutOfRange::Error)
+            .build()
+            .unwrap();
+        let mut list = youtube.search().list([Part::Id]);
+        list.max_results(100);
+        assert!(list.request().await.is_err());
+        assert_eq!(transport.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit() {
+        let rate_limit = RateLimit::per_second(100);
+        let start = Instant::now();
+        for _ in 0..5 {
+            rate_limit.acquire().await;
+        }
+        assert!(start.elapsed() >= Duration::from_millis(40));
+    }
+}
@@ -0,0 +1,285 @@
+use crate::{
+    auth::{Authorizer, OAuthTokenAuth},
+    comments::{CommentBodySnippet, CommentListResource},
+    error::{Error, Result},
+    json, RequestBase, YouTube, YouTubeDataApi,
+};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[derive(Clone, Copy)]
+pub struct CommentThreadsService<'a> {
+    youtube: &'a YouTube,
+}
+
+impl<'a> CommentThreadsService<'a> {
+    pub(crate) fn new(youtube: &'a YouTube) -> Self {
+        Self { youtube }
+    }
+
+    pub fn insert(
+        self,
+        video_id: impl Into<String>,
+        text_original: impl Into<String>,
+    ) -> CommentThreadInsert<'a> {
+        CommentThreadInsert::new(self, video_id, text_original)
+    }
+}
+
+/// Parameters for the `insert` method of the `commentThreads` api. details:
+/// [link](https://developers.google.com/youtube/v3/docs/commentThreads/insert)
+pub struct CommentThreadInsert<'a> {
+    service: CommentThreadsService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    part: Vec<Part>,
+
+    // request body
+    video_id: String,
+    text_original: String,
+}
+
+impl RequestBase for CommentThreadInsert<'_> {
+    fn api_path(&self) -> &str {
+        "commentThreads"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for CommentThreadInsert<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> CommentThreadInsert<'a> {
+    pub(crate) fn new(
+        service: CommentThreadsService<'a>,
+        video_id: impl Into<String>,
+        text_original: impl Into<String>,
+    ) -> Self {
+        Self {
+            service,
+            authorizer: None,
+            part: vec![Part::Snippet],
+            video_id: video_id.into(),
+            text_original: text_original.into(),
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+
+    pub fn part(&mut self, part: Vec<Part>) -> &mut Self {
+        self.part = part;
+        self
+    }
+
+    /// Creates a new top-level comment on a video and returns the created comment thread.
+    pub async fn request(&self) -> Result<CommentThreadListResource> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        // required parameters
+        self.insert_query_parameters(&mut params, "part", Some(&self.part));
+
+        // request body
+        if self.video_id.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required property `snippet.video_id` is empty",
+            ));
+        }
+        if self.text_original.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required property `snippet.top_level_comment.snippet.text_original` is empty",
+            ));
+        }
+        let body = CommentThreadBody {
+            snippet: CommentThreadBodySnippet {
+                video_id: &self.video_id,
+                top_level_comment: TopLevelCommentBody {
+                    snippet: CommentBodySnippet {
+                        parent_id: None,
+                        text_original: &self.text_original,
+                    },
+                },
+            },
+        };
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .post(self.url(&youtube.inner().base_path))
+                .query(&params)
+                .json(&body),
+        )?;
+        let response = self.send(youtube, request).await?;
+        json(response).await
+    }
+}
+
+/// The request body of `commentThreads.insert`.
+#[derive(Serialize)]
+struct CommentThreadBody<'a> {
+    snippet: CommentThreadBodySnippet<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CommentThreadBodySnippet<'a> {
+    video_id: &'a str,
+    top_level_comment: TopLevelCommentBody<'a>,
+}
+
+#[derive(Serialize)]
+struct TopLevelCommentBody<'a> {
+    snippet: CommentBodySnippet<'a>,
+}
+
+pub enum Part {
+    Id,
+    Replies,
+    Snippet,
+}
+
+impl std::fmt::Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            Part::Id => "id",
+            Part::Replies => "replies",
+            Part::Snippet => "snippet",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommentThreadListResource {
+    /// Identifies the API resource's type. The value will be `youtube#commentThread`.
+    #[serde(default)]
+    pub kind: String,
+
+    /// The ETag of the response.
+    #[serde(default)]
+    pub etag: String,
+
+    /// The ID that YouTube uses to uniquely identify the comment thread.
+    #[serde(default)]
+    pub id: String,
+
+    pub snippet: Option<CommentThreadSnippet>,
+
+    pub replies: Option<CommentThreadReplies>,
+
+    /// The fields of the resource that this version of the crate does not know, e.g. added to the api after its
+    /// release. They are serialized back with the resource.
+    #[serde(flatten, deserialize_with = "crate::serde_helpers::extra_fields")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// The snippet object contains basic details about the comment thread. It also contains the thread's top-level
+/// comment, which is a comment resource.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentThreadSnippet {
+    /// The YouTube channel that is associated with the comments in the thread.
+    pub channel_id: Option<String>,
+
+    /// The ID of the video that the comments refer to.
+    pub video_id: Option<String>,
+
+    /// The thread's top-level comment.
+    #[serde(default)]
+    pub top_level_comment: CommentListResource,
+
+    /// This setting indicates whether the current viewer can reply to the thread.
+    #[serde(default)]
+    pub can_reply: bool,
+
+    /// The total number of replies (not including the top-level comment).
+    #[serde(default)]
+    pub total_reply_count: u32,
+
+    /// This setting indicates whether the thread, including all of its comments and comment replies, is visible to all
+    /// YouTube users.
+    #[serde(default)]
+    pub is_public: bool,
+}
+
+/// The replies object is a container that contains a list of replies to the comment, if any exist.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommentThreadReplies {
+    /// A list of one or more replies to the top-level comment.
+    #[serde(default)]
+    pub comments: Vec<CommentListResource>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{get_youtube_without_user_agent, oauth::Scope};
+
+    #[tokio::test]
+    async fn test_insert_without_auth() {
+        let without_auth = get_youtube_without_user_agent()
+            .comment_threads()
+            .insert("Ks-_Mh1QhMc", "First!")
+            .request()
+            .await;
+        assert!(without_auth.is_err());
+        let err = without_auth.unwrap_err();
+        assert_eq!(
+            "builder error: \"The request to `commentThreads` requires OAuth 2.0 authorization\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_insert_with_insufficient_scopes() {
+        let readonly = get_youtube_without_user_agent()
+            .with_access_token("access-token")
+            .with_scopes(&[Scope::YoutubeReadonly])
+            .comment_threads()
+            .insert("Ks-_Mh1QhMc", "First!")
+            .request()
+            .await;
+        assert!(readonly.is_err());
+        let err = readonly.unwrap_err();
+        assert_eq!(
+            "builder error: \"The request to `commentThreads` requires one of the scopes: https://www.googleapis.com/auth/youtube.force-ssl https://www.googleapis.com/auth/youtubepartner\"",
+            format!("{}", err)
+        );
+    }
+
+    #[test]
+    fn test_serialize_comment_thread_body() {
+        let body = CommentThreadBody {
+            snippet: CommentThreadBodySnippet {
+                video_id: "Ks-_Mh1QhMc",
+                top_level_comment: TopLevelCommentBody {
+                    snippet: CommentBodySnippet {
+                        parent_id: None,
+                        text_original: "First!",
+                    },
+                },
+            },
+        };
+        assert_eq!(
+            r#"{"snippet":{"videoId":"Ks-_Mh1QhMc","topLevelComment":{"snippet":{"textOriginal":"First!"}}}}"#,
+            serde_json::to_string(&body).unwrap()
+        );
+    }
+}
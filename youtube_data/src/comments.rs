@@ -0,0 +1,1007 @@
+use crate::{
+    auth::{Authorizer, OAuthTokenAuth},
+    cursor::PageCursor,
+    error::{Error, Result},
+    json, list_json, ListApi, ListResponse, PagedListApi, RequestBase, YouTube, YouTubeDataApi,
+};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use youtube_data_derive::QueryBuilder;
+
+pub type CommentListResponse = ListResponse<CommentListResource>;
+
+#[derive(Clone, Copy)]
+pub struct CommentsService<'a> {
+    youtube: &'a YouTube,
+}
+
+impl<'a> CommentsService<'a> {
+    pub(crate) fn new(youtube: &'a YouTube) -> Self {
+        Self { youtube }
+    }
+
+    pub fn list(self, part: impl IntoIterator<Item = Part>) -> CommentList<'a> {
+        CommentList::new(self, part)
+    }
+
+    pub fn insert(
+        self,
+        parent_id: impl Into<String>,
+        text_original: impl Into<String>,
+    ) -> CommentInsert<'a> {
+        CommentInsert::new(self, parent_id, text_original)
+    }
+
+    pub fn update(
+        self,
+        id: impl Into<String>,
+        text_original: impl Into<String>,
+    ) -> CommentUpdate<'a> {
+        CommentUpdate::new(self, id, text_original)
+    }
+
+    pub fn set_moderation_status(
+        self,
+        id: impl IntoIterator<Item = impl Into<String>>,
+        moderation_status: ModerationStatus,
+        ban_author: bool,
+    ) -> CommentSetModerationStatus<'a> {
+        CommentSetModerationStatus::new(self, id, moderation_status, ban_author)
+    }
+
+    pub fn mark_as_spam(
+        self,
+        id: impl IntoIterator<Item = impl Into<String>>,
+    ) -> CommentMarkAsSpam<'a> {
+        CommentMarkAsSpam::new(self, id)
+    }
+
+    pub fn delete(self, id: impl Into<String>) -> CommentDelete<'a> {
+        CommentDelete::new(self, id)
+    }
+}
+
+/// Parameters for the `list` method of the `comments` api. details:
+/// [link](https://developers.google.com/youtube/v3/docs/comments/list)
+#[derive(QueryBuilder)]
+pub struct CommentList<'a> {
+    service: CommentsService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    #[query(unique)]
+    part: Vec<Part>,
+
+    // filters (specify exactly one of the following parameters)
+    #[query(filter)]
+    id: Option<Vec<String>>,
+    #[query(filter)]
+    parent_id: Option<String>,
+
+    // optional parameters
+    /// Selects the fields of the response, e.g. `items(id,snippet/title)`, so only the needed part of every
+    /// resource is returned. details: [link](https://developers.google.com/youtube/v3/getting-started#fields)
+    #[query]
+    fields: Option<String>,
+    /// Fails the request with a timeout error when it does not complete in time, instead of the timeout of the
+    /// `YouTube` handle.
+    #[setter]
+    timeout: Option<Duration>,
+    #[query(min = 1, max = 100)]
+    max_results: Option<u32>,
+    #[setter]
+    page_token: Option<String>,
+    #[query]
+    text_format: Option<TextFormat>,
+}
+
+impl RequestBase for CommentList<'_> {
+    fn api_path(&self) -> &str {
+        "comments"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for CommentList<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+
+    fn request_timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl ListApi<CommentListResponse> for CommentList<'_> {
+    async fn request(&self) -> Result<CommentListResponse> {
+        self.request_page(None).await
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl PagedListApi<CommentListResource> for CommentList<'_> {
+    async fn request_page(&self, page_token: Option<&str>) -> Result<CommentListResponse> {
+        let youtube = &self.service.youtube;
+        let params = self.params(page_token)?;
+
+        let response = self
+            .send(
+                youtube,
+                youtube
+                    .inner()
+                    .client
+                    .get(self.url(&youtube.inner().base_path))
+                    .query(&params),
+            )
+            .await?;
+        list_json(response).await
+    }
+
+    fn cursor(&self, page_token: Option<&str>) -> Result<PageCursor> {
+        Ok(PageCursor::new(self.api_path(), self.params(page_token)?))
+    }
+}
+
+impl CommentList<'_> {
+    /// The query parameters of the page of `page_token`, or of the `page_token` of the builder for `None`.
+    fn params(&self, page_token: Option<&str>) -> Result<HashMap<String, String>> {
+        let page_token = page_token.or(self.page_token.as_deref());
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        self.insert_query(self.service.youtube, &mut params)?;
+        // `maxResults` and `pageToken` are not supported for use in conjunction with the `id` parameter.
+        if self.id.is_some() && (self.max_results.is_some() || page_token.is_some()) {
+            return Err(Error::incompatible_parameters(
+                "Incompatible parameters specified in the request: id, max_results, page_token",
+            ));
+        }
+        self.insert_query_parameter(&mut params, "pageToken", page_token);
+
+        Ok(params)
+    }
+}
+
+impl<'a> CommentList<'a> {
+    pub(crate) fn new(service: CommentsService<'a>, part: impl IntoIterator<Item = Part>) -> Self {
+        let part = crate::dedupe(part);
+        let part = if part.is_empty() {
+            vec![Part::Snippet]
+        } else {
+            part
+        };
+        Self {
+            service,
+            authorizer: None,
+            part,
+            id: None,
+            parent_id: None,
+            fields: None,
+            timeout: None,
+            max_results: None,
+            page_token: None,
+            text_format: None,
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+}
+
+/// Parameters for the `insert` method of the `comments` api. details:
+/// [link](https://developers.google.com/youtube/v3/docs/comments/insert)
+pub struct CommentInsert<'a> {
+    service: CommentsService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    part: Vec<Part>,
+
+    // request body
+    parent_id: String,
+    text_original: String,
+}
+
+impl RequestBase for CommentInsert<'_> {
+    fn api_path(&self) -> &str {
+        "comments"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for CommentInsert<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> CommentInsert<'a> {
+    pub(crate) fn new(
+        service: CommentsService<'a>,
+        parent_id: impl Into<String>,
+        text_original: impl Into<String>,
+    ) -> Self {
+        Self {
+            service,
+            authorizer: None,
+            part: vec![Part::Snippet],
+            parent_id: parent_id.into(),
+            text_original: text_original.into(),
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+
+    pub fn part(&mut self, part: Vec<Part>) -> &mut Self {
+        self.part = part;
+        self
+    }
+
+    /// Creates a reply to an existing comment and returns the created comment. Top-level comments are created with
+    /// `comment_threads().insert`.
+    pub async fn request(&self) -> Result<CommentListResource> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        // required parameters
+        self.insert_query_parameters(&mut params, "part", Some(&self.part));
+
+        // request body
+        if self.parent_id.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required property `snippet.parent_id` is empty",
+            ));
+        }
+        if self.text_original.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required property `snippet.text_original` is empty",
+            ));
+        }
+        let body = CommentBody {
+            id: None,
+            snippet: CommentBodySnippet {
+                parent_id: Some(&self.parent_id),
+                text_original: &self.text_original,
+            },
+        };
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .post(self.url(&youtube.inner().base_path))
+                .query(&params)
+                .json(&body),
+        )?;
+        let response = self.send(youtube, request).await?;
+        json(response).await
+    }
+}
+
+/// Parameters for the `update` method of the `comments` api. details:
+/// [link](https://developers.google.com/youtube/v3/docs/comments/update)
+pub struct CommentUpdate<'a> {
+    service: CommentsService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    part: Vec<Part>,
+
+    // request body
+    id: String,
+    text_original: String,
+}
+
+impl RequestBase for CommentUpdate<'_> {
+    fn api_path(&self) -> &str {
+        "comments"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for CommentUpdate<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> CommentUpdate<'a> {
+    pub(crate) fn new(
+        service: CommentsService<'a>,
+        id: impl Into<String>,
+        text_original: impl Into<String>,
+    ) -> Self {
+        Self {
+            service,
+            authorizer: None,
+            part: vec![Part::Snippet],
+            id: id.into(),
+            text_original: text_original.into(),
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+
+    pub fn part(&mut self, part: Vec<Part>) -> &mut Self {
+        self.part = part;
+        self
+    }
+
+    /// Modifies the text of a comment and returns the updated comment.
+    pub async fn request(&self) -> Result<CommentListResource> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        // required parameters
+        self.insert_query_parameters(&mut params, "part", Some(&self.part));
+
+        // request body
+        if self.id.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required property `id` is empty",
+            ));
+        }
+        if self.text_original.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required property `snippet.text_original` is empty",
+            ));
+        }
+        let body = CommentBody {
+            id: Some(&self.id),
+            snippet: CommentBodySnippet {
+                parent_id: None,
+                text_original: &self.text_original,
+            },
+        };
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .put(self.url(&youtube.inner().base_path))
+                .query(&params)
+                .json(&body),
+        )?;
+        let response = self.send(youtube, request).await?;
+        json(response).await
+    }
+}
+
+/// Parameters for the `setModerationStatus` method of the `comments` api. details:
+/// [link](https://developers.google.com/youtube/v3/docs/comments/setModerationStatus)
+pub struct CommentSetModerationStatus<'a> {
+    service: CommentsService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    id: Vec<String>,
+    moderation_status: ModerationStatus,
+
+    // optional parameters
+    ban_author: bool,
+}
+
+impl RequestBase for CommentSetModerationStatus<'_> {
+    fn api_path(&self) -> &str {
+        "comments/setModerationStatus"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for CommentSetModerationStatus<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> CommentSetModerationStatus<'a> {
+    pub(crate) fn new(
+        service: CommentsService<'a>,
+        id: impl IntoIterator<Item = impl Into<String>>,
+        moderation_status: ModerationStatus,
+        ban_author: bool,
+    ) -> Self {
+        Self {
+            service,
+            authorizer: None,
+            id: id.into_iter().map(Into::into).collect(),
+            moderation_status,
+            ban_author,
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+
+    /// Sets the moderation status of one or more comments. The api responds with an empty body on success.
+    pub async fn request(&self) -> Result<()> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        // required parameters
+        if self.id.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required parameter `id` is empty",
+            ));
+        }
+        self.insert_query_parameters(&mut params, "id", Some(&self.id));
+        self.insert_query_parameter(
+            &mut params,
+            "moderationStatus",
+            Some(&self.moderation_status),
+        );
+
+        // optional parameters
+        if self.ban_author {
+            // the author can only be banned when the comment is rejected
+            if self.moderation_status != ModerationStatus::Rejected {
+                return Err(Error::incompatible_parameters(
+                    "Incompatible parameters specified in the request: ban_author, moderation_status",
+                ));
+            }
+            self.insert_query_parameter(&mut params, "banAuthor", Some(self.ban_author));
+        }
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .post(self.url(&youtube.inner().base_path))
+                .query(&params),
+        )?;
+        self.send_without_content(youtube, request).await
+    }
+}
+
+/// Parameters for the `markAsSpam` method of the `comments` api. details:
+/// [link](https://developers.google.com/youtube/v3/docs/comments/markAsSpam)
+pub struct CommentMarkAsSpam<'a> {
+    service: CommentsService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    id: Vec<String>,
+}
+
+impl RequestBase for CommentMarkAsSpam<'_> {
+    fn api_path(&self) -> &str {
+        "comments/markAsSpam"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for CommentMarkAsSpam<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> CommentMarkAsSpam<'a> {
+    pub(crate) fn new(
+        service: CommentsService<'a>,
+        id: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        Self {
+            service,
+            authorizer: None,
+            id: id.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+
+    /// Expresses the caller's opinion that one or more comments should be flagged as spam. The api responds with an
+    /// empty body on success.
+    pub async fn request(&self) -> Result<()> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        // required parameters
+        if self.id.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required parameter `id` is empty",
+            ));
+        }
+        self.insert_query_parameters(&mut params, "id", Some(&self.id));
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .post(self.url(&youtube.inner().base_path))
+                .query(&params),
+        )?;
+        self.send_without_content(youtube, request).await
+    }
+}
+
+/// Parameters for the `delete` method of the `comments` api. details:
+/// [link](https://developers.google.com/youtube/v3/docs/comments/delete)
+pub struct CommentDelete<'a> {
+    service: CommentsService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    id: String,
+}
+
+impl RequestBase for CommentDelete<'_> {
+    fn api_path(&self) -> &str {
+        "comments"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for CommentDelete<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> CommentDelete<'a> {
+    pub(crate) fn new(service: CommentsService<'a>, id: impl Into<String>) -> Self {
+        Self {
+            service,
+            authorizer: None,
+            id: id.into(),
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+
+    /// Deletes a comment. The api responds with an empty body on success.
+    pub async fn request(&self) -> Result<()> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        // required parameters
+        if self.id.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required parameter `id` is empty",
+            ));
+        }
+        self.insert_query_parameter(&mut params, "id", Some(&self.id));
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .delete(self.url(&youtube.inner().base_path))
+                .query(&params),
+        )?;
+        self.send_without_content(youtube, request).await
+    }
+}
+
+/// The request body of `comments.insert` and `comments.update`.
+#[derive(Serialize)]
+pub(crate) struct CommentBody<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<&'a str>,
+
+    pub snippet: CommentBodySnippet<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CommentBodySnippet<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<&'a str>,
+
+    pub text_original: &'a str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    Id,
+    Snippet,
+}
+
+impl Part {
+    /// Every part of the resource, to request all of its properties.
+    pub fn all_public() -> Vec<Part> {
+        vec![Part::Id, Part::Snippet]
+    }
+}
+
+impl std::fmt::Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            Part::Id => "id",
+            Part::Snippet => "snippet",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+/// The textFormat parameter indicates whether the API should return comments formatted as HTML or as plain text.
+pub enum TextFormat {
+    /// Returns the comments in HTML format. This is the default value.
+    Html,
+
+    /// Returns the comments in plain text format.
+    PlainText,
+}
+
+impl std::fmt::Display for TextFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            TextFormat::Html => "html",
+            TextFormat::PlainText => "plainText",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+/// The moderation status of a comment.
+#[derive(Debug, PartialEq)]
+pub enum ModerationStatus {
+    /// Marks a comment as awaiting review by a moderator.
+    HeldForReview,
+
+    /// Clears a comment for public display.
+    Published,
+
+    /// Rejects a comment as being unfit for display. This action also effectively hides all replies to the rejected
+    /// comment.
+    Rejected,
+}
+
+impl std::fmt::Display for ModerationStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            ModerationStatus::HeldForReview => "heldForReview",
+            ModerationStatus::Published => "published",
+            ModerationStatus::Rejected => "rejected",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommentListResource {
+    /// Identifies the API resource's type. The value will be `youtube#comment`.
+    #[serde(default)]
+    pub kind: String,
+
+    /// The ETag of the response.
+    #[serde(default)]
+    pub etag: String,
+
+    /// The ID that YouTube uses to uniquely identify the comment.
+    #[serde(default)]
+    pub id: String,
+
+    pub snippet: Option<CommentSnippet>,
+
+    /// The fields of the resource that this version of the crate does not know, e.g. added to the api after its
+    /// release. They are serialized back with the resource.
+    #[serde(flatten, deserialize_with = "crate::serde_helpers::extra_fields")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// The snippet object contains basic details about the comment.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentSnippet {
+    /// The display name of the user who posted the comment.
+    #[serde(default)]
+    pub author_display_name: String,
+
+    /// The URL for the avatar of the user who posted the comment.
+    pub author_profile_image_url: Option<String>,
+
+    /// The URL of the comment author's YouTube channel, if available.
+    pub author_channel_url: Option<String>,
+
+    /// This object encapsulates information about the comment author's YouTube channel, if available.
+    pub author_channel_id: Option<AuthorChannelId>,
+
+    /// The ID of the YouTube channel associated with the comment.
+    pub channel_id: Option<String>,
+
+    /// The comment's text. The text can be retrieved in either plain text or HTML. (The
+    /// [comments.list](https://developers.google.com/youtube/v3/docs/comments/list) and
+    /// [commentThreads.list](https://developers.google.com/youtube/v3/docs/commentThreads/list) methods both support
+    /// a `textFormat` parameter, which specifies the desired text format.)
+    ///
+    /// Note that even the plain text may differ from the original comment text. For example, it may replace video
+    /// links with video titles.
+    #[serde(default)]
+    pub text_display: String,
+
+    /// The original, raw text of the comment as it was initially posted or last updated. The original text is only
+    /// returned if it is accessible to the authenticated user, which is only guaranteed if the user is the comment's author.
+    pub text_original: Option<String>,
+
+    /// The unique ID of the parent comment. This property is only set if the comment was submitted as a reply to
+    /// another comment.
+    pub parent_id: Option<String>,
+
+    /// This setting indicates whether the current viewer can rate the comment.
+    #[serde(default)]
+    pub can_rate: bool,
+
+    /// The rating the viewer has given to this comment. This property does not currently identify `dislike` ratings,
+    /// though this behavior is subject to change.
+    ///
+    /// Valid values for this property are:
+    ///
+    /// * like
+    /// * none
+    #[serde(default)]
+    pub viewer_rating: String,
+
+    /// The total number of likes (positive ratings) the comment has received.
+    #[serde(default)]
+    pub like_count: u64,
+
+    /// The comment's moderation status. This property is only returned if the API request was authorized by the owner
+    /// of the channel or the video on which the requested comments were made.
+    ///
+    /// Valid values for this property are:
+    ///
+    /// * heldForReview
+    /// * likelySpam
+    /// * published
+    /// * rejected
+    pub moderation_status: Option<String>,
+
+    /// The date and time when the comment was originally published. The value is specified in
+    /// [ISO 8601](https://www.w3.org/TR/NOTE-datetime) format.
+    pub published_at: Option<DateTime<Utc>>,
+
+    /// The date and time when the comment was last updated. The value is specified in
+    /// [ISO 8601](https://www.w3.org/TR/NOTE-datetime) format.
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuthorChannelId {
+    /// The ID of the comment author's YouTube channel.
+    #[serde(default)]
+    pub value: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::MockTransport;
+    use crate::{get_youtube_with_transport, get_youtube_without_user_agent};
+
+    #[tokio::test]
+    async fn test_get_by_parent_id() {
+        let transport = Arc::new(MockTransport::new().respond(
+            reqwest::Method::GET,
+            "comments",
+            200,
+            r#"{
+                "kind": "youtube#commentListResponse",
+                "items": [{
+                    "kind": "youtube#comment",
+                    "id": "UgzDE2tasfmrYLyNkGt4AaABAg.8wWQ4tB1Ub48wWQbkVT8Ry",
+                    "snippet": {
+                        "authorDisplayName": "A viewer",
+                        "authorChannelId": { "value": "UCBR8-60-B28hp2BmDPdntcQ" },
+                        "textDisplay": "Thanks!",
+                        "textOriginal": "Thanks!",
+                        "parentId": "UgzDE2tasfmrYLyNkGt4AaABAg",
+                        "canRate": true,
+                        "viewerRating": "none",
+                        "likeCount": 3,
+                        "publishedAt": "2020-01-01T00:00:00Z",
+                        "updatedAt": "2020-01-01T00:00:00Z"
+                    }
+                }]
+            }"#,
+        ));
+        let response = get_youtube_with_transport(transport.clone())
+            .comments()
+            .list(vec![Part::Snippet])
+            .parent_id("UgzDE2tasfmrYLyNkGt4AaABAg")
+            .max_results(5)
+            .request()
+            .await
+            .unwrap();
+        assert_eq!(response.items.len(), 1);
+        let snippet = response.items[0].snippet.as_ref().unwrap();
+        assert_eq!(
+            snippet.parent_id.as_deref(),
+            Some("UgzDE2tasfmrYLyNkGt4AaABAg")
+        );
+        assert_eq!(snippet.author_display_name, "A viewer");
+        assert_eq!(
+            snippet.author_channel_id.as_ref().unwrap().value,
+            "UCBR8-60-B28hp2BmDPdntcQ"
+        );
+        assert_eq!(snippet.text_display, "Thanks!");
+        assert_eq!(snippet.like_count, 3);
+        let query = transport.requests()[0].1.query().unwrap().to_string();
+        assert!(query.contains("parentId=UgzDE2tasfmrYLyNkGt4AaABAg"));
+        assert!(query.contains("maxResults=5"));
+    }
+
+    #[tokio::test]
+    async fn test_request_without_filters() {
+        let without_filters = get_youtube_without_user_agent()
+            .comments()
+            .list(vec![])
+            .request()
+            .await;
+        assert!(without_filters.is_err());
+        let err = without_filters.unwrap_err();
+        assert_eq!(
+            "builder error: \"No filter selected. Expected one of: id, parent_id\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_request_id_with_max_results() {
+        let incompatible = get_youtube_without_user_agent()
+            .comments()
+            .list(vec![])
+            .id(vec!["something"])
+            .max_results(10)
+            .request()
+            .await;
+        assert!(incompatible.is_err());
+        let err = incompatible.unwrap_err();
+        assert_eq!(
+            "builder error: \"Incompatible parameters specified in the request: id, max_results, page_token\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_insert_without_auth() {
+        let without_auth = get_youtube_without_user_agent()
+            .comments()
+            .insert("UgzDE2tasfmrYLyNkGt4AaABAg", "Thanks for watching!")
+            .request()
+            .await;
+        assert!(without_auth.is_err());
+        let err = without_auth.unwrap_err();
+        assert_eq!(
+            "builder error: \"The request to `comments` requires OAuth 2.0 authorization\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_with_empty_text() {
+        let empty_text = get_youtube_without_user_agent()
+            .comments()
+            .update("UgzDE2tasfmrYLyNkGt4AaABAg", "")
+            .request()
+            .await;
+        assert!(empty_text.is_err());
+        let err = empty_text.unwrap_err();
+        assert_eq!(
+            "builder error: \"Required property `snippet.text_original` is empty\"",
+            format!("{}", err)
+        );
+    }
+
+    #[test]
+    fn test_serialize_comment_body() {
+        let body = CommentBody {
+            id: None,
+            snippet: CommentBodySnippet {
+                parent_id: Some("UgzDE2tasfmrYLyNkGt4AaABAg"),
+                text_original: "Thanks for watching!",
+            },
+        };
+        assert_eq!(
+            r#"{"snippet":{"parentId":"UgzDE2tasfmrYLyNkGt4AaABAg","textOriginal":"Thanks for watching!"}}"#,
+            serde_json::to_string(&body).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_moderation_status_ban_author_without_rejected() {
+        let ban_author = get_youtube_without_user_agent()
+            .comments()
+            .set_moderation_status(
+                vec!["UgzDE2tasfmrYLyNkGt4AaABAg"],
+                ModerationStatus::Published,
+                true,
+            )
+            .request()
+            .await;
+        assert!(ban_author.is_err());
+        let err = ban_author.unwrap_err();
+        assert_eq!(
+            "builder error: \"Incompatible parameters specified in the request: ban_author, moderation_status\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_mark_as_spam_without_auth() {
+        let without_auth = get_youtube_without_user_agent()
+            .comments()
+            .mark_as_spam(vec!["UgzDE2tasfmrYLyNkGt4AaABAg"])
+            .request()
+            .await;
+        assert!(without_auth.is_err());
+        let err = without_auth.unwrap_err();
+        assert_eq!(
+            "builder error: \"The request to `comments/markAsSpam` requires OAuth 2.0 authorization\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_with_empty_id() {
+        let empty_id = get_youtube_without_user_agent()
+            .comments()
+            .delete("")
+            .request()
+            .await;
+        assert!(empty_id.is_err());
+        let err = empty_id.unwrap_err();
+        assert_eq!(
+            "builder error: \"Required parameter `id` is empty\"",
+            format!("{}", err)
+        );
+    }
+}
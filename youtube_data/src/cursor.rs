@@ -0,0 +1,167 @@
+//! Cursors of paged list requests. A [PageCursor] is saved while a long crawl runs and resumed with
+//! [YouTube::resume] after a restart, instead of starting again from the first page.
+
+use crate::{
+    auth::Authorizer,
+    error::Result,
+    list_json,
+    videos::{merge_chunks, poll_list, ChunkedList, MAX_IDS_PER_REQUEST},
+    BoxStream, ListApi, ListResponse, PagedListApi, RequestBase, YouTube, YouTubeDataApi,
+};
+
+use async_trait::async_trait;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// The endpoint, the query parameters and the page token of a paged list request, see [PagedListApi::cursor]. The
+/// credentials are not part of the cursor: the handle resuming it attaches its own.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PageCursor {
+    /// The api path of the endpoint, e.g. `playlistItems`.
+    pub endpoint: String,
+
+    /// The query parameters without the page token, sorted by name.
+    pub params: Vec<(String, String)>,
+
+    /// The page to request, `None` for the first page.
+    pub page_token: Option<String>,
+}
+
+impl PageCursor {
+    pub(crate) fn new(endpoint: &str, mut params: HashMap<String, String>) -> PageCursor {
+        let page_token = params.remove("pageToken");
+        let mut params = params.into_iter().collect::<Vec<(String, String)>>();
+        params.sort();
+        PageCursor {
+            endpoint: endpoint.to_string(),
+            params,
+            page_token,
+        }
+    }
+}
+
+/// A paged list request resumed from a [PageCursor], see [YouTube::resume]. `T` is the resource of the endpoint, e.g.
+/// `PlaylistItemListResource` for `playlistItems`.
+pub struct CursorList<'a, T> {
+    youtube: &'a YouTube,
+    cursor: PageCursor,
+    resource: PhantomData<fn() -> T>,
+}
+
+impl<'a, T> CursorList<'a, T> {
+    pub(crate) fn new(youtube: &'a YouTube, cursor: PageCursor) -> CursorList<'a, T> {
+        CursorList {
+            youtube,
+            cursor,
+            resource: PhantomData,
+        }
+    }
+}
+
+impl<T> RequestBase for CursorList<'_, T> {
+    fn api_path(&self) -> &str {
+        &self.cursor.endpoint
+    }
+}
+
+impl<T> YouTubeDataApi for CursorList<'_, T> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        None
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl<T> ListApi<ListResponse<T>> for CursorList<'_, T>
+where
+    T: Serialize + DeserializeOwned + Clone + Send + 'static,
+{
+    async fn request(&self) -> Result<ListResponse<T>> {
+        self.request_page(None).await
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl<T> PagedListApi<T> for CursorList<'_, T>
+where
+    T: Serialize + DeserializeOwned + Clone + Send + 'static,
+{
+    async fn request_page(&self, page_token: Option<&str>) -> Result<ListResponse<T>> {
+        let chunks = self.chunks(page_token)?;
+        if let [params] = chunks.as_slice() {
+            return self.request_chunk(params).await;
+        }
+        let mut pages = Vec::new();
+        for params in &chunks {
+            pages.push(self.request_chunk(params).await?);
+        }
+        Ok(self.merge(pages))
+    }
+
+    fn cursor(&self, page_token: Option<&str>) -> Result<PageCursor> {
+        Ok(PageCursor {
+            page_token: page_token
+                .map(ToString::to_string)
+                .or_else(|| self.cursor.page_token.clone()),
+            ..self.cursor.clone()
+        })
+    }
+
+    fn poll(&self, interval: Duration) -> BoxStream<'_, Result<ListResponse<T>>>
+    where
+        Self: Sync,
+    {
+        poll_list(self, interval)
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl<T> ChunkedList<T> for CursorList<'_, T>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    fn chunks(&self, page_token: Option<&str>) -> Result<Vec<Vec<(String, String)>>> {
+        let mut params = self.cursor.params.clone();
+        if let Some(page_token) = page_token.or(self.cursor.page_token.as_deref()) {
+            params.push(("pageToken".to_string(), page_token.to_string()));
+        }
+
+        // the cursor of a `VideoList` of more than 50 ids is requested in chunks, as the list itself is
+        let id = match params.iter().position(|(key, _)| key == "id") {
+            Some(position) if self.cursor.endpoint == "videos" => params.remove(position).1,
+            _ => return Ok(vec![params]),
+        };
+        let id = id.split(',').collect::<Vec<&str>>();
+        Ok(id
+            .chunks(MAX_IDS_PER_REQUEST)
+            .map(|chunk| {
+                let mut params = params.clone();
+                params.push(("id".to_string(), chunk.join(",")));
+                params
+            })
+            .collect())
+    }
+
+    async fn request_chunk(&self, params: &[(String, String)]) -> Result<ListResponse<T>> {
+        let youtube = self.youtube;
+        let request = self.optional_bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .get(self.url(&youtube.inner().base_path))
+                .query(params),
+        )?;
+        let response = self.send(youtube, request).await?;
+        list_json(response).await
+    }
+
+    fn merge(&self, pages: Vec<ListResponse<T>>) -> ListResponse<T> {
+        merge_chunks(pages)
+    }
+}
@@ -1,6 +1,6 @@
 use http::StatusCode;
 use reqwest::Url;
-use std::{error::Error as StdError, fmt};
+use std::{error::Error as StdError, fmt, time::Duration};
 use thiserror::Error;
 
 /// A `Result` alias where the `Err` case is `youtube_data::Error`.
@@ -15,16 +15,22 @@ pub struct Error(#[from] ErrorRepr);
 impl From<reqwest::Error> for Error {
     #[cfg(not(debug_assertions))]
     fn from(value: reqwest::Error) -> Self {
-        Error::new(
-            ErrorKind::ReqwestError,
-            Some(value.without_url()),
-            None::<Url>,
-        )
+        let kind = reqwest_error_kind(&value);
+        Error::new(kind, Some(value.without_url()), None::<Url>)
     }
 
     #[cfg(debug_assertions)]
     fn from(value: reqwest::Error) -> Self {
-        Error::new(ErrorKind::ReqwestError, Some(value), None::<Url>)
+        Error::new(reqwest_error_kind(&value), Some(value), None::<Url>)
+    }
+}
+
+/// Timeouts get their own kind, so that a slow api can be told apart from a failed request.
+fn reqwest_error_kind(error: &reqwest::Error) -> ErrorKind {
+    if error.is_timeout() {
+        ErrorKind::Timeout
+    } else {
+        ErrorKind::ReqwestError
     }
 }
 
@@ -49,13 +55,11 @@ impl Error {
     where
         E: Into<BoxError>,
     {
-        Error {
-            0: ErrorRepr {
-                kind,
-                source: source.map(Into::into),
-                url: replace_sensitive_query_params(url),
-            },
-        }
+        Error(ErrorRepr {
+            kind,
+            source: source.map(Into::into),
+            url: replace_sensitive_query_params(url),
+        })
     }
 
     pub(crate) fn invalid_parameter(message: impl Into<String>) -> Error {
@@ -101,6 +105,149 @@ impl Error {
     pub(crate) fn client_error(source: YouTubeError, url: Url) -> Error {
         Error::new(ErrorKind::ClientError, Some(source), Some(url))
     }
+
+    pub(crate) fn server_error(source: YouTubeError, url: Url) -> Error {
+        Error::new(ErrorKind::ServerError, Some(source), Some(url))
+    }
+
+    pub(crate) fn not_modified(url: Url) -> Error {
+        Error::new(ErrorKind::NotModified, None::<Error>, Some(url))
+    }
+
+    pub(crate) fn cancelled() -> Error {
+        Error::new(ErrorKind::Cancelled, None::<Error>, None::<Url>)
+    }
+
+    /// A request that the crate timed out itself, on wasm32 where reqwest has no timeouts.
+    #[cfg(target_arch = "wasm32")]
+    pub(crate) fn timed_out() -> Error {
+        Error::new(ErrorKind::Timeout, None::<Error>, None::<Url>)
+    }
+
+    pub(crate) fn oauth_error(source: OAuthError) -> Error {
+        Error::new(ErrorKind::OAuthError, Some(source), None::<Url>)
+    }
+
+    pub(crate) fn decode_error(source: DecodeError, url: Option<Url>) -> Error {
+        Error::new(ErrorKind::DecodeError, Some(source), url)
+    }
+
+    /// The body and the failing field of a response that did not match the expected schema.
+    pub fn as_decode_error(&self) -> Option<&DecodeError> {
+        self.0.source.as_ref()?.downcast_ref::<DecodeError>()
+    }
+
+    /// The kind of the error, to tell e.g. an invalid parameter from an error of the api without matching the
+    /// message.
+    pub fn kind(&self) -> &ErrorKind {
+        &self.0.kind
+    }
+
+    /// The HTTP status code of the response that caused the error, if a response was received.
+    pub fn status(&self) -> Option<StatusCode> {
+        if let Some(error) = self.youtube_error() {
+            return Some(error.code());
+        }
+        self.0
+            .source
+            .as_ref()?
+            .downcast_ref::<reqwest::Error>()?
+            .status()
+            .and_then(|status| StatusCode::from_u16(status.as_u16()).ok())
+    }
+
+    /// The name of the parameter the api rejected, e.g. `id` for an invalid video id.
+    pub fn parameter(&self) -> Option<&str> {
+        self.youtube_error()?
+            .errors()
+            .iter()
+            .find(|detail| detail.location_type.as_deref() == Some("parameter"))?
+            .location
+            .as_deref()
+    }
+
+    /// The path and the query parameters of the request that caused the error, with the api key replaced by
+    /// `[API_KEY]`.
+    pub fn url(&self) -> Option<&str> {
+        self.0.url.as_deref()
+    }
+
+    /// The error returned by the api, if the request was rejected by it.
+    fn youtube_error(&self) -> Option<&YouTubeError> {
+        self.0.source.as_ref()?.downcast_ref::<YouTubeError>()
+    }
+
+    /// Whether the project of the api key exhausted its daily quota (`quotaExceeded`). Retrying before the quota is
+    /// reset at midnight Pacific Time is pointless.
+    pub fn is_quota_exceeded(&self) -> bool {
+        self.youtube_error()
+            .is_some_and(|error| error.is_quota_exceeded())
+    }
+
+    /// Whether the request was rejected because too many requests were sent in a short time (`429 Too Many Requests`
+    /// or `rateLimitExceeded`). The request can be retried after a while, see [Error::retry_after].
+    pub fn is_rate_limited(&self) -> bool {
+        self.youtube_error()
+            .is_some_and(|error| error.is_rate_limited())
+    }
+
+    /// Whether the api answered `404 Not Found`, e.g. for a deleted video.
+    pub fn is_not_found(&self) -> bool {
+        self.youtube_error()
+            .is_some_and(|error| error.code() == StatusCode::NOT_FOUND)
+    }
+
+    /// Whether the api answered `403 Forbidden`, e.g. for missing permissions or an exceeded quota.
+    pub fn is_forbidden(&self) -> bool {
+        self.youtube_error()
+            .is_some_and(|error| error.code() == StatusCode::FORBIDDEN)
+    }
+
+    /// The delay the api asked for with the `Retry-After` header before the request is sent again.
+    pub fn retry_after(&self) -> Option<Duration> {
+        self.youtube_error().and_then(|error| error.retry_after)
+    }
+
+    /// Whether the connection to the server could not be established. The `fetch` of wasm32 does not tell.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn is_connect(&self) -> bool {
+        self.0
+            .source
+            .as_ref()
+            .and_then(|source| source.downcast_ref::<reqwest::Error>())
+            .is_some_and(|source| source.is_connect())
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub(crate) fn is_connect(&self) -> bool {
+        false
+    }
+
+    /// Whether the api answered `304 Not Modified` to a conditional request: the resource did not change since the
+    /// response with the ETag sent in `If-None-Match`.
+    pub fn is_not_modified(&self) -> bool {
+        matches!(self.0.kind, ErrorKind::NotModified)
+    }
+
+    /// Whether the request was aborted by the cancellation token of the handle.
+    pub fn is_cancelled(&self) -> bool {
+        matches!(self.0.kind, ErrorKind::Cancelled)
+    }
+
+    /// Whether the request did not complete within the timeout of the request or of the `YouTube` handle.
+    pub fn is_timeout(&self) -> bool {
+        matches!(self.0.kind, ErrorKind::Timeout)
+    }
+
+    /// Wraps an error of an [Authorizer](crate::auth::Authorizer), e.g. a failure of a custom token broker.
+    pub fn authorizer_error(source: impl Into<BoxError>) -> Error {
+        Error::new(ErrorKind::AuthorizerError, Some(source), None::<Url>)
+    }
+
+    /// Wraps an error of a [TokenStore](crate::token_store::TokenStore), e.g. an I/O error of a custom storage.
+    pub fn token_store_error(source: impl Into<BoxError>) -> Error {
+        Error::new(ErrorKind::TokenStoreError, Some(source), None::<Url>)
+    }
 }
 
 #[derive(Debug, Error)]
@@ -140,6 +287,27 @@ impl fmt::Display for ErrorRepr {
             ErrorKind::ReqwestError => {
                 f.write_str("reqwest error")?;
             }
+            ErrorKind::Timeout => {
+                f.write_str("timeout error")?;
+            }
+            ErrorKind::DecodeError => {
+                f.write_str("decode error")?;
+            }
+            ErrorKind::Cancelled => {
+                f.write_str("cancelled")?;
+            }
+            ErrorKind::NotModified => {
+                f.write_str("not modified")?;
+            }
+            ErrorKind::OAuthError => {
+                f.write_str("oauth error")?;
+            }
+            ErrorKind::TokenStoreError => {
+                f.write_str("token store error")?;
+            }
+            ErrorKind::AuthorizerError => {
+                f.write_str("authorizer error")?;
+            }
         }
 
         if let Some(url) = &self.url {
@@ -154,9 +322,10 @@ impl fmt::Display for ErrorRepr {
     }
 }
 
-/// Represents the kind of an error.
-#[derive(Debug)]
-pub(crate) enum ErrorKind {
+/// Represents the kind of an error, see [Error::kind]. New kinds may be added in minor releases.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
     /// An error occurred while building the request.
     BuilderError(BuilderErrorKind),
 
@@ -168,11 +337,33 @@ pub(crate) enum ErrorKind {
 
     /// An error occurred in reqwest lib.
     ReqwestError,
+
+    /// The request did not complete within its timeout.
+    Timeout,
+
+    /// The body of a response does not match the expected schema, e.g. because the api changed.
+    DecodeError,
+
+    /// The request was aborted by a cancellation token.
+    Cancelled,
+
+    /// The resource did not change since the ETag of a conditional request.
+    NotModified,
+
+    /// An error returned by the Google OAuth 2.0 server.
+    OAuthError,
+
+    /// An error occurred while loading or saving tokens.
+    TokenStoreError,
+
+    /// An error occurred while attaching credentials to a request.
+    AuthorizerError,
 }
 
 /// Represents the error that occurred before the request was sent (request build process)
-#[derive(Debug)]
-pub(crate) enum BuilderErrorKind {
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BuilderErrorKind {
     /// The request specifies an invalid parameter value.
     InvalidParameter { message: String },
 
@@ -188,9 +379,13 @@ pub(crate) enum BuilderErrorKind {
 
 /// Represents an error returned by the YouTube Data API.
 #[derive(Error, serde::Deserialize)]
-#[error(transparent)]
+#[error("{error}")]
 pub(crate) struct YouTubeError {
     error: YouTubeErrorRepr,
+
+    /// The delay requested by the `Retry-After` header of the response.
+    #[serde(skip)]
+    retry_after: Option<Duration>,
 }
 
 impl fmt::Debug for YouTubeError {
@@ -207,16 +402,50 @@ impl YouTubeError {
         self.error.code
     }
 
-    pub(crate) fn message(&self) -> &str {
-        &self.error.message
-    }
-
     pub(crate) fn errors(&self) -> &[YouTubeErrorDetail] {
         &self.error.errors
     }
 
-    pub(crate) fn status(&self) -> Option<&str> {
-        self.error.status.as_deref()
+    /// An error for a response whose body is not an error of the api.
+    pub(crate) fn from_status(status: u16, body: &[u8]) -> YouTubeError {
+        let code = StatusCode::from_u16(status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        let message = if body.is_empty() {
+            code.canonical_reason().unwrap_or_default().to_string()
+        } else {
+            redact_body(body)
+        };
+        YouTubeError {
+            error: YouTubeErrorRepr {
+                code,
+                message,
+                errors: Vec::new(),
+                status: None,
+            },
+            retry_after: None,
+        }
+    }
+
+    pub(crate) fn with_retry_after(mut self, retry_after: Option<Duration>) -> YouTubeError {
+        self.retry_after = retry_after;
+        self
+    }
+
+    fn has_reason(&self, reasons: &[&str]) -> bool {
+        self.error
+            .errors
+            .iter()
+            .any(|detail| reasons.contains(&detail.reason.as_str()))
+    }
+
+    /// Whether the request was rejected because the project of the api key exhausted its quota.
+    pub(crate) fn is_quota_exceeded(&self) -> bool {
+        self.has_reason(&["quotaExceeded", "dailyLimitExceeded"])
+    }
+
+    /// Whether the request was rejected because too many requests were sent in a short time.
+    pub(crate) fn is_rate_limited(&self) -> bool {
+        self.error.code == StatusCode::TOO_MANY_REQUESTS
+            || self.has_reason(&["rateLimitExceeded", "userRateLimitExceeded"])
     }
 }
 
@@ -252,12 +481,12 @@ impl fmt::Display for YouTubeErrorRepr {
 }
 
 #[derive(Debug, Error, serde::Deserialize)]
-struct YouTubeErrorDetail {
+#[serde(rename_all = "camelCase")]
+pub(crate) struct YouTubeErrorDetail {
     message: String,
     domain: String,
     reason: String,
     location: Option<String>,
-    #[serde(alias = "locationType")]
     location_type: Option<String>,
 }
 
@@ -277,21 +506,158 @@ impl fmt::Display for YouTubeErrorDetail {
     }
 }
 
-fn replace_sensitive_query_params(url: Option<Url>) -> Option<String> {
-    if url.is_none() {
-        return None;
+/// The bodies longer than this are truncated in a [DecodeError].
+const MAX_DECODE_ERROR_BODY_LEN: usize = 4096;
+
+/// A response body that could not be deserialized, with the path of the field that failed (e.g.
+/// `items[0].snippet.publishedAt`). The body is truncated to 4 KiB and the tokens of OAuth responses are replaced.
+#[derive(Debug, Error)]
+#[error("{source} at `{path}`")]
+pub struct DecodeError {
+    path: String,
+    body: String,
+    source: serde_json::Error,
+}
+
+impl DecodeError {
+    fn new(error: serde_path_to_error::Error<serde_json::Error>, body: &[u8]) -> DecodeError {
+        DecodeError {
+            path: error.path().to_string(),
+            body: redact_body(body),
+            source: error.into_inner(),
+        }
+    }
+
+    /// The path of the field that failed to deserialize, `.` for the top level.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The (possibly truncated) body of the response.
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+}
+
+/// Deserializes a response body, keeping the body and the failing field in the error. With the `strict` feature,
+/// a field unknown to the model fails the deserialization too.
+pub(crate) fn decode_json<T>(body: &[u8], url: Option<Url>) -> Result<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let deserializer = &mut serde_json::Deserializer::from_slice(body);
+    #[cfg(feature = "strict")]
+    {
+        let mut unknown = None;
+        let mut track = |path: serde_ignored::Path| {
+            if let serde_ignored::Path::Map { parent, key } = path {
+                unknown.get_or_insert_with(|| (field_path(parent), key));
+            }
+        };
+        let deserializer = serde_ignored::Deserializer::new(deserializer, &mut track);
+        let value = serde_path_to_error::deserialize(deserializer)
+            .map_err(|e| Error::decode_error(DecodeError::new(e, body), url.clone()))?;
+        if let Some((path, field)) = unknown {
+            let error = DecodeError {
+                path,
+                body: redact_body(body),
+                source: serde::de::Error::custom(format!("unknown field `{}`", field)),
+            };
+            return Err(Error::decode_error(error, url));
+        }
+        Ok(value)
+    }
+    #[cfg(not(feature = "strict"))]
+    serde_path_to_error::deserialize(deserializer)
+        .map_err(|e| Error::decode_error(DecodeError::new(e, body), url))
+}
+
+/// The path of an ignored field in the format of `serde_path_to_error`, e.g. `items[1].snippet`.
+#[cfg(feature = "strict")]
+fn field_path(path: &serde_ignored::Path) -> String {
+    match path {
+        serde_ignored::Path::Root => ".".to_string(),
+        serde_ignored::Path::Seq { parent, index } => match field_path(parent).as_str() {
+            "." => format!("[{}]", index),
+            parent => format!("{}[{}]", parent, index),
+        },
+        serde_ignored::Path::Map { parent, key } => match field_path(parent).as_str() {
+            "." => key.clone(),
+            parent => format!("{}.{}", parent, key),
+        },
+        serde_ignored::Path::Some { parent }
+        | serde_ignored::Path::NewtypeStruct { parent }
+        | serde_ignored::Path::NewtypeVariant { parent } => field_path(parent),
+    }
+}
+
+fn redact_body(body: &[u8]) -> String {
+    let mut body = match serde_json::from_slice::<serde_json::Value>(body) {
+        Ok(mut json) => {
+            if let Some(object) = json.as_object_mut() {
+                for key in ["access_token", "refresh_token", "id_token"] {
+                    if let Some(value) = object.get_mut(key) {
+                        *value = serde_json::Value::String("[REDACTED]".to_string());
+                    }
+                }
+            }
+            json.to_string()
+        }
+        Err(_) => String::from_utf8_lossy(body).into_owned(),
+    };
+    if body.len() > MAX_DECODE_ERROR_BODY_LEN {
+        let mut end = MAX_DECODE_ERROR_BODY_LEN;
+        while !body.is_char_boundary(end) {
+            end -= 1;
+        }
+        body.truncate(end);
+        body.push('…');
+    }
+    body
+}
+
+/// Represents an error returned by the token endpoints of the Google OAuth 2.0 server.
+#[derive(Debug, Error, serde::Deserialize)]
+pub(crate) struct OAuthError {
+    pub(crate) error: String,
+    pub(crate) error_description: Option<String>,
+}
+
+impl OAuthError {
+    pub(crate) fn new(
+        error: impl Into<String>,
+        error_description: impl Into<String>,
+    ) -> OAuthError {
+        OAuthError {
+            error: error.into(),
+            error_description: Some(error_description.into()),
+        }
     }
-    let url = url.unwrap();
+}
+
+impl fmt::Display for OAuthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{}\"", &self.error)?;
+        if let Some(error_description) = &self.error_description {
+            write!(f, " description: \"{}\"", error_description)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub(crate) fn replace_sensitive_query_params(url: Option<Url>) -> Option<String> {
+    let url = url?;
     let path = url.path().to_string();
     let queries = url.query_pairs();
     // replace sensitive query params
     let mut queries = queries
         .map(|(key, val)| {
-            return if key != "key" {
+            if key != "key" {
                 format!("{}={}", key, val)
             } else {
                 format!("{}={}", key, "[API_KEY]")
-            };
+            }
         })
         .collect::<Vec<String>>();
     // if you do not sort, the results will be different every time
@@ -352,6 +718,104 @@ mod tests {
         assert_eq!(format!("{}", client_error), assert_message);
     }
 
+    #[test]
+    fn test_error_accessors() {
+        let missing_param = Error::missing_required_parameter("`id` is required");
+        assert_eq!(
+            missing_param.kind(),
+            &ErrorKind::BuilderError(BuilderErrorKind::MissingRequiredParameter {
+                message: "`id` is required".to_string()
+            })
+        );
+        assert_eq!(missing_param.status(), None);
+        assert_eq!(missing_param.url(), None);
+
+        let youtube_error = serde_json::from_str(
+            r#"{
+                "error": {
+                    "code": 400,
+                    "message": "The request specifies an invalid filter parameter.",
+                    "errors": [
+                        {
+                            "message": "The request specifies an invalid filter parameter.",
+                            "domain": "youtube.parameter",
+                            "reason": "invalidFilters",
+                            "location": "id",
+                            "locationType": "parameter"
+                        }
+                    ]
+                }
+            }"#,
+        )
+        .unwrap();
+        let client_error = Error::client_error(
+            youtube_error,
+            Url::parse("https://www.googleapis.com/youtube/v3/videos?id=&key=secret").unwrap(),
+        );
+        assert_eq!(client_error.kind(), &ErrorKind::ClientError);
+        assert_eq!(client_error.status(), Some(StatusCode::BAD_REQUEST));
+        assert_eq!(client_error.parameter(), Some("id"));
+        assert_eq!(
+            client_error.url(),
+            Some("/youtube/v3/videos?id=&key=[API_KEY]")
+        );
+    }
+
+    #[test]
+    fn test_decode_error() {
+        #[derive(Debug, serde::Deserialize)]
+        struct List {
+            #[allow(dead_code)]
+            items: Vec<Item>,
+        }
+        #[derive(Debug, serde::Deserialize)]
+        struct Item {
+            #[allow(dead_code)]
+            count: u64,
+        }
+
+        let url = Url::parse("https://www.googleapis.com/youtube/v3/videos?key=secret").unwrap();
+        let body = br#"{"items":[{"count":1},{"count":"2"}]}"#;
+        let error = decode_json::<List>(body, Some(url)).unwrap_err();
+        assert_eq!(error.kind(), &ErrorKind::DecodeError);
+        assert_eq!(error.url(), Some("/youtube/v3/videos?key=[API_KEY]"));
+        let decode_error = error.as_decode_error().unwrap();
+        assert_eq!(decode_error.path(), "items[1].count");
+        assert_eq!(
+            decode_error.body(),
+            r#"{"items":[{"count":1},{"count":"2"}]}"#
+        );
+
+        let body = br#"{"access_token":"secret","expires_in":"3599"}"#;
+        let error = decode_json::<List>(body, None).unwrap_err();
+        assert!(!error.as_decode_error().unwrap().body().contains("secret"));
+
+        let body = format!(r#"{{"items":"{}"}}"#, "a".repeat(10_000));
+        let error = decode_json::<List>(body.as_bytes(), None).unwrap_err();
+        assert!(error.as_decode_error().unwrap().body().len() <= MAX_DECODE_ERROR_BODY_LEN + 3);
+    }
+
+    #[cfg(feature = "strict")]
+    #[test]
+    fn test_decode_unknown_field() {
+        let body = br#"{"items":[{"id":"video-id"},{"id":"video-id","snippet":{"title":"title","newField":1}}]}"#;
+        let error = decode_json::<crate::videos::VideoListResponse>(body, None).unwrap_err();
+        let decode_error = error.as_decode_error().unwrap();
+        assert_eq!(decode_error.path(), "items[1].snippet");
+        assert_eq!(
+            std::error::Error::source(decode_error).unwrap().to_string(),
+            "unknown field `newField`"
+        );
+
+        // the unknown fields of a resource would be kept in its `extra` fields
+        let body = br#"{"items":[{"id":"video-id","newPart":{}}]}"#;
+        let error = decode_json::<crate::videos::VideoListResponse>(body, None).unwrap_err();
+        assert_eq!(error.as_decode_error().unwrap().path(), "items[0]");
+
+        let body = br#"{"items":[{"id":"video-id","snippet":{"title":"title"}}]}"#;
+        assert!(decode_json::<crate::videos::VideoListResponse>(body, None).is_ok());
+    }
+
     #[test]
     fn test_replace_sensitive_query_params() {
         let binnding = reqwest::Client::new()
@@ -0,0 +1,248 @@
+use crate::{
+    auth::{Authorizer, OAuthTokenAuth},
+    error::Result,
+    list_json, ListApi, RequestBase, YouTube, YouTubeDataApi,
+};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use youtube_data_derive::QueryBuilder;
+
+#[derive(Clone, Copy)]
+pub struct I18nLanguagesService<'a> {
+    youtube: &'a YouTube,
+}
+
+impl<'a> I18nLanguagesService<'a> {
+    pub(crate) fn new(youtube: &'a YouTube) -> Self {
+        Self { youtube }
+    }
+
+    pub fn list(self, part: impl IntoIterator<Item = Part>) -> I18nLanguageList<'a> {
+        I18nLanguageList::new(self, part)
+    }
+}
+
+/// Parameters for the `list` method of the `i18nLanguages` api. details:
+/// [link](https://developers.google.com/youtube/v3/docs/i18nLanguages/list)
+#[derive(QueryBuilder)]
+pub struct I18nLanguageList<'a> {
+    service: I18nLanguagesService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    #[query(unique)]
+    part: Vec<Part>,
+
+    // optional parameters
+    /// Selects the fields of the response, e.g. `items(id,snippet/title)`, so only the needed part of every
+    /// resource is returned. details: [link](https://developers.google.com/youtube/v3/getting-started#fields)
+    #[query]
+    fields: Option<String>,
+    /// Fails the request with a timeout error when it does not complete in time, instead of the timeout of the
+    /// `YouTube` handle.
+    #[setter]
+    timeout: Option<Duration>,
+    #[query(default)]
+    hl: Option<String>,
+}
+
+impl RequestBase for I18nLanguageList<'_> {
+    fn api_path(&self) -> &str {
+        "i18nLanguages"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for I18nLanguageList<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+
+    fn request_timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl ListApi<I18nLanguageListResponse> for I18nLanguageList<'_> {
+    async fn request(&self) -> Result<I18nLanguageListResponse> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        self.insert_query(youtube, &mut params)?;
+
+        let response = self
+            .send(
+                youtube,
+                youtube
+                    .inner()
+                    .client
+                    .get(self.url(&youtube.inner().base_path))
+                    .query(&params),
+            )
+            .await?;
+        list_json(response).await
+    }
+}
+
+impl<'a> I18nLanguageList<'a> {
+    pub(crate) fn new(
+        service: I18nLanguagesService<'a>,
+        part: impl IntoIterator<Item = Part>,
+    ) -> Self {
+        let part = crate::dedupe(part);
+        let part = if part.is_empty() {
+            vec![Part::Snippet]
+        } else {
+            part
+        };
+        Self {
+            service,
+            authorizer: None,
+            part,
+            fields: None,
+            timeout: None,
+            hl: None,
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    Snippet,
+}
+
+impl Part {
+    /// Every part of the resource, to request all of its properties.
+    pub fn all_public() -> Vec<Part> {
+        vec![Part::Snippet]
+    }
+}
+
+impl std::fmt::Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            Part::Snippet => "snippet",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct I18nLanguageListResponse {
+    /// Identifies the API resource's type. The value will be `youtube#i18nLanguageListResponse`.
+    #[serde(default)]
+    pub kind: String,
+
+    /// The ETag of the response.
+    #[serde(default)]
+    pub etag: String,
+
+    /// A list of application languages that YouTube supports.
+    #[serde(default)]
+    pub items: Vec<I18nLanguageListResource>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct I18nLanguageListResource {
+    /// Identifies the API resource's type. The value will be `youtube#i18nLanguage`.
+    #[serde(default)]
+    pub kind: String,
+
+    /// The ETag of the response.
+    #[serde(default)]
+    pub etag: String,
+
+    /// The ID that YouTube uses to uniquely identify the i18n language.
+    #[serde(default)]
+    pub id: String,
+
+    pub snippet: Option<I18nLanguageSnippet>,
+
+    /// The fields of the resource that this version of the crate does not know, e.g. added to the api after its
+    /// release. They are serialized back with the resource.
+    #[serde(flatten, deserialize_with = "crate::serde_helpers::extra_fields")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// The snippet object contains basic details about the i18n language, such as its language code and name.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct I18nLanguageSnippet {
+    /// A BCP-47 code that uniquely identifies a language.
+    #[serde(default)]
+    pub hl: String,
+
+    /// The name of the language as it is written in the language specified using the `hl` parameter.
+    #[serde(default)]
+    pub name: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_youtube_with_transport;
+    use crate::transport::MockTransport;
+
+    #[tokio::test]
+    async fn test_list() {
+        let transport = Arc::new(MockTransport::new().respond(
+            reqwest::Method::GET,
+            "i18nLanguages",
+            200,
+            r#"{
+                "kind": "youtube#i18nLanguageListResponse",
+                "items": [
+                    {
+                        "kind": "youtube#i18nLanguage",
+                        "id": "en",
+                        "snippet": { "hl": "en", "name": "English" }
+                    },
+                    {
+                        "kind": "youtube#i18nLanguage",
+                        "id": "ko",
+                        "snippet": { "hl": "ko", "name": "Korean" }
+                    }
+                ]
+            }"#,
+        ));
+        let response = get_youtube_with_transport(transport.clone())
+            .i18n_languages()
+            .list(vec![Part::Snippet])
+            .hl("en_US")
+            .request()
+            .await
+            .unwrap();
+        assert_eq!(
+            response
+                .items
+                .iter()
+                .map(|item| {
+                    let snippet = item.snippet.as_ref().unwrap();
+                    (snippet.hl.as_str(), snippet.name.as_str())
+                })
+                .collect::<Vec<_>>(),
+            vec![("en", "English"), ("ko", "Korean")]
+        );
+        assert_eq!(response.items[1].id, "ko");
+        assert!(transport.requests()[0]
+            .1
+            .query()
+            .unwrap()
+            .contains("hl=en_US"));
+    }
+}
@@ -0,0 +1,449 @@
+use crate::{
+    auth::{Authorizer, OAuthTokenAuth},
+    error::{Error, Result},
+    list_json, ListApi, RequestBase, YouTube, YouTubeDataApi,
+};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use youtube_data_derive::QueryBuilder;
+
+#[derive(Clone, Copy)]
+pub struct I18nRegionsService<'a> {
+    youtube: &'a YouTube,
+}
+
+impl<'a> I18nRegionsService<'a> {
+    pub(crate) fn new(youtube: &'a YouTube) -> Self {
+        Self { youtube }
+    }
+
+    pub fn list(self, part: impl IntoIterator<Item = Part>) -> I18nRegionList<'a> {
+        I18nRegionList::new(self, part)
+    }
+}
+
+/// Parameters for the `list` method of the `i18nRegions` api. details:
+/// [link](https://developers.google.com/youtube/v3/docs/i18nRegions/list)
+#[derive(QueryBuilder)]
+pub struct I18nRegionList<'a> {
+    service: I18nRegionsService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    #[query(unique)]
+    part: Vec<Part>,
+
+    // optional parameters
+    /// Selects the fields of the response, e.g. `items(id,snippet/title)`, so only the needed part of every
+    /// resource is returned. details: [link](https://developers.google.com/youtube/v3/getting-started#fields)
+    #[query]
+    fields: Option<String>,
+    /// Fails the request with a timeout error when it does not complete in time, instead of the timeout of the
+    /// `YouTube` handle.
+    #[setter]
+    timeout: Option<Duration>,
+    #[query(default)]
+    hl: Option<String>,
+}
+
+impl RequestBase for I18nRegionList<'_> {
+    fn api_path(&self) -> &str {
+        "i18nRegions"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for I18nRegionList<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+
+    fn request_timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl ListApi<I18nRegionListResponse> for I18nRegionList<'_> {
+    async fn request(&self) -> Result<I18nRegionListResponse> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        self.insert_query(youtube, &mut params)?;
+
+        let response = self
+            .send(
+                youtube,
+                youtube
+                    .inner()
+                    .client
+                    .get(self.url(&youtube.inner().base_path))
+                    .query(&params),
+            )
+            .await?;
+        list_json(response).await
+    }
+}
+
+impl<'a> I18nRegionList<'a> {
+    pub(crate) fn new(
+        service: I18nRegionsService<'a>,
+        part: impl IntoIterator<Item = Part>,
+    ) -> Self {
+        let part = crate::dedupe(part);
+        let part = if part.is_empty() {
+            vec![Part::Snippet]
+        } else {
+            part
+        };
+        Self {
+            service,
+            authorizer: None,
+            part,
+            fields: None,
+            timeout: None,
+            hl: None,
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    Snippet,
+}
+
+impl Part {
+    /// Every part of the resource, to request all of its properties.
+    pub fn all_public() -> Vec<Part> {
+        vec![Part::Snippet]
+    }
+}
+
+impl std::fmt::Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            Part::Snippet => "snippet",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct I18nRegionListResponse {
+    /// Identifies the API resource's type. The value will be `youtube#i18nRegionListResponse`.
+    #[serde(default)]
+    pub kind: String,
+
+    /// The ETag of the response.
+    #[serde(default)]
+    pub etag: String,
+
+    /// A list of regions where YouTube is available.
+    #[serde(default)]
+    pub items: Vec<I18nRegionListResource>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct I18nRegionListResource {
+    /// Identifies the API resource's type. The value will be `youtube#i18nRegion`.
+    #[serde(default)]
+    pub kind: String,
+
+    /// The ETag of the response.
+    #[serde(default)]
+    pub etag: String,
+
+    /// The ID that YouTube uses to uniquely identify the i18n region.
+    #[serde(default)]
+    pub id: String,
+
+    pub snippet: Option<I18nRegionSnippet>,
+
+    /// The fields of the resource that this version of the crate does not know, e.g. added to the api after its
+    /// release. They are serialized back with the resource.
+    #[serde(flatten, deserialize_with = "crate::serde_helpers::extra_fields")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// The snippet object contains basic details about the i18n region, such as its region code and name.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct I18nRegionSnippet {
+    /// The two-letter ISO country code that identifies the region.
+    #[serde(default)]
+    pub gl: String,
+
+    /// The name of the region.
+    #[serde(default)]
+    pub name: String,
+}
+
+/// The two-letter ISO 3166-1 country code of a region, e.g. `KR`, as the `gl` of [I18nRegionSnippet] lists them.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RegionCode(String);
+
+impl RegionCode {
+    /// Fails with a builder error unless `code` is two ASCII letters. The code is uppercased.
+    pub fn new(code: impl Into<String>) -> Result<RegionCode> {
+        let code = code.into();
+        if code.len() != 2 || !code.bytes().all(|byte| byte.is_ascii_alphabetic()) {
+            return Err(Error::invalid_parameter(format!(
+                "`{}` is not a two-letter ISO 3166-1 region code",
+                code
+            )));
+        }
+        Ok(RegionCode(code.to_ascii_uppercase()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::str::FromStr for RegionCode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        RegionCode::new(s)
+    }
+}
+
+impl std::fmt::Display for RegionCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<RegionCode> for String {
+    fn from(code: RegionCode) -> String {
+        code.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::CancellationToken;
+    use crate::error::ErrorKind;
+    use crate::get_youtube_with_transport;
+    use crate::metrics::{MetricsRecorder, RequestMetrics};
+    use crate::transport::{HttpTransport, MockTransport};
+    use reqwest::{Method, StatusCode};
+    use std::sync::Mutex;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    #[tokio::test]
+    async fn test_list() {
+        let transport = Arc::new(MockTransport::new().respond(
+            Method::GET,
+            "i18nRegions",
+            200,
+            r#"{
+                "kind": "youtube#i18nRegionListResponse",
+                "items": [
+                    {
+                        "kind": "youtube#i18nRegion",
+                        "id": "KR",
+                        "snippet": { "gl": "KR", "name": "South Korea" }
+                    },
+                    {
+                        "kind": "youtube#i18nRegion",
+                        "id": "US",
+                        "snippet": { "gl": "US", "name": "United States" }
+                    }
+                ]
+            }"#,
+        ));
+        let response = get_youtube_with_transport(transport.clone())
+            .i18n_regions()
+            .list(vec![Part::Snippet])
+            .hl("en_US")
+            .request()
+            .await
+            .unwrap();
+        assert_eq!(
+            response
+                .items
+                .iter()
+                .map(|item| {
+                    let snippet = item.snippet.as_ref().unwrap();
+                    (snippet.gl.as_str(), snippet.name.as_str())
+                })
+                .collect::<Vec<_>>(),
+            vec![("KR", "South Korea"), ("US", "United States")]
+        );
+        assert_eq!(response.items[1].id, "US");
+        assert!(transport.requests()[0]
+            .1
+            .query()
+            .unwrap()
+            .contains("hl=en_US"));
+    }
+
+    #[tokio::test]
+    async fn test_list_timeout() {
+        // a server that accepts the connection but never answers
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let _connection = listener.accept().await;
+            std::future::pending::<()>().await;
+        });
+
+        let youtube = YouTube::builder("api-key")
+            .base_path(format!("http://{}/youtube/v3", address))
+            .build()
+            .unwrap();
+        let timeout = youtube
+            .i18n_regions()
+            .list(vec![Part::Snippet])
+            .timeout(Duration::from_millis(100))
+            .request()
+            .await;
+        assert!(timeout.is_err());
+        let err = timeout.unwrap_err();
+        assert!(err.is_timeout());
+        assert!(format!("{}", err).starts_with("timeout error"));
+    }
+
+    #[tokio::test]
+    async fn test_list_cancelled() {
+        // a transport that never answers
+        struct Pending;
+
+        #[async_trait::async_trait]
+        impl HttpTransport for Pending {
+            async fn execute(&self, _request: reqwest::Request) -> Result<reqwest::Response> {
+                std::future::pending().await
+            }
+        }
+
+        let cancellation = CancellationToken::new();
+        let youtube = YouTube::builder("api-key")
+            .transport(Pending)
+            .build()
+            .unwrap()
+            .with_cancellation(cancellation.clone());
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            cancellation.cancel();
+        });
+        let cancelled = youtube
+            .i18n_regions()
+            .list(vec![Part::Snippet])
+            .request()
+            .await
+            .unwrap_err();
+        assert!(cancelled.is_cancelled());
+        assert_eq!(cancelled.kind(), &ErrorKind::Cancelled);
+    }
+
+    #[derive(Default)]
+    struct Recorded(Mutex<Vec<(String, Option<StatusCode>, u32)>>);
+
+    impl MetricsRecorder for Arc<Recorded> {
+        fn record(&self, metrics: &RequestMetrics<'_>) {
+            self.0.lock().unwrap().push((
+                metrics.endpoint.to_string(),
+                metrics.status,
+                metrics.quota_cost,
+            ));
+        }
+    }
+
+    /// Answers the first request with an empty list, returns the base path of the server.
+    async fn serve_empty_list() -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut connection, _) = listener.accept().await.unwrap();
+            let mut buffer = [0; 4096];
+            let _ = connection.read(&mut buffer).await;
+            let body = r#"{"items":[]}"#;
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            connection.write_all(response.as_bytes()).await.unwrap();
+        });
+        format!("http://{}/youtube/v3", address)
+    }
+
+    #[tokio::test]
+    async fn test_list_metrics() {
+        let recorded = Arc::new(Recorded::default());
+        let youtube = YouTube::builder("api-key")
+            .transport(MockTransport::new().respond(
+                Method::GET,
+                "i18nRegions",
+                200,
+                r#"{"items":[]}"#,
+            ))
+            .metrics(recorded.clone())
+            .build()
+            .unwrap();
+        youtube
+            .i18n_regions()
+            .list(vec![Part::Snippet])
+            .request()
+            .await
+            .unwrap();
+        assert_eq!(
+            *recorded.0.lock().unwrap(),
+            vec![("i18nRegions".to_string(), Some(StatusCode::OK), 1)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_fallback_base_path() {
+        // a port nothing listens on
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let refused = format!("http://{}/youtube/v3", listener.local_addr().unwrap());
+        drop(listener);
+
+        let youtube = YouTube::builder("api-key")
+            .base_path(refused)
+            .fallback_base_path(serve_empty_list().await)
+            .build()
+            .unwrap();
+        let response = youtube
+            .i18n_regions()
+            .list(vec![Part::Snippet])
+            .request()
+            .await
+            .unwrap();
+        assert!(response.items.is_empty());
+    }
+
+    #[test]
+    fn test_region_code() {
+        assert_eq!(RegionCode::new("kr").unwrap().as_str(), "KR");
+        assert_eq!("US".parse::<RegionCode>().unwrap().to_string(), "US");
+        assert_eq!(String::from(RegionCode::new("jp").unwrap()), "JP");
+
+        for code in ["", "K", "KOR", "K1", "한국"] {
+            assert_eq!(
+                format!(
+                    "builder error: \"`{}` is not a two-letter ISO 3166-1 region code\"",
+                    code
+                ),
+                format!("{}", RegionCode::new(code).unwrap_err())
+            );
+        }
+    }
+}
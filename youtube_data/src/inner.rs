@@ -0,0 +1,75 @@
+//! The configuration shared by the clones of a [YouTube](crate::YouTube) handle.
+
+use crate::{
+    auth::{ApiKeyAuth, Authorizer},
+    client::{CancellationToken, OutOfRange, RateLimit, RetryPolicy},
+    metrics::MetricsRecorder,
+    oauth,
+    search::SafeSearch,
+    transport::HttpTransport,
+};
+
+use reqwest::Client;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// The configuration of a [YouTube](crate::YouTube) handle, shared by its clones.
+#[derive(Clone)]
+pub struct YouTubeInner {
+    /// The API key used to authenticate requests to the YouTube Data API.
+    pub(crate) api_key: ApiKeyAuth,
+
+    /// The HTTP client used to make requests to the YouTube Data API.
+    pub(crate) client: Client,
+
+    /// The transport executing the requests, by default `client`.
+    pub(crate) transport: Arc<dyn HttpTransport>,
+
+    /// The base path for the YouTube Data API.
+    pub(crate) base_path: String,
+
+    /// The base paths tried in order when the base path can not be connected to.
+    pub(crate) fallback_base_paths: Vec<String>,
+
+    /// The base path for the media upload endpoints of the YouTube Data API.
+    pub(crate) upload_base_path: String,
+
+    /// (optioanl) The user agent used to make requests to the YouTube Data API.
+    pub(crate) user_agent: Option<String>,
+
+    /// (optional) The time limit of a request.
+    pub(crate) timeout: Option<Duration>,
+
+    /// The query parameters sent with every request that does not set them already.
+    pub(crate) default_params: Vec<(String, String)>,
+
+    /// (optional) The `regionCode` of the requests that accept one and do not set it.
+    pub(crate) region_code: Option<String>,
+
+    /// (optional) The `hl` of the requests that accept one and do not set it.
+    pub(crate) hl: Option<String>,
+
+    /// (optional) The `safeSearch` of the searches that do not set it.
+    pub(crate) safe_search: Option<SafeSearch>,
+
+    /// What the requests do with the values out of the range accepted by the api.
+    pub(crate) out_of_range: OutOfRange,
+
+    /// How requests that failed with a rate limit or server error are retried.
+    pub(crate) retry: RetryPolicy,
+
+    /// (optional) The rate limit shared by every clone of the handle.
+    pub(crate) rate_limit: Option<Arc<RateLimit>>,
+
+    /// The recorder receiving the measurements of every request.
+    pub(crate) metrics: Arc<dyn MetricsRecorder>,
+
+    /// (optional) The token aborting the requests in flight and the waits between retries once cancelled.
+    pub(crate) cancellation: Option<CancellationToken>,
+
+    /// (optional) The authorizer attaching the credentials of a user, e.g. an OAuth 2.0 access token, to requests.
+    pub(crate) authorizer: Option<Arc<dyn Authorizer>>,
+
+    /// (optional) The OAuth 2.0 scopes granted to the access token, checked against each request before it is sent.
+    pub(crate) scopes: Option<Vec<oauth::Scope>>,
+}
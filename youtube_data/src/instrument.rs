@@ -0,0 +1,82 @@
+//! `tracing` spans of the requests sent by [YouTubeDataApi::send](crate::YouTubeDataApi::send). Enabled with the
+//! `tracing` feature.
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::error::decode_json;
+use crate::{error::Result, timer::Instant};
+
+use serde::de::DeserializeOwned;
+#[cfg(not(target_arch = "wasm32"))]
+use serde::{de::IgnoredAny, Deserialize};
+use tracing::{field::Empty, Span};
+
+/// The span of one request, named `youtube_data.request`. `status`, `latency_ms` and `attempts` are recorded once
+/// the response arrived, `result_count` once the items of a list response are deserialized.
+pub(crate) fn request_span(endpoint: &str, request: &reqwest::Request) -> Span {
+    let parts = request
+        .url()
+        .query_pairs()
+        .find(|(key, _)| key == "part")
+        .map(|(_, value)| value.into_owned());
+    tracing::info_span!(
+        "youtube_data.request",
+        endpoint,
+        method = %request.method(),
+        parts = parts.as_deref(),
+        status = Empty,
+        latency_ms = Empty,
+        attempts = Empty,
+        result_count = Empty,
+    )
+}
+
+/// Records the response on the span and keeps the span in the response, so that [list_json] can add the result
+/// count. The responses of wasm32 have no extensions, so the result count is not recorded there.
+pub(crate) fn record_response(
+    span: Span,
+    started: Instant,
+    attempts: u32,
+    #[cfg_attr(target_arch = "wasm32", allow(unused_mut))] mut response: reqwest::Response,
+) -> reqwest::Response {
+    span.record("status", response.status().as_u16());
+    span.record("latency_ms", started.elapsed().as_millis() as u64);
+    span.record("attempts", attempts);
+    tracing::debug!(parent: &span, status = response.status().as_u16(), "response received");
+    #[cfg(not(target_arch = "wasm32"))]
+    response.extensions_mut().insert(span);
+    response
+}
+
+/// Deserializes a list response, recording the number of its `items` on the span of the request.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn list_json<T>(response: reqwest::Response) -> Result<T>
+where
+    T: DeserializeOwned,
+{
+    let span = match response.extensions().get::<Span>() {
+        Some(span) if !span.is_disabled() => span.clone(),
+        _ => return crate::json(response).await,
+    };
+    let url = response.url().clone();
+    let body = response.bytes().await?;
+    if let Ok(list) = serde_json::from_slice::<Items>(&body) {
+        span.record("result_count", list.items.len());
+    }
+    decode_json(&body, Some(url))
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) async fn list_json<T>(response: reqwest::Response) -> Result<T>
+where
+    T: DeserializeOwned,
+{
+    crate::json(response).await
+}
+
+/// Counts the items of a list response without deserializing them.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Deserialize)]
+struct Items {
+    #[serde(default)]
+    items: Vec<IgnoredAny>,
+}
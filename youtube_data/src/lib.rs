@@ -1,68 +1,375 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use reqwest::Client;
+use futures_util::stream::{StreamExt, TryStreamExt};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
 
+#[cfg(feature = "analytics")]
+pub mod analytics;
+pub mod auth;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod batch;
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+pub mod blocking;
+pub mod captions;
+pub mod channel_banners;
+pub mod channel_sections;
 pub mod channels;
+pub mod client;
+pub mod comment_threads;
+pub mod comments;
+pub mod cursor;
 pub mod error;
+pub mod i18n_languages;
+pub mod i18n_regions;
+mod inner;
+#[cfg(feature = "tracing")]
+mod instrument;
+pub mod live_broadcasts;
+pub mod live_chat_messages;
+pub mod live_streams;
+pub mod metrics;
+pub mod oauth;
+pub mod playlist_items;
+pub mod playlists;
+#[cfg(feature = "reporting")]
+pub mod reporting;
+pub mod response;
 pub mod search;
+mod serde_helpers;
+#[cfg(feature = "service-account")]
+pub mod service_account;
+pub mod subscriptions;
+pub mod super_chat_events;
+pub mod thumbnails;
+mod timer;
+pub mod token_store;
+pub mod transport;
+pub mod url;
+pub mod video_abuse_report_reasons;
+pub mod video_categories;
 pub mod videos;
+pub mod watermarks;
 
-use channels::ChannelsService;
-use search::SearchService;
-use videos::VideosService;
+use auth::{Authorizer, OAuthTokenAuth};
+use client::YouTubeBuilder;
+use inner::YouTubeInner;
+use metrics::RequestMetrics;
+use transport::HttpTransport;
 
+// the services of the apis, with their request builders and the resources they return
+#[cfg(feature = "analytics")]
+pub use analytics::{AnalyticsService, ReportQuery, ResultTable};
+pub use captions::{
+    CaptionDelete, CaptionDownload, CaptionInsert, CaptionList, CaptionListResource,
+    CaptionListResponse, CaptionUpdate, CaptionsService,
+};
+pub use channel_banners::{
+    ChannelBannerInsert, ChannelBannerResource, ChannelBannerSet, ChannelBannersService,
+};
+pub use channel_sections::{
+    ChannelSectionDelete, ChannelSectionInsert, ChannelSectionList, ChannelSectionListResource,
+    ChannelSectionListResponse, ChannelSectionUpdate, ChannelSectionsService,
+};
+pub use channels::{
+    ChannelFilter, ChannelList, ChannelListResource, ChannelListResponse, ChannelUpdateBanner,
+    ChannelsService,
+};
+pub use comment_threads::{CommentThreadInsert, CommentThreadListResource, CommentThreadsService};
+pub use comments::{
+    CommentDelete, CommentInsert, CommentList, CommentListResource, CommentListResponse,
+    CommentMarkAsSpam, CommentSetModerationStatus, CommentUpdate, CommentsService,
+};
+pub use i18n_languages::{
+    I18nLanguageList, I18nLanguageListResource, I18nLanguageListResponse, I18nLanguagesService,
+};
+pub use i18n_regions::{
+    I18nRegionList, I18nRegionListResource, I18nRegionListResponse, I18nRegionsService, RegionCode,
+};
+pub use live_broadcasts::{
+    LiveBroadcastBind, LiveBroadcastDelete, LiveBroadcastInsert, LiveBroadcastInsertCuepoint,
+    LiveBroadcastList, LiveBroadcastListResource, LiveBroadcastListResponse,
+    LiveBroadcastTransition, LiveBroadcastUpdate, LiveBroadcastsService,
+};
+pub use live_chat_messages::{
+    LiveChatMessageDelete, LiveChatMessageInsert, LiveChatMessageList, LiveChatMessageListResource,
+    LiveChatMessageListResponse, LiveChatMessagesService,
+};
+pub use live_streams::{
+    LiveStreamDelete, LiveStreamInsert, LiveStreamList, LiveStreamListResource,
+    LiveStreamListResponse, LiveStreamUpdate, LiveStreamsService,
+};
+pub use playlist_items::{
+    PlaylistItemList, PlaylistItemListResource, PlaylistItemListResponse, PlaylistItemsService,
+};
+pub use playlists::{PlaylistList, PlaylistListResource, PlaylistListResponse, PlaylistsService};
+#[cfg(feature = "reporting")]
+pub use reporting::{
+    JobCreate, JobDelete, JobList, JobListResponse, ReportDownload, ReportList, ReportListResponse,
+    ReportTypeList, ReportTypeListResponse, ReportingService,
+};
+pub use search::{
+    ChannelSearchResult, PlaylistSearchResult, SearchList, SearchListResource, SearchListResponse,
+    SearchResult, SearchService, VideoSearchResult,
+};
+pub use subscriptions::{
+    SubscriptionList, SubscriptionListResource, SubscriptionListResponse, SubscriptionsService,
+};
+pub use super_chat_events::{
+    SuperChatEventList, SuperChatEventListResource, SuperChatEventListResponse,
+    SuperChatEventsService,
+};
+pub use thumbnails::{ThumbnailSet, ThumbnailSetResponse, ThumbnailsService};
+pub use video_abuse_report_reasons::{
+    VideoAbuseReportReasonList, VideoAbuseReportReasonListResource,
+    VideoAbuseReportReasonListResponse, VideoAbuseReportReasonsService,
+};
+pub use video_categories::{
+    VideoCategoriesService, VideoCategoryList, VideoCategoryListResource, VideoCategoryListResponse,
+};
+pub use videos::{
+    VideoFilter, VideoGetRating, VideoGetRatingResponse, VideoList, VideoListResource,
+    VideoListResponse, VideoRate, VideoReportAbuse, VideosService,
+};
+pub use watermarks::{WatermarkSet, WatermarkUnset, WatermarksService};
+
+/// A boxed stream of results. It is `Send` except on wasm32, whose requests are futures of the JavaScript host.
+#[cfg(not(target_arch = "wasm32"))]
+pub type BoxStream<'a, T> = futures_util::stream::BoxStream<'a, T>;
+#[cfg(target_arch = "wasm32")]
+pub type BoxStream<'a, T> = futures_util::stream::LocalBoxStream<'a, T>;
+
+/// A handle to the YouTube Data API. Clones share the configuration, the connection pool of the HTTP client and the
+/// rate limit; the services returned by the accessors borrow the handle.
 #[derive(Clone)]
 pub struct YouTube {
-    /// The API key used to authenticate requests to the YouTube Data API.
-    pub(crate) api_key: String,
+    inner: Arc<YouTubeInner>,
+}
 
-    /// The HTTP client used to make requests to the YouTube Data API.
-    pub(crate) client: Client,
+impl YouTube {
+    /// Starts the configuration of a handle that authenticates its requests with `api_key`.
+    pub fn builder(api_key: impl Into<String>) -> YouTubeBuilder {
+        YouTubeBuilder::new(api_key)
+    }
 
-    /// The base path for the YouTube Data API.
-    pub(crate) base_path: String,
+    /// Attaches an OAuth 2.0 access token, which is required by the apis and parameters that act on behalf of a user.
+    pub fn with_access_token(self, access_token: impl Into<String>) -> YouTube {
+        self.with_authorizer(OAuthTokenAuth::new(access_token))
+    }
 
-    /// (optioanl) The user agent used to make requests to the YouTube Data API.
-    pub(crate) user_agent: Option<String>,
+    /// Attaches the credentials of a user through a custom [Authorizer], e.g. a token broker that refreshes the
+    /// access token on its own. The api key is still sent with every request.
+    pub fn with_authorizer(mut self, authorizer: impl Authorizer + 'static) -> YouTube {
+        self.config_mut().authorizer = Some(Arc::new(authorizer));
+        self
+    }
 
-    // services
-    channels: Option<ChannelsService>,
-    search: Option<SearchService>,
-    videos: Option<VideosService>,
-}
+    /// A handle whose requests fail with a cancelled error once `cancellation` is cancelled, e.g. to abort a crawl,
+    /// an upload or the polling of a live chat from a scheduler. A request in flight is dropped, which closes its
+    /// connection; the responses already returned are not affected.
+    pub fn with_cancellation(mut self, cancellation: client::CancellationToken) -> YouTube {
+        self.config_mut().cancellation = Some(cancellation);
+        self
+    }
 
-impl YouTube {
-    pub fn new(api_key: String, user_agent: Option<String>) -> YouTube {
-        let mut youtube = YouTube {
-            api_key,
-            client: Client::new(),
-            base_path: "https://www.googleapis.com/youtube/v3".to_string(),
-            user_agent,
-            channels: None,
-            videos: None,
-            search: None,
-        };
+    /// Resumes a paged list request from a saved cursor, e.g.
+    /// `youtube.resume::<PlaylistItemListResource>(cursor).pages()`. The request is sent with the credentials of this
+    /// handle.
+    pub fn resume<T>(&self, cursor: cursor::PageCursor) -> cursor::CursorList<'_, T> {
+        cursor::CursorList::new(self, cursor)
+    }
+
+    /// Starts a batch, which sends the requests built with its handle in one HTTP round trip. Not available on
+    /// wasm32, where a response can only come from the browser.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn batch(&self) -> error::Result<batch::Batch> {
+        batch::Batch::new(self)
+    }
+
+    /// Declares the OAuth 2.0 scopes granted to the access token. Requests whose endpoint is not covered by any of
+    /// them fail with a builder error instead of a `403 insufficientPermissions` response.
+    pub fn with_scopes(mut self, scopes: &[oauth::Scope]) -> YouTube {
+        self.config_mut().scopes = Some(scopes.to_vec());
+        self
+    }
+
+    /// Attaches the access token of `token`, together with its granted scopes when the token response listed them.
+    pub fn with_token(mut self, token: &oauth::TokenSet) -> YouTube {
+        let config = self.config_mut();
+        config.authorizer = Some(Arc::new(OAuthTokenAuth::new(token.access_token.clone())));
+        config.scopes = token.scope.as_deref().map(oauth::Scope::parse_list);
+        self
+    }
+
+    /// Awaits `future`, unless the cancellation token of the handle is cancelled first.
+    async fn cancellable<F: std::future::Future>(&self, future: F) -> error::Result<F::Output> {
+        match &self.inner.cancellation {
+            Some(cancellation) => tokio::select! {
+                biased;
+                _ = cancellation.cancelled() => Err(error::Error::cancelled()),
+                output = future => Ok(output),
+            },
+            None => Ok(future.await),
+        }
+    }
+
+    /// Moves a request that could not connect to the next base path, starting at the base path with index `base` (0
+    /// for the base path, then the fallback base paths). `None` once every base path was tried.
+    fn failover(&self, url: &reqwest::Url, base: &mut usize) -> Option<reqwest::Url> {
+        let base_paths = std::iter::once(&self.inner.base_path)
+            .chain(&self.inner.fallback_base_paths)
+            .collect::<Vec<&String>>();
+        let rest = url.as_str().strip_prefix(base_paths[*base].as_str())?;
+        let next = base_paths.get(*base + 1)?;
+        *base += 1;
+        reqwest::Url::parse(&format!("{}{}", next, rest)).ok()
+    }
+
+    /// Applies the client-wide configuration to a request: the user agent, the timeout unless the request sets its
+    /// own, and the default query parameters it does not set already.
+    fn apply_defaults(&self, request: &mut reqwest::Request) -> error::Result<()> {
+        if let Some(user_agent) = &self.inner.user_agent {
+            let user_agent = reqwest::header::HeaderValue::from_str(user_agent).map_err(|_| {
+                error::Error::invalid_parameter("The user agent is not a valid header value")
+            })?;
+            request
+                .headers_mut()
+                .insert(reqwest::header::USER_AGENT, user_agent);
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        if request.timeout().is_none() {
+            *request.timeout_mut() = self.inner.timeout;
+        }
+        if !self.inner.default_params.is_empty() {
+            let set = request
+                .url()
+                .query_pairs()
+                .map(|(key, _)| key.into_owned())
+                .collect::<Vec<String>>();
+            let mut query = request.url_mut().query_pairs_mut();
+            for (key, value) in &self.inner.default_params {
+                if !set.contains(key) {
+                    query.append_pair(key, value);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether the configured authorizer acts on behalf of a user.
+    fn authorizes_user(&self) -> bool {
+        self.inner
+            .authorizer
+            .as_ref()
+            .is_some_and(|authorizer| authorizer.authorizes_user())
+    }
+
+    /// The configuration of this handle.
+    pub(crate) fn inner(&self) -> &YouTubeInner {
+        &self.inner
+    }
+
+    /// The configuration of this handle, copied first when a clone still shares it.
+    fn config_mut(&mut self) -> &mut YouTubeInner {
+        Arc::make_mut(&mut self.inner)
+    }
+
+    /// The YouTube Analytics API, which shares the api key and access token of the YouTube Data API.
+    #[cfg(feature = "analytics")]
+    pub fn analytics(&self) -> AnalyticsService<'_> {
+        AnalyticsService::new(self)
+    }
+
+    pub fn captions(&self) -> CaptionsService<'_> {
+        CaptionsService::new(self)
+    }
+
+    pub fn channel_banners(&self) -> ChannelBannersService<'_> {
+        ChannelBannersService::new(self)
+    }
+
+    pub fn channel_sections(&self) -> ChannelSectionsService<'_> {
+        ChannelSectionsService::new(self)
+    }
+
+    pub fn channels(&self) -> ChannelsService<'_> {
+        ChannelsService::new(self)
+    }
+
+    pub fn comment_threads(&self) -> CommentThreadsService<'_> {
+        CommentThreadsService::new(self)
+    }
+
+    pub fn comments(&self) -> CommentsService<'_> {
+        CommentsService::new(self)
+    }
+
+    pub fn i18n_languages(&self) -> I18nLanguagesService<'_> {
+        I18nLanguagesService::new(self)
+    }
 
-        // Initialize services
-        youtube.channels = Some(ChannelsService::new(Box::new(youtube.clone())));
-        youtube.search = Some(SearchService::new(Box::new(youtube.clone())));
-        youtube.videos = Some(VideosService::new(Box::new(youtube.clone())));
+    pub fn i18n_regions(&self) -> I18nRegionsService<'_> {
+        I18nRegionsService::new(self)
+    }
+
+    pub fn live_broadcasts(&self) -> LiveBroadcastsService<'_> {
+        LiveBroadcastsService::new(self)
+    }
+
+    pub fn live_chat_messages(&self) -> LiveChatMessagesService<'_> {
+        LiveChatMessagesService::new(self)
+    }
+
+    pub fn live_streams(&self) -> LiveStreamsService<'_> {
+        LiveStreamsService::new(self)
+    }
+
+    pub fn playlist_items(&self) -> PlaylistItemsService<'_> {
+        PlaylistItemsService::new(self)
+    }
+
+    pub fn playlists(&self) -> PlaylistsService<'_> {
+        PlaylistsService::new(self)
+    }
+
+    /// The YouTube Reporting API, which shares the api key and access token of the YouTube Data API.
+    #[cfg(feature = "reporting")]
+    pub fn reporting(&self) -> ReportingService<'_> {
+        ReportingService::new(self)
+    }
+
+    pub fn search(&self) -> SearchService<'_> {
+        SearchService::new(self)
+    }
+
+    pub fn subscriptions(&self) -> SubscriptionsService<'_> {
+        SubscriptionsService::new(self)
+    }
 
-        youtube
+    pub fn super_chat_events(&self) -> SuperChatEventsService<'_> {
+        SuperChatEventsService::new(self)
     }
 
-    pub fn channels(&self) -> &ChannelsService {
-        self.channels.as_ref().unwrap()
+    pub fn thumbnails(&self) -> ThumbnailsService<'_> {
+        ThumbnailsService::new(self)
     }
 
-    pub fn search(&self) -> &SearchService {
-        self.search.as_ref().unwrap()
+    pub fn video_abuse_report_reasons(&self) -> VideoAbuseReportReasonsService<'_> {
+        VideoAbuseReportReasonsService::new(self)
     }
 
-    pub fn videos(&self) -> &VideosService {
-        self.videos.as_ref().unwrap()
+    pub fn video_categories(&self) -> VideoCategoriesService<'_> {
+        VideoCategoriesService::new(self)
+    }
+
+    pub fn watermarks(&self) -> WatermarksService<'_> {
+        WatermarksService::new(self)
+    }
+
+    pub fn videos(&self) -> VideosService<'_> {
+        VideosService::new(self)
     }
 }
 
@@ -95,7 +402,7 @@ pub(crate) trait RequestBase {
     ) {
         if let Some(value) = value {
             let value = value
-                .into_iter()
+                .iter()
                 .filter(|v| !v.to_string().is_empty())
                 .map(|v| v.to_string())
                 .collect::<Vec<String>>()
@@ -121,120 +428,761 @@ pub(crate) trait RequestBase {
     }
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub(crate) trait YouTubeDataApi: RequestBase {
-    async fn send(&self, request: reqwest::RequestBuilder) -> error::Result<reqwest::Response> {
-        let response = request.send().await?;
-        let status = response.status();
-        // handle status code
-        if status.is_success() {
-            return Ok(response);
+    /// The authorizer selected for this request with `as_user`, which replaces the authorizer of the handle.
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>>;
+
+    /// The timeout selected for this request, which replaces the timeout of the handle.
+    fn request_timeout(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Whether the request is sent with credentials acting on behalf of a user.
+    fn authorizes_user(&self, youtube: &YouTube) -> bool {
+        match self.authorizer() {
+            Some(authorizer) => authorizer.authorizes_user(),
+            None => youtube.authorizes_user(),
         }
-        // TODO: implement a handle for the informational status code
-        if status.is_informational() {
-            panic!("Informational Responses");
+    }
+
+    /// Checks that the request is authorized on behalf of a user before it is sent. The api can not be called with an
+    /// api key only, so a builder error is returned when no authorizer acts on behalf of a user, or when the
+    /// configured scopes do not cover the endpoint. The credentials are attached by [YouTubeDataApi::send].
+    fn bearer_auth(
+        &self,
+        youtube: &YouTube,
+        request: reqwest::RequestBuilder,
+    ) -> error::Result<reqwest::RequestBuilder> {
+        if !self.authorizes_user(youtube) {
+            return Err(error::Error::authorization_required(format!(
+                "The request to `{}` requires OAuth 2.0 authorization",
+                self.api_path()
+            )));
         }
-        // TODO: implement a handle for the redirection status code
-        if status.is_redirection() {
-            panic!("Redirection Responses");
+
+        // the scopes of the handle do not describe the token of another user
+        let scopes = youtube
+            .inner()
+            .scopes
+            .as_ref()
+            .filter(|_| self.authorizer().is_none());
+        if let Some(scopes) = scopes {
+            // requests with a streamed body can not be cloned, and are left for the api to reject
+            if let Some(Ok(built)) = request.try_clone().map(|request| request.build()) {
+                let required = oauth::Scope::required_for(built.method(), built.url());
+                if !required.iter().any(|scope| scopes.contains(scope)) {
+                    return Err(error::Error::authorization_required(format!(
+                        "The request to `{}` requires one of the scopes: {}",
+                        self.api_path(),
+                        oauth::join_scopes(&required)
+                    )));
+                }
+            }
         }
-        if status.is_client_error() {
-            let url = response.url().clone();
-            return Err(error::Error::client_error(
-                response.json::<error::YouTubeError>().await?,
-                url,
+
+        Ok(request)
+    }
+
+    /// Checks the scopes of the request when it is authorized on behalf of a user, for apis that also accept an api
+    /// key only and return private data to an authorized user.
+    fn optional_bearer_auth(
+        &self,
+        youtube: &YouTube,
+        request: reqwest::RequestBuilder,
+    ) -> error::Result<reqwest::RequestBuilder> {
+        if self.authorizes_user(youtube) {
+            self.bearer_auth(youtube, request)
+        } else {
+            Ok(request)
+        }
+    }
+
+    /// Returns a builder error when `parameter`, which acts on behalf of the authorized user, is used while no
+    /// authorizer acts on behalf of a user.
+    fn require_authorization(&self, youtube: &YouTube, parameter: &str) -> error::Result<()> {
+        if self.authorizes_user(youtube) {
+            Ok(())
+        } else {
+            Err(error::Error::authorization_required(format!(
+                "The request uses the `{}` parameter but is not properly authorized",
+                parameter
+            )))
+        }
+    }
+
+    /// Returns a builder error when one of `parts` is only returned to the owner of the resource and no authorizer
+    /// acts on behalf of a user, instead of spending the quota of a request that the api rejects.
+    fn require_part_authorization(
+        &self,
+        youtube: &YouTube,
+        parts: impl IntoIterator<Item = impl std::fmt::Display>,
+    ) -> error::Result<()> {
+        if self.authorizes_user(youtube) {
+            return Ok(());
+        }
+        let parts = parts
+            .into_iter()
+            .map(|part| part.to_string())
+            .collect::<Vec<String>>();
+        if parts.is_empty() {
+            Ok(())
+        } else {
+            Err(error::Error::authorization_required(format!(
+                "The request uses the parts only returned to the owner of the resource but is not properly \
+                 authorized: {}",
+                parts.join(", ")
+            )))
+        }
+    }
+
+    /// Inserts the content owner delegation parameters. `on_behalf_of_content_owner` identifies the content owner
+    /// whose CMS account the access token belongs to, so it requires authorization, and
+    /// `on_behalf_of_content_owner_channel` is only valid together with it.
+    fn insert_content_owner_parameters(
+        &self,
+        youtube: &YouTube,
+        map: &mut HashMap<String, String>,
+        on_behalf_of_content_owner: Option<&str>,
+        on_behalf_of_content_owner_channel: Option<&str>,
+    ) -> error::Result<()> {
+        let on_behalf_of_content_owner = on_behalf_of_content_owner.filter(|v| !v.is_empty());
+        let on_behalf_of_content_owner_channel =
+            on_behalf_of_content_owner_channel.filter(|v| !v.is_empty());
+        if on_behalf_of_content_owner_channel.is_some() && on_behalf_of_content_owner.is_none() {
+            return Err(error::Error::missing_required_parameter(
+                "The `on_behalf_of_content_owner_channel` parameter requires `on_behalf_of_content_owner`",
             ));
+        }
+        if on_behalf_of_content_owner.is_some() {
+            self.require_authorization(youtube, "on_behalf_of_content_owner")?;
+        }
+        self.insert_query_parameter(map, "onBehalfOfContentOwner", on_behalf_of_content_owner);
+        self.insert_query_parameter(
+            map,
+            "onBehalfOfContentOwnerChannel",
+            on_behalf_of_content_owner_channel,
+        );
+        Ok(())
+    }
+
+    /// Sends a request whose successful response has no content (`204 No Content`). Requests without a body still
+    /// announce an empty body, since the api rejects `POST` requests without a `Content-Length`.
+    async fn send_without_content(
+        &self,
+        youtube: &YouTube,
+        request: reqwest::RequestBuilder,
+    ) -> error::Result<()> {
+        self.send(youtube, request.header(reqwest::header::CONTENT_LENGTH, 0))
+            .await?;
+        Ok(())
+    }
+
+    /// Attaches the api key of `youtube` and the credentials of the user, either selected for this request or
+    /// configured on the handle, and sends the request.
+    async fn send(
+        &self,
+        youtube: &YouTube,
+        request: reqwest::RequestBuilder,
+    ) -> error::Result<reqwest::Response> {
+        let mut request = request.build()?;
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(timeout) = self.request_timeout() {
+            *request.timeout_mut() = Some(timeout);
+        }
+        #[cfg(target_arch = "wasm32")]
+        let timeout = self.request_timeout().or(youtube.inner().timeout);
+        youtube.apply_defaults(&mut request)?;
+        // a request polled with `PagedListApi::poll` is conditional on the ETag of the last response
+        if let Ok(Ok(etag)) =
+            IF_NONE_MATCH.try_with(|etag| reqwest::header::HeaderValue::from_str(etag))
+        {
+            request
+                .headers_mut()
+                .insert(reqwest::header::IF_NONE_MATCH, etag);
+        }
+        let first_key = youtube.inner().api_key.select();
+        let mut key = first_key;
+        if let Some(key) = key {
+            youtube.inner().api_key.apply(&mut request, key);
+        }
+        if let Some(authorizer) = self.authorizer().or(youtube.inner().authorizer.as_ref()) {
+            authorizer.authorize(&mut request).await?;
+        }
+
+        #[cfg(feature = "tracing")]
+        let span = instrument::request_span(self.api_path(), &request);
+        let method = request.method().clone();
+        let quota_cost = metrics::quota_cost(&method, self.api_path(), request.url());
+        let started = timer::Instant::now();
+        let record = |status: Option<reqwest::StatusCode>, attempts: u32| {
+            youtube.inner().metrics.record(&RequestMetrics {
+                endpoint: self.api_path(),
+                method: &method,
+                duration: started.elapsed(),
+                status,
+                attempts,
+                quota_cost: quota_cost * attempts,
+            });
+        };
+
+        let mut attempt = 0;
+        let mut base = 0;
+        let response = loop {
+            if let Some(rate_limit) = &youtube.inner().rate_limit {
+                if let Err(e) = youtube.cancellable(rate_limit.acquire()).await {
+                    record(None, attempt);
+                    return Err(e);
+                }
+            }
+            // requests with a streamed body can not be cloned, and are sent only once
+            let retry = request.try_clone();
+            let execute = youtube.inner().transport.execute(request);
+            #[cfg(target_arch = "wasm32")]
+            let execute = timer::timeout(timeout, execute);
+            let response = match youtube
+                .cancellable(execute)
+                .await
+                .and_then(|response| response)
+            {
+                Ok(response) => response,
+                Err(e) => {
+                    if e.is_connect() {
+                        if let Some(mut retry) = retry {
+                            if let Some(url) = youtube.failover(retry.url(), &mut base) {
+                                *retry.url_mut() = url;
+                                request = retry;
+                                attempt += 1;
+                                continue;
+                            }
+                        }
+                    }
+                    record(None, attempt + 1);
+                    return Err(e);
+                }
+            };
+            let backoff =
+                youtube
+                    .inner()
+                    .retry
+                    .backoff(attempt, response.status(), response.headers());
+            match (retry, backoff) {
+                (Some(retry), Some(backoff)) => {
+                    if let Err(e) = youtube.cancellable(timer::sleep(backoff)).await {
+                        record(Some(response.status()), attempt + 1);
+                        return Err(e);
+                    }
+                    request = retry;
+                    attempt += 1;
+                }
+                (Some(mut retry), None)
+                    if response.status() == reqwest::StatusCode::FORBIDDEN
+                        && youtube.inner().api_key.is_pool() =>
+                {
+                    let url = response.url().clone();
+                    let error = youtube_error(response).await?;
+                    let next = match (key, first_key) {
+                        (Some(exhausted), Some(first)) if error.is_quota_exceeded() => {
+                            youtube.inner().api_key.rotate(exhausted, first)
+                        }
+                        _ => None,
+                    };
+                    match next {
+                        // retry with the next key of the pool
+                        Some(next) => {
+                            youtube.inner().api_key.apply(&mut retry, next);
+                            key = Some(next);
+                            request = retry;
+                            attempt += 1;
+                        }
+                        None => {
+                            record(Some(reqwest::StatusCode::FORBIDDEN), attempt + 1);
+                            return Err(error::Error::client_error(error, url));
+                        }
+                    }
+                }
+                _ => break response,
+            }
+        };
+        record(Some(response.status()), attempt + 1);
+        #[cfg(feature = "tracing")]
+        let response = instrument::record_response(span, started, attempt + 1, response);
+        if response.status().is_success() {
+            response::record(&response, quota_cost * (attempt + 1));
+            return Ok(response);
+        }
+        let status = response.status();
+        let url = response.url().clone();
+        if status == reqwest::StatusCode::NOT_MODIFIED {
+            return Err(error::Error::not_modified(url));
+        }
+        let error = youtube_error(response).await?;
+        if status.is_client_error() {
+            Err(error::Error::client_error(error, url))
         } else {
-            // TODO: implement a handle for the server error status code
-            panic!("Server Error");
+            // the api does not answer with informational or redirection responses, a proxy in between might
+            Err(error::Error::server_error(error, url))
         }
     }
 }
 
-#[async_trait]
+// the private supertrait seals the list traits: only the builders of the crate implement them
+#[allow(private_bounds)]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait ListApi<T>: YouTubeDataApi
 where
     T: serde::Serialize,
 {
     async fn request(&self) -> error::Result<T>;
+
+    /// Sends the request like [request](Self::request), with the status, the ETag, the caching headers, the latency
+    /// and the quota cost of the response, see [Response::capture](response::Response::capture).
+    async fn request_with_response(&self) -> error::Result<response::Response<T>>
+    where
+        Self: Sync,
+        T: Send,
+    {
+        response::Response::capture(self.request()).await
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// A list endpoint whose results are split into pages, linked by `nextPageToken`. The endpoints that return all of
+/// their results at once (e.g. `i18nRegions` or `captions`) and the live chat, whose pages never end, are not paged.
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait PagedListApi<T>: ListApi<ListResponse<T>>
+where
+    T: serde::Serialize + Send + 'static,
+{
+    /// Requests the page of `page_token` with the other parameters of the builder, or the page of the `page_token` of
+    /// the builder for `None`.
+    async fn request_page(&self, page_token: Option<&str>) -> error::Result<ListResponse<T>>;
+
+    /// A cursor of the page of `page_token`, e.g. the `next_page_token` of the last page processed, or of the
+    /// `page_token` of the builder for `None`. Saved cursors are resumed with [YouTube::resume].
+    fn cursor(&self, page_token: Option<&str>) -> error::Result<cursor::PageCursor>;
+
+    /// The pages of the results, starting at the `page_token` of the builder and following `nextPageToken` until the
+    /// last page. A page is requested when the stream is polled; an error ends the stream.
+    fn pages(&self) -> BoxStream<'_, error::Result<ListResponse<T>>>
+    where
+        Self: Sync,
+    {
+        follow_pages(self, Paginated::next_token)
+    }
+
+    /// The pages before the `page_token` of the builder, starting at that page and following `prevPageToken` back
+    /// to the first page.
+    fn pages_rev(&self) -> BoxStream<'_, error::Result<ListResponse<T>>>
+    where
+        Self: Sync,
+    {
+        follow_pages(self, Paginated::prev_token)
+    }
+
+    /// The page after `page`, `None` when `page` is the last page.
+    async fn next_page(&self, page: &ListResponse<T>) -> error::Result<Option<ListResponse<T>>>
+    where
+        T: Sync,
+    {
+        match page.next_token() {
+            Some(page_token) => Ok(Some(self.request_page(Some(page_token)).await?)),
+            None => Ok(None),
+        }
+    }
+
+    /// The page before `page`, `None` when `page` is the first page.
+    async fn prev_page(&self, page: &ListResponse<T>) -> error::Result<Option<ListResponse<T>>>
+    where
+        T: Sync,
+    {
+        match page.prev_token() {
+            Some(page_token) => Ok(Some(self.request_page(Some(page_token)).await?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Sends the request of the first page every `interval` and yields the responses that changed: the first
+    /// response, then every response whose ETag differs from the previous one. The requests carry the ETag of the
+    /// last response in `If-None-Match`, so the api answers `304 Not Modified` without a body while nothing changed,
+    /// e.g. to notice the uploads of a channel by polling its uploads playlist; a `200 OK` with the same ETag is
+    /// skipped as well. A response without an ETag can not be compared and is always yielded. An error ends the
+    /// stream. The chunks of a [VideoList](videos::VideoList) of more than 50 ids are polled with an ETag each, and
+    /// the merged response is yielded when one of them changed.
+    fn poll(&self, interval: Duration) -> BoxStream<'_, error::Result<ListResponse<T>>>
+    where
+        Self: Sync,
+    {
+        poll_pages(self, interval)
+    }
+
+    /// The resources of every page, one at a time, e.g. `while let Some(video) = items.try_next().await? { ... }`.
+    /// With a `limit`, the stream ends after `limit` resources and the following pages are not requested.
+    fn items(&self, limit: Option<usize>) -> BoxStream<'_, error::Result<T>>
+    where
+        Self: Sync,
+    {
+        let items = self
+            .pages()
+            .map_ok(|page| futures_util::stream::iter(page.items.into_iter().map(Ok)))
+            .try_flatten();
+        match limit {
+            Some(limit) => Box::pin(items.take(limit)),
+            None => Box::pin(items),
+        }
+    }
+
+    /// Requests pages until `max_items` resources are collected or the last page is returned. Every page costs the
+    /// quota of one list request, so `max_items` also bounds the quota spent; set `max_results` to the maximum of the
+    /// endpoint to need fewer requests.
+    async fn collect_all(&self, max_items: usize) -> error::Result<Collected<T>>
+    where
+        Self: Sync,
+    {
+        let mut collected = Collected {
+            items: Vec::new(),
+            requests: 0,
+        };
+        let mut pages = self.pages();
+        while collected.items.len() < max_items {
+            let page = match pages.try_next().await? {
+                Some(page) => page,
+                None => break,
+            };
+            collected.requests += 1;
+            let remaining = max_items - collected.items.len();
+            collected
+                .items
+                .extend(page.items.into_iter().take(remaining));
+        }
+        Ok(collected)
+    }
+}
+
+tokio::task_local! {
+    /// The ETag sent as `If-None-Match` by the requests of [PagedListApi::poll].
+    static IF_NONE_MATCH: String;
+}
+
+/// Requests the pages of `list`, starting at its `page_token` and following the token returned by `token` until it
+/// is missing.
+pub(crate) fn follow_pages<'a, T, L>(
+    list: &'a L,
+    token: fn(&ListResponse<T>) -> Option<&str>,
+) -> BoxStream<'a, error::Result<ListResponse<T>>>
+where
+    T: serde::Serialize + Send + 'static,
+    L: PagedListApi<T> + Sync + ?Sized,
+{
+    Box::pin(futures_util::stream::try_unfold(
+        Some(None::<String>),
+        move |page_token| async move {
+            // `None` once the last page was returned
+            let page_token = match page_token {
+                Some(page_token) => page_token,
+                None => return Ok(None),
+            };
+            let page = list.request_page(page_token.as_deref()).await?;
+            let next = token(&page).map(|next| Some(next.to_string()));
+            Ok(Some((page, next)))
+        },
+    ))
+}
+
+/// Requests the first page of `list` every `interval` and yields the responses that changed, see
+/// [PagedListApi::poll].
+pub(crate) fn poll_pages<'a, T, L>(
+    list: &'a L,
+    interval: Duration,
+) -> BoxStream<'a, error::Result<ListResponse<T>>>
+where
+    T: serde::Serialize + Send + 'static,
+    L: PagedListApi<T> + Sync + ?Sized,
+{
+    // the state is the ETag of the last response, `None` before the first request
+    Box::pin(futures_util::stream::try_unfold(
+        None::<Option<String>>,
+        move |last| async move {
+            loop {
+                let response = match &last {
+                    Some(etag) => {
+                        timer::sleep(interval).await;
+                        match etag {
+                            Some(etag) => IF_NONE_MATCH.scope(etag.clone(), list.request()).await,
+                            None => list.request().await,
+                        }
+                    }
+                    None => list.request().await,
+                };
+                match response {
+                    Ok(page) => {
+                        let etag = Some(page.etag.clone()).filter(|etag| !etag.is_empty());
+                        if etag.is_some() && last.as_ref() == Some(&etag) {
+                            continue;
+                        }
+                        return Ok(Some((page, Some(etag))));
+                    }
+                    Err(e) if e.is_not_modified() => continue,
+                    Err(e) => return Err(e),
+                }
+            }
+        },
+    ))
+}
+
+/// The paging information shared by the list responses: the tokens of the adjacent pages and the page sizes.
+pub trait Paginated {
+    /// The token of the next page, `None` on the last page.
+    fn next_token(&self) -> Option<&str>;
+
+    /// The token of the previous page, `None` on the first page or when the endpoint only pages forward.
+    fn prev_token(&self) -> Option<&str>;
+
+    fn page_info(&self) -> &PageInfo;
+
+    fn has_next(&self) -> bool {
+        self.next_token().is_some()
+    }
+
+    fn has_prev(&self) -> bool {
+        self.prev_token().is_some()
+    }
+
+    /// The number of resources in the page, as reported by the api.
+    fn results_per_page(&self) -> i32 {
+        self.page_info().results_per_page
+    }
+
+    /// The approximate number of resources in all pages, at most 1,000,000.
+    fn total_results(&self) -> i32 {
+        self.page_info().total_results
+    }
+}
+
+impl<T> Paginated for ListResponse<T> {
+    fn next_token(&self) -> Option<&str> {
+        self.next_page_token
+            .as_deref()
+            .filter(|token| !token.is_empty())
+    }
+
+    fn prev_token(&self) -> Option<&str> {
+        self.prev_page_token
+            .as_deref()
+            .filter(|token| !token.is_empty())
+    }
+
+    fn page_info(&self) -> &PageInfo {
+        &self.page_info
+    }
+}
+
+/// The resources collected by [PagedListApi::collect_all].
+#[derive(Debug)]
+pub struct Collected<T> {
+    /// The resources in the order of the pages, at most `max_items` of them.
+    pub items: Vec<T>,
+
+    /// The number of pages requested.
+    pub requests: u32,
+}
+
+/// Deserializes the body of a list response. With the `tracing` feature, the number of items is recorded on the span
+/// of the request.
+pub(crate) async fn list_json<T>(response: reqwest::Response) -> error::Result<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    #[cfg(feature = "tracing")]
+    return instrument::list_json(response).await;
+    #[cfg(not(feature = "tracing"))]
+    json(response).await
+}
+
+/// The parts of a request without the repeated ones, in the order of their first occurrence, so that part lists can be
+/// combined, e.g. `all_public()` with a part of the owner, without repeating a part in the query.
+pub(crate) fn dedupe<P: PartialEq>(parts: impl IntoIterator<Item = P>) -> Vec<P> {
+    let mut unique = Vec::new();
+    for part in parts {
+        if !unique.contains(&part) {
+            unique.push(part);
+        }
+    }
+    unique
+}
+
+/// Reads the error of a response that was not successful. A body that is not an error of the api, e.g. the HTML page
+/// of a proxy, is kept as the message of an error with the status code of the response.
+async fn youtube_error(response: reqwest::Response) -> error::Result<error::YouTubeError> {
+    let status = response.status().as_u16();
+    let retry_after = client::retry_after(response.headers());
+    let body = response.bytes().await?;
+    let error = serde_json::from_slice::<error::YouTubeError>(&body)
+        .unwrap_or_else(|_| error::YouTubeError::from_status(status, &body));
+    Ok(error.with_retry_after(retry_after))
+}
+
+/// Deserializes the body of a response. When the body does not match `T`, the error keeps the body and the path of
+/// the failing field.
+pub(crate) async fn json<T>(response: reqwest::Response) -> error::Result<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let url = response.url().clone();
+    let body = response.bytes().await?;
+    error::decode_json(&body, Some(url))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ListResponse<T> {
     /// Identifies the API resource's type.
+    #[serde(default)]
     pub kind: String,
 
     /// The ETag of the response.
+    #[serde(default)]
     pub etag: String,
 
     /// The token that chan be used as the value of pageToken parameter to retrieve the next page in the result set.
-    #[serde(alias = "nextPageToken")]
     pub next_page_token: Option<String>,
 
     /// The token that can be used as the value of the pageToken parameter to retrieve the previous page in the result set.
-    #[serde(alias = "prevPageToken")]
     pub prev_page_token: Option<String>,
 
     /// The region code that was used for the search query. The property value is a two-letter ISO country code that
     /// identifies the region. The [i18nRegions](https://developers.google.com/youtube/v3/docs/i18nRegions/list).
     /// list method returns a list of supported regions. The default value is US. if a non-supported region is specified,
     /// YouTube might still select another region, rather than the default value, to handle the query.
-    #[serde(alias = "regionCode")]
     pub region_code: Option<String>,
 
-    #[serde(alias = "pageInfo")]
+    #[serde(default)]
     pub page_info: PageInfo,
 
     /// A list of results that match the criteria.
+    #[serde(default = "Vec::new")]
     pub items: Vec<T>,
 }
 
 /// The `pageInfo` object encapsulates paging information for the result set.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PageInfo {
     /// The total number of results in the result set. Please note that the value is an approximation and may not
     /// represent an exact value. In addition, the maximum value is 1,000,000.
-    #[serde(alias = "totalResults")]
+    #[serde(default)]
     pub total_results: i32,
 
     /// The number of results included in the API response.
-    #[serde(alias = "resultsPerPage")]
+    #[serde(default)]
     pub results_per_page: i32,
 }
 
-#[derive(Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub enum ThumbnailKind {
     /// The default thumbnail image. The default thumbnail for a video – or a resource that refers to a video,
     /// such as a playlist item or search result – is 120px wide and 90px tall. The default thumbnail for a channel is
     /// 88px wide and 88px tall.
-    #[serde(alias = "default")]
     Default,
 
     /// A higher resolution version of the thumbnail image. For a video (or a resource that refers to a video),
     /// this image is 320px wide and 180px tall. For a channel, this image is 240px wide and 240px tall.
-    #[serde(alias = "medium")]
     Medium,
 
     /// A high resolution version of the thumbnail image. For a video (or a resource that refers to a video),
     /// this image is 480px wide and 360px tall. For a channel, this image is 800px wide and 800px tall.
-    #[serde(alias = "high")]
     High,
 
     /// An even higher resolution version of the thumbnail image than the high resolution image.
     /// This image is available for some videos and other resources that refer to videos, like playlist items
     /// or search results. This image is 640px wide and 480px tall.
-    #[serde(alias = "standard")]
     Standard,
 
     /// The highest resolution version of the thumbnail image. This image size is available for some videos and
     /// other resources that refer to videos, like playlist items or search results. This image is 1280px wide and 720px tall.
-    #[serde(alias = "maxres")]
     Maxres,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+impl ThumbnailKind {
+    /// The kinds from the smallest to the largest image.
+    const ALL: [ThumbnailKind; 5] = [
+        ThumbnailKind::Default,
+        ThumbnailKind::Medium,
+        ThumbnailKind::High,
+        ThumbnailKind::Standard,
+        ThumbnailKind::Maxres,
+    ];
+}
+
+/// The thumbnail images of a resource. Which sizes are available depends on the resource, e.g. only some videos
+/// have a `maxres` thumbnail and channels have no `standard` and `maxres` thumbnails.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Thumbnails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<Thumbnail>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub medium: Option<Thumbnail>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub high: Option<Thumbnail>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub standard: Option<Thumbnail>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maxres: Option<Thumbnail>,
+}
+
+impl Thumbnails {
+    /// The image of the `kind` size, if available.
+    pub fn get(&self, kind: ThumbnailKind) -> Option<&Thumbnail> {
+        match kind {
+            ThumbnailKind::Default => self.default.as_ref(),
+            ThumbnailKind::Medium => self.medium.as_ref(),
+            ThumbnailKind::High => self.high.as_ref(),
+            ThumbnailKind::Standard => self.standard.as_ref(),
+            ThumbnailKind::Maxres => self.maxres.as_ref(),
+        }
+    }
+
+    /// The available images, from the smallest to the largest.
+    pub fn iter(&self) -> impl Iterator<Item = (ThumbnailKind, &Thumbnail)> {
+        ThumbnailKind::ALL
+            .into_iter()
+            .filter_map(|kind| self.get(kind).map(|thumbnail| (kind, thumbnail)))
+    }
+
+    /// The largest available image.
+    pub fn best(&self) -> Option<&Thumbnail> {
+        self.iter().last().map(|(_, thumbnail)| thumbnail)
+    }
+
+    /// The smallest available image.
+    pub fn smallest(&self) -> Option<&Thumbnail> {
+        self.iter().next().map(|(_, thumbnail)| thumbnail)
+    }
+
+    /// The smallest available image that is at least `width` pixels wide, e.g. to fill a grid cell without
+    /// upscaling. The images of unknown width are skipped.
+    pub fn at_least(&self, width: u32) -> Option<&Thumbnail> {
+        self.iter()
+            .map(|(_, thumbnail)| thumbnail)
+            .find(|thumbnail| thumbnail.width.is_some_and(|w| w >= width))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Thumbnail {
     /// The image's URL.
+    #[serde(default)]
     pub url: String,
 
     /// The image's width.
@@ -244,26 +1192,160 @@ pub struct Thumbnail {
     pub height: Option<u32>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// The media types accepted for image uploads such as thumbnails and channel banners.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImageMimeType {
+    Jpeg,
+    Png,
+}
+
+impl std::fmt::Display for ImageMimeType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            ImageMimeType::Jpeg => "image/jpeg",
+            ImageMimeType::Png => "image/png",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+/// Called while a media body is being sent with the number of bytes sent so far and the total number of bytes.
+pub type UploadProgress = Arc<dyn Fn(u64, u64) + Send + Sync>;
+
+/// The size of the chunks a media body is split into when upload progress is reported.
+#[cfg(not(target_arch = "wasm32"))]
+const UPLOAD_CHUNK_SIZE: usize = 256 * 1024;
+
+/// Builds the body of a media upload. Without a progress callback the media is sent as a single buffer, otherwise it
+/// is streamed in chunks and `on_progress` is called as each chunk is handed to the connection.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn media_body(media: Vec<u8>, on_progress: Option<UploadProgress>) -> reqwest::Body {
+    let on_progress = match on_progress {
+        Some(on_progress) => on_progress,
+        None => return reqwest::Body::from(media),
+    };
+    let total = media.len() as u64;
+    let chunks = media
+        .chunks(UPLOAD_CHUNK_SIZE)
+        .map(|chunk| chunk.to_vec())
+        .collect::<Vec<Vec<u8>>>();
+    let mut sent = 0;
+    let stream = futures_util::stream::iter(chunks.into_iter().map(move |chunk| {
+        sent += chunk.len() as u64;
+        on_progress(sent, total);
+        Ok::<_, std::io::Error>(chunk)
+    }));
+    reqwest::Body::wrap_stream(stream)
+}
+
+/// Builds the body of a media upload. A body can not be streamed on wasm32, so `on_progress` is called once with the
+/// whole media when the body is handed to the browser.
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn media_body(media: Vec<u8>, on_progress: Option<UploadProgress>) -> reqwest::Body {
+    if let Some(on_progress) = on_progress {
+        let total = media.len() as u64;
+        on_progress(total, total);
+    }
+    reqwest::Body::from(media)
+}
+
+/// A random boundary of a multipart body, e.g. `upload_8c1f…`, that does not occur in any of `contents`.
+pub(crate) fn multipart_boundary(prefix: &str, contents: &[&[u8]]) -> String {
+    loop {
+        let boundary = format!("{}_{:016x}", prefix, rand::random::<u64>());
+        let occurs = contents.iter().any(|content| {
+            content
+                .windows(boundary.len())
+                .any(|window| window == boundary.as_bytes())
+        });
+        if !occurs {
+            return boundary;
+        }
+    }
+}
+
+/// Builds the body of a `multipart/related` upload, which sends the metadata of a resource as JSON together with its
+/// media. Returns the content type of the body and the body itself.
+pub(crate) fn multipart_related_body(
+    metadata: &impl Serialize,
+    media: &[u8],
+    media_type: impl std::fmt::Display,
+) -> error::Result<(String, Vec<u8>)> {
+    let metadata = serde_json::to_vec(metadata)
+        .map_err(|err| error::Error::invalid_parameter(format!("Invalid metadata: {}", err)))?;
+    let boundary = multipart_boundary("upload", &[&metadata, media]);
+
+    let mut body = Vec::with_capacity(metadata.len() + media.len() + 256);
+    body.extend_from_slice(
+        format!(
+            "--{}\r\nContent-Type: application/json; charset=UTF-8\r\n\r\n",
+            boundary
+        )
+        .as_bytes(),
+    );
+    body.extend_from_slice(&metadata);
+    body.extend_from_slice(
+        format!("\r\n--{}\r\nContent-Type: {}\r\n\r\n", boundary, media_type).as_bytes(),
+    );
+    body.extend_from_slice(media);
+    body.extend_from_slice(format!("\r\n--{}--\r\n", boundary).as_bytes());
+
+    Ok((format!("multipart/related; boundary={}", boundary), body))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Localization {
     /// The localized description.
+    #[serde(default)]
     pub description: String,
 
     /// The localized title.
+    #[serde(default)]
     pub title: String,
 }
 
+serde_helpers::string_enum! {
+    /// The privacy status of a video, a broadcast, a playlist or a channel.
+    pub enum PrivacyStatus {
+        Private => "private",
+        Public => "public",
+        Unlisted => "unlisted",
+    }
+}
+
+serde_helpers::string_enum! {
+    /// Whether a video or a channel has live broadcast content.
+    pub enum LiveBroadcastContent {
+        /// An active live broadcast.
+        Live => "live",
+        /// No live broadcast.
+        None => "none",
+        /// A live broadcast that has not started yet.
+        Upcoming => "upcoming",
+    }
+}
+
 #[cfg(test)]
 pub(crate) fn get_develop_key() -> String {
     use dotenv::dotenv;
 
     dotenv().ok();
-    std::env::var("TEST_API_KEY").unwrap()
+    // the tests answer the requests with canned responses, so any key will do
+    std::env::var("TEST_API_KEY").unwrap_or_else(|_| "api-key".to_string())
 }
 
 #[cfg(test)]
 pub(crate) fn get_youtube_without_user_agent() -> YouTube {
-    YouTube::new(get_develop_key(), None)
+    YouTube::builder(get_develop_key()).build().unwrap()
+}
+
+/// A handle answering the requests with `transport`, e.g. a [MockTransport](transport::MockTransport).
+#[cfg(test)]
+pub(crate) fn get_youtube_with_transport(transport: impl HttpTransport + 'static) -> YouTube {
+    YouTube::builder("api-key")
+        .transport(transport)
+        .build()
+        .unwrap()
 }
 
 #[cfg(test)]
@@ -280,6 +1362,56 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_thumbnails() {
+        let thumbnails: Thumbnails = serde_json::from_str(
+            r#"{
+                "default": { "url": "https://i.ytimg.com/vi/Ks-_Mh1QhMc/default.jpg", "width": 120, "height": 90 },
+                "medium": { "url": "https://i.ytimg.com/vi/Ks-_Mh1QhMc/mqdefault.jpg", "width": 320, "height": 180 },
+                "high": { "url": "https://i.ytimg.com/vi/Ks-_Mh1QhMc/hqdefault.jpg", "width": 480, "height": 360 }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(thumbnails.best().unwrap().width, Some(480));
+        assert_eq!(thumbnails.smallest().unwrap().width, Some(120));
+        assert_eq!(thumbnails.at_least(200).unwrap().width, Some(320));
+        assert_eq!(thumbnails.at_least(320).unwrap().width, Some(320));
+        assert!(thumbnails.at_least(1280).is_none());
+        assert!(thumbnails.get(ThumbnailKind::Maxres).is_none());
+        assert_eq!(
+            thumbnails.iter().map(|(kind, _)| kind).collect::<Vec<_>>(),
+            vec![
+                ThumbnailKind::Default,
+                ThumbnailKind::Medium,
+                ThumbnailKind::High
+            ]
+        );
+        assert_eq!(
+            serde_json::to_value(&thumbnails).unwrap()["medium"]["width"],
+            320
+        );
+        assert!(serde_json::to_value(&thumbnails)
+            .unwrap()
+            .get("maxres")
+            .is_none());
+
+        let empty: Thumbnails = serde_json::from_str("{}").unwrap();
+        assert!(empty.is_empty());
+        assert!(empty.best().is_none());
+    }
+
+    #[test]
+    fn test_clone_shares_configuration() {
+        let youtube = YouTube::builder("api-key").build().unwrap();
+        let clone = youtube.clone();
+        assert!(Arc::ptr_eq(&youtube.inner, &clone.inner));
+
+        let scoped = clone.with_scopes(&[oauth::Scope::YoutubeReadonly]);
+        assert!(!Arc::ptr_eq(&youtube.inner, &scoped.inner));
+        assert!(youtube.inner().scopes.is_none());
+        assert!(scoped.inner().scopes.is_some());
+    }
+
     #[test]
     fn test_url() {
         let test = Test {};
@@ -303,8 +1435,8 @@ mod tests {
         test.insert_query_parameter(&mut map, "key4", Some(true));
 
         assert_eq!(map.get("key1").unwrap(), "value");
-        assert_eq!(map.contains_key("key2"), false);
-        assert_eq!(map.contains_key("key3"), false);
+        assert!(!map.contains_key("key2"));
+        assert!(!map.contains_key("key3"));
         assert_eq!(map.get("key4").unwrap(), "true");
     }
 
@@ -331,4 +1463,35 @@ mod tests {
 
         assert_eq!(map.get("key1").unwrap(), "value1,value2");
     }
+
+    #[test]
+    fn test_multipart_related_body() {
+        let (content_type, body) =
+            multipart_related_body(&serde_json::json!({ "id": "test" }), b"media", "image/png")
+                .unwrap();
+
+        let boundary = content_type
+            .strip_prefix("multipart/related; boundary=upload_")
+            .unwrap();
+        assert_eq!(boundary.len(), 16);
+        assert_eq!(
+            String::from_utf8(body).unwrap(),
+            format!(
+                "--upload_{0}\r\n\
+                 Content-Type: application/json; charset=UTF-8\r\n\r\n\
+                 {{\"id\":\"test\"}}\r\n\
+                 --upload_{0}\r\n\
+                 Content-Type: image/png\r\n\r\n\
+                 media\r\n\
+                 --upload_{0}--\r\n",
+                boundary
+            )
+        );
+
+        // every body gets its own boundary
+        let (other, _) =
+            multipart_related_body(&serde_json::json!({ "id": "test" }), b"media", "image/png")
+                .unwrap();
+        assert_ne!(content_type, other);
+    }
 }
@@ -0,0 +1,1714 @@
+use crate::{
+    auth::{Authorizer, OAuthTokenAuth},
+    cursor::PageCursor,
+    error::{Error, Result},
+    json, list_json, ListApi, ListResponse, PagedListApi, RequestBase, Thumbnails, YouTube,
+    YouTubeDataApi,
+};
+
+pub use crate::PrivacyStatus;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use youtube_data_derive::QueryBuilder;
+
+pub type LiveBroadcastListResponse = ListResponse<LiveBroadcastListResource>;
+
+#[derive(Clone, Copy)]
+pub struct LiveBroadcastsService<'a> {
+    youtube: &'a YouTube,
+}
+
+impl<'a> LiveBroadcastsService<'a> {
+    pub(crate) fn new(youtube: &'a YouTube) -> Self {
+        Self { youtube }
+    }
+
+    pub fn list(self, part: impl IntoIterator<Item = Part>) -> LiveBroadcastList<'a> {
+        LiveBroadcastList::new(self, part)
+    }
+
+    pub fn insert(
+        self,
+        title: impl Into<String>,
+        scheduled_start_time: DateTime<Utc>,
+        privacy_status: PrivacyStatus,
+    ) -> LiveBroadcastInsert<'a> {
+        LiveBroadcastInsert::new(self, title, scheduled_start_time, privacy_status)
+    }
+
+    pub fn update(
+        self,
+        id: impl Into<String>,
+        title: impl Into<String>,
+        scheduled_start_time: DateTime<Utc>,
+        privacy_status: PrivacyStatus,
+    ) -> LiveBroadcastUpdate<'a> {
+        LiveBroadcastUpdate::new(self, id, title, scheduled_start_time, privacy_status)
+    }
+
+    pub fn delete(self, id: impl Into<String>) -> LiveBroadcastDelete<'a> {
+        LiveBroadcastDelete::new(self, id)
+    }
+
+    pub fn transition(
+        self,
+        id: impl Into<String>,
+        broadcast_status: TransitionStatus,
+    ) -> LiveBroadcastTransition<'a> {
+        LiveBroadcastTransition::new(self, id, broadcast_status)
+    }
+
+    pub fn bind(self, id: impl Into<String>) -> LiveBroadcastBind<'a> {
+        LiveBroadcastBind::new(self, id)
+    }
+
+    pub fn insert_cuepoint(self, id: impl Into<String>) -> LiveBroadcastInsertCuepoint<'a> {
+        LiveBroadcastInsertCuepoint::new(self, id)
+    }
+}
+
+/// Parameters for the `list` method of the `liveBroadcasts` api. details:
+/// [link](https://developers.google.com/youtube/v3/live/docs/liveBroadcasts/list)
+#[derive(QueryBuilder)]
+pub struct LiveBroadcastList<'a> {
+    service: LiveBroadcastsService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    #[query(unique)]
+    part: Vec<Part>,
+
+    // filters (specify exactly one of the following parameters)
+    #[query(filter)]
+    broadcast_status: Option<BroadcastStatus>,
+    #[query(filter)]
+    id: Option<Vec<String>>,
+    #[query(filter)]
+    mine: Option<bool>,
+
+    // optional parameters
+    /// Selects the fields of the response, e.g. `items(id,snippet/title)`, so only the needed part of every
+    /// resource is returned. details: [link](https://developers.google.com/youtube/v3/getting-started#fields)
+    #[query]
+    fields: Option<String>,
+    /// Fails the request with a timeout error when it does not complete in time, instead of the timeout of the
+    /// `YouTube` handle.
+    #[setter]
+    timeout: Option<Duration>,
+    #[query]
+    broadcast_type: Option<BroadcastType>,
+    #[query(max = 50)]
+    max_results: Option<u32>,
+    #[setter]
+    on_behalf_of_content_owner: Option<String>,
+    #[setter]
+    on_behalf_of_content_owner_channel: Option<String>,
+    #[setter]
+    page_token: Option<String>,
+}
+
+impl RequestBase for LiveBroadcastList<'_> {
+    fn api_path(&self) -> &str {
+        "liveBroadcasts"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for LiveBroadcastList<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+
+    fn request_timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl ListApi<LiveBroadcastListResponse> for LiveBroadcastList<'_> {
+    async fn request(&self) -> Result<LiveBroadcastListResponse> {
+        self.request_page(None).await
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl PagedListApi<LiveBroadcastListResource> for LiveBroadcastList<'_> {
+    async fn request_page(&self, page_token: Option<&str>) -> Result<LiveBroadcastListResponse> {
+        let youtube = &self.service.youtube;
+        let params = self.params(page_token)?;
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .get(self.url(&youtube.inner().base_path))
+                .query(&params),
+        )?;
+        let response = self.send(youtube, request).await?;
+        list_json(response).await
+    }
+
+    fn cursor(&self, page_token: Option<&str>) -> Result<PageCursor> {
+        Ok(PageCursor::new(self.api_path(), self.params(page_token)?))
+    }
+}
+
+impl LiveBroadcastList<'_> {
+    /// The query parameters of the page of `page_token`, or of the `page_token` of the builder for `None`.
+    fn params(&self, page_token: Option<&str>) -> Result<HashMap<String, String>> {
+        let youtube = &self.service.youtube;
+        let page_token = page_token.or(self.page_token.as_deref());
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        self.insert_query(youtube, &mut params)?;
+        self.insert_content_owner_parameters(
+            youtube,
+            &mut params,
+            self.on_behalf_of_content_owner.as_deref(),
+            self.on_behalf_of_content_owner_channel.as_deref(),
+        )?;
+        self.insert_query_parameter(&mut params, "pageToken", page_token);
+
+        Ok(params)
+    }
+}
+
+impl<'a> LiveBroadcastList<'a> {
+    pub(crate) fn new(
+        service: LiveBroadcastsService<'a>,
+        part: impl IntoIterator<Item = Part>,
+    ) -> Self {
+        let part = crate::dedupe(part);
+        let part = if part.is_empty() {
+            vec![Part::Id]
+        } else {
+            part
+        };
+        Self {
+            service,
+            authorizer: None,
+            part,
+            broadcast_status: None,
+            id: None,
+            mine: None,
+            fields: None,
+            timeout: None,
+            broadcast_type: None,
+            max_results: None,
+            on_behalf_of_content_owner: None,
+            on_behalf_of_content_owner_channel: None,
+            page_token: None,
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+}
+
+/// Parameters for the `insert` method of the `liveBroadcasts` api. details:
+/// [link](https://developers.google.com/youtube/v3/live/docs/liveBroadcasts/insert)
+pub struct LiveBroadcastInsert<'a> {
+    service: LiveBroadcastsService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // optional parameters
+    on_behalf_of_content_owner: Option<String>,
+    on_behalf_of_content_owner_channel: Option<String>,
+
+    // request body
+    body: LiveBroadcastBody,
+}
+
+impl RequestBase for LiveBroadcastInsert<'_> {
+    fn api_path(&self) -> &str {
+        "liveBroadcasts"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for LiveBroadcastInsert<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> LiveBroadcastInsert<'a> {
+    pub(crate) fn new(
+        service: LiveBroadcastsService<'a>,
+        title: impl Into<String>,
+        scheduled_start_time: DateTime<Utc>,
+        privacy_status: PrivacyStatus,
+    ) -> Self {
+        Self {
+            service,
+            authorizer: None,
+            on_behalf_of_content_owner: None,
+            on_behalf_of_content_owner_channel: None,
+            body: LiveBroadcastBody::new(None, title.into(), scheduled_start_time, privacy_status),
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        self.body.snippet.description = Some(description.into());
+        self
+    }
+
+    pub fn scheduled_end_time(&mut self, scheduled_end_time: DateTime<Utc>) -> &mut Self {
+        self.body.snippet.scheduled_end_time = Some(scheduled_end_time);
+        self
+    }
+
+    pub fn self_declared_made_for_kids(&mut self, self_declared_made_for_kids: bool) -> &mut Self {
+        self.body.status.self_declared_made_for_kids = Some(self_declared_made_for_kids);
+        self
+    }
+
+    pub fn enable_auto_start(&mut self, enable_auto_start: bool) -> &mut Self {
+        self.body.content_details().enable_auto_start = Some(enable_auto_start);
+        self
+    }
+
+    pub fn enable_auto_stop(&mut self, enable_auto_stop: bool) -> &mut Self {
+        self.body.content_details().enable_auto_stop = Some(enable_auto_stop);
+        self
+    }
+
+    pub fn enable_dvr(&mut self, enable_dvr: bool) -> &mut Self {
+        self.body.content_details().enable_dvr = Some(enable_dvr);
+        self
+    }
+
+    pub fn enable_embed(&mut self, enable_embed: bool) -> &mut Self {
+        self.body.content_details().enable_embed = Some(enable_embed);
+        self
+    }
+
+    pub fn record_from_start(&mut self, record_from_start: bool) -> &mut Self {
+        self.body.content_details().record_from_start = Some(record_from_start);
+        self
+    }
+
+    pub fn latency_preference(&mut self, latency_preference: LatencyPreference) -> &mut Self {
+        self.body.content_details().latency_preference = Some(latency_preference);
+        self
+    }
+
+    /// Enables or disables the monitor stream. `broadcast_stream_delay_ms` is the delay of the broadcast stream when
+    /// the monitor stream is enabled.
+    pub fn monitor_stream(
+        &mut self,
+        enable_monitor_stream: bool,
+        broadcast_stream_delay_ms: u32,
+    ) -> &mut Self {
+        self.body.content_details().monitor_stream = Some(MonitorStreamBody {
+            enable_monitor_stream,
+            broadcast_stream_delay_ms,
+        });
+        self
+    }
+
+    pub fn on_behalf_of_content_owner(
+        &mut self,
+        on_behalf_of_content_owner: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+        self
+    }
+
+    pub fn on_behalf_of_content_owner_channel(
+        &mut self,
+        on_behalf_of_content_owner_channel: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner_channel = Some(on_behalf_of_content_owner_channel.into());
+        self
+    }
+
+    /// Creates a scheduled broadcast and returns the created broadcast.
+    pub async fn request(&self) -> Result<LiveBroadcastListResource> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        // required parameters
+        self.insert_query_parameters(&mut params, "part", Some(&self.body.part()));
+
+        // optional parameters
+        self.insert_content_owner_parameters(
+            youtube,
+            &mut params,
+            self.on_behalf_of_content_owner.as_deref(),
+            self.on_behalf_of_content_owner_channel.as_deref(),
+        )?;
+
+        // request body
+        self.body.validate()?;
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .post(self.url(&youtube.inner().base_path))
+                .query(&params)
+                .json(&self.body),
+        )?;
+        let response = self.send(youtube, request).await?;
+        json(response).await
+    }
+}
+
+/// Parameters for the `update` method of the `liveBroadcasts` api. details:
+/// [link](https://developers.google.com/youtube/v3/live/docs/liveBroadcasts/update)
+///
+/// The update replaces every property of the written parts, so properties that should be kept have to be set again.
+/// When any content detail is set, the `monitor_stream` settings are required as well.
+pub struct LiveBroadcastUpdate<'a> {
+    service: LiveBroadcastsService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // optional parameters
+    on_behalf_of_content_owner: Option<String>,
+    on_behalf_of_content_owner_channel: Option<String>,
+
+    // request body
+    body: LiveBroadcastBody,
+}
+
+impl RequestBase for LiveBroadcastUpdate<'_> {
+    fn api_path(&self) -> &str {
+        "liveBroadcasts"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for LiveBroadcastUpdate<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> LiveBroadcastUpdate<'a> {
+    pub(crate) fn new(
+        service: LiveBroadcastsService<'a>,
+        id: impl Into<String>,
+        title: impl Into<String>,
+        scheduled_start_time: DateTime<Utc>,
+        privacy_status: PrivacyStatus,
+    ) -> Self {
+        Self {
+            service,
+            authorizer: None,
+            on_behalf_of_content_owner: None,
+            on_behalf_of_content_owner_channel: None,
+            body: LiveBroadcastBody::new(
+                Some(id.into()),
+                title.into(),
+                scheduled_start_time,
+                privacy_status,
+            ),
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        self.body.snippet.description = Some(description.into());
+        self
+    }
+
+    pub fn scheduled_end_time(&mut self, scheduled_end_time: DateTime<Utc>) -> &mut Self {
+        self.body.snippet.scheduled_end_time = Some(scheduled_end_time);
+        self
+    }
+
+    pub fn self_declared_made_for_kids(&mut self, self_declared_made_for_kids: bool) -> &mut Self {
+        self.body.status.self_declared_made_for_kids = Some(self_declared_made_for_kids);
+        self
+    }
+
+    pub fn enable_auto_start(&mut self, enable_auto_start: bool) -> &mut Self {
+        self.body.content_details().enable_auto_start = Some(enable_auto_start);
+        self
+    }
+
+    pub fn enable_auto_stop(&mut self, enable_auto_stop: bool) -> &mut Self {
+        self.body.content_details().enable_auto_stop = Some(enable_auto_stop);
+        self
+    }
+
+    pub fn enable_dvr(&mut self, enable_dvr: bool) -> &mut Self {
+        self.body.content_details().enable_dvr = Some(enable_dvr);
+        self
+    }
+
+    pub fn enable_embed(&mut self, enable_embed: bool) -> &mut Self {
+        self.body.content_details().enable_embed = Some(enable_embed);
+        self
+    }
+
+    pub fn record_from_start(&mut self, record_from_start: bool) -> &mut Self {
+        self.body.content_details().record_from_start = Some(record_from_start);
+        self
+    }
+
+    pub fn latency_preference(&mut self, latency_preference: LatencyPreference) -> &mut Self {
+        self.body.content_details().latency_preference = Some(latency_preference);
+        self
+    }
+
+    /// Enables or disables the monitor stream. `broadcast_stream_delay_ms` is the delay of the broadcast stream when
+    /// the monitor stream is enabled.
+    pub fn monitor_stream(
+        &mut self,
+        enable_monitor_stream: bool,
+        broadcast_stream_delay_ms: u32,
+    ) -> &mut Self {
+        self.body.content_details().monitor_stream = Some(MonitorStreamBody {
+            enable_monitor_stream,
+            broadcast_stream_delay_ms,
+        });
+        self
+    }
+
+    pub fn on_behalf_of_content_owner(
+        &mut self,
+        on_behalf_of_content_owner: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+        self
+    }
+
+    pub fn on_behalf_of_content_owner_channel(
+        &mut self,
+        on_behalf_of_content_owner_channel: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner_channel = Some(on_behalf_of_content_owner_channel.into());
+        self
+    }
+
+    /// Updates a broadcast and returns the updated broadcast.
+    pub async fn request(&self) -> Result<LiveBroadcastListResource> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        // required parameters
+        self.insert_query_parameters(&mut params, "part", Some(&self.body.part()));
+
+        // optional parameters
+        self.insert_content_owner_parameters(
+            youtube,
+            &mut params,
+            self.on_behalf_of_content_owner.as_deref(),
+            self.on_behalf_of_content_owner_channel.as_deref(),
+        )?;
+
+        // request body
+        if self.body.id.as_deref().is_none_or(str::is_empty) {
+            return Err(Error::missing_required_parameter(
+                "Required property `id` is empty",
+            ));
+        }
+        if let Some(content_details) = &self.body.content_details {
+            if content_details.monitor_stream.is_none() {
+                return Err(Error::missing_required_parameter(
+                    "Required property `content_details.monitor_stream` is not set",
+                ));
+            }
+        }
+        self.body.validate()?;
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .put(self.url(&youtube.inner().base_path))
+                .query(&params)
+                .json(&self.body),
+        )?;
+        let response = self.send(youtube, request).await?;
+        json(response).await
+    }
+}
+
+/// Parameters for the `delete` method of the `liveBroadcasts` api. details:
+/// [link](https://developers.google.com/youtube/v3/live/docs/liveBroadcasts/delete)
+pub struct LiveBroadcastDelete<'a> {
+    service: LiveBroadcastsService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    id: String,
+
+    // optional parameters
+    on_behalf_of_content_owner: Option<String>,
+    on_behalf_of_content_owner_channel: Option<String>,
+}
+
+impl RequestBase for LiveBroadcastDelete<'_> {
+    fn api_path(&self) -> &str {
+        "liveBroadcasts"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for LiveBroadcastDelete<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> LiveBroadcastDelete<'a> {
+    pub(crate) fn new(service: LiveBroadcastsService<'a>, id: impl Into<String>) -> Self {
+        Self {
+            service,
+            authorizer: None,
+            id: id.into(),
+            on_behalf_of_content_owner: None,
+            on_behalf_of_content_owner_channel: None,
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+
+    pub fn on_behalf_of_content_owner(
+        &mut self,
+        on_behalf_of_content_owner: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+        self
+    }
+
+    pub fn on_behalf_of_content_owner_channel(
+        &mut self,
+        on_behalf_of_content_owner_channel: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner_channel = Some(on_behalf_of_content_owner_channel.into());
+        self
+    }
+
+    /// Deletes a broadcast. The api responds with an empty body on success.
+    pub async fn request(&self) -> Result<()> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        // required parameters
+        if self.id.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required parameter `id` is empty",
+            ));
+        }
+        self.insert_query_parameter(&mut params, "id", Some(&self.id));
+
+        // optional parameters
+        self.insert_content_owner_parameters(
+            youtube,
+            &mut params,
+            self.on_behalf_of_content_owner.as_deref(),
+            self.on_behalf_of_content_owner_channel.as_deref(),
+        )?;
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .delete(self.url(&youtube.inner().base_path))
+                .query(&params),
+        )?;
+        self.send_without_content(youtube, request).await
+    }
+}
+
+/// Parameters for the `transition` method of the `liveBroadcasts` api. details:
+/// [link](https://developers.google.com/youtube/v3/live/docs/liveBroadcasts/transition)
+pub struct LiveBroadcastTransition<'a> {
+    service: LiveBroadcastsService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    part: Vec<Part>,
+    id: String,
+    broadcast_status: TransitionStatus,
+
+    // optional parameters
+    on_behalf_of_content_owner: Option<String>,
+    on_behalf_of_content_owner_channel: Option<String>,
+}
+
+impl RequestBase for LiveBroadcastTransition<'_> {
+    fn api_path(&self) -> &str {
+        "liveBroadcasts/transition"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for LiveBroadcastTransition<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> LiveBroadcastTransition<'a> {
+    pub(crate) fn new(
+        service: LiveBroadcastsService<'a>,
+        id: impl Into<String>,
+        broadcast_status: TransitionStatus,
+    ) -> Self {
+        Self {
+            service,
+            authorizer: None,
+            part: vec![Part::Id, Part::Status],
+            id: id.into(),
+            broadcast_status,
+            on_behalf_of_content_owner: None,
+            on_behalf_of_content_owner_channel: None,
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+
+    pub fn part(&mut self, part: Vec<Part>) -> &mut Self {
+        self.part = part;
+        self
+    }
+
+    pub fn on_behalf_of_content_owner(
+        &mut self,
+        on_behalf_of_content_owner: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+        self
+    }
+
+    pub fn on_behalf_of_content_owner_channel(
+        &mut self,
+        on_behalf_of_content_owner_channel: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner_channel = Some(on_behalf_of_content_owner_channel.into());
+        self
+    }
+
+    /// Changes the status of a broadcast and returns the broadcast. The transition is asynchronous, so the returned
+    /// `status.life_cycle_status` may still be an intermediate state such as `testStarting` or `liveStarting`.
+    pub async fn request(&self) -> Result<LiveBroadcastListResource> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        // required parameters
+        self.insert_query_parameters(&mut params, "part", Some(&self.part));
+        if self.id.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required parameter `id` is empty",
+            ));
+        }
+        self.insert_query_parameter(&mut params, "id", Some(&self.id));
+        self.insert_query_parameter(&mut params, "broadcastStatus", Some(&self.broadcast_status));
+
+        // optional parameters
+        self.insert_content_owner_parameters(
+            youtube,
+            &mut params,
+            self.on_behalf_of_content_owner.as_deref(),
+            self.on_behalf_of_content_owner_channel.as_deref(),
+        )?;
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .post(self.url(&youtube.inner().base_path))
+                .header(reqwest::header::CONTENT_LENGTH, 0)
+                .query(&params),
+        )?;
+        let response = self.send(youtube, request).await?;
+        json(response).await
+    }
+}
+
+/// Parameters for the `bind` method of the `liveBroadcasts` api. details:
+/// [link](https://developers.google.com/youtube/v3/live/docs/liveBroadcasts/bind)
+pub struct LiveBroadcastBind<'a> {
+    service: LiveBroadcastsService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    part: Vec<Part>,
+    id: String,
+
+    // optional parameters
+    on_behalf_of_content_owner: Option<String>,
+    on_behalf_of_content_owner_channel: Option<String>,
+    stream_id: Option<String>,
+}
+
+impl RequestBase for LiveBroadcastBind<'_> {
+    fn api_path(&self) -> &str {
+        "liveBroadcasts/bind"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for LiveBroadcastBind<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> LiveBroadcastBind<'a> {
+    pub(crate) fn new(service: LiveBroadcastsService<'a>, id: impl Into<String>) -> Self {
+        Self {
+            service,
+            authorizer: None,
+            part: vec![Part::Id, Part::ContentDetails],
+            id: id.into(),
+            on_behalf_of_content_owner: None,
+            on_behalf_of_content_owner_channel: None,
+            stream_id: None,
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+
+    pub fn part(&mut self, part: Vec<Part>) -> &mut Self {
+        self.part = part;
+        self
+    }
+
+    pub fn on_behalf_of_content_owner(
+        &mut self,
+        on_behalf_of_content_owner: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+        self
+    }
+
+    pub fn on_behalf_of_content_owner_channel(
+        &mut self,
+        on_behalf_of_content_owner_channel: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner_channel = Some(on_behalf_of_content_owner_channel.into());
+        self
+    }
+
+    /// The stream to bind to the broadcast. Without a stream id, the broadcast is unbound from its current stream.
+    pub fn stream_id(&mut self, stream_id: impl Into<String>) -> &mut Self {
+        self.stream_id = Some(stream_id.into());
+        self
+    }
+
+    /// Binds a live stream to the broadcast, or removes the existing binding, and returns the broadcast.
+    pub async fn request(&self) -> Result<LiveBroadcastListResource> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        // required parameters
+        self.insert_query_parameters(&mut params, "part", Some(&self.part));
+        if self.id.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required parameter `id` is empty",
+            ));
+        }
+        self.insert_query_parameter(&mut params, "id", Some(&self.id));
+
+        // optional parameters
+        self.insert_content_owner_parameters(
+            youtube,
+            &mut params,
+            self.on_behalf_of_content_owner.as_deref(),
+            self.on_behalf_of_content_owner_channel.as_deref(),
+        )?;
+        self.insert_query_parameter(&mut params, "streamId", self.stream_id.as_deref());
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .post(self.url(&youtube.inner().base_path))
+                .header(reqwest::header::CONTENT_LENGTH, 0)
+                .query(&params),
+        )?;
+        let response = self.send(youtube, request).await?;
+        json(response).await
+    }
+}
+
+/// Parameters for the `insertCuepoint` method of the `liveBroadcasts` api, which replaces the former
+/// `liveCuepoints.insert` method. details:
+/// [link](https://developers.google.com/youtube/v3/live/docs/liveBroadcasts/insertCuepoint)
+///
+/// The cuepoint is inserted immediately unless either `insertion_offset_time_ms` or `walltime_ms` is set.
+pub struct LiveBroadcastInsertCuepoint<'a> {
+    service: LiveBroadcastsService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    id: String,
+
+    // optional parameters
+    on_behalf_of_content_owner: Option<String>,
+    on_behalf_of_content_owner_channel: Option<String>,
+
+    // request body
+    body: CuepointBody,
+}
+
+impl RequestBase for LiveBroadcastInsertCuepoint<'_> {
+    fn api_path(&self) -> &str {
+        "liveBroadcasts/cuepoint"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for LiveBroadcastInsertCuepoint<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> LiveBroadcastInsertCuepoint<'a> {
+    pub(crate) fn new(service: LiveBroadcastsService<'a>, id: impl Into<String>) -> Self {
+        Self {
+            service,
+            authorizer: None,
+            id: id.into(),
+            on_behalf_of_content_owner: None,
+            on_behalf_of_content_owner_channel: None,
+            body: CuepointBody {
+                cue_type: CueType::CueTypeAd,
+                duration_secs: None,
+                insertion_offset_time_ms: None,
+                walltime_ms: None,
+            },
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+
+    pub fn on_behalf_of_content_owner(
+        &mut self,
+        on_behalf_of_content_owner: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+        self
+    }
+
+    pub fn on_behalf_of_content_owner_channel(
+        &mut self,
+        on_behalf_of_content_owner_channel: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner_channel = Some(on_behalf_of_content_owner_channel.into());
+        self
+    }
+
+    /// The duration of the ad break, in seconds. The default value is `30`.
+    pub fn duration_secs(&mut self, duration_secs: u32) -> &mut Self {
+        self.body.duration_secs = Some(duration_secs);
+        self
+    }
+
+    /// The time offset, in milliseconds, from the start of the monitor stream at which the cuepoint is inserted.
+    pub fn insertion_offset_time_ms(&mut self, insertion_offset_time_ms: i64) -> &mut Self {
+        self.body.insertion_offset_time_ms = Some(insertion_offset_time_ms);
+        self
+    }
+
+    /// The wall clock time at which the cuepoint is inserted.
+    pub fn walltime(&mut self, walltime: DateTime<Utc>) -> &mut Self {
+        self.body.walltime_ms = Some(walltime.timestamp_millis());
+        self
+    }
+
+    /// Inserts the cuepoint into the broadcast and returns the inserted cuepoint.
+    pub async fn request(&self) -> Result<Cuepoint> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        // required parameters
+        if self.id.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required parameter `id` is empty",
+            ));
+        }
+        self.insert_query_parameter(&mut params, "id", Some(&self.id));
+
+        // optional parameters
+        self.insert_content_owner_parameters(
+            youtube,
+            &mut params,
+            self.on_behalf_of_content_owner.as_deref(),
+            self.on_behalf_of_content_owner_channel.as_deref(),
+        )?;
+
+        // request body
+        if self.body.insertion_offset_time_ms.is_some() && self.body.walltime_ms.is_some() {
+            return Err(Error::incompatible_parameters(
+                "Incompatible parameters specified in the request: insertion_offset_time_ms, walltime",
+            ));
+        }
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .post(self.url(&youtube.inner().base_path))
+                .query(&params)
+                .json(&self.body),
+        )?;
+        let response = self.send(youtube, request).await?;
+        json(response).await
+    }
+}
+
+/// The request body of `liveBroadcasts.insertCuepoint`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CuepointBody {
+    cue_type: CueType,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duration_secs: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    insertion_offset_time_ms: Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    walltime_ms: Option<i64>,
+}
+
+/// The request body of `liveBroadcasts.insert` and `liveBroadcasts.update`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LiveBroadcastBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+
+    snippet: LiveBroadcastBodySnippet,
+
+    status: LiveBroadcastBodyStatus,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_details: Option<LiveBroadcastBodyContentDetails>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LiveBroadcastBodySnippet {
+    title: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+
+    scheduled_start_time: DateTime<Utc>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scheduled_end_time: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LiveBroadcastBodyStatus {
+    privacy_status: PrivacyStatus,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    self_declared_made_for_kids: Option<bool>,
+}
+
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LiveBroadcastBodyContentDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    monitor_stream: Option<MonitorStreamBody>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enable_auto_start: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enable_auto_stop: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enable_dvr: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enable_embed: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    record_from_start: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    latency_preference: Option<LatencyPreference>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MonitorStreamBody {
+    enable_monitor_stream: bool,
+    broadcast_stream_delay_ms: u32,
+}
+
+impl LiveBroadcastBody {
+    fn new(
+        id: Option<String>,
+        title: String,
+        scheduled_start_time: DateTime<Utc>,
+        privacy_status: PrivacyStatus,
+    ) -> Self {
+        Self {
+            id,
+            snippet: LiveBroadcastBodySnippet {
+                title,
+                description: None,
+                scheduled_start_time,
+                scheduled_end_time: None,
+            },
+            status: LiveBroadcastBodyStatus {
+                privacy_status,
+                self_declared_made_for_kids: None,
+            },
+            content_details: None,
+        }
+    }
+
+    fn content_details(&mut self) -> &mut LiveBroadcastBodyContentDetails {
+        self.content_details.get_or_insert_with(Default::default)
+    }
+
+    /// The parts written by the request. `contentDetails` is only sent when one of its properties is set.
+    fn part(&self) -> Vec<Part> {
+        let mut part = vec![Part::Snippet, Part::Status];
+        if self.content_details.is_some() {
+            part.push(Part::ContentDetails);
+        }
+        part
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.snippet.title.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required property `snippet.title` is empty",
+            ));
+        }
+        if let Some(scheduled_end_time) = self.snippet.scheduled_end_time {
+            if scheduled_end_time <= self.snippet.scheduled_start_time {
+                return Err(Error::invalid_parameter(
+                    "The `snippet.scheduled_end_time` must be later than the `snippet.scheduled_start_time`",
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    ContentDetails,
+    Id,
+    MonetizationDetails,
+    Snippet,
+    Status,
+}
+
+impl Part {
+    /// Every part of the resource, to request all of its properties.
+    pub fn all_public() -> Vec<Part> {
+        vec![
+            Part::ContentDetails,
+            Part::Id,
+            Part::MonetizationDetails,
+            Part::Snippet,
+            Part::Status,
+        ]
+    }
+}
+
+impl std::fmt::Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            Part::ContentDetails => "contentDetails",
+            Part::Id => "id",
+            Part::MonetizationDetails => "monetizationDetails",
+            Part::Snippet => "snippet",
+            Part::Status => "status",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+/// The broadcastStatus parameter filters the api response to only include broadcasts with the specified status.
+pub enum BroadcastStatus {
+    /// Return current live broadcasts.
+    Active,
+
+    /// Return all broadcasts.
+    All,
+
+    /// Return broadcasts that have already ended.
+    Completed,
+
+    /// Return broadcasts that have not yet started.
+    Upcoming,
+}
+
+impl std::fmt::Display for BroadcastStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            BroadcastStatus::Active => "active",
+            BroadcastStatus::All => "all",
+            BroadcastStatus::Completed => "completed",
+            BroadcastStatus::Upcoming => "upcoming",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+/// The broadcastType parameter filters the api response to only include broadcasts with the specified type.
+pub enum BroadcastType {
+    /// Return all broadcasts. This is the default value.
+    All,
+
+    /// Return only scheduled event broadcasts.
+    Event,
+
+    /// Return only persistent broadcasts.
+    Persistent,
+}
+
+impl std::fmt::Display for BroadcastType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            BroadcastType::All => "all",
+            BroadcastType::Event => "event",
+            BroadcastType::Persistent => "persistent",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+/// The status a broadcast is transitioned to.
+pub enum TransitionStatus {
+    /// The broadcast is over. YouTube stops transmitting video.
+    Complete,
+
+    /// The broadcast is visible to its audience. YouTube transmits video to the broadcast's monitor stream and its
+    /// broadcast stream.
+    Live,
+
+    /// Start testing the broadcast. YouTube transmits video to the broadcast's monitor stream. The monitor stream has
+    /// to be enabled to transition to this status.
+    Testing,
+}
+
+impl std::fmt::Display for TransitionStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            TransitionStatus::Complete => "complete",
+            TransitionStatus::Live => "live",
+            TransitionStatus::Testing => "testing",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+/// The latency setting of a broadcast.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LatencyPreference {
+    Low,
+    Normal,
+    UltraLow,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveBroadcastListResource {
+    /// Identifies the API resource's type. The value will be `youtube#liveBroadcast`.
+    #[serde(default)]
+    pub kind: String,
+
+    /// The ETag of the response.
+    #[serde(default)]
+    pub etag: String,
+
+    /// The ID that YouTube assigns to uniquely identify the broadcast.
+    #[serde(default)]
+    pub id: String,
+
+    pub snippet: Option<LiveBroadcastSnippet>,
+
+    pub status: Option<LiveBroadcastStatus>,
+
+    pub content_details: Option<LiveBroadcastContentDetails>,
+
+    pub monetization_details: Option<LiveBroadcastMonetizationDetails>,
+
+    /// The fields of the resource that this version of the crate does not know, e.g. added to the api after its
+    /// release. They are serialized back with the resource.
+    #[serde(flatten, deserialize_with = "crate::serde_helpers::extra_fields")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// The snippet object contains basic details about the event, including its title, description, start time, and end
+/// time.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveBroadcastSnippet {
+    /// The date and time that the broadcast was added to YouTube's live broadcast schedule. The value is specified in
+    /// [ISO 8601](https://www.w3.org/TR/NOTE-datetime) format.
+    pub published_at: Option<DateTime<Utc>>,
+
+    /// The ID that YouTube uses to uniquely identify the channel that is publishing the broadcast.
+    #[serde(default)]
+    pub channel_id: String,
+
+    /// The broadcast's title. Note that the broadcast represents exactly one YouTube video.
+    #[serde(default)]
+    pub title: String,
+
+    /// The broadcast's description. As with the title, you can set this field by modifying the broadcast resource or
+    /// by setting the description field of the corresponding video resource.
+    #[serde(default)]
+    pub description: String,
+
+    /// A map of thumbnail images associated with the broadcast. For each nested object in this object, the key is the
+    /// name of the thumbnail image, and the value is an object that contains other information about the thumbnail.
+    #[serde(default)]
+    pub thumbnails: Thumbnails,
+
+    /// The date and time that the broadcast is scheduled to start.
+    pub scheduled_start_time: Option<DateTime<Utc>>,
+
+    /// The date and time that the broadcast is scheduled to end. If this property is not set, the broadcast is
+    /// scheduled to continue indefinitely.
+    pub scheduled_end_time: Option<DateTime<Utc>>,
+
+    /// The date and time that the broadcast actually started. This information is only available once the broadcast's
+    /// state is `live`.
+    pub actual_start_time: Option<DateTime<Utc>>,
+
+    /// The date and time that the broadcast actually ended. This information is only available once the broadcast's
+    /// state is `complete`.
+    pub actual_end_time: Option<DateTime<Utc>>,
+
+    /// Indicates whether this broadcast is the default broadcast.
+    pub is_default_broadcast: Option<bool>,
+
+    /// The ID for the broadcast's YouTube live chat. With this ID, you can use the `liveChatMessages` resource to
+    /// retrieve, insert, or delete chat messages.
+    pub live_chat_id: Option<String>,
+}
+
+/// The status object contains information about the event's status.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveBroadcastStatus {
+    /// The broadcast's status. The status can be updated using the `liveBroadcasts.transition` method.
+    ///
+    /// Valid values for this property are:
+    ///
+    /// * complete
+    /// * created
+    /// * live
+    /// * liveStarting
+    /// * ready
+    /// * revoked
+    /// * testStarting
+    /// * testing
+    #[serde(default)]
+    pub life_cycle_status: String,
+
+    /// The broadcast's privacy status.
+    pub privacy_status: Option<PrivacyStatus>,
+
+    /// The broadcast's recording status.
+    ///
+    /// Valid values for this property are:
+    ///
+    /// * notRecording
+    /// * recorded
+    /// * recording
+    pub recording_status: Option<String>,
+
+    /// This value indicates whether the broadcast is designated as child-directed, and it contains the current
+    /// "made for kids" status of the broadcast.
+    pub made_for_kids: Option<bool>,
+
+    /// In a `liveBroadcasts.insert` or `liveBroadcasts.update` request, this property allows the channel owner to
+    /// designate the broadcast as being child-directed.
+    pub self_declared_made_for_kids: Option<bool>,
+}
+
+/// The contentDetails object contains information about the event's video content, such as whether the content can
+/// be shown in an embedded video player or if it will be archived and therefore available for viewing after the event
+/// has concluded.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveBroadcastContentDetails {
+    /// This value uniquely identifies the live video stream bound to the broadcast.
+    pub bound_stream_id: Option<String>,
+
+    /// The date and time that the live stream referenced by `bound_stream_id` was last updated.
+    pub bound_stream_last_update_time_ms: Option<DateTime<Utc>>,
+
+    /// The monitorStream object contains information about the monitor stream, which the broadcaster can use to
+    /// review the event content before the broadcast stream is shown publicly.
+    pub monitor_stream: Option<MonitorStream>,
+
+    /// This setting indicates whether the broadcast video can be played in an embedded player.
+    pub enable_embed: Option<bool>,
+
+    /// This setting determines whether viewers can access DVR controls while watching the video.
+    pub enable_dvr: Option<bool>,
+
+    /// This setting indicates whether YouTube will automatically start recording the broadcast after the event's
+    /// status changes to live.
+    pub record_from_start: Option<bool>,
+
+    /// This property has been deprecated. Use `closed_captions_type` instead.
+    pub enable_closed_captions: Option<bool>,
+
+    /// Indicates how closed captions are provided for the broadcast.
+    ///
+    /// Valid values for this property are:
+    ///
+    /// * closedCaptionsDisabled
+    /// * closedCaptionsHttpPost
+    /// * closedCaptionsEmbedded
+    pub closed_captions_type: Option<String>,
+
+    /// The projection format of this broadcast. The default value is `rectangular`.
+    ///
+    /// Valid values for this property are:
+    ///
+    /// * 360
+    /// * rectangular
+    pub projection: Option<String>,
+
+    /// Indicates whether this broadcast has low latency enabled.
+    pub enable_low_latency: Option<bool>,
+
+    /// Indicates which latency setting to use for this broadcast.
+    ///
+    /// Valid values for this property are:
+    ///
+    /// * low
+    /// * normal
+    /// * ultraLow
+    pub latency_preference: Option<String>,
+
+    /// Indicates whether the broadcast should automatically begin with an in-stream slate when you update the
+    /// broadcast's status to live.
+    pub enable_auto_start: Option<bool>,
+
+    /// Indicates whether the broadcast should automatically end when the stream ends.
+    pub enable_auto_stop: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MonitorStream {
+    /// This value determines whether the monitor stream is enabled for the broadcast.
+    #[serde(default)]
+    pub enable_monitor_stream: bool,
+
+    /// If you have set the `enable_monitor_stream` property to true, then this property determines the length of the
+    /// live broadcast delay.
+    pub broadcast_stream_delay_ms: Option<u32>,
+
+    /// HTML code that embeds a player that plays the monitor stream.
+    pub embed_html: Option<String>,
+}
+
+/// The monetizationDetails object contains information about the broadcast's monetization details.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveBroadcastMonetizationDetails {
+    /// The cuepointSchedule object specifies a schedule for automated ad cuepoints.
+    pub cuepoint_schedule: Option<CuepointSchedule>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CuepointSchedule {
+    /// This value determines whether automated cuepoints are enabled for the broadcast.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// If set, automatic cuepoint insertion is paused until this timestamp.
+    pub pause_ads_until: Option<DateTime<Utc>>,
+
+    /// The strategy to use when scheduling cuepoints.
+    ///
+    /// Valid values for this property are:
+    ///
+    /// * concurrent
+    /// * nonConcurrent
+    pub schedule_strategy: Option<String>,
+
+    /// Interval frequency in seconds that api uses to insert cuepoints automatically.
+    pub repeat_interval_secs: Option<u32>,
+}
+
+/// A cuepoint inserted into a broadcast.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Cuepoint {
+    /// The ID that YouTube assigns to uniquely identify the cuepoint.
+    pub id: Option<String>,
+
+    /// The ETag of the response.
+    pub etag: Option<String>,
+
+    /// The type of the cuepoint.
+    pub cue_type: CueType,
+
+    /// The duration of the cuepoint, in seconds.
+    pub duration_secs: Option<u32>,
+
+    /// The time offset, in milliseconds, from the start of the monitor stream at which the cuepoint was inserted.
+    pub insertion_offset_time_ms: Option<String>,
+
+    /// The wall clock time at which the cuepoint was inserted, in milliseconds since the epoch.
+    pub walltime_ms: Option<String>,
+}
+
+/// The type of a cuepoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CueType {
+    /// An ad break.
+    CueTypeAd,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_youtube_without_user_agent;
+
+    use chrono::TimeZone;
+
+    #[tokio::test]
+    async fn test_request_without_auth() {
+        let without_auth = get_youtube_without_user_agent()
+            .live_broadcasts()
+            .list(vec![Part::Snippet, Part::Status])
+            .broadcast_status(BroadcastStatus::Upcoming)
+            .request()
+            .await;
+        assert!(without_auth.is_err());
+        let err = without_auth.unwrap_err();
+        assert_eq!(
+            "builder error: \"The request to `liveBroadcasts` requires OAuth 2.0 authorization\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_request_multiple_filters() {
+        let multiple_filters = get_youtube_without_user_agent()
+            .live_broadcasts()
+            .list(vec![])
+            .mine(true)
+            .broadcast_status(BroadcastStatus::Active)
+            .request()
+            .await;
+        assert!(multiple_filters.is_err());
+        let err = multiple_filters.unwrap_err();
+        assert_eq!(
+            "builder error: \"Incompatible parameters specified in the request: broadcast_status, mine\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_insert_without_auth() {
+        let without_auth = get_youtube_without_user_agent()
+            .live_broadcasts()
+            .insert(
+                "Launch stream",
+                Utc.with_ymd_and_hms(2030, 1, 1, 12, 0, 0).unwrap(),
+                PrivacyStatus::Unlisted,
+            )
+            .enable_auto_start(true)
+            .request()
+            .await;
+        assert!(without_auth.is_err());
+        let err = without_auth.unwrap_err();
+        assert_eq!(
+            "builder error: \"The request to `liveBroadcasts` requires OAuth 2.0 authorization\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_insert_with_end_before_start() {
+        let end_before_start = get_youtube_without_user_agent()
+            .live_broadcasts()
+            .insert(
+                "Launch stream",
+                Utc.with_ymd_and_hms(2030, 1, 1, 12, 0, 0).unwrap(),
+                PrivacyStatus::Private,
+            )
+            .scheduled_end_time(Utc.with_ymd_and_hms(2030, 1, 1, 11, 0, 0).unwrap())
+            .request()
+            .await;
+        assert!(end_before_start.is_err());
+        let err = end_before_start.unwrap_err();
+        assert_eq!(
+            "builder error: \"The `snippet.scheduled_end_time` must be later than the `snippet.scheduled_start_time`\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_content_details_without_monitor_stream() {
+        let without_monitor_stream = get_youtube_without_user_agent()
+            .live_broadcasts()
+            .update(
+                "broadcast-id",
+                "Launch stream",
+                Utc.with_ymd_and_hms(2030, 1, 1, 12, 0, 0).unwrap(),
+                PrivacyStatus::Public,
+            )
+            .enable_dvr(false)
+            .request()
+            .await;
+        assert!(without_monitor_stream.is_err());
+        let err = without_monitor_stream.unwrap_err();
+        assert_eq!(
+            "builder error: \"Required property `content_details.monitor_stream` is not set\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_transition_without_auth() {
+        let without_auth = get_youtube_without_user_agent()
+            .live_broadcasts()
+            .transition("broadcast-id", TransitionStatus::Live)
+            .request()
+            .await;
+        assert!(without_auth.is_err());
+        let err = without_auth.unwrap_err();
+        assert_eq!(
+            "builder error: \"The request to `liveBroadcasts/transition` requires OAuth 2.0 authorization\"",
+            format!("{}", err)
+        );
+    }
+
+    #[test]
+    fn test_serialize_live_broadcast_body() {
+        let mut body = LiveBroadcastBody::new(
+            None,
+            "Launch stream".to_string(),
+            Utc.with_ymd_and_hms(2030, 1, 1, 12, 0, 0).unwrap(),
+            PrivacyStatus::Unlisted,
+        );
+        body.content_details().latency_preference = Some(LatencyPreference::UltraLow);
+        assert_eq!(
+            r#"{"snippet":{"title":"Launch stream","scheduledStartTime":"2030-01-01T12:00:00Z"},"status":{"privacyStatus":"unlisted"},"contentDetails":{"latencyPreference":"ultraLow"}}"#,
+            serde_json::to_string(&body).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_insert_cuepoint_with_offset_and_walltime() {
+        let offset_and_walltime = get_youtube_without_user_agent()
+            .live_broadcasts()
+            .insert_cuepoint("broadcast-id")
+            .insertion_offset_time_ms(1000)
+            .walltime(Utc.with_ymd_and_hms(2030, 1, 1, 12, 0, 0).unwrap())
+            .request()
+            .await;
+        assert!(offset_and_walltime.is_err());
+        let err = offset_and_walltime.unwrap_err();
+        assert_eq!(
+            "builder error: \"Incompatible parameters specified in the request: insertion_offset_time_ms, walltime\"",
+            format!("{}", err)
+        );
+    }
+
+    #[test]
+    fn test_serialize_cuepoint_body() {
+        let body = CuepointBody {
+            cue_type: CueType::CueTypeAd,
+            duration_secs: Some(60),
+            insertion_offset_time_ms: None,
+            walltime_ms: Some(
+                Utc.with_ymd_and_hms(2030, 1, 1, 12, 0, 0)
+                    .unwrap()
+                    .timestamp_millis(),
+            ),
+        };
+        assert_eq!(
+            r#"{"cueType":"cueTypeAd","durationSecs":60,"walltimeMs":1893499200000}"#,
+            serde_json::to_string(&body).unwrap()
+        );
+    }
+}
@@ -0,0 +1,956 @@
+use crate::{
+    auth::{Authorizer, OAuthTokenAuth},
+    error::{Error, Result},
+    json, list_json,
+    super_chat_events::SuperStickerMetadata,
+    ListApi, PageInfo, Paginated, RequestBase, YouTube, YouTubeDataApi,
+};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use youtube_data_derive::QueryBuilder;
+
+#[derive(Clone, Copy)]
+pub struct LiveChatMessagesService<'a> {
+    youtube: &'a YouTube,
+}
+
+impl<'a> LiveChatMessagesService<'a> {
+    pub(crate) fn new(youtube: &'a YouTube) -> Self {
+        Self { youtube }
+    }
+
+    pub fn list(
+        self,
+        live_chat_id: impl Into<String>,
+        part: impl IntoIterator<Item = Part>,
+    ) -> LiveChatMessageList<'a> {
+        LiveChatMessageList::new(self, live_chat_id, part)
+    }
+
+    pub fn insert(
+        self,
+        live_chat_id: impl Into<String>,
+        message_text: impl Into<String>,
+    ) -> LiveChatMessageInsert<'a> {
+        LiveChatMessageInsert::new(self, live_chat_id, message_text)
+    }
+
+    pub fn delete(self, id: impl Into<String>) -> LiveChatMessageDelete<'a> {
+        LiveChatMessageDelete::new(self, id)
+    }
+}
+
+/// Parameters for the `list` method of the `liveChatMessages` api. details:
+/// [link](https://developers.google.com/youtube/v3/live/docs/liveChatMessages/list)
+#[derive(QueryBuilder)]
+pub struct LiveChatMessageList<'a> {
+    service: LiveChatMessagesService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    live_chat_id: String,
+    #[query(unique)]
+    part: Vec<Part>,
+
+    // optional parameters
+    /// Selects the fields of the response, e.g. `items(id,snippet/title)`, so only the needed part of every
+    /// resource is returned. details: [link](https://developers.google.com/youtube/v3/getting-started#fields)
+    #[query]
+    fields: Option<String>,
+    /// Fails the request with a timeout error when it does not complete in time, instead of the timeout of the
+    /// `YouTube` handle.
+    #[setter]
+    timeout: Option<Duration>,
+    /// The language used for the `snippet.display_message` of Super Chats and Super Stickers.
+    #[query(default)]
+    hl: Option<String>,
+    #[query(min = 200, max = 2000)]
+    max_results: Option<u32>,
+    /// The `next_page_token` of the previous response. Without a page token, the most recent messages are returned.
+    #[query]
+    page_token: Option<String>,
+    /// The size of the user profile pictures in `author_details`, in pixels.
+    #[query(min = 16, max = 720)]
+    profile_image_size: Option<u32>,
+}
+
+impl RequestBase for LiveChatMessageList<'_> {
+    fn api_path(&self) -> &str {
+        "liveChat/messages"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for LiveChatMessageList<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+
+    fn request_timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl ListApi<LiveChatMessageListResponse> for LiveChatMessageList<'_> {
+    async fn request(&self) -> Result<LiveChatMessageListResponse> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        // required parameters
+        if self.live_chat_id.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required parameter `live_chat_id` is empty",
+            ));
+        }
+        self.insert_query_parameter(&mut params, "liveChatId", Some(&self.live_chat_id));
+        self.insert_query(youtube, &mut params)?;
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .get(self.url(&youtube.inner().base_path))
+                .query(&params),
+        )?;
+        let response = self.send(youtube, request).await?;
+        list_json(response).await
+    }
+}
+
+impl<'a> LiveChatMessageList<'a> {
+    pub(crate) fn new(
+        service: LiveChatMessagesService<'a>,
+        live_chat_id: impl Into<String>,
+        part: impl IntoIterator<Item = Part>,
+    ) -> Self {
+        let part = crate::dedupe(part);
+        let part = if part.is_empty() {
+            vec![Part::Id, Part::Snippet]
+        } else {
+            part
+        };
+        Self {
+            service,
+            authorizer: None,
+            live_chat_id: live_chat_id.into(),
+            part,
+            fields: None,
+            timeout: None,
+            hl: None,
+            max_results: None,
+            page_token: None,
+            profile_image_size: None,
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+}
+
+/// Parameters for the `insert` method of the `liveChatMessages` api. details:
+/// [link](https://developers.google.com/youtube/v3/live/docs/liveChatMessages/insert)
+pub struct LiveChatMessageInsert<'a> {
+    service: LiveChatMessagesService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    part: Vec<Part>,
+
+    // optional parameters
+    on_behalf_of_content_owner: Option<String>,
+    on_behalf_of_content_owner_channel: Option<String>,
+
+    // request body
+    live_chat_id: String,
+    message_text: String,
+}
+
+impl RequestBase for LiveChatMessageInsert<'_> {
+    fn api_path(&self) -> &str {
+        "liveChat/messages"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for LiveChatMessageInsert<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> LiveChatMessageInsert<'a> {
+    pub(crate) fn new(
+        service: LiveChatMessagesService<'a>,
+        live_chat_id: impl Into<String>,
+        message_text: impl Into<String>,
+    ) -> Self {
+        Self {
+            service,
+            authorizer: None,
+            part: vec![Part::Snippet],
+            on_behalf_of_content_owner: None,
+            on_behalf_of_content_owner_channel: None,
+            live_chat_id: live_chat_id.into(),
+            message_text: message_text.into(),
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+
+    /// The parts returned in the response. `snippet` is always written.
+    pub fn part(&mut self, part: Vec<Part>) -> &mut Self {
+        self.part = part;
+        self
+    }
+
+    pub fn on_behalf_of_content_owner(
+        &mut self,
+        on_behalf_of_content_owner: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+        self
+    }
+
+    /// The channel the message is posted as, when the request is made on behalf of a content owner. The
+    /// `on_behalf_of_content_owner` parameter has to be set as well.
+    pub fn on_behalf_of_content_owner_channel(
+        &mut self,
+        on_behalf_of_content_owner_channel: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner_channel = Some(on_behalf_of_content_owner_channel.into());
+        self
+    }
+
+    /// Posts a text message to the live chat and returns the posted message.
+    pub async fn request(&self) -> Result<LiveChatMessageListResource> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        // required parameters
+        self.insert_query_parameters(&mut params, "part", Some(&self.part));
+
+        // optional parameters
+        self.insert_content_owner_parameters(
+            youtube,
+            &mut params,
+            self.on_behalf_of_content_owner.as_deref(),
+            self.on_behalf_of_content_owner_channel.as_deref(),
+        )?;
+
+        // request body
+        if self.live_chat_id.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required property `snippet.live_chat_id` is empty",
+            ));
+        }
+        if self.message_text.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required property `snippet.text_message_details.message_text` is empty",
+            ));
+        }
+        let body = LiveChatMessageBody {
+            snippet: LiveChatMessageBodySnippet {
+                live_chat_id: &self.live_chat_id,
+                message_type: "textMessageEvent",
+                text_message_details: TextMessageBodyDetails {
+                    message_text: &self.message_text,
+                },
+            },
+        };
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .post(self.url(&youtube.inner().base_path))
+                .query(&params)
+                .json(&body),
+        )?;
+        let response = self.send(youtube, request).await?;
+        json(response).await
+    }
+}
+
+/// Parameters for the `delete` method of the `liveChatMessages` api. details:
+/// [link](https://developers.google.com/youtube/v3/live/docs/liveChatMessages/delete)
+pub struct LiveChatMessageDelete<'a> {
+    service: LiveChatMessagesService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    id: String,
+}
+
+impl RequestBase for LiveChatMessageDelete<'_> {
+    fn api_path(&self) -> &str {
+        "liveChat/messages"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for LiveChatMessageDelete<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> LiveChatMessageDelete<'a> {
+    pub(crate) fn new(service: LiveChatMessagesService<'a>, id: impl Into<String>) -> Self {
+        Self {
+            service,
+            authorizer: None,
+            id: id.into(),
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+
+    /// Deletes a message from the live chat. Only the chat owner and moderators can delete messages of other users.
+    /// The api responds with an empty body on success.
+    pub async fn request(&self) -> Result<()> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        // required parameters
+        if self.id.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required parameter `id` is empty",
+            ));
+        }
+        self.insert_query_parameter(&mut params, "id", Some(&self.id));
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .delete(self.url(&youtube.inner().base_path))
+                .query(&params),
+        )?;
+        self.send_without_content(youtube, request).await
+    }
+}
+
+/// The request body of `liveChatMessages.insert`. Only text messages can be inserted.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LiveChatMessageBody<'a> {
+    snippet: LiveChatMessageBodySnippet<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LiveChatMessageBodySnippet<'a> {
+    live_chat_id: &'a str,
+    #[serde(rename = "type")]
+    message_type: &'a str,
+    text_message_details: TextMessageBodyDetails<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TextMessageBodyDetails<'a> {
+    message_text: &'a str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    AuthorDetails,
+    Id,
+    Snippet,
+}
+
+impl Part {
+    /// Every part of the resource, to request all of its properties.
+    pub fn all_public() -> Vec<Part> {
+        vec![Part::AuthorDetails, Part::Id, Part::Snippet]
+    }
+}
+
+impl std::fmt::Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            Part::AuthorDetails => "authorDetails",
+            Part::Id => "id",
+            Part::Snippet => "snippet",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+/// The response of `liveChatMessages.list`. Unlike other list responses, it tells how long to wait before polling for
+/// new messages with `next_page_token`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveChatMessageListResponse {
+    /// Identifies the API resource's type. The value will be `youtube#liveChatMessageListResponse`.
+    #[serde(default)]
+    pub kind: String,
+
+    /// The ETag of the response.
+    #[serde(default)]
+    pub etag: String,
+
+    /// The token that can be used as the value of the pageToken parameter to retrieve the next page in the result set.
+    pub next_page_token: Option<String>,
+
+    /// The amount of time, in milliseconds, that the client should wait before polling for new live chat messages.
+    #[serde(default)]
+    pub polling_interval_millis: u64,
+
+    /// The date and time when the underlying stream went offline. This property is only present if the stream has
+    /// already gone offline.
+    pub offline_at: Option<DateTime<Utc>>,
+
+    #[serde(default)]
+    pub page_info: PageInfo,
+
+    /// A list of live chat messages.
+    #[serde(default)]
+    pub items: Vec<LiveChatMessageListResource>,
+}
+
+impl Paginated for LiveChatMessageListResponse {
+    fn next_token(&self) -> Option<&str> {
+        self.next_page_token
+            .as_deref()
+            .filter(|token| !token.is_empty())
+    }
+
+    /// The live chat only pages forward.
+    fn prev_token(&self) -> Option<&str> {
+        None
+    }
+
+    fn page_info(&self) -> &PageInfo {
+        &self.page_info
+    }
+}
+
+impl LiveChatMessageListResponse {
+    /// The time to wait before requesting the next page.
+    pub fn polling_interval(&self) -> Duration {
+        Duration::from_millis(self.polling_interval_millis)
+    }
+
+    /// Whether the chat has ended, in which case no new messages will be returned.
+    pub fn is_offline(&self) -> bool {
+        self.offline_at.is_some()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveChatMessageListResource {
+    /// Identifies the API resource's type. The value will be `youtube#liveChatMessage`.
+    #[serde(default)]
+    pub kind: String,
+
+    /// The ETag of the response.
+    #[serde(default)]
+    pub etag: String,
+
+    /// The ID that YouTube assigns to uniquely identify the message.
+    #[serde(default)]
+    pub id: String,
+
+    pub snippet: Option<LiveChatMessageSnippet>,
+
+    pub author_details: Option<AuthorDetails>,
+}
+
+/// The snippet object contains basic details about the message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveChatMessageSnippet {
+    /// The ID that uniquely identifies the live chat that the message is associated with.
+    #[serde(default)]
+    pub live_chat_id: String,
+
+    /// The ID of the user that authored the message.
+    pub author_channel_id: Option<String>,
+
+    /// The date and time when the message was orignally published. The value is specified in
+    /// [ISO 8601](https://www.w3.org/TR/NOTE-datetime) format.
+    pub published_at: Option<DateTime<Utc>>,
+
+    /// Indicates whether the message has display content that should be displayed to users.
+    #[serde(default)]
+    pub has_display_content: bool,
+
+    /// Contains a string that can be displayed to the user. This property is only present if `has_display_content`
+    /// is true.
+    pub display_message: Option<String>,
+
+    /// The type of the message and its type-specific details.
+    #[serde(flatten)]
+    pub message: LiveChatMessage,
+}
+
+/// The type of a message, together with the details object that is present for that type.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum LiveChatMessage {
+    /// A user has sent a text message.
+    TextMessageEvent {
+        #[serde(rename = "textMessageDetails")]
+        text_message_details: TextMessageDetails,
+    },
+
+    /// A user has purchased a Super Chat.
+    SuperChatEvent {
+        #[serde(rename = "superChatDetails")]
+        super_chat_details: SuperChatDetails,
+    },
+
+    /// A user has purchased a Super Sticker.
+    SuperStickerEvent {
+        #[serde(rename = "superStickerDetails")]
+        super_sticker_details: SuperStickerDetails,
+    },
+
+    /// A new user has joined the channel's membership.
+    NewSponsorEvent {
+        #[serde(rename = "newSponsorDetails")]
+        new_sponsor_details: NewSponsorDetails,
+    },
+
+    /// A member has sent a milestone chat.
+    MemberMilestoneChatEvent {
+        #[serde(rename = "memberMilestoneChatDetails")]
+        member_milestone_chat_details: MemberMilestoneChatDetails,
+    },
+
+    /// A user has purchased memberships for other viewers.
+    MembershipGiftingEvent {
+        #[serde(rename = "membershipGiftingDetails")]
+        membership_gifting_details: MembershipGiftingDetails,
+    },
+
+    /// A user has received a gift membership.
+    GiftMembershipReceivedEvent {
+        #[serde(rename = "giftMembershipReceivedDetails")]
+        gift_membership_received_details: GiftMembershipReceivedDetails,
+    },
+
+    /// A message has been deleted by a moderator.
+    MessageDeletedEvent {
+        #[serde(rename = "messageDeletedDetails")]
+        message_deleted_details: MessageDeletedDetails,
+    },
+
+    /// A user has been banned by a moderator.
+    UserBannedEvent {
+        #[serde(rename = "userBannedDetails")]
+        user_banned_details: UserBannedDetails,
+    },
+
+    /// The chat has ended, and no more messages can be inserted.
+    ChatEndedEvent,
+
+    /// The chat is now only open to members.
+    SponsorOnlyModeStartedEvent,
+
+    /// The chat is open to all users again.
+    SponsorOnlyModeEndedEvent,
+
+    /// A message that used to exist but has been deleted or retracted.
+    Tombstone,
+
+    /// A message type that is not covered by this enum.
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextMessageDetails {
+    /// The user's message.
+    #[serde(default)]
+    pub message_text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuperChatDetails {
+    /// The amount purchased by the user, in micros (1,750,000 micros = 1.75).
+    #[serde(default)]
+    pub amount_micros: String,
+
+    /// The currency in which the purchase was made.
+    #[serde(default)]
+    pub currency: String,
+
+    /// A string containing the amount and currency, localized for the language specified by the `hl` parameter.
+    #[serde(default)]
+    pub amount_display_string: String,
+
+    /// The comment added by the user to this Super Chat event.
+    pub user_comment: Option<String>,
+
+    /// The tier in which the amount belongs. Lower amounts belong to lower tiers. The lowest tier is `1`.
+    #[serde(default)]
+    pub tier: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuperStickerDetails {
+    /// Information about the Super Sticker.
+    #[serde(default)]
+    pub super_sticker_metadata: SuperStickerMetadata,
+
+    /// The amount purchased by the user, in micros (1,750,000 micros = 1.75).
+    #[serde(default)]
+    pub amount_micros: String,
+
+    /// The currency in which the purchase was made.
+    #[serde(default)]
+    pub currency: String,
+
+    /// A string containing the amount and currency, localized for the language specified by the `hl` parameter.
+    #[serde(default)]
+    pub amount_display_string: String,
+
+    /// The tier in which the amount belongs. Lower amounts belong to lower tiers. The lowest tier is `1`.
+    #[serde(default)]
+    pub tier: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewSponsorDetails {
+    /// The name of the membership level that the viewer joined.
+    pub member_level_name: Option<String>,
+
+    /// Whether the event is an upgrade from one membership level to another rather than a new membership.
+    pub is_upgrade: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MemberMilestoneChatDetails {
+    /// The name of the membership level of the member.
+    pub member_level_name: Option<String>,
+
+    /// The total length of the membership, in months.
+    #[serde(default)]
+    pub member_month: u32,
+
+    /// The comment added by the member to this milestone chat.
+    pub user_comment: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MembershipGiftingDetails {
+    /// The number of gift memberships purchased by the user.
+    #[serde(default)]
+    pub gift_memberships_count: u32,
+
+    /// The name of the membership level of the gift memberships.
+    #[serde(default)]
+    pub gift_memberships_level_name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GiftMembershipReceivedDetails {
+    /// The name of the membership level of the received gift membership.
+    #[serde(default)]
+    pub member_level_name: String,
+
+    /// The ID of the user that purchased the gift membership.
+    #[serde(default)]
+    pub gifter_channel_id: String,
+
+    /// The ID of the `membershipGiftingEvent` message this gift membership belongs to.
+    #[serde(default)]
+    pub associated_membership_gifting_message_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageDeletedDetails {
+    /// The ID of the deleted message.
+    #[serde(default)]
+    pub deleted_message_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserBannedDetails {
+    /// Details about the banned user.
+    #[serde(default)]
+    pub banned_user_details: BannedUserDetails,
+
+    /// The type of ban. Valid values are `permanent` and `temporary`.
+    #[serde(default)]
+    pub ban_type: String,
+
+    /// The duration of the ban, in seconds. This property is only present if `ban_type` is `temporary`.
+    pub ban_duration_seconds: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BannedUserDetails {
+    /// The YouTube channel ID of the banned user.
+    #[serde(default)]
+    pub channel_id: String,
+
+    /// The banned user's YouTube channel URL.
+    #[serde(default)]
+    pub channel_url: String,
+
+    /// The banned user's display name.
+    #[serde(default)]
+    pub display_name: String,
+
+    /// The banned user's avatar URL.
+    #[serde(default)]
+    pub profile_image_url: String,
+}
+
+/// The authorDetails object contains basic details about the user that posted the message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorDetails {
+    /// The YouTube channel ID.
+    #[serde(default)]
+    pub channel_id: String,
+
+    /// The channel's URL.
+    #[serde(default)]
+    pub channel_url: String,
+
+    /// The channel's display name.
+    #[serde(default)]
+    pub display_name: String,
+
+    /// The channel's avatar URL.
+    #[serde(default)]
+    pub profile_image_url: String,
+
+    /// Indicates whether the channel is verified.
+    #[serde(default)]
+    pub is_verified: bool,
+
+    /// Indicates whether the user is the owner of the live chat.
+    #[serde(default)]
+    pub is_chat_owner: bool,
+
+    /// Indicates whether the user is a member of the channel that owns the live chat.
+    #[serde(default)]
+    pub is_chat_sponsor: bool,
+
+    /// Indicates whether the user is a moderator of the live chat.
+    #[serde(default)]
+    pub is_chat_moderator: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_youtube_without_user_agent;
+
+    #[tokio::test]
+    async fn test_request_without_auth() {
+        let without_auth = get_youtube_without_user_agent()
+            .live_chat_messages()
+            .list("live-chat-id", vec![Part::Snippet, Part::AuthorDetails])
+            .request()
+            .await;
+        assert!(without_auth.is_err());
+        let err = without_auth.unwrap_err();
+        assert_eq!(
+            "builder error: \"The request to `liveChat/messages` requires OAuth 2.0 authorization\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_request_with_empty_live_chat_id() {
+        let empty_live_chat_id = get_youtube_without_user_agent()
+            .live_chat_messages()
+            .list("", vec![])
+            .request()
+            .await;
+        assert!(empty_live_chat_id.is_err());
+        let err = empty_live_chat_id.unwrap_err();
+        assert_eq!(
+            "builder error: \"Required parameter `live_chat_id` is empty\"",
+            format!("{}", err)
+        );
+    }
+
+    #[test]
+    fn test_deserialize_live_chat_messages() {
+        let response: LiveChatMessageListResponse = serde_json::from_str(
+            r#"{
+                "kind": "youtube#liveChatMessageListResponse",
+                "etag": "etag",
+                "nextPageToken": "next",
+                "pollingIntervalMillis": 5000,
+                "offlineAt": "2023-01-01T02:00:00Z",
+                "pageInfo": {"totalResults": 3, "resultsPerPage": 3},
+                "items": [
+                    {
+                        "kind": "youtube#liveChatMessage",
+                        "etag": "etag",
+                        "id": "message-1",
+                        "snippet": {
+                            "type": "textMessageEvent",
+                            "liveChatId": "live-chat-id",
+                            "authorChannelId": "UCauthor",
+                            "publishedAt": "2023-01-01T00:00:00Z",
+                            "hasDisplayContent": true,
+                            "displayMessage": "hello",
+                            "textMessageDetails": {"messageText": "hello"}
+                        }
+                    },
+                    {
+                        "kind": "youtube#liveChatMessage",
+                        "etag": "etag",
+                        "id": "message-2",
+                        "snippet": {
+                            "type": "membershipGiftingEvent",
+                            "liveChatId": "live-chat-id",
+                            "publishedAt": "2023-01-01T00:01:00Z",
+                            "hasDisplayContent": true,
+                            "membershipGiftingDetails": {
+                                "giftMembershipsCount": 5,
+                                "giftMembershipsLevelName": "Member"
+                            }
+                        }
+                    },
+                    {
+                        "kind": "youtube#liveChatMessage",
+                        "etag": "etag",
+                        "id": "message-3",
+                        "snippet": {
+                            "type": "pollEvent",
+                            "liveChatId": "live-chat-id",
+                            "publishedAt": "2023-01-01T00:02:00Z",
+                            "hasDisplayContent": false
+                        }
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(response.polling_interval(), Duration::from_secs(5));
+        assert!(response.is_offline());
+
+        let messages = response
+            .items
+            .into_iter()
+            .map(|item| item.snippet.unwrap().message)
+            .collect::<Vec<LiveChatMessage>>();
+        assert!(matches!(
+            &messages[0],
+            LiveChatMessage::TextMessageEvent { text_message_details } if text_message_details.message_text == "hello"
+        ));
+        assert!(matches!(
+            &messages[1],
+            LiveChatMessage::MembershipGiftingEvent { membership_gifting_details }
+                if membership_gifting_details.gift_memberships_count == 5
+        ));
+        assert!(matches!(&messages[2], LiveChatMessage::Unknown));
+    }
+
+    #[tokio::test]
+    async fn test_insert_without_auth() {
+        let without_auth = get_youtube_without_user_agent()
+            .live_chat_messages()
+            .insert("live-chat-id", "Welcome to the stream!")
+            .request()
+            .await;
+        assert!(without_auth.is_err());
+        let err = without_auth.unwrap_err();
+        assert_eq!(
+            "builder error: \"The request to `liveChat/messages` requires OAuth 2.0 authorization\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_insert_channel_without_content_owner() {
+        let without_content_owner = get_youtube_without_user_agent()
+            .live_chat_messages()
+            .insert("live-chat-id", "Welcome to the stream!")
+            .on_behalf_of_content_owner_channel("UC_x5XG1OV2P6uZZ5FSM9Ttw")
+            .request()
+            .await;
+        assert!(without_content_owner.is_err());
+        let err = without_content_owner.unwrap_err();
+        assert_eq!(
+            "builder error: \"The `on_behalf_of_content_owner_channel` parameter requires `on_behalf_of_content_owner`\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_with_empty_id() {
+        let empty_id = get_youtube_without_user_agent()
+            .live_chat_messages()
+            .delete("")
+            .request()
+            .await;
+        assert!(empty_id.is_err());
+        let err = empty_id.unwrap_err();
+        assert_eq!(
+            "builder error: \"Required parameter `id` is empty\"",
+            format!("{}", err)
+        );
+    }
+
+    #[test]
+    fn test_serialize_live_chat_message_body() {
+        let body = LiveChatMessageBody {
+            snippet: LiveChatMessageBodySnippet {
+                live_chat_id: "live-chat-id",
+                message_type: "textMessageEvent",
+                text_message_details: TextMessageBodyDetails {
+                    message_text: "hello",
+                },
+            },
+        };
+        assert_eq!(
+            r#"{"snippet":{"liveChatId":"live-chat-id","type":"textMessageEvent","textMessageDetails":{"messageText":"hello"}}}"#,
+            serde_json::to_string(&body).unwrap()
+        );
+    }
+}
@@ -0,0 +1,950 @@
+use crate::{
+    auth::{Authorizer, OAuthTokenAuth},
+    cursor::PageCursor,
+    error::{Error, Result},
+    json, list_json, ListApi, ListResponse, PagedListApi, RequestBase, YouTube, YouTubeDataApi,
+};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use youtube_data_derive::QueryBuilder;
+
+pub type LiveStreamListResponse = ListResponse<LiveStreamListResource>;
+
+#[derive(Clone, Copy)]
+pub struct LiveStreamsService<'a> {
+    youtube: &'a YouTube,
+}
+
+impl<'a> LiveStreamsService<'a> {
+    pub(crate) fn new(youtube: &'a YouTube) -> Self {
+        Self { youtube }
+    }
+
+    pub fn list(self, part: impl IntoIterator<Item = Part>) -> LiveStreamList<'a> {
+        LiveStreamList::new(self, part)
+    }
+
+    pub fn insert(
+        self,
+        title: impl Into<String>,
+        cdn: LiveStreamCdnSettings,
+    ) -> LiveStreamInsert<'a> {
+        LiveStreamInsert::new(self, title, cdn)
+    }
+
+    pub fn update(
+        self,
+        id: impl Into<String>,
+        title: impl Into<String>,
+        cdn: LiveStreamCdnSettings,
+    ) -> LiveStreamUpdate<'a> {
+        LiveStreamUpdate::new(self, id, title, cdn)
+    }
+
+    pub fn delete(self, id: impl Into<String>) -> LiveStreamDelete<'a> {
+        LiveStreamDelete::new(self, id)
+    }
+}
+
+/// Parameters for the `list` method of the `liveStreams` api. details:
+/// [link](https://developers.google.com/youtube/v3/live/docs/liveStreams/list)
+#[derive(QueryBuilder)]
+pub struct LiveStreamList<'a> {
+    service: LiveStreamsService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    #[query(unique)]
+    part: Vec<Part>,
+
+    // filters (specify exactly one of the following parameters)
+    #[query(filter)]
+    id: Option<Vec<String>>,
+    #[query(filter)]
+    mine: Option<bool>,
+
+    // optional parameters
+    /// Selects the fields of the response, e.g. `items(id,snippet/title)`, so only the needed part of every
+    /// resource is returned. details: [link](https://developers.google.com/youtube/v3/getting-started#fields)
+    #[query]
+    fields: Option<String>,
+    /// Fails the request with a timeout error when it does not complete in time, instead of the timeout of the
+    /// `YouTube` handle.
+    #[setter]
+    timeout: Option<Duration>,
+    #[query(max = 50)]
+    max_results: Option<u32>,
+    #[setter]
+    on_behalf_of_content_owner: Option<String>,
+    #[setter]
+    on_behalf_of_content_owner_channel: Option<String>,
+    #[setter]
+    page_token: Option<String>,
+}
+
+impl RequestBase for LiveStreamList<'_> {
+    fn api_path(&self) -> &str {
+        "liveStreams"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for LiveStreamList<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+
+    fn request_timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl ListApi<LiveStreamListResponse> for LiveStreamList<'_> {
+    async fn request(&self) -> Result<LiveStreamListResponse> {
+        self.request_page(None).await
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl PagedListApi<LiveStreamListResource> for LiveStreamList<'_> {
+    async fn request_page(&self, page_token: Option<&str>) -> Result<LiveStreamListResponse> {
+        let youtube = &self.service.youtube;
+        let params = self.params(page_token)?;
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .get(self.url(&youtube.inner().base_path))
+                .query(&params),
+        )?;
+        let response = self.send(youtube, request).await?;
+        list_json(response).await
+    }
+
+    fn cursor(&self, page_token: Option<&str>) -> Result<PageCursor> {
+        Ok(PageCursor::new(self.api_path(), self.params(page_token)?))
+    }
+}
+
+impl LiveStreamList<'_> {
+    /// The query parameters of the page of `page_token`, or of the `page_token` of the builder for `None`.
+    fn params(&self, page_token: Option<&str>) -> Result<HashMap<String, String>> {
+        let youtube = &self.service.youtube;
+        let page_token = page_token.or(self.page_token.as_deref());
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        self.insert_query(youtube, &mut params)?;
+        self.insert_content_owner_parameters(
+            youtube,
+            &mut params,
+            self.on_behalf_of_content_owner.as_deref(),
+            self.on_behalf_of_content_owner_channel.as_deref(),
+        )?;
+        self.insert_query_parameter(&mut params, "pageToken", page_token);
+
+        Ok(params)
+    }
+}
+
+impl<'a> LiveStreamList<'a> {
+    pub(crate) fn new(
+        service: LiveStreamsService<'a>,
+        part: impl IntoIterator<Item = Part>,
+    ) -> Self {
+        let part = crate::dedupe(part);
+        let part = if part.is_empty() {
+            vec![Part::Id]
+        } else {
+            part
+        };
+        Self {
+            service,
+            authorizer: None,
+            part,
+            id: None,
+            mine: None,
+            fields: None,
+            timeout: None,
+            max_results: None,
+            on_behalf_of_content_owner: None,
+            on_behalf_of_content_owner_channel: None,
+            page_token: None,
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+}
+
+/// Parameters for the `insert` method of the `liveStreams` api. details:
+/// [link](https://developers.google.com/youtube/v3/live/docs/liveStreams/insert)
+pub struct LiveStreamInsert<'a> {
+    service: LiveStreamsService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // optional parameters
+    on_behalf_of_content_owner: Option<String>,
+    on_behalf_of_content_owner_channel: Option<String>,
+
+    // request body
+    body: LiveStreamBody,
+}
+
+impl RequestBase for LiveStreamInsert<'_> {
+    fn api_path(&self) -> &str {
+        "liveStreams"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for LiveStreamInsert<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> LiveStreamInsert<'a> {
+    pub(crate) fn new(
+        service: LiveStreamsService<'a>,
+        title: impl Into<String>,
+        cdn: LiveStreamCdnSettings,
+    ) -> Self {
+        Self {
+            service,
+            authorizer: None,
+            on_behalf_of_content_owner: None,
+            on_behalf_of_content_owner_channel: None,
+            body: LiveStreamBody::new(None, title.into(), cdn),
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        self.body.snippet.description = Some(description.into());
+        self
+    }
+
+    /// Whether the stream can be bound to more than one broadcast. The default value is `true`.
+    pub fn is_reusable(&mut self, is_reusable: bool) -> &mut Self {
+        self.body.content_details = Some(LiveStreamBodyContentDetails {
+            is_reusable: Some(is_reusable),
+        });
+        self
+    }
+
+    pub fn on_behalf_of_content_owner(
+        &mut self,
+        on_behalf_of_content_owner: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+        self
+    }
+
+    pub fn on_behalf_of_content_owner_channel(
+        &mut self,
+        on_behalf_of_content_owner_channel: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner_channel = Some(on_behalf_of_content_owner_channel.into());
+        self
+    }
+
+    /// Creates a stream and returns it, including the ingestion address and stream key in `cdn.ingestion_info`.
+    pub async fn request(&self) -> Result<LiveStreamListResource> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        // required parameters
+        self.insert_query_parameters(&mut params, "part", Some(&self.body.part()));
+
+        // optional parameters
+        self.insert_content_owner_parameters(
+            youtube,
+            &mut params,
+            self.on_behalf_of_content_owner.as_deref(),
+            self.on_behalf_of_content_owner_channel.as_deref(),
+        )?;
+
+        // request body
+        if self.body.snippet.title.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required property `snippet.title` is empty",
+            ));
+        }
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .post(self.url(&youtube.inner().base_path))
+                .query(&params)
+                .json(&self.body),
+        )?;
+        let response = self.send(youtube, request).await?;
+        json(response).await
+    }
+}
+
+/// Parameters for the `update` method of the `liveStreams` api. details:
+/// [link](https://developers.google.com/youtube/v3/live/docs/liveStreams/update)
+///
+/// The cdn settings of a stream can only be changed while the stream is not bound to a broadcast.
+pub struct LiveStreamUpdate<'a> {
+    service: LiveStreamsService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // optional parameters
+    on_behalf_of_content_owner: Option<String>,
+    on_behalf_of_content_owner_channel: Option<String>,
+
+    // request body
+    body: LiveStreamBody,
+}
+
+impl RequestBase for LiveStreamUpdate<'_> {
+    fn api_path(&self) -> &str {
+        "liveStreams"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for LiveStreamUpdate<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> LiveStreamUpdate<'a> {
+    pub(crate) fn new(
+        service: LiveStreamsService<'a>,
+        id: impl Into<String>,
+        title: impl Into<String>,
+        cdn: LiveStreamCdnSettings,
+    ) -> Self {
+        Self {
+            service,
+            authorizer: None,
+            on_behalf_of_content_owner: None,
+            on_behalf_of_content_owner_channel: None,
+            body: LiveStreamBody::new(Some(id.into()), title.into(), cdn),
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        self.body.snippet.description = Some(description.into());
+        self
+    }
+
+    /// Whether the stream can be bound to more than one broadcast. The default value is `true`.
+    pub fn is_reusable(&mut self, is_reusable: bool) -> &mut Self {
+        self.body.content_details = Some(LiveStreamBodyContentDetails {
+            is_reusable: Some(is_reusable),
+        });
+        self
+    }
+
+    pub fn on_behalf_of_content_owner(
+        &mut self,
+        on_behalf_of_content_owner: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+        self
+    }
+
+    pub fn on_behalf_of_content_owner_channel(
+        &mut self,
+        on_behalf_of_content_owner_channel: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner_channel = Some(on_behalf_of_content_owner_channel.into());
+        self
+    }
+
+    /// Updates a stream and returns the updated stream.
+    pub async fn request(&self) -> Result<LiveStreamListResource> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        // required parameters
+        self.insert_query_parameters(&mut params, "part", Some(&self.body.part()));
+
+        // optional parameters
+        self.insert_content_owner_parameters(
+            youtube,
+            &mut params,
+            self.on_behalf_of_content_owner.as_deref(),
+            self.on_behalf_of_content_owner_channel.as_deref(),
+        )?;
+
+        // request body
+        if self.body.id.as_deref().is_none_or(str::is_empty) {
+            return Err(Error::missing_required_parameter(
+                "Required property `id` is empty",
+            ));
+        }
+        if self.body.snippet.title.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required property `snippet.title` is empty",
+            ));
+        }
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .put(self.url(&youtube.inner().base_path))
+                .query(&params)
+                .json(&self.body),
+        )?;
+        let response = self.send(youtube, request).await?;
+        json(response).await
+    }
+}
+
+/// Parameters for the `delete` method of the `liveStreams` api. details:
+/// [link](https://developers.google.com/youtube/v3/live/docs/liveStreams/delete)
+pub struct LiveStreamDelete<'a> {
+    service: LiveStreamsService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    id: String,
+
+    // optional parameters
+    on_behalf_of_content_owner: Option<String>,
+    on_behalf_of_content_owner_channel: Option<String>,
+}
+
+impl RequestBase for LiveStreamDelete<'_> {
+    fn api_path(&self) -> &str {
+        "liveStreams"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for LiveStreamDelete<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> LiveStreamDelete<'a> {
+    pub(crate) fn new(service: LiveStreamsService<'a>, id: impl Into<String>) -> Self {
+        Self {
+            service,
+            authorizer: None,
+            id: id.into(),
+            on_behalf_of_content_owner: None,
+            on_behalf_of_content_owner_channel: None,
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+
+    pub fn on_behalf_of_content_owner(
+        &mut self,
+        on_behalf_of_content_owner: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+        self
+    }
+
+    pub fn on_behalf_of_content_owner_channel(
+        &mut self,
+        on_behalf_of_content_owner_channel: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner_channel = Some(on_behalf_of_content_owner_channel.into());
+        self
+    }
+
+    /// Deletes a stream. The api responds with an empty body on success.
+    pub async fn request(&self) -> Result<()> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        // required parameters
+        if self.id.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required parameter `id` is empty",
+            ));
+        }
+        self.insert_query_parameter(&mut params, "id", Some(&self.id));
+
+        // optional parameters
+        self.insert_content_owner_parameters(
+            youtube,
+            &mut params,
+            self.on_behalf_of_content_owner.as_deref(),
+            self.on_behalf_of_content_owner_channel.as_deref(),
+        )?;
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .delete(self.url(&youtube.inner().base_path))
+                .query(&params),
+        )?;
+        self.send_without_content(youtube, request).await
+    }
+}
+
+/// The request body of `liveStreams.insert` and `liveStreams.update`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LiveStreamBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+
+    snippet: LiveStreamBodySnippet,
+
+    cdn: LiveStreamCdnSettings,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_details: Option<LiveStreamBodyContentDetails>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LiveStreamBodySnippet {
+    title: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LiveStreamBodyContentDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_reusable: Option<bool>,
+}
+
+impl LiveStreamBody {
+    fn new(id: Option<String>, title: String, cdn: LiveStreamCdnSettings) -> Self {
+        Self {
+            id,
+            snippet: LiveStreamBodySnippet {
+                title,
+                description: None,
+            },
+            cdn,
+            content_details: None,
+        }
+    }
+
+    /// The parts written by the request. `contentDetails` is only sent when one of its properties is set.
+    fn part(&self) -> Vec<Part> {
+        let mut part = vec![Part::Snippet, Part::Cdn];
+        if self.content_details.is_some() {
+            part.push(Part::ContentDetails);
+        }
+        part
+    }
+}
+
+/// The ingestion settings of a new or updated stream.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveStreamCdnSettings {
+    pub ingestion_type: IngestionType,
+    pub resolution: Resolution,
+    pub frame_rate: FrameRate,
+}
+
+impl LiveStreamCdnSettings {
+    pub fn new(
+        ingestion_type: IngestionType,
+        resolution: Resolution,
+        frame_rate: FrameRate,
+    ) -> Self {
+        Self {
+            ingestion_type,
+            resolution,
+            frame_rate,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    Cdn,
+    ContentDetails,
+    Id,
+    Snippet,
+    Status,
+}
+
+impl Part {
+    /// Every part of the resource, to request all of its properties.
+    pub fn all_public() -> Vec<Part> {
+        vec![
+            Part::Cdn,
+            Part::ContentDetails,
+            Part::Id,
+            Part::Snippet,
+            Part::Status,
+        ]
+    }
+}
+
+impl std::fmt::Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            Part::Cdn => "cdn",
+            Part::ContentDetails => "contentDetails",
+            Part::Id => "id",
+            Part::Snippet => "snippet",
+            Part::Status => "status",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+/// The method or protocol used to transmit the video stream.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IngestionType {
+    #[serde(rename = "dash")]
+    Dash,
+
+    #[serde(rename = "hls")]
+    Hls,
+
+    #[serde(rename = "rtmp")]
+    Rtmp,
+}
+
+/// The resolution of the inbound video data.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Resolution {
+    #[serde(rename = "240p")]
+    P240,
+
+    #[serde(rename = "360p")]
+    P360,
+
+    #[serde(rename = "480p")]
+    P480,
+
+    #[serde(rename = "720p")]
+    P720,
+
+    #[serde(rename = "1080p")]
+    P1080,
+
+    #[serde(rename = "1440p")]
+    P1440,
+
+    #[serde(rename = "2160p")]
+    P2160,
+
+    #[serde(rename = "variable")]
+    Variable,
+}
+
+/// The frame rate of the inbound video data.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FrameRate {
+    #[serde(rename = "30fps")]
+    Fps30,
+
+    #[serde(rename = "60fps")]
+    Fps60,
+
+    #[serde(rename = "variable")]
+    Variable,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveStreamListResource {
+    /// Identifies the API resource's type. The value will be `youtube#liveStream`.
+    #[serde(default)]
+    pub kind: String,
+
+    /// The ETag of the response.
+    #[serde(default)]
+    pub etag: String,
+
+    /// The ID that YouTube assigns to uniquely identify the stream.
+    #[serde(default)]
+    pub id: String,
+
+    pub snippet: Option<LiveStreamSnippet>,
+    pub cdn: Option<LiveStreamCdn>,
+    pub status: Option<LiveStreamStatus>,
+
+    pub content_details: Option<LiveStreamContentDetails>,
+
+    /// The fields of the resource that this version of the crate does not know, e.g. added to the api after its
+    /// release. They are serialized back with the resource.
+    #[serde(flatten, deserialize_with = "crate::serde_helpers::extra_fields")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// The snippet object contains basic details about the stream, including its channel, title, and description.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveStreamSnippet {
+    /// The date and time that the stream was created. The value is specified in
+    /// [ISO 8601](https://www.w3.org/TR/NOTE-datetime) format.
+    pub published_at: Option<DateTime<Utc>>,
+
+    /// The ID that YouTube uses to uniquely identify the channel that is transmitting the stream.
+    #[serde(default)]
+    pub channel_id: String,
+
+    /// The stream's title. The value must be between 1 and 128 characters long.
+    #[serde(default)]
+    pub title: String,
+
+    /// The stream's description. The value cannot be longer than 10000 characters.
+    #[serde(default)]
+    pub description: String,
+
+    /// Indicates whether this stream is the default stream of the channel.
+    pub is_default_stream: Option<bool>,
+}
+
+/// The cdn object defines the live stream's content delivery network (CDN) settings. These settings provide details
+/// about the manner in which you stream your content to YouTube.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveStreamCdn {
+    /// The method or protocol used to transmit the video stream.
+    pub ingestion_type: IngestionType,
+
+    /// The ingestionInfo object contains information that YouTube provides that you need to transmit your stream to
+    /// YouTube.
+    pub ingestion_info: Option<IngestionInfo>,
+
+    /// The resolution of the inbound video data.
+    pub resolution: Resolution,
+
+    /// The frame rate of the inbound video data.
+    pub frame_rate: FrameRate,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IngestionInfo {
+    /// The stream name that YouTube assigns to the video stream. This is the stream key to configure in the encoder.
+    #[serde(default)]
+    pub stream_name: String,
+
+    /// The primary ingestion URL that you should use to stream video to YouTube.
+    #[serde(default)]
+    pub ingestion_address: String,
+
+    /// The backup ingestion URL that you should use to stream video to YouTube.
+    #[serde(default)]
+    pub backup_ingestion_address: String,
+
+    /// The primary secured ingestion URL that you should use to stream video to YouTube.
+    pub rtmps_ingestion_address: Option<String>,
+
+    /// The backup secured ingestion URL that you should use to stream video to YouTube.
+    pub rtmps_backup_ingestion_address: Option<String>,
+}
+
+/// The status object contains information about live stream's status.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveStreamStatus {
+    /// The stream's status. Valid values are `active`, `created`, `error`, `inactive` and `ready`.
+    #[serde(default)]
+    pub stream_status: String,
+
+    /// The health status of the stream.
+    pub health_status: Option<HealthStatus>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HealthStatus {
+    /// The status code of this stream. Valid values are `good`, `ok`, `bad` and `noData`.
+    #[serde(default)]
+    pub status: String,
+
+    /// The last time this status was updated (in seconds).
+    pub last_update_time_seconds: Option<String>,
+
+    /// The configurations issues on this stream.
+    #[serde(default)]
+    pub configuration_issues: Vec<ConfigurationIssue>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigurationIssue {
+    /// The kind of error happening.
+    #[serde(rename = "type", default)]
+    pub issue_type: String,
+
+    /// How severe this issue is to the stream. Valid values are `info`, `warning` and `error`.
+    #[serde(default)]
+    pub severity: String,
+
+    /// The short-form reason for this issue.
+    #[serde(default)]
+    pub reason: String,
+
+    /// The long-form description of the issue and how to resolve it.
+    #[serde(default)]
+    pub description: String,
+}
+
+/// The contentDetails object contains information about the stream's content.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveStreamContentDetails {
+    /// The ingestion URL where the closed captions of this stream are sent.
+    pub closed_captions_ingestion_url: Option<String>,
+
+    /// Indicates whether the stream is reusable, which means that it can be bound to multiple broadcasts.
+    pub is_reusable: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_youtube_without_user_agent;
+
+    #[tokio::test]
+    async fn test_request_without_auth() {
+        let without_auth = get_youtube_without_user_agent()
+            .live_streams()
+            .list(vec![Part::Cdn])
+            .mine(true)
+            .request()
+            .await;
+        assert!(without_auth.is_err());
+        let err = without_auth.unwrap_err();
+        assert_eq!(
+            "builder error: \"The request to `liveStreams` requires OAuth 2.0 authorization\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_request_without_filter() {
+        let without_filter = get_youtube_without_user_agent()
+            .live_streams()
+            .list(vec![])
+            .request()
+            .await;
+        assert!(without_filter.is_err());
+        let err = without_filter.unwrap_err();
+        assert_eq!(
+            "builder error: \"No filter selected. Expected one of: id, mine\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_insert_with_empty_title() {
+        let empty_title = get_youtube_without_user_agent()
+            .live_streams()
+            .insert(
+                "",
+                LiveStreamCdnSettings::new(
+                    IngestionType::Rtmp,
+                    Resolution::P1080,
+                    FrameRate::Fps60,
+                ),
+            )
+            .request()
+            .await;
+        assert!(empty_title.is_err());
+        let err = empty_title.unwrap_err();
+        assert_eq!(
+            "builder error: \"Required property `snippet.title` is empty\"",
+            format!("{}", err)
+        );
+    }
+
+    #[test]
+    fn test_serialize_live_stream_body() {
+        let body = LiveStreamBody::new(
+            None,
+            "Main encoder".to_string(),
+            LiveStreamCdnSettings::new(IngestionType::Rtmp, Resolution::Variable, FrameRate::Fps30),
+        );
+        assert_eq!(
+            r#"{"snippet":{"title":"Main encoder"},"cdn":{"ingestionType":"rtmp","resolution":"variable","frameRate":"30fps"}}"#,
+            serde_json::to_string(&body).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_deserialize_live_stream() {
+        let stream: LiveStreamListResource = serde_json::from_str(
+            r#"{
+                "kind": "youtube#liveStream",
+                "etag": "etag",
+                "id": "stream-id",
+                "cdn": {
+                    "ingestionType": "rtmp",
+                    "ingestionInfo": {
+                        "streamName": "abcd-efgh-ijkl-mnop",
+                        "ingestionAddress": "rtmp://a.rtmp.youtube.com/live2",
+                        "backupIngestionAddress": "rtmp://b.rtmp.youtube.com/live2?backup=1"
+                    },
+                    "resolution": "1080p",
+                    "frameRate": "60fps"
+                }
+            }"#,
+        )
+        .unwrap();
+        let cdn = stream.cdn.unwrap();
+        assert_eq!(cdn.resolution, Resolution::P1080);
+        assert_eq!(
+            cdn.ingestion_info.unwrap().stream_name,
+            "abcd-efgh-ijkl-mnop"
+        );
+    }
+}
@@ -0,0 +1,80 @@
+//! A hook reporting every request sent by a [YouTube](crate::YouTube) handle, to export the latency, the status
+//! codes and the consumed quota to Prometheus, StatsD, ...
+
+use reqwest::{Method, StatusCode, Url};
+use std::time::Duration;
+
+/// The measurements of one request.
+#[derive(Debug, Clone)]
+pub struct RequestMetrics<'a> {
+    /// The api path of the endpoint, e.g. `videos` or `videos/rate`.
+    pub endpoint: &'a str,
+
+    /// The HTTP method, which tells the operations of an endpoint apart (e.g. `GET videos` is `videos.list`).
+    pub method: &'a Method,
+
+    /// The time from the first attempt until the response arrived, including the retries.
+    pub duration: Duration,
+
+    /// The status code of the response, or `None` when no response was received (e.g. a connection error or a
+    /// timeout).
+    pub status: Option<StatusCode>,
+
+    /// How many times the request was sent, more than once when it was retried.
+    pub attempts: u32,
+
+    /// The estimated quota units charged for all attempts, following the
+    /// [quota calculator](https://developers.google.com/youtube/v3/determine_quota_cost). The YouTube Analytics and
+    /// Reporting APIs have their own quota and cost 0.
+    pub quota_cost: u32,
+}
+
+/// Receives the measurements of every request. The default recorder of a handle is [NoopRecorder].
+pub trait MetricsRecorder: Send + Sync {
+    fn record(&self, metrics: &RequestMetrics<'_>);
+}
+
+/// Discards the measurements.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopRecorder;
+
+impl MetricsRecorder for NoopRecorder {
+    fn record(&self, _metrics: &RequestMetrics<'_>) {}
+}
+
+/// The quota units of one call to an endpoint.
+pub(crate) fn quota_cost(method: &Method, endpoint: &str, url: &Url) -> u32 {
+    match (endpoint, method) {
+        // YouTube Analytics and YouTube Reporting APIs
+        ("reports" | "reportTypes" | "jobs" | "media", _) => 0,
+        ("search", _) => 100,
+        ("videos", &Method::POST) => 1600,
+        // captions.download has the id of the track in the path
+        ("captions", &Method::GET) if !url.path().ends_with("/captions") => 200,
+        ("captions", &Method::GET) => 50,
+        ("captions", &Method::POST) => 400,
+        ("captions", &Method::PUT) => 450,
+        (_, &Method::GET) => 1,
+        _ => 50,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_quota_cost() {
+        let url = Url::parse("https://www.googleapis.com/youtube/v3/videos").unwrap();
+        assert_eq!(quota_cost(&Method::GET, "videos", &url), 1);
+        assert_eq!(quota_cost(&Method::PUT, "videos", &url), 50);
+
+        let url = Url::parse("https://www.googleapis.com/upload/youtube/v3/videos").unwrap();
+        assert_eq!(quota_cost(&Method::POST, "videos", &url), 1600);
+
+        let url = Url::parse("https://www.googleapis.com/youtube/v3/captions/caption-id").unwrap();
+        assert_eq!(quota_cost(&Method::GET, "captions", &url), 200);
+
+        let url = Url::parse("https://youtubeanalytics.googleapis.com/v2/reports").unwrap();
+        assert_eq!(quota_cost(&Method::GET, "reports", &url), 0);
+    }
+}
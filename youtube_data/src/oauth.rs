@@ -0,0 +1,708 @@
+//! OAuth 2.0 flows of the Google authorization server, used to obtain the access token passed to
+//! [YouTube::with_access_token](crate::YouTube::with_access_token).
+
+use crate::{
+    error::{Error, OAuthError, Result},
+    json,
+    token_store::TokenStore,
+};
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime, Duration, Utc};
+use rand::{distributions::Alphanumeric, Rng};
+use reqwest::{Client, Url};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+#[cfg(not(target_arch = "wasm32"))]
+use std::collections::HashMap;
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::net::TcpListener;
+
+/// The authorization endpoint of the Google OAuth 2.0 server.
+const AUTH_URL: &str = "https://accounts.google.com/o/oauth2/v2/auth";
+
+/// The token endpoint of the Google OAuth 2.0 server.
+const TOKEN_URL: &str = "https://oauth2.googleapis.com/token";
+
+/// The device authorization endpoint of the Google OAuth 2.0 server.
+const DEVICE_CODE_URL: &str = "https://oauth2.googleapis.com/device/code";
+
+/// The common prefix of the scopes of the Google APIs.
+const SCOPE_PREFIX: &str = "https://www.googleapis.com/auth/";
+
+/// The grant type of the device authorization grant ([RFC 8628](https://www.rfc-editor.org/rfc/rfc8628)).
+const DEVICE_CODE_GRANT_TYPE: &str = "urn:ietf:params:oauth:grant-type:device_code";
+
+/// An OAuth 2.0 client registered in the Google API Console.
+#[derive(Clone)]
+pub struct OAuthClient {
+    client_id: String,
+    client_secret: Option<String>,
+    client: Client,
+}
+
+impl OAuthClient {
+    /// Creates a client with the credentials of the Google API Console. Clients of the "TVs and Limited Input
+    /// devices" and "Desktop app" types are issued a client secret, which is not treated as confidential.
+    pub fn new(client_id: impl Into<String>, client_secret: Option<String>) -> OAuthClient {
+        OAuthClient {
+            client_id: client_id.into(),
+            client_secret,
+            client: Client::new(),
+        }
+    }
+
+    /// Runs the device authorization grant: requests a user code, hands it to `on_user_code` to be displayed, then
+    /// polls until the user granted or denied access on another device.
+    pub async fn device_flow(
+        &self,
+        scopes: &[Scope],
+        on_user_code: impl FnOnce(&DeviceAuthorization),
+    ) -> Result<TokenSet> {
+        let authorization = self.request_device_code(scopes).await?;
+        on_user_code(&authorization);
+        self.poll_device_token(&authorization).await
+    }
+
+    /// Requests a device code and a user code for the given scopes. details:
+    /// [link](https://developers.google.com/youtube/v3/guides/auth/devices#step-1:-request-device-and-user-codes)
+    pub async fn request_device_code(&self, scopes: &[Scope]) -> Result<DeviceAuthorization> {
+        if scopes.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required parameter `scopes` is empty",
+            ));
+        }
+        let scope = join_scopes(scopes);
+        let form = [("client_id", self.client_id.as_str()), ("scope", &scope)];
+
+        let response = self.client.post(DEVICE_CODE_URL).form(&form).send().await?;
+        if !response.status().is_success() {
+            return Err(Error::oauth_error(json(response).await?));
+        }
+        json(response).await
+    }
+
+    /// Polls the token endpoint at the interval requested by the server until the user responded to the
+    /// authorization request, or until the device code expired. details:
+    /// [link](https://developers.google.com/youtube/v3/guides/auth/devices#step-4:-poll-googles-authorization-server)
+    pub async fn poll_device_token(&self, authorization: &DeviceAuthorization) -> Result<TokenSet> {
+        let mut interval = authorization.interval;
+        let mut form = vec![
+            ("client_id", self.client_id.as_str()),
+            ("device_code", authorization.device_code.as_str()),
+            ("grant_type", DEVICE_CODE_GRANT_TYPE),
+        ];
+        if let Some(client_secret) = &self.client_secret {
+            form.push(("client_secret", client_secret));
+        }
+
+        loop {
+            if Utc::now() >= authorization.expires_at() {
+                return Err(Error::oauth_error(OAuthError::new(
+                    "expired_token",
+                    "The device code expired before the user responded",
+                )));
+            }
+            crate::timer::sleep(std::time::Duration::from_secs(interval)).await;
+
+            match self.token_response(&form).await? {
+                Ok(token) => return Ok(token),
+                Err(error) => match error.error.as_str() {
+                    "authorization_pending" => {}
+                    // the server asks to increase the polling interval by 5 seconds
+                    "slow_down" => interval += 5,
+                    _ => return Err(Error::oauth_error(error)),
+                },
+            }
+        }
+    }
+
+    /// Builds the consent URL of the authorization code flow, with PKCE and a random state. Redirect the user to
+    /// `url`, then pass the `code` of the redirect to [OAuthClient::exchange_code] after checking its `state`.
+    /// details: [link](https://developers.google.com/youtube/v3/guides/auth/server-side-web-apps#creatingclient)
+    pub fn authorization_request(
+        &self,
+        scopes: &[Scope],
+        redirect_uri: &str,
+    ) -> Result<AuthorizationRequest> {
+        if scopes.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required parameter `scopes` is empty",
+            ));
+        }
+
+        // PKCE (RFC 7636) and CSRF protection
+        let state = random_string(32);
+        let code_verifier = random_string(64);
+        let code_challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()));
+
+        let url = Url::parse_with_params(
+            AUTH_URL,
+            &[
+                ("client_id", self.client_id.as_str()),
+                ("redirect_uri", redirect_uri),
+                ("response_type", "code"),
+                ("scope", &join_scopes(scopes)),
+                ("state", &state),
+                ("code_challenge", &code_challenge),
+                ("code_challenge_method", "S256"),
+                ("access_type", "offline"),
+            ],
+        )
+        .map_err(|e| Error::invalid_parameter(e.to_string()))?;
+
+        Ok(AuthorizationRequest {
+            url: url.to_string(),
+            state,
+            redirect_uri: redirect_uri.to_string(),
+            code_verifier,
+        })
+    }
+
+    /// Exchanges the authorization code of the redirect for tokens.
+    pub async fn exchange_code(
+        &self,
+        code: &str,
+        request: &AuthorizationRequest,
+    ) -> Result<TokenSet> {
+        if code.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required parameter `code` is empty",
+            ));
+        }
+        let mut form = vec![
+            ("client_id", self.client_id.as_str()),
+            ("code", code),
+            ("code_verifier", request.code_verifier.as_str()),
+            ("grant_type", "authorization_code"),
+            ("redirect_uri", request.redirect_uri.as_str()),
+        ];
+        if let Some(client_secret) = &self.client_secret {
+            form.push(("client_secret", client_secret));
+        }
+        self.token_response(&form)
+            .await?
+            .map_err(Error::oauth_error)
+    }
+
+    /// Runs the authorization code flow of installed apps with a loopback redirect: listens on an ephemeral port of
+    /// `127.0.0.1`, hands the consent URL to `on_consent_url` (e.g. [open_in_browser]), waits for the browser to be
+    /// redirected back with the authorization code and exchanges it for tokens. details:
+    /// [link](https://developers.google.com/youtube/v3/guides/auth/installed-apps)
+    ///
+    /// The flow waits until the browser is redirected, so wrap it in `tokio::time::timeout` to give up eventually.
+    /// Not available on wasm32, which can not listen on a port.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn loopback_flow(
+        &self,
+        scopes: &[Scope],
+        on_consent_url: impl FnOnce(&str),
+    ) -> Result<TokenSet> {
+        if scopes.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required parameter `scopes` is empty",
+            ));
+        }
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .map_err(|e| Error::oauth_error(OAuthError::new("loopback_error", e.to_string())))?;
+        let port = listener
+            .local_addr()
+            .map_err(|e| Error::oauth_error(OAuthError::new("loopback_error", e.to_string())))?
+            .port();
+        let request = self.authorization_request(scopes, &format!("http://127.0.0.1:{}", port))?;
+        on_consent_url(&request.url);
+
+        let params = accept_redirect(&listener).await?;
+        if params.get("state") != Some(&request.state) {
+            return Err(Error::oauth_error(OAuthError::new(
+                "invalid_state",
+                "The state of the redirect does not match the state of the consent URL",
+            )));
+        }
+        if let Some(error) = params.get("error") {
+            return Err(Error::oauth_error(OAuthError::new(
+                error.as_str(),
+                "The user did not grant access",
+            )));
+        }
+        let code = params.get("code").ok_or_else(|| {
+            Error::oauth_error(OAuthError::new(
+                "invalid_request",
+                "The redirect does not contain an authorization code",
+            ))
+        })?;
+        self.exchange_code(code, &request).await
+    }
+
+    /// Obtains a new access token with a refresh token. details:
+    /// [link](https://developers.google.com/youtube/v3/guides/auth/installed-apps#offline)
+    ///
+    /// The refresh token is not issued again, so it is copied into the returned token set.
+    pub async fn refresh(&self, refresh_token: &str) -> Result<TokenSet> {
+        if refresh_token.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required parameter `refresh_token` is empty",
+            ));
+        }
+        let mut form = vec![
+            ("client_id", self.client_id.as_str()),
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token),
+        ];
+        if let Some(client_secret) = &self.client_secret {
+            form.push(("client_secret", client_secret));
+        }
+        let mut token = self
+            .token_response(&form)
+            .await?
+            .map_err(Error::oauth_error)?;
+        token
+            .refresh_token
+            .get_or_insert_with(|| refresh_token.to_string());
+        Ok(token)
+    }
+
+    /// Loads the tokens from `store`, refreshing and saving them first if the access token expired. Returns `None`
+    /// if the store is empty, in which case one of the flows has to be run and its tokens saved.
+    pub async fn load_token(&self, store: &dyn TokenStore) -> Result<Option<TokenSet>> {
+        let token = match store.load().await? {
+            Some(token) => token,
+            None => return Ok(None),
+        };
+        if !token.is_expired() {
+            return Ok(Some(token));
+        }
+        let refresh_token = token.refresh_token.as_deref().ok_or_else(|| {
+            Error::oauth_error(OAuthError::new(
+                "invalid_grant",
+                "The access token expired and there is no refresh token",
+            ))
+        })?;
+        let token = self.refresh(refresh_token).await?;
+        store.on_refresh(&token).await?;
+        Ok(Some(token))
+    }
+
+    /// Sends a request to the token endpoint. An error response of the authorization server is returned as the
+    /// inner error, so the caller can decide whether to retry.
+    async fn token_response(
+        &self,
+        form: &[(&str, &str)],
+    ) -> Result<std::result::Result<TokenSet, OAuthError>> {
+        let response = self.client.post(TOKEN_URL).form(form).send().await?;
+        if !response.status().is_success() {
+            return Ok(Err(json(response).await?));
+        }
+        let token: TokenResponse = json(response).await?;
+        Ok(Ok(token.into()))
+    }
+}
+
+/// The state of an authorization code flow, between the redirect to the consent URL and the exchange of the code.
+#[derive(Debug, Clone)]
+pub struct AuthorizationRequest {
+    /// The consent URL the user is redirected to.
+    pub url: String,
+
+    /// The random value the redirect has to carry in its `state` parameter.
+    pub state: String,
+
+    redirect_uri: String,
+    code_verifier: String,
+}
+
+/// The OAuth 2.0 scopes of the YouTube APIs. details:
+/// [link](https://developers.google.com/youtube/v3/guides/auth/installed-apps#identify-access-scopes)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Scope {
+    /// Manage your YouTube account.
+    Youtube,
+
+    /// See a list of your current active channel members, their current level, and when they became a member.
+    YoutubeChannelMembershipsCreator,
+
+    /// See, edit, and permanently delete your YouTube videos, ratings, comments and captions.
+    YoutubeForceSsl,
+
+    /// View your YouTube account.
+    YoutubeReadonly,
+
+    /// Manage your YouTube videos.
+    YoutubeUpload,
+
+    /// View and manage your assets and associated content on YouTube.
+    YoutubePartner,
+
+    /// View private information of your YouTube channel relevant during the audit process with a YouTube partner.
+    YoutubePartnerChannelAudit,
+
+    /// View YouTube Analytics reports for your YouTube content.
+    YtAnalyticsReadonly,
+
+    /// View monetary and non-monetary YouTube Analytics reports for your YouTube content.
+    YtAnalyticsMonetaryReadonly,
+}
+
+impl Scope {
+    /// Every scope, in the order of their declaration.
+    pub const ALL: [Scope; 9] = [
+        Scope::Youtube,
+        Scope::YoutubeChannelMembershipsCreator,
+        Scope::YoutubeForceSsl,
+        Scope::YoutubeReadonly,
+        Scope::YoutubeUpload,
+        Scope::YoutubePartner,
+        Scope::YoutubePartnerChannelAudit,
+        Scope::YtAnalyticsReadonly,
+        Scope::YtAnalyticsMonetaryReadonly,
+    ];
+
+    /// Parses the space separated scopes of a token response. Scopes of other Google APIs are skipped.
+    pub fn parse_list(scopes: &str) -> Vec<Scope> {
+        scopes
+            .split_whitespace()
+            .filter_map(|scope| scope.parse().ok())
+            .collect()
+    }
+
+    /// The scopes of which at least one is required to send `method` to `url`. The YouTube Data API documents
+    /// the scopes per method; broadly, reads accept the read-only scope, writes need a full scope, uploads accept
+    /// the upload scope, and comments and captions need `youtube.force-ssl`.
+    pub(crate) fn required_for(method: &reqwest::Method, url: &Url) -> Vec<Scope> {
+        let host = url.host_str().unwrap_or_default();
+        let path = url.path();
+        if host.starts_with("youtubeanalytics.") || host.starts_with("youtubereporting.") {
+            return vec![
+                Scope::YtAnalyticsReadonly,
+                Scope::YtAnalyticsMonetaryReadonly,
+            ];
+        }
+        if path.starts_with("/upload/") && path != "/upload/youtube/v3/captions" {
+            return vec![
+                Scope::Youtube,
+                Scope::YoutubeForceSsl,
+                Scope::YoutubeUpload,
+                Scope::YoutubePartner,
+            ];
+        }
+        let resource = path.rsplit('/').next().unwrap_or_default();
+        if [
+            "comments",
+            "commentThreads",
+            "captions",
+            "markAsSpam",
+            "setModerationStatus",
+        ]
+        .contains(&resource)
+        {
+            return vec![Scope::YoutubeForceSsl, Scope::YoutubePartner];
+        }
+        if method == reqwest::Method::GET {
+            return vec![
+                Scope::Youtube,
+                Scope::YoutubeForceSsl,
+                Scope::YoutubeReadonly,
+                Scope::YoutubePartner,
+            ];
+        }
+        vec![
+            Scope::Youtube,
+            Scope::YoutubeForceSsl,
+            Scope::YoutubePartner,
+        ]
+    }
+}
+
+impl std::fmt::Display for Scope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            Scope::Youtube => "youtube",
+            Scope::YoutubeChannelMembershipsCreator => "youtube.channel-memberships.creator",
+            Scope::YoutubeForceSsl => "youtube.force-ssl",
+            Scope::YoutubeReadonly => "youtube.readonly",
+            Scope::YoutubeUpload => "youtube.upload",
+            Scope::YoutubePartner => "youtubepartner",
+            Scope::YoutubePartnerChannelAudit => "youtubepartner-channel-audit",
+            Scope::YtAnalyticsReadonly => "yt-analytics.readonly",
+            Scope::YtAnalyticsMonetaryReadonly => "yt-analytics-monetary.readonly",
+        };
+        write!(f, "{}{}", SCOPE_PREFIX, str)
+    }
+}
+
+impl std::str::FromStr for Scope {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Scope::ALL
+            .into_iter()
+            .find(|scope| scope.to_string() == s)
+            .ok_or_else(|| Error::invalid_parameter(format!("Unknown scope `{}`", s)))
+    }
+}
+
+pub(crate) fn join_scopes(scopes: &[Scope]) -> String {
+    scopes
+        .iter()
+        .map(Scope::to_string)
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// Opens `url` in the default browser of the system, to be passed to [OAuthClient::loopback_flow].
+#[cfg(not(target_arch = "wasm32"))]
+pub fn open_in_browser(url: &str) {
+    // there is nothing to recover here, the caller can show the URL when the browser does not open
+    let _ = webbrowser::open(url);
+}
+
+/// Accepts connections on the loopback listener until the browser is redirected with the result of the consent, and
+/// returns the query parameters of the redirect.
+#[cfg(not(target_arch = "wasm32"))]
+async fn accept_redirect(listener: &TcpListener) -> Result<HashMap<String, String>> {
+    loop {
+        let (stream, _) = listener
+            .accept()
+            .await
+            .map_err(|e| Error::oauth_error(OAuthError::new("loopback_error", e.to_string())))?;
+        let mut reader = BufReader::new(stream);
+        let mut request_line = String::new();
+        if reader.read_line(&mut request_line).await.is_err() {
+            continue;
+        }
+
+        // e.g. `GET /?state=...&code=... HTTP/1.1`
+        let params = request_line
+            .split_whitespace()
+            .nth(1)
+            .and_then(|target| Url::parse(&format!("http://127.0.0.1{}", target)).ok())
+            .map(|url| {
+                url.query_pairs()
+                    .into_owned()
+                    .collect::<HashMap<String, String>>()
+            })
+            .unwrap_or_default();
+        let done = params.contains_key("code") || params.contains_key("error");
+
+        let (status, body) = if done {
+            (
+                "200 OK",
+                "Authorization complete. You can close this window.",
+            )
+        } else {
+            ("404 Not Found", "Not Found")
+        };
+        let response = format!(
+            "HTTP/1.1 {}\r\nContent-Type: text/plain; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        );
+        // the result of the consent is known even if the browser does not receive the page
+        let _ = reader.get_mut().write_all(response.as_bytes()).await;
+
+        if done {
+            return Ok(params);
+        }
+    }
+}
+
+fn random_string(len: usize) -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(len)
+        .map(char::from)
+        .collect()
+}
+
+/// The codes issued for the device authorization grant.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct DeviceAuthorization {
+    /// The code that uniquely identifies the device. It is not shown to the user.
+    pub device_code: String,
+
+    /// The code the user enters at `verification_url` to grant access.
+    pub user_code: String,
+
+    /// The URL the user has to open on another device to enter the `user_code`.
+    pub verification_url: String,
+
+    /// The number of seconds the codes are valid for.
+    pub expires_in: i64,
+
+    /// The number of seconds to wait between polling requests.
+    pub interval: u64,
+
+    #[serde(skip, default = "Utc::now")]
+    requested_at: DateTime<Utc>,
+}
+
+impl DeviceAuthorization {
+    /// The time after which the codes can no longer be used.
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.requested_at + Duration::seconds(self.expires_in)
+    }
+}
+
+/// The tokens issued by the authorization server.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenSet {
+    /// The token sent with requests to the YouTube Data API.
+    pub access_token: String,
+
+    /// The token used to obtain a new access token. It is only issued with the first authorization of a user.
+    pub refresh_token: Option<String>,
+
+    /// The time after which the access token is no longer valid.
+    pub expires_at: DateTime<Utc>,
+
+    /// The scopes granted by the user, separated by spaces.
+    pub scope: Option<String>,
+
+    /// The type of the access token. The value is always `Bearer`.
+    pub token_type: String,
+}
+
+impl TokenSet {
+    /// Whether the access token expired, or expires within the next minute.
+    pub fn is_expired(&self) -> bool {
+        Utc::now() + Duration::minutes(1) >= self.expires_at
+    }
+}
+
+/// The successful response of the token endpoint.
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: i64,
+    refresh_token: Option<String>,
+    scope: Option<String>,
+    token_type: String,
+}
+
+impl From<TokenResponse> for TokenSet {
+    fn from(value: TokenResponse) -> Self {
+        TokenSet {
+            access_token: value.access_token,
+            refresh_token: value.refresh_token,
+            expires_at: Utc::now() + Duration::seconds(value.expires_in),
+            scope: value.scope,
+            token_type: value.token_type,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_request_device_code_without_scopes() {
+        let without_scopes = OAuthClient::new("client-id", None)
+            .request_device_code(&[])
+            .await;
+        assert!(without_scopes.is_err());
+        let err = without_scopes.unwrap_err();
+        assert_eq!(
+            "builder error: \"Required parameter `scopes` is empty\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_poll_expired_device_code() {
+        let authorization: DeviceAuthorization = serde_json::from_str(
+            r#"{
+                "device_code": "device-code",
+                "user_code": "GQVQ-JKEC",
+                "verification_url": "https://www.google.com/device",
+                "expires_in": 0,
+                "interval": 5
+            }"#,
+        )
+        .unwrap();
+        let expired = OAuthClient::new("client-id", None)
+            .poll_device_token(&authorization)
+            .await;
+        assert!(expired.is_err());
+        let err = expired.unwrap_err();
+        assert_eq!(
+            "oauth error: \"expired_token\" description: \"The device code expired before the user responded\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_loopback_flow_denied() {
+        let denied = OAuthClient::new("client-id", None)
+            .loopback_flow(&[Scope::Youtube], |consent_url| {
+                let consent_url = Url::parse(consent_url).unwrap();
+                let params = consent_url
+                    .query_pairs()
+                    .into_owned()
+                    .collect::<HashMap<String, String>>();
+                let redirect = format!(
+                    "{}/?error=access_denied&state={}",
+                    params["redirect_uri"], params["state"]
+                );
+                tokio::spawn(async move {
+                    let _ = reqwest::get(redirect).await;
+                });
+            })
+            .await;
+        assert!(denied.is_err());
+        let err = denied.unwrap_err();
+        assert_eq!(
+            "oauth error: \"access_denied\" description: \"The user did not grant access\"",
+            format!("{}", err)
+        );
+    }
+
+    #[test]
+    fn test_scope_round_trip() {
+        for scope in Scope::ALL {
+            assert_eq!(scope.to_string().parse::<Scope>().unwrap(), scope);
+        }
+        assert_eq!(
+            Scope::parse_list("openid https://www.googleapis.com/auth/youtube.readonly"),
+            vec![Scope::YoutubeReadonly]
+        );
+    }
+
+    #[test]
+    fn test_required_scopes() {
+        let url = Url::parse("https://www.googleapis.com/youtube/v3/commentThreads").unwrap();
+        assert_eq!(
+            Scope::required_for(&reqwest::Method::POST, &url),
+            vec![Scope::YoutubeForceSsl, Scope::YoutubePartner]
+        );
+        let url =
+            Url::parse("https://www.googleapis.com/upload/youtube/v3/thumbnails/set").unwrap();
+        assert!(Scope::required_for(&reqwest::Method::POST, &url).contains(&Scope::YoutubeUpload));
+        let url = Url::parse("https://www.googleapis.com/youtube/v3/subscriptions").unwrap();
+        assert!(Scope::required_for(&reqwest::Method::GET, &url).contains(&Scope::YoutubeReadonly));
+        assert!(
+            !Scope::required_for(&reqwest::Method::POST, &url).contains(&Scope::YoutubeReadonly)
+        );
+    }
+
+    #[test]
+    fn test_token_set_from_response() {
+        let response: TokenResponse = serde_json::from_str(
+            r#"{
+                "access_token": "access-token",
+                "expires_in": 3599,
+                "refresh_token": "refresh-token",
+                "scope": "https://www.googleapis.com/auth/youtube.readonly",
+                "token_type": "Bearer"
+            }"#,
+        )
+        .unwrap();
+        let token = TokenSet::from(response);
+        assert_eq!(token.refresh_token.as_deref(), Some("refresh-token"));
+        assert!(!token.is_expired());
+    }
+}
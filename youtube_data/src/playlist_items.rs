@@ -0,0 +1,647 @@
+use crate::{
+    auth::{Authorizer, OAuthTokenAuth},
+    cursor::PageCursor,
+    error::Result,
+    list_json,
+    search::ResourceId,
+    ListApi, ListResponse, PagedListApi, PrivacyStatus, RequestBase, Thumbnails, YouTube,
+    YouTubeDataApi,
+};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use youtube_data_derive::QueryBuilder;
+
+pub type PlaylistItemListResponse = ListResponse<PlaylistItemListResource>;
+
+#[derive(Clone, Copy)]
+pub struct PlaylistItemsService<'a> {
+    youtube: &'a YouTube,
+}
+
+impl<'a> PlaylistItemsService<'a> {
+    pub(crate) fn new(youtube: &'a YouTube) -> Self {
+        Self { youtube }
+    }
+
+    pub fn list(self, part: impl IntoIterator<Item = Part>) -> PlaylistItemList<'a> {
+        PlaylistItemList::new(self, part)
+    }
+}
+
+/// Parameters for the `list` method of the `playlistItems` api. details:
+/// [link](https://developers.google.com/youtube/v3/docs/playlistItems/list)
+#[derive(QueryBuilder)]
+pub struct PlaylistItemList<'a> {
+    service: PlaylistItemsService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    #[query(unique)]
+    part: Vec<Part>,
+
+    // filters (specify exactly one of the following parameters)
+    #[query(filter)]
+    id: Option<Vec<String>>,
+    #[query(filter)]
+    playlist_id: Option<String>,
+
+    // optional parameters
+    /// Selects the fields of the response, e.g. `items(id,snippet/title)`, so only the needed part of every
+    /// resource is returned. details: [link](https://developers.google.com/youtube/v3/getting-started#fields)
+    #[query]
+    fields: Option<String>,
+    /// Fails the request with a timeout error when it does not complete in time, instead of the timeout of the
+    /// `YouTube` handle.
+    #[setter]
+    timeout: Option<Duration>,
+    #[query(max = 50)]
+    max_results: Option<u32>,
+    #[setter]
+    on_behalf_of_content_owner: Option<String>,
+    #[setter]
+    page_token: Option<String>,
+    #[query]
+    video_id: Option<String>,
+}
+
+impl RequestBase for PlaylistItemList<'_> {
+    fn api_path(&self) -> &str {
+        "playlistItems"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for PlaylistItemList<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+
+    fn request_timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl ListApi<PlaylistItemListResponse> for PlaylistItemList<'_> {
+    async fn request(&self) -> Result<PlaylistItemListResponse> {
+        self.request_page(None).await
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl PagedListApi<PlaylistItemListResource> for PlaylistItemList<'_> {
+    async fn request_page(&self, page_token: Option<&str>) -> Result<PlaylistItemListResponse> {
+        let youtube = &self.service.youtube;
+        let params = self.params(page_token)?;
+
+        let request = self.optional_bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .get(self.url(&youtube.inner().base_path))
+                .query(&params),
+        )?;
+        let response = self.send(youtube, request).await?;
+        list_json(response).await
+    }
+
+    fn cursor(&self, page_token: Option<&str>) -> Result<PageCursor> {
+        Ok(PageCursor::new(self.api_path(), self.params(page_token)?))
+    }
+}
+
+impl PlaylistItemList<'_> {
+    /// The query parameters of the page of `page_token`, or of the `page_token` of the builder for `None`.
+    fn params(&self, page_token: Option<&str>) -> Result<HashMap<String, String>> {
+        let youtube = &self.service.youtube;
+        let page_token = page_token.or(self.page_token.as_deref());
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        self.insert_query(youtube, &mut params)?;
+        self.insert_content_owner_parameters(
+            youtube,
+            &mut params,
+            self.on_behalf_of_content_owner.as_deref(),
+            None,
+        )?;
+        self.insert_query_parameter(&mut params, "pageToken", page_token);
+
+        Ok(params)
+    }
+}
+
+impl<'a> PlaylistItemList<'a> {
+    pub(crate) fn new(
+        service: PlaylistItemsService<'a>,
+        part: impl IntoIterator<Item = Part>,
+    ) -> Self {
+        let part = crate::dedupe(part);
+        let part = if part.is_empty() {
+            vec![Part::Id]
+        } else {
+            part
+        };
+        Self {
+            service,
+            authorizer: None,
+            part,
+            id: None,
+            playlist_id: None,
+            fields: None,
+            timeout: None,
+            max_results: None,
+            on_behalf_of_content_owner: None,
+            page_token: None,
+            video_id: None,
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    ContentDetails,
+    Id,
+    Snippet,
+    Status,
+}
+
+impl Part {
+    /// Every part of the resource, to request all of its properties.
+    pub fn all_public() -> Vec<Part> {
+        vec![Part::ContentDetails, Part::Id, Part::Snippet, Part::Status]
+    }
+}
+
+impl std::fmt::Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            Part::ContentDetails => "contentDetails",
+            Part::Id => "id",
+            Part::Snippet => "snippet",
+            Part::Status => "status",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaylistItemListResource {
+    /// Identifies the API resource's type. The value will be `youtube#playlistItem`.
+    #[serde(default)]
+    pub kind: String,
+
+    /// The ETag of the response.
+    #[serde(default)]
+    pub etag: String,
+
+    /// The ID that YouTube uses to uniquely identify the playlist item.
+    #[serde(default)]
+    pub id: String,
+
+    pub snippet: Option<PlaylistItemSnippet>,
+
+    pub content_details: Option<PlaylistItemContentDetails>,
+
+    pub status: Option<PlaylistItemStatus>,
+
+    /// The fields of the resource that this version of the crate does not know, e.g. added to the api after its
+    /// release. They are serialized back with the resource.
+    #[serde(flatten, deserialize_with = "crate::serde_helpers::extra_fields")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// The snippet object contains basic details about the playlist item, such as its title and position in the playlist.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaylistItemSnippet {
+    /// The date and time that the item was added to the playlist. The value is specified in
+    /// [ISO 8601](https://www.w3.org/TR/NOTE-datetime) format.
+    pub published_at: Option<DateTime<Utc>>,
+
+    /// The ID that YouTube uses to uniquely identify the user that added the item to the playlist.
+    #[serde(default)]
+    pub channel_id: String,
+
+    /// The item's title.
+    #[serde(default)]
+    pub title: String,
+
+    /// The item's description.
+    #[serde(default)]
+    pub description: String,
+
+    /// A map of thumbnail images associated with the playlist item. For each object in the map, the key is the name
+    /// of the thumbnail image, and the value is an object that contains other information about the thumbnail.
+    #[serde(default)]
+    pub thumbnails: Thumbnails,
+
+    /// The channel title of the channel that the playlist item belongs to.
+    #[serde(default)]
+    pub channel_title: String,
+
+    /// The channel title of the channel that uploaded this video.
+    pub video_owner_channel_title: Option<String>,
+
+    /// The channel ID of the channel that uploaded this video.
+    pub video_owner_channel_id: Option<String>,
+
+    /// The ID that YouTube uses to uniquely identify the playlist that the playlist item is in.
+    #[serde(default)]
+    pub playlist_id: String,
+
+    /// The order in which the item appears in the playlist. The value uses a zero-based index, so the first item has
+    /// a position of 0, the second item has a position of 1, and so forth.
+    pub position: Option<u32>,
+
+    /// The id object contains information that can be used to uniquely identify the resource that is included in the
+    /// playlist as the playlist item.
+    #[serde(default)]
+    pub resource_id: ResourceId,
+}
+
+/// The contentDetails object is included in the resource if the included item is a YouTube video. The object
+/// contains additional information about the video.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaylistItemContentDetails {
+    /// The ID that YouTube uses to uniquely identify a video.
+    #[serde(default)]
+    pub video_id: String,
+
+    /// A user-generated note for this item. The property value has a maximum length of 280 characters.
+    pub note: Option<String>,
+
+    /// The date and time that the video was published to YouTube. The value is specified in
+    /// [ISO 8601](https://www.w3.org/TR/NOTE-datetime) format.
+    pub video_published_at: Option<DateTime<Utc>>,
+}
+
+/// The status object contains information about the playlist item's privacy status.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaylistItemStatus {
+    /// The playlist item's privacy status. The channel that uploaded the video that the playlist item represents can
+    /// set this value.
+    pub privacy_status: Option<PrivacyStatus>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cursor::PageCursor;
+    use crate::transport::{json_response, HttpTransport, MockTransport};
+    use crate::Paginated;
+    use crate::{get_youtube_with_transport, get_youtube_without_user_agent};
+    use futures_util::{StreamExt, TryStreamExt};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    /// Serves a playlist of `pages` pages with two items each, linked by the page tokens `page-1`, `page-2`, ...
+    pub(crate) struct PagedPlaylist {
+        pub(crate) pages: usize,
+        pub(crate) requests: AtomicUsize,
+        pub(crate) queries: Mutex<Vec<String>>,
+    }
+
+    impl PagedPlaylist {
+        pub(crate) fn new(pages: usize) -> PagedPlaylist {
+            PagedPlaylist {
+                pages,
+                requests: AtomicUsize::new(0),
+                queries: Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl HttpTransport for PagedPlaylist {
+        async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+            self.requests.fetch_add(1, Ordering::SeqCst);
+            self.queries
+                .lock()
+                .unwrap()
+                .push(request.url().query().unwrap_or_default().to_string());
+            let page = request
+                .url()
+                .query_pairs()
+                .find(|(key, _)| key == "pageToken")
+                .and_then(|(_, token)| token.strip_prefix("page-")?.parse::<usize>().ok())
+                .unwrap_or(0);
+            let mut body = serde_json::json!({
+                "kind": "youtube#playlistItemListResponse",
+                "etag": format!("etag-{}", page),
+                "pageInfo": { "totalResults": self.pages * 2, "resultsPerPage": 2 },
+                "items": [{ "id": format!("item-{}-0", page) }, { "id": format!("item-{}-1", page) }],
+            });
+            if page + 1 < self.pages {
+                body["nextPageToken"] = format!("page-{}", page + 1).into();
+            }
+            if page > 0 {
+                body["prevPageToken"] = format!("page-{}", page - 1).into();
+            }
+            json_response(200, body.to_string())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_by_playlist_id() {
+        let transport = Arc::new(MockTransport::new().respond(
+            reqwest::Method::GET,
+            "playlistItems",
+            200,
+            r#"{
+                "kind": "youtube#playlistItemListResponse",
+                "etag": "etag-0",
+                "nextPageToken": "EAAaBlBUOkNBVQ",
+                "pageInfo": { "totalResults": 120, "resultsPerPage": 2 },
+                "items": [
+                    {
+                        "kind": "youtube#playlistItem",
+                        "id": "item-0",
+                        "snippet": {
+                            "publishedAt": "2023-05-01T12:00:00Z",
+                            "channelId": "UCa9Y57gfeY0Zro_noHRVrnw",
+                            "title": "The newest upload",
+                            "description": "",
+                            "thumbnails": {
+                                "default": { "url": "https://i.ytimg.com/vi/video-0/default.jpg", "width": 120, "height": 90 }
+                            },
+                            "channelTitle": "A channel",
+                            "playlistId": "UUa9Y57gfeY0Zro_noHRVrnw",
+                            "position": 0,
+                            "resourceId": { "kind": "youtube#video", "videoId": "video-0" }
+                        },
+                        "contentDetails": { "videoId": "video-0", "videoPublishedAt": "2023-05-01T12:00:00Z" }
+                    },
+                    {
+                        "kind": "youtube#playlistItem",
+                        "id": "item-1",
+                        "snippet": {
+                            "channelId": "UCa9Y57gfeY0Zro_noHRVrnw",
+                            "title": "An older upload",
+                            "playlistId": "UUa9Y57gfeY0Zro_noHRVrnw",
+                            "position": 1
+                        },
+                        "contentDetails": { "videoId": "video-1" }
+                    }
+                ]
+            }"#,
+        ));
+        let response = get_youtube_with_transport(transport.clone())
+            .playlist_items()
+            .list(vec![Part::Snippet, Part::ContentDetails])
+            .playlist_id("UUa9Y57gfeY0Zro_noHRVrnw")
+            .max_results(2)
+            .request()
+            .await
+            .unwrap();
+        assert_eq!(response.items.len(), 2);
+        assert_eq!(response.next_token(), Some("EAAaBlBUOkNBVQ"));
+        assert_eq!(response.total_results(), 120);
+        let snippet = response.items[0].snippet.as_ref().unwrap();
+        assert_eq!(snippet.playlist_id, "UUa9Y57gfeY0Zro_noHRVrnw");
+        assert_eq!(snippet.title, "The newest upload");
+        assert_eq!(snippet.position, Some(0));
+        assert_eq!(
+            snippet.thumbnails.default.as_ref().unwrap().width,
+            Some(120)
+        );
+        assert_eq!(
+            response
+                .items
+                .iter()
+                .map(|item| item.content_details.as_ref().unwrap().video_id.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["video-0", "video-1"]
+        );
+        let query = transport.requests()[0].1.query().unwrap().to_string();
+        assert!(query.contains("playlistId=UUa9Y57gfeY0Zro_noHRVrnw"));
+        assert!(query.contains("maxResults=2"));
+    }
+
+    #[tokio::test]
+    async fn test_pages() {
+        let transport = Arc::new(PagedPlaylist::new(3));
+        let youtube = get_youtube_with_transport(transport.clone());
+        let mut list = youtube.playlist_items().list(vec![Part::Id]);
+        list.playlist_id("playlist-id");
+
+        let pages = list.pages().try_collect::<Vec<_>>().await.unwrap();
+        assert_eq!(pages.len(), 3);
+        assert_eq!(pages[2].items[1].id, "item-2-1");
+        assert_eq!(transport.requests.load(Ordering::SeqCst), 3);
+
+        // starts at the page token of the builder
+        list.page_token("page-1");
+        let pages = list.pages().try_collect::<Vec<_>>().await.unwrap();
+        assert_eq!(pages.len(), 2);
+        assert_eq!(pages[0].items[0].id, "item-1-0");
+    }
+
+    #[tokio::test]
+    async fn test_items() {
+        let transport = Arc::new(PagedPlaylist::new(3));
+        let youtube = get_youtube_with_transport(transport.clone());
+        let mut list = youtube.playlist_items().list(vec![Part::Id]);
+        list.playlist_id("playlist-id");
+
+        let items = list.items(None).try_collect::<Vec<_>>().await.unwrap();
+        assert_eq!(items.len(), 6);
+        assert_eq!(items[3].id, "item-1-1");
+
+        // the third page is not requested
+        transport.requests.store(0, Ordering::SeqCst);
+        let mut items = list.items(Some(3));
+        let mut ids = Vec::new();
+        while let Some(item) = items.try_next().await.unwrap() {
+            ids.push(item.id);
+        }
+        assert_eq!(ids, vec!["item-0-0", "item-0-1", "item-1-0"]);
+        assert_eq!(transport.requests.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_collect_all() {
+        let transport = Arc::new(PagedPlaylist::new(3));
+        let youtube = get_youtube_with_transport(transport.clone());
+        let mut list = youtube.playlist_items().list(vec![Part::Id]);
+        list.playlist_id("playlist-id");
+
+        let collected = list.collect_all(3).await.unwrap();
+        assert_eq!(collected.items.len(), 3);
+        assert_eq!(collected.requests, 2);
+
+        let collected = list.collect_all(100).await.unwrap();
+        assert_eq!(collected.items.len(), 6);
+        assert_eq!(collected.requests, 3);
+
+        let collected = list.collect_all(0).await.unwrap();
+        assert!(collected.items.is_empty());
+        assert_eq!(collected.requests, 0);
+    }
+
+    #[tokio::test]
+    async fn test_resume_cursor() {
+        let transport = Arc::new(PagedPlaylist::new(3));
+        let youtube = get_youtube_with_transport(transport.clone());
+        let mut list = youtube.playlist_items().list(vec![Part::Id]);
+        list.playlist_id("playlist-id").max_results(2);
+
+        let first = list.request().await.unwrap();
+        let cursor = list.cursor(first.next_page_token.as_deref()).unwrap();
+        assert_eq!(cursor.endpoint, "playlistItems");
+        assert_eq!(cursor.page_token.as_deref(), Some("page-1"));
+        assert!(cursor.params.iter().all(|(key, _)| key != "key"));
+
+        // saved and restored, e.g. after a restart
+        let cursor = serde_json::to_string(&cursor).unwrap();
+        let cursor = serde_json::from_str::<PageCursor>(&cursor).unwrap();
+        let resumed = youtube.resume::<PlaylistItemListResource>(cursor);
+        let pages = resumed.pages().try_collect::<Vec<_>>().await.unwrap();
+        assert_eq!(pages.len(), 2);
+        assert_eq!(pages[0].items[0].id, "item-1-0");
+        assert_eq!(pages[1].items[1].id, "item-2-1");
+
+        let queries = transport.queries.lock().unwrap();
+        assert!(queries[1].contains("playlistId=playlist-id"));
+        assert!(queries[1].contains("maxResults=2"));
+        assert!(queries[1].contains("pageToken=page-1"));
+    }
+
+    #[tokio::test]
+    async fn test_prev_pages() {
+        let transport = Arc::new(PagedPlaylist::new(3));
+        let youtube = get_youtube_with_transport(transport.clone());
+        let mut list = youtube.playlist_items().list(vec![Part::Id]);
+        list.playlist_id("playlist-id").page_token("page-2");
+
+        let pages = list.pages_rev().try_collect::<Vec<_>>().await.unwrap();
+        let first_ids = pages
+            .iter()
+            .map(|page| page.items[0].id.as_str())
+            .collect::<Vec<&str>>();
+        assert_eq!(first_ids, vec!["item-2-0", "item-1-0", "item-0-0"]);
+
+        let last = list.request().await.unwrap();
+        assert!(!last.has_next());
+        assert!(last.has_prev());
+        assert_eq!(last.results_per_page(), 2);
+        assert_eq!(last.total_results(), 6);
+        assert!(list.next_page(&last).await.unwrap().is_none());
+        let prev = list.prev_page(&last).await.unwrap().unwrap();
+        assert_eq!(prev.items[0].id, "item-1-0");
+        assert_eq!(prev.next_token(), Some("page-2"));
+    }
+
+    /// Serves a playlist that changes every third request, answering `304 Not Modified` to the requests with the
+    /// current ETag.
+    struct ChangingPlaylist(AtomicUsize);
+
+    #[async_trait]
+    impl HttpTransport for ChangingPlaylist {
+        async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+            let version = self.0.fetch_add(1, Ordering::SeqCst) / 3;
+            let etag = format!("etag-{}", version);
+            let if_none_match = request.headers().get(reqwest::header::IF_NONE_MATCH);
+            if if_none_match.is_some_and(|if_none_match| if_none_match == etag.as_str()) {
+                return json_response(304, "");
+            }
+            let body = serde_json::json!({
+                "etag": etag,
+                "items": [{ "id": format!("item-{}", version) }],
+            });
+            json_response(200, body.to_string())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_poll() {
+        let transport = Arc::new(ChangingPlaylist(AtomicUsize::new(0)));
+        let youtube = get_youtube_with_transport(transport.clone());
+        let mut list = youtube.playlist_items().list(vec![Part::Id]);
+        list.playlist_id("playlist-id");
+
+        let changes = list
+            .poll(Duration::from_millis(1))
+            .take(2)
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        assert_eq!(changes[0].items[0].id, "item-0");
+        assert_eq!(changes[1].items[0].id, "item-1");
+        // two requests were answered with `304 Not Modified`
+        assert_eq!(transport.0.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn test_poll_same_etag() {
+        // a server that ignores `If-None-Match` and answers every request with the same page
+        let transport = Arc::new(MockTransport::new().respond(
+            reqwest::Method::GET,
+            "playlistItems",
+            200,
+            r#"{"etag": "etag-0", "items": [{"id": "item-0"}]}"#,
+        ));
+        let youtube = get_youtube_with_transport(transport.clone());
+        let mut list = youtube.playlist_items().list(vec![Part::Id]);
+        list.playlist_id("playlist-id");
+
+        let mut changes = list.poll(Duration::from_millis(1));
+        let first = changes.try_next().await.unwrap().unwrap();
+        assert_eq!(first.items[0].id, "item-0");
+        let next = tokio::time::timeout(Duration::from_millis(50), changes.try_next()).await;
+        assert!(next.is_err());
+        assert!(transport.requests().len() > 1);
+    }
+
+    #[tokio::test]
+    async fn test_request_without_filters() {
+        let without_filters = get_youtube_without_user_agent()
+            .playlist_items()
+            .list(vec![])
+            .request()
+            .await;
+        assert!(without_filters.is_err());
+        let err = without_filters.unwrap_err();
+        assert_eq!(
+            "builder error: \"No filter selected. Expected one of: id, playlist_id\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_request_multiple_filters() {
+        let multiple_filters = get_youtube_without_user_agent()
+            .playlist_items()
+            .list(vec![])
+            .id(vec!["something"])
+            .playlist_id("something")
+            .request()
+            .await;
+        assert!(multiple_filters.is_err());
+        let err = multiple_filters.unwrap_err();
+        assert_eq!(
+            "builder error: \"Incompatible parameters specified in the request: id, playlist_id\"",
+            format!("{}", err)
+        );
+    }
+}
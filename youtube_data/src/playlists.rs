@@ -0,0 +1,395 @@
+use crate::{
+    auth::{Authorizer, OAuthTokenAuth},
+    cursor::PageCursor,
+    error::Result,
+    list_json, ListApi, ListResponse, Localization, PagedListApi, PrivacyStatus, RequestBase,
+    Thumbnails, YouTube, YouTubeDataApi,
+};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use youtube_data_derive::QueryBuilder;
+
+pub type PlaylistListResponse = ListResponse<PlaylistListResource>;
+
+#[derive(Clone, Copy)]
+pub struct PlaylistsService<'a> {
+    youtube: &'a YouTube,
+}
+
+impl<'a> PlaylistsService<'a> {
+    pub(crate) fn new(youtube: &'a YouTube) -> Self {
+        Self { youtube }
+    }
+
+    pub fn list(self, part: impl IntoIterator<Item = Part>) -> PlaylistList<'a> {
+        PlaylistList::new(self, part)
+    }
+
+    /// Fetches one playlist with its `snippet`, `status` and `contentDetails` parts, or `None` when no playlist has
+    /// the id. Playlists have no `statistics`, the number of their videos is in `contentDetails`.
+    pub async fn get(self, id: impl Into<String>) -> Result<Option<PlaylistListResource>> {
+        let response = self
+            .list([Part::Snippet, Part::Status, Part::ContentDetails])
+            .id(vec![id.into()])
+            .request()
+            .await?;
+        Ok(response.items.into_iter().next())
+    }
+}
+
+/// Parameters for the `list` method of the `playlists` api. details:
+/// [link](https://developers.google.com/youtube/v3/docs/playlists/list)
+#[derive(QueryBuilder)]
+pub struct PlaylistList<'a> {
+    service: PlaylistsService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    #[query(unique)]
+    part: Vec<Part>,
+
+    // filters (specify exactly one of the following parameters)
+    #[query(filter)]
+    channel_id: Option<String>,
+    #[query(filter)]
+    id: Option<Vec<String>>,
+    #[query(filter, authorized)]
+    mine: Option<bool>,
+
+    // optional parameters
+    /// Selects the fields of the response, e.g. `items(id,snippet/title)`, so only the needed part of every
+    /// resource is returned. details: [link](https://developers.google.com/youtube/v3/getting-started#fields)
+    #[query]
+    fields: Option<String>,
+    /// Fails the request with a timeout error when it does not complete in time, instead of the timeout of the
+    /// `YouTube` handle.
+    #[setter]
+    timeout: Option<Duration>,
+    #[query(default)]
+    hl: Option<String>,
+    #[query(max = 50)]
+    max_results: Option<u32>,
+    #[setter]
+    on_behalf_of_content_owner: Option<String>,
+    #[setter]
+    on_behalf_of_content_owner_channel: Option<String>,
+    #[setter]
+    page_token: Option<String>,
+}
+
+impl RequestBase for PlaylistList<'_> {
+    fn api_path(&self) -> &str {
+        "playlists"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for PlaylistList<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+
+    fn request_timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl ListApi<PlaylistListResponse> for PlaylistList<'_> {
+    async fn request(&self) -> Result<PlaylistListResponse> {
+        self.request_page(None).await
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl PagedListApi<PlaylistListResource> for PlaylistList<'_> {
+    async fn request_page(&self, page_token: Option<&str>) -> Result<PlaylistListResponse> {
+        let youtube = &self.service.youtube;
+        let params = self.params(page_token)?;
+
+        let request = self.optional_bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .get(self.url(&youtube.inner().base_path))
+                .query(&params),
+        )?;
+        let response = self.send(youtube, request).await?;
+        list_json(response).await
+    }
+
+    fn cursor(&self, page_token: Option<&str>) -> Result<PageCursor> {
+        Ok(PageCursor::new(self.api_path(), self.params(page_token)?))
+    }
+}
+
+impl PlaylistList<'_> {
+    /// The query parameters of the page of `page_token`, or of the `page_token` of the builder for `None`.
+    fn params(&self, page_token: Option<&str>) -> Result<HashMap<String, String>> {
+        let youtube = &self.service.youtube;
+        let page_token = page_token.or(self.page_token.as_deref());
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        self.insert_query(youtube, &mut params)?;
+        self.insert_content_owner_parameters(
+            youtube,
+            &mut params,
+            self.on_behalf_of_content_owner.as_deref(),
+            self.on_behalf_of_content_owner_channel.as_deref(),
+        )?;
+        self.insert_query_parameter(&mut params, "pageToken", page_token);
+
+        Ok(params)
+    }
+}
+
+impl<'a> PlaylistList<'a> {
+    pub(crate) fn new(service: PlaylistsService<'a>, part: impl IntoIterator<Item = Part>) -> Self {
+        let part = crate::dedupe(part);
+        let part = if part.is_empty() {
+            vec![Part::Id]
+        } else {
+            part
+        };
+        Self {
+            service,
+            authorizer: None,
+            part,
+            channel_id: None,
+            id: None,
+            mine: None,
+            fields: None,
+            timeout: None,
+            hl: None,
+            max_results: None,
+            on_behalf_of_content_owner: None,
+            on_behalf_of_content_owner_channel: None,
+            page_token: None,
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    ContentDetails,
+    Id,
+    Localizations,
+    Player,
+    Snippet,
+    Status,
+}
+
+impl Part {
+    /// Every part of the resource, to request all of its properties.
+    pub fn all_public() -> Vec<Part> {
+        vec![
+            Part::ContentDetails,
+            Part::Id,
+            Part::Localizations,
+            Part::Player,
+            Part::Snippet,
+            Part::Status,
+        ]
+    }
+}
+
+impl std::fmt::Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            Part::ContentDetails => "contentDetails",
+            Part::Id => "id",
+            Part::Localizations => "localizations",
+            Part::Player => "player",
+            Part::Snippet => "snippet",
+            Part::Status => "status",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaylistListResource {
+    /// Identifies the API resource's type. The value will be `youtube#playlist`.
+    #[serde(default)]
+    pub kind: String,
+
+    /// The ETag of the response.
+    #[serde(default)]
+    pub etag: String,
+
+    /// The ID that YouTube uses to uniquely identify the playlist.
+    #[serde(default)]
+    pub id: String,
+
+    pub snippet: Option<PlaylistSnippet>,
+
+    pub status: Option<PlaylistStatus>,
+
+    pub content_details: Option<PlaylistContentDetails>,
+
+    pub player: Option<PlaylistPlayer>,
+
+    /// The localizations object encapsulates translations of the playlist's metadata, by language.
+    pub localizations: Option<HashMap<String, Localization>>,
+
+    /// The fields of the resource that this version of the crate does not know, e.g. added to the api after its
+    /// release. They are serialized back with the resource.
+    #[serde(flatten, deserialize_with = "crate::serde_helpers::extra_fields")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// The snippet object contains basic details about the playlist, such as its title and description.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaylistSnippet {
+    /// The date and time that the playlist was created. The value is specified in
+    /// [ISO 8601](https://www.w3.org/TR/NOTE-datetime) format.
+    pub published_at: Option<DateTime<Utc>>,
+
+    /// The ID that YouTube uses to uniquely identify the channel that published the playlist.
+    #[serde(default)]
+    pub channel_id: String,
+
+    /// The playlist's title.
+    #[serde(default)]
+    pub title: String,
+
+    /// The playlist's description.
+    #[serde(default)]
+    pub description: String,
+
+    /// A map of thumbnail images associated with the playlist. For each object in the map, the key is the name of the
+    /// thumbnail image, and the value is an object that contains other information about the thumbnail.
+    #[serde(default)]
+    pub thumbnails: Thumbnails,
+
+    /// The channel title of the channel that the video belongs to.
+    pub channel_title: Option<String>,
+
+    /// The language of the text in the playlist resource's `snippet.title` and `snippet.description` properties.
+    pub default_language: Option<String>,
+
+    /// The title and description of the playlist in the language of the `hl` parameter, or in the default language.
+    pub localized: Option<Localization>,
+}
+
+/// The status object contains status information for the playlist.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaylistStatus {
+    /// The playlist's privacy status.
+    pub privacy_status: Option<PrivacyStatus>,
+}
+
+/// The contentDetails object contains information about the playlist content.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaylistContentDetails {
+    /// The number of videos in the playlist.
+    #[serde(default)]
+    pub item_count: u32,
+}
+
+/// The player object contains information that you would use to play the playlist in an embedded player.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaylistPlayer {
+    /// An `<iframe>` tag that embeds a player that will play the playlist.
+    #[serde(default)]
+    pub embed_html: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::MockTransport;
+    use crate::{get_youtube_with_transport, get_youtube_without_user_agent};
+
+    #[tokio::test]
+    async fn test_get() {
+        let transport = Arc::new(MockTransport::new().respond(
+            reqwest::Method::GET,
+            "playlists",
+            200,
+            r#"{"items": [{
+                "kind": "youtube#playlist",
+                "id": "PLOU2XLYxmsIIuiBfYad6rFYQU_jL2ryal",
+                "snippet": {
+                    "publishedAt": "2013-05-14T16:10:54Z",
+                    "channelId": "UC_x5XG1OV2P6uZZ5FSM9Ttw",
+                    "title": "Google I/O 2013",
+                    "description": ""
+                },
+                "status": { "privacyStatus": "public" },
+                "contentDetails": { "itemCount": 118 }
+            }]}"#,
+        ));
+        let youtube = get_youtube_with_transport(transport.clone());
+        let playlist = youtube
+            .playlists()
+            .get("PLOU2XLYxmsIIuiBfYad6rFYQU_jL2ryal")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(playlist.id, "PLOU2XLYxmsIIuiBfYad6rFYQU_jL2ryal");
+        let snippet = playlist.snippet.unwrap();
+        assert_eq!(snippet.title, "Google I/O 2013");
+        assert_eq!(snippet.channel_id, "UC_x5XG1OV2P6uZZ5FSM9Ttw");
+        assert_eq!(
+            playlist.status.unwrap().privacy_status,
+            Some(PrivacyStatus::Public)
+        );
+        assert_eq!(playlist.content_details.unwrap().item_count, 118);
+        let requests = transport.requests();
+        let query = requests[0].1.query().unwrap();
+        assert!(query.contains("part=snippet%2Cstatus%2CcontentDetails"));
+        assert!(query.contains("id=PLOU2XLYxmsIIuiBfYad6rFYQU_jL2ryal"));
+
+        let transport = MockTransport::new().respond(
+            reqwest::Method::GET,
+            "playlists",
+            200,
+            r#"{"items": []}"#,
+        );
+        let youtube = get_youtube_with_transport(transport);
+        assert!(youtube.playlists().get("deleted").await.unwrap().is_none());
+    }
+
+    #[test]
+    fn test_params() {
+        let youtube = get_youtube_without_user_agent();
+        let mut list = youtube.playlists().list(vec![Part::Snippet, Part::Snippet]);
+        list.channel_id("UC_x5XG1OV2P6uZZ5FSM9Ttw").max_results(100);
+        let params = list.params(Some("page")).unwrap();
+        assert_eq!(params.len(), 4);
+        assert_eq!(params["part"], "snippet");
+        assert_eq!(params["channelId"], "UC_x5XG1OV2P6uZZ5FSM9Ttw");
+        assert_eq!(params["maxResults"], "50");
+        assert_eq!(params["pageToken"], "page");
+
+        let err = list.mine(true).params(None).unwrap_err();
+        assert_eq!(
+            "builder error: \"Incompatible parameters specified in the request: channel_id, mine\"",
+            format!("{}", err)
+        );
+    }
+}
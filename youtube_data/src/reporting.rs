@@ -0,0 +1,846 @@
+//! A client for the [YouTube Reporting API](https://developers.google.com/youtube/reporting/v1/reports), which
+//! generates bulk reports for a channel or content owner once a reporting job is scheduled. Enabled with the
+//! `reporting` feature.
+
+use crate::{
+    auth::{Authorizer, OAuthTokenAuth},
+    error::{Error, Result},
+    json, BoxStream, RequestBase, YouTube, YouTubeDataApi,
+};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures_util::TryStreamExt;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// The base path for the YouTube Reporting API.
+const REPORTING_BASE_PATH: &str = "https://youtubereporting.googleapis.com/v1";
+
+#[derive(Clone, Copy)]
+pub struct ReportingService<'a> {
+    youtube: &'a YouTube,
+}
+
+impl<'a> ReportingService<'a> {
+    pub(crate) fn new(youtube: &'a YouTube) -> Self {
+        Self { youtube }
+    }
+
+    pub fn report_types(self) -> ReportTypeList<'a> {
+        ReportTypeList::new(self)
+    }
+
+    pub fn jobs(self) -> JobList<'a> {
+        JobList::new(self)
+    }
+
+    pub fn create_job(
+        self,
+        report_type_id: impl Into<String>,
+        name: impl Into<String>,
+    ) -> JobCreate<'a> {
+        JobCreate::new(self, report_type_id, name)
+    }
+
+    pub fn delete_job(self, job_id: impl Into<String>) -> JobDelete<'a> {
+        JobDelete::new(self, job_id)
+    }
+
+    pub fn reports(self, job_id: impl Into<String>) -> ReportList<'a> {
+        ReportList::new(self, job_id)
+    }
+
+    /// Downloads the content of a report, see [Report::download_url].
+    pub fn download(self, download_url: impl Into<String>) -> ReportDownload<'a> {
+        ReportDownload::new(self, download_url)
+    }
+}
+
+/// Parameters for the `list` method of the `reportTypes` api. details:
+/// [link](https://developers.google.com/youtube/reporting/v1/reference/rest/v1/reportTypes/list)
+pub struct ReportTypeList<'a> {
+    service: ReportingService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // optional parameters
+    include_system_managed: Option<bool>,
+    on_behalf_of_content_owner: Option<String>,
+    page_size: Option<u32>,
+    page_token: Option<String>,
+}
+
+impl RequestBase for ReportTypeList<'_> {
+    fn api_path(&self) -> &str {
+        "reportTypes"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for ReportTypeList<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> ReportTypeList<'a> {
+    pub(crate) fn new(service: ReportingService<'a>) -> Self {
+        Self {
+            service,
+            authorizer: None,
+            include_system_managed: None,
+            on_behalf_of_content_owner: None,
+            page_size: None,
+            page_token: None,
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+
+    /// Whether to include the report types of the reports generated automatically for content owners.
+    pub fn include_system_managed(&mut self, include_system_managed: bool) -> &mut Self {
+        self.include_system_managed = Some(include_system_managed);
+        self
+    }
+
+    pub fn on_behalf_of_content_owner(
+        &mut self,
+        on_behalf_of_content_owner: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+        self
+    }
+
+    pub fn page_size(&mut self, page_size: u32) -> &mut Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn page_token(&mut self, page_token: impl Into<String>) -> &mut Self {
+        self.page_token = Some(page_token.into());
+        self
+    }
+
+    pub async fn request(&self) -> Result<ReportTypeListResponse> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        // optional parameters
+        self.insert_query_parameter(
+            &mut params,
+            "includeSystemManaged",
+            self.include_system_managed,
+        );
+        self.insert_content_owner_parameters(
+            youtube,
+            &mut params,
+            self.on_behalf_of_content_owner.as_deref(),
+            None,
+        )?;
+        self.insert_query_parameter(&mut params, "pageSize", self.page_size);
+        self.insert_query_parameter(&mut params, "pageToken", self.page_token.as_deref());
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .get(self.url(REPORTING_BASE_PATH))
+                .query(&params),
+        )?;
+        let response = self.send(youtube, request).await?;
+        json(response).await
+    }
+}
+
+/// Parameters for the `list` method of the `jobs` api. details:
+/// [link](https://developers.google.com/youtube/reporting/v1/reference/rest/v1/jobs/list)
+pub struct JobList<'a> {
+    service: ReportingService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // optional parameters
+    include_system_managed: Option<bool>,
+    on_behalf_of_content_owner: Option<String>,
+    page_size: Option<u32>,
+    page_token: Option<String>,
+}
+
+impl RequestBase for JobList<'_> {
+    fn api_path(&self) -> &str {
+        "jobs"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for JobList<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> JobList<'a> {
+    pub(crate) fn new(service: ReportingService<'a>) -> Self {
+        Self {
+            service,
+            authorizer: None,
+            include_system_managed: None,
+            on_behalf_of_content_owner: None,
+            page_size: None,
+            page_token: None,
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+
+    /// Whether to include the jobs created automatically for content owners.
+    pub fn include_system_managed(&mut self, include_system_managed: bool) -> &mut Self {
+        self.include_system_managed = Some(include_system_managed);
+        self
+    }
+
+    pub fn on_behalf_of_content_owner(
+        &mut self,
+        on_behalf_of_content_owner: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+        self
+    }
+
+    pub fn page_size(&mut self, page_size: u32) -> &mut Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn page_token(&mut self, page_token: impl Into<String>) -> &mut Self {
+        self.page_token = Some(page_token.into());
+        self
+    }
+
+    pub async fn request(&self) -> Result<JobListResponse> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        // optional parameters
+        self.insert_query_parameter(
+            &mut params,
+            "includeSystemManaged",
+            self.include_system_managed,
+        );
+        self.insert_content_owner_parameters(
+            youtube,
+            &mut params,
+            self.on_behalf_of_content_owner.as_deref(),
+            None,
+        )?;
+        self.insert_query_parameter(&mut params, "pageSize", self.page_size);
+        self.insert_query_parameter(&mut params, "pageToken", self.page_token.as_deref());
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .get(self.url(REPORTING_BASE_PATH))
+                .query(&params),
+        )?;
+        let response = self.send(youtube, request).await?;
+        json(response).await
+    }
+}
+
+/// Parameters for the `create` method of the `jobs` api. details:
+/// [link](https://developers.google.com/youtube/reporting/v1/reference/rest/v1/jobs/create)
+pub struct JobCreate<'a> {
+    service: ReportingService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // optional parameters
+    on_behalf_of_content_owner: Option<String>,
+
+    // request body
+    report_type_id: String,
+    name: String,
+}
+
+impl RequestBase for JobCreate<'_> {
+    fn api_path(&self) -> &str {
+        "jobs"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for JobCreate<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> JobCreate<'a> {
+    pub(crate) fn new(
+        service: ReportingService<'a>,
+        report_type_id: impl Into<String>,
+        name: impl Into<String>,
+    ) -> Self {
+        Self {
+            service,
+            authorizer: None,
+            on_behalf_of_content_owner: None,
+            report_type_id: report_type_id.into(),
+            name: name.into(),
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+
+    pub fn on_behalf_of_content_owner(
+        &mut self,
+        on_behalf_of_content_owner: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+        self
+    }
+
+    /// Schedules a job that generates a report of the given type every day, and returns the created job.
+    pub async fn request(&self) -> Result<Job> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        // optional parameters
+        self.insert_content_owner_parameters(
+            youtube,
+            &mut params,
+            self.on_behalf_of_content_owner.as_deref(),
+            None,
+        )?;
+
+        // request body
+        if self.report_type_id.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required property `report_type_id` is empty",
+            ));
+        }
+        let body = JobBody {
+            report_type_id: &self.report_type_id,
+            name: &self.name,
+        };
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .post(self.url(REPORTING_BASE_PATH))
+                .query(&params)
+                .json(&body),
+        )?;
+        let response = self.send(youtube, request).await?;
+        json(response).await
+    }
+}
+
+/// Parameters for the `delete` method of the `jobs` api. details:
+/// [link](https://developers.google.com/youtube/reporting/v1/reference/rest/v1/jobs/delete)
+pub struct JobDelete<'a> {
+    service: ReportingService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    api_path: String,
+    job_id: String,
+
+    // optional parameters
+    on_behalf_of_content_owner: Option<String>,
+}
+
+impl RequestBase for JobDelete<'_> {
+    fn api_path(&self) -> &str {
+        &self.api_path
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for JobDelete<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> JobDelete<'a> {
+    pub(crate) fn new(service: ReportingService<'a>, job_id: impl Into<String>) -> Self {
+        let job_id: String = job_id.into();
+        Self {
+            service,
+            authorizer: None,
+            api_path: format!("jobs/{}", job_id),
+            job_id,
+            on_behalf_of_content_owner: None,
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+
+    pub fn on_behalf_of_content_owner(
+        &mut self,
+        on_behalf_of_content_owner: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+        self
+    }
+
+    /// Deletes a job. Reports that were already generated stay available until they expire.
+    pub async fn request(&self) -> Result<()> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        // required parameters
+        if self.job_id.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required parameter `job_id` is empty",
+            ));
+        }
+
+        // optional parameters
+        self.insert_content_owner_parameters(
+            youtube,
+            &mut params,
+            self.on_behalf_of_content_owner.as_deref(),
+            None,
+        )?;
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .delete(self.url(REPORTING_BASE_PATH))
+                .query(&params),
+        )?;
+        self.send(youtube, request).await?;
+        Ok(())
+    }
+}
+
+/// Parameters for the `list` method of the `jobs.reports` api. details:
+/// [link](https://developers.google.com/youtube/reporting/v1/reference/rest/v1/jobs.reports/list)
+pub struct ReportList<'a> {
+    service: ReportingService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    api_path: String,
+    job_id: String,
+
+    // optional parameters
+    created_after: Option<DateTime<Utc>>,
+    on_behalf_of_content_owner: Option<String>,
+    page_size: Option<u32>,
+    page_token: Option<String>,
+    start_time_at_or_after: Option<DateTime<Utc>>,
+    start_time_before: Option<DateTime<Utc>>,
+}
+
+impl RequestBase for ReportList<'_> {
+    fn api_path(&self) -> &str {
+        &self.api_path
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for ReportList<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> ReportList<'a> {
+    pub(crate) fn new(service: ReportingService<'a>, job_id: impl Into<String>) -> Self {
+        let job_id: String = job_id.into();
+        Self {
+            service,
+            authorizer: None,
+            api_path: format!("jobs/{}/reports", job_id),
+            job_id,
+            created_after: None,
+            on_behalf_of_content_owner: None,
+            page_size: None,
+            page_token: None,
+            start_time_at_or_after: None,
+            start_time_before: None,
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+
+    /// Only returns the reports created after this time, which is useful to fetch new reports only.
+    pub fn created_after(&mut self, created_after: DateTime<Utc>) -> &mut Self {
+        self.created_after = Some(created_after);
+        self
+    }
+
+    pub fn on_behalf_of_content_owner(
+        &mut self,
+        on_behalf_of_content_owner: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+        self
+    }
+
+    pub fn page_size(&mut self, page_size: u32) -> &mut Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn page_token(&mut self, page_token: impl Into<String>) -> &mut Self {
+        self.page_token = Some(page_token.into());
+        self
+    }
+
+    /// Only returns the reports whose data starts at or after this time.
+    pub fn start_time_at_or_after(&mut self, start_time_at_or_after: DateTime<Utc>) -> &mut Self {
+        self.start_time_at_or_after = Some(start_time_at_or_after);
+        self
+    }
+
+    /// Only returns the reports whose data starts before this time.
+    pub fn start_time_before(&mut self, start_time_before: DateTime<Utc>) -> &mut Self {
+        self.start_time_before = Some(start_time_before);
+        self
+    }
+
+    pub async fn request(&self) -> Result<ReportListResponse> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        // required parameters
+        if self.job_id.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required parameter `job_id` is empty",
+            ));
+        }
+
+        // optional parameters
+        self.insert_date_time_query_parameter(&mut params, "createdAfter", self.created_after);
+        self.insert_content_owner_parameters(
+            youtube,
+            &mut params,
+            self.on_behalf_of_content_owner.as_deref(),
+            None,
+        )?;
+        self.insert_query_parameter(&mut params, "pageSize", self.page_size);
+        self.insert_query_parameter(&mut params, "pageToken", self.page_token.as_deref());
+        self.insert_date_time_query_parameter(
+            &mut params,
+            "startTimeAtOrAfter",
+            self.start_time_at_or_after,
+        );
+        self.insert_date_time_query_parameter(
+            &mut params,
+            "startTimeBefore",
+            self.start_time_before,
+        );
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .get(self.url(REPORTING_BASE_PATH))
+                .query(&params),
+        )?;
+        let response = self.send(youtube, request).await?;
+        json(response).await
+    }
+}
+
+/// Downloads the content of a report with the `media.download` method. details:
+/// [link](https://developers.google.com/youtube/reporting/v1/reference/rest/v1/media/download)
+pub struct ReportDownload<'a> {
+    service: ReportingService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    download_url: String,
+}
+
+impl RequestBase for ReportDownload<'_> {
+    fn api_path(&self) -> &str {
+        "media"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for ReportDownload<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> ReportDownload<'a> {
+    pub(crate) fn new(service: ReportingService<'a>, download_url: impl Into<String>) -> Self {
+        Self {
+            service,
+            authorizer: None,
+            download_url: download_url.into(),
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+
+    /// Returns the CSV content of the report as a stream of chunks, so large reports do not have to be held in
+    /// memory.
+    pub async fn request(&self) -> Result<BoxStream<'static, Result<Vec<u8>>>> {
+        let youtube = &self.service.youtube;
+
+        // required parameters
+        if !self.download_url.starts_with(REPORTING_BASE_PATH) {
+            return Err(Error::invalid_parameter(format!(
+                "The `download_url` must start with `{}`",
+                REPORTING_BASE_PATH
+            )));
+        }
+
+        let request = self.bearer_auth(youtube, youtube.inner().client.get(&self.download_url))?;
+        let response = self.send(youtube, request).await?;
+        Ok(Box::pin(
+            response
+                .bytes_stream()
+                .map_ok(|chunk| chunk.to_vec())
+                .map_err(Error::from),
+        ))
+    }
+}
+
+/// The request body of `jobs.create`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JobBody<'a> {
+    report_type_id: &'a str,
+    name: &'a str,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportTypeListResponse {
+    /// The list of report types.
+    #[serde(default)]
+    pub report_types: Vec<ReportType>,
+
+    /// A token to retrieve the next page of results.
+    pub next_page_token: Option<String>,
+}
+
+/// A type of report that a job can generate.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportType {
+    /// The ID of the report type, used to create a job.
+    #[serde(default)]
+    pub id: String,
+
+    /// The name of the report type.
+    #[serde(default)]
+    pub name: String,
+
+    /// The date and time after which the report type is deprecated.
+    pub deprecate_time: Option<DateTime<Utc>>,
+
+    /// True if this is a report type generated automatically for content owners.
+    pub system_managed: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobListResponse {
+    /// The list of jobs.
+    #[serde(default)]
+    pub jobs: Vec<Job>,
+
+    /// A token to retrieve the next page of results.
+    pub next_page_token: Option<String>,
+}
+
+/// A job creating reports of a specific type.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Job {
+    /// The server-generated ID of the job.
+    #[serde(default)]
+    pub id: String,
+
+    /// The type of reports that the job creates.
+    #[serde(default)]
+    pub report_type_id: String,
+
+    /// The name of the job.
+    pub name: Option<String>,
+
+    /// The creation date and time of the job.
+    pub create_time: Option<DateTime<Utc>>,
+
+    /// The date and time when the job will expire or expired. After a job expired, no new reports are generated.
+    pub expire_time: Option<DateTime<Utc>>,
+
+    /// True if this a system-managed job that cannot be modified by the user.
+    pub system_managed: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportListResponse {
+    /// The list of reports.
+    #[serde(default)]
+    pub reports: Vec<Report>,
+
+    /// A token to retrieve the next page of results.
+    pub next_page_token: Option<String>,
+}
+
+/// A report generated by a job.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Report {
+    /// The server-generated ID of the report.
+    #[serde(default)]
+    pub id: String,
+
+    /// The ID of the job that created this report.
+    #[serde(default)]
+    pub job_id: String,
+
+    /// The start of the time period that the report instance covers.
+    pub start_time: Option<DateTime<Utc>>,
+
+    /// The end of the time period that the report instance covers.
+    pub end_time: Option<DateTime<Utc>>,
+
+    /// The date and time when the report was created.
+    pub create_time: Option<DateTime<Utc>>,
+
+    /// The date and time when the job that created this report expires or expired.
+    pub job_expire_time: Option<DateTime<Utc>>,
+
+    /// The URL from which the report can be downloaded, see [ReportingService::download].
+    #[serde(default)]
+    pub download_url: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_youtube_without_user_agent;
+
+    #[tokio::test]
+    async fn test_report_types_without_auth() {
+        let without_auth = get_youtube_without_user_agent()
+            .reporting()
+            .report_types()
+            .request()
+            .await;
+        assert!(without_auth.is_err());
+        let err = without_auth.unwrap_err();
+        assert_eq!(
+            "builder error: \"The request to `reportTypes` requires OAuth 2.0 authorization\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reports_without_auth() {
+        let without_auth = get_youtube_without_user_agent()
+            .reporting()
+            .reports("job-id")
+            .request()
+            .await;
+        assert!(without_auth.is_err());
+        let err = without_auth.unwrap_err();
+        assert_eq!(
+            "builder error: \"The request to `jobs/job-id/reports` requires OAuth 2.0 authorization\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_download_from_other_host() {
+        let other_host = get_youtube_without_user_agent()
+            .reporting()
+            .download("https://example.com/report.csv")
+            .request()
+            .await;
+        assert!(other_host.is_err());
+        let err = other_host.err().unwrap();
+        assert_eq!(
+            "builder error: \"The `download_url` must start with `https://youtubereporting.googleapis.com/v1`\"",
+            format!("{}", err)
+        );
+    }
+
+    #[test]
+    fn test_deserialize_report_list() {
+        let response: ReportListResponse = serde_json::from_str(
+            r#"{
+                "reports": [
+                    {
+                        "id": "report-id",
+                        "jobId": "job-id",
+                        "startTime": "2023-01-01T08:00:00Z",
+                        "endTime": "2023-01-02T08:00:00Z",
+                        "createTime": "2023-01-03T10:00:00.123Z",
+                        "downloadUrl": "https://youtubereporting.googleapis.com/v1/media/CHANNEL/report-id?alt=media"
+                    }
+                ],
+                "nextPageToken": "next"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(response.reports[0].job_id, "job-id");
+        assert_eq!(response.next_page_token.as_deref(), Some("next"));
+    }
+}
@@ -0,0 +1,193 @@
+//! The HTTP metadata of a response next to its parsed body, for the callers that cache the responses or monitor the
+//! requests without sending them with a second client.
+
+use crate::{error::Result, timer::Instant};
+
+use reqwest::header::{HeaderMap, HeaderName};
+use reqwest::StatusCode;
+use std::future::Future;
+use std::ops::Deref;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// The headers of a response kept in [Response::headers]: the ones that tell how long a response may be cached.
+const KEPT_HEADERS: [HeaderName; 6] = [
+    reqwest::header::AGE,
+    reqwest::header::CACHE_CONTROL,
+    reqwest::header::DATE,
+    reqwest::header::EXPIRES,
+    reqwest::header::LAST_MODIFIED,
+    reqwest::header::VARY,
+];
+
+tokio::task_local! {
+    /// Receives the metadata of the responses of the requests sent within [Response::capture].
+    static CAPTURED: Arc<Mutex<Option<Captured>>>;
+}
+
+/// The metadata of the successful responses sent within [Response::capture]: the last response, and the quota of
+/// all of them.
+struct Captured {
+    status: StatusCode,
+    headers: HeaderMap,
+    quota_cost: u32,
+}
+
+/// A parsed response body with the metadata of the HTTP response. It dereferences to the body.
+#[derive(Debug, Clone)]
+pub struct Response<T> {
+    /// The parsed body.
+    pub body: T,
+
+    /// The status code of the response.
+    pub status: StatusCode,
+
+    /// The `ETag` header of the response, to send as `If-None-Match` by the next request.
+    pub etag: Option<String>,
+
+    /// The caching headers of the response: `Age`, `Cache-Control`, `Date`, `Expires`, `Last-Modified` and `Vary`.
+    pub headers: HeaderMap,
+
+    /// The time until the body was parsed, including the retries.
+    pub latency: Duration,
+
+    /// The estimated quota units charged for the request, see
+    /// [RequestMetrics::quota_cost](crate::metrics::RequestMetrics::quota_cost). A call that sends more than one
+    /// request, e.g. a `videos.list` of more than 50 ids, is charged for all of them.
+    pub quota_cost: u32,
+}
+
+impl<T> Response<T> {
+    /// Runs `call`, e.g. `list.request()` or `videos().get(id)`, and returns its result with the metadata of the
+    /// responses that it received. A call that sends more than one request reports the status and the headers of
+    /// the last one. Fails with the error of `call`, or when `call` sent no request.
+    pub async fn capture(call: impl Future<Output = Result<T>>) -> Result<Response<T>> {
+        let captured = Arc::new(Mutex::new(None));
+        let started = Instant::now();
+        let body = CAPTURED.scope(captured.clone(), call).await?;
+        let latency = started.elapsed();
+        let captured = captured.lock().unwrap().take().ok_or_else(|| {
+            crate::error::Error::invalid_parameter("The call did not send any request")
+        })?;
+        let etag = captured
+            .headers
+            .get(reqwest::header::ETAG)
+            .and_then(|etag| etag.to_str().ok())
+            .map(str::to_string);
+        let headers = captured
+            .headers
+            .iter()
+            .filter(|(name, _)| KEPT_HEADERS.contains(name))
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect();
+        Ok(Response {
+            body,
+            status: captured.status,
+            etag,
+            headers,
+            latency,
+            quota_cost: captured.quota_cost,
+        })
+    }
+
+    pub fn into_body(self) -> T {
+        self.body
+    }
+}
+
+impl<T> Deref for Response<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.body
+    }
+}
+
+/// Records a successful response for the enclosing [Response::capture], if any.
+pub(crate) fn record(response: &reqwest::Response, quota_cost: u32) {
+    let _ = CAPTURED.try_with(|captured| {
+        let mut captured = captured.lock().unwrap();
+        let quota_cost = quota_cost + captured.as_ref().map_or(0, |last| last.quota_cost);
+        *captured = Some(Captured {
+            status: response.status(),
+            headers: response.headers().clone(),
+            quota_cost,
+        });
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::{json_response, HttpTransport};
+    use crate::videos::{Part, VideoFilter};
+    use crate::{get_youtube_with_transport, ListApi};
+    use async_trait::async_trait;
+
+    /// Answers with the requested ids as videos, with an ETag and caching headers.
+    struct CachedVideos;
+
+    #[async_trait]
+    impl HttpTransport for CachedVideos {
+        async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+            let (_, id) = request
+                .url()
+                .query_pairs()
+                .find(|(key, _)| key == "id")
+                .unwrap();
+            let items = id
+                .split(',')
+                .map(|id| format!(r#"{{"id":"{}"}}"#, id))
+                .collect::<Vec<String>>();
+            let mut response = json_response(200, format!(r#"{{"items":[{}]}}"#, items.join(",")))?;
+            let headers = response.headers_mut();
+            headers.insert(reqwest::header::ETAG, "\"etag-1\"".parse().unwrap());
+            headers.insert(
+                reqwest::header::CACHE_CONTROL,
+                "private, max-age=0".parse().unwrap(),
+            );
+            Ok(response)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_request_with_response() {
+        let youtube = get_youtube_with_transport(CachedVideos);
+        let response = youtube
+            .videos()
+            .list(vec![Part::Id], VideoFilter::id(["Ks-_Mh1QhMc"]))
+            .request_with_response()
+            .await
+            .unwrap();
+        assert_eq!(response.items.len(), 1);
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(response.etag.as_deref(), Some("\"etag-1\""));
+        assert_eq!(response.headers.len(), 1);
+        assert_eq!(
+            response.headers[reqwest::header::CACHE_CONTROL],
+            "private, max-age=0"
+        );
+        assert_eq!(response.quota_cost, 1);
+
+        // the chunks of more than 50 ids are three requests
+        let ids = (0..120)
+            .map(|i| format!("video-{}", i))
+            .collect::<Vec<String>>();
+        let response = Response::capture(
+            youtube
+                .videos()
+                .list(vec![Part::Id], VideoFilter::id(&ids))
+                .request(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.quota_cost, 3);
+        assert_eq!(response.into_body().items.len(), 120);
+
+        let err = Response::capture(async { Ok(()) }).await.unwrap_err();
+        assert_eq!(
+            "builder error: \"The call did not send any request\"",
+            format!("{}", err)
+        );
+    }
+}
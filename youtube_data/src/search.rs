@@ -1,97 +1,248 @@
 use crate::{
+    auth::{Authorizer, OAuthTokenAuth},
+    cursor::PageCursor,
     error::{Error, Result},
-    ListApi, ListResponse, RequestBase, Thumbnail, ThumbnailKind, YouTube, YouTubeDataApi,
+    follow_pages, list_json, BoxStream, ListApi, ListResponse, LiveBroadcastContent, PagedListApi,
+    Paginated, RequestBase, Thumbnails, YouTube, YouTubeDataApi,
 };
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use futures_util::stream::TryStreamExt;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::time::Duration;
+use youtube_data_derive::QueryBuilder;
 
 pub type SearchListResponse = ListResponse<SearchListResource>;
 
-#[derive(Clone)]
-pub(crate) struct SearchService {
-    youtube: Box<YouTube>,
+#[derive(Clone, Copy)]
+pub struct SearchService<'a> {
+    youtube: &'a YouTube,
 }
 
-impl SearchService {
-    pub fn new(youtube: Box<YouTube>) -> Self {
+impl<'a> SearchService<'a> {
+    pub(crate) fn new(youtube: &'a YouTube) -> Self {
         Self { youtube }
     }
 
-    pub fn list(&self, part: Vec<Part>) -> SearchList {
+    pub fn list(self, part: impl IntoIterator<Item = Part>) -> SearchList<'a> {
         SearchList::new(self, part)
     }
+
+    /// A search of the videos that match `q`, with the `id` and `snippet` of every result.
+    pub fn videos(self, q: impl Into<String>) -> SearchList<'a, VideoSearchResult> {
+        self.of_type(q, ResourceType::Video)
+    }
+
+    /// A search of the channels that match `q`, with the `id` and `snippet` of every result.
+    pub fn channels(self, q: impl Into<String>) -> SearchList<'a, ChannelSearchResult> {
+        self.of_type(q, ResourceType::Channel)
+    }
+
+    /// A search of the playlists that match `q`, with the `id` and `snippet` of every result.
+    pub fn playlists(self, q: impl Into<String>) -> SearchList<'a, PlaylistSearchResult> {
+        self.of_type(q, ResourceType::Playlist)
+    }
+
+    fn of_type<T>(self, q: impl Into<String>, resource_type: ResourceType) -> SearchList<'a, T> {
+        let mut list = SearchList::new(self, [Part::Id, Part::Snippet]);
+        list.q(q);
+        list.resource_type = vec![resource_type];
+        list
+    }
 }
 
 /// Parameters for the `list` method of the `search` api. details:
 /// [link](https://developers.google.com/youtube/v3/docs/search/list)
-struct SearchList<'a> {
-    service: &'a SearchService,
+///
+/// The results are [SearchListResource]s, or the results of one type with [SearchService::videos],
+/// [SearchService::channels] and [SearchService::playlists].
+#[derive(QueryBuilder)]
+pub struct SearchList<'a, T = SearchListResource> {
+    service: SearchService<'a>,
+    results: PhantomData<fn() -> T>,
+    authorizer: Option<Arc<dyn Authorizer>>,
 
     // required parameters
+    #[query(unique)]
     part: Vec<Part>,
 
     // filters specify 0 or 1 of the following parameters
+    #[setter]
     for_content_owner: Option<bool>,
+    #[setter]
     for_developer: Option<bool>,
+    #[setter]
     for_mine: Option<bool>,
 
     // optional parameters
-    channel_id: Option<&'a str>,
+    /// Selects the fields of the response, e.g. `items(id,snippet/title)`, so only the needed part of every
+    /// resource is returned. details: [link](https://developers.google.com/youtube/v3/getting-started#fields)
+    #[query]
+    fields: Option<String>,
+    /// Fails the request with a timeout error when it does not complete in time, instead of the timeout of the
+    /// `YouTube` handle.
+    #[setter]
+    timeout: Option<Duration>,
+    #[query]
+    channel_id: Option<String>,
+    #[query]
     channel_type: Option<ChannelType>,
+    #[setter]
     event_type: Option<EventType>,
-    location: Option<&'a str>,
-    location_radius: Option<&'a str>,
+    /// The center of the circular area of a location search, together with `location_radius`.
+    #[setter]
+    location: Option<GeoPoint>,
+    /// The radius of the circular area around `location`, at most 1000 kilometers.
+    #[setter]
+    location_radius: Option<Radius>,
+    #[query(max = 50)]
     max_results: Option<u32>,
-    on_behalf_of_content_owner: Option<&'a str>,
+    #[setter]
+    on_behalf_of_content_owner: Option<String>,
+    #[query]
     order: Option<Order>,
-    page_token: Option<&'a str>,
+    #[setter]
+    page_token: Option<String>,
+    #[setter]
     published_after: Option<DateTime<Utc>>,
+    #[setter]
     published_before: Option<DateTime<Utc>>,
-    q: Option<&'a str>,
-    region_code: Option<&'a str>,
-    relevance_language: Option<&'a str>,
+    #[query]
+    q: Option<String>,
+    #[query(default)]
+    region_code: Option<String>,
+    #[query]
+    relevance_language: Option<String>,
+    #[query(default)]
     safe_search: Option<SafeSearch>,
-    topic_id: Option<&'a str>,
+    #[query]
+    topic_id: Option<String>,
     /// The actual parameter name is `type`, but `type` is a keyword in Rust. and this parameter is optional but
-    /// provides a default value as it is used as a prerequisite for may other parameters.
+    /// provides a default value as it is used as a prerequisite for may other parameters. Only the untyped search
+    /// has a setter, the typed searches are bound to the type of their results.
     resource_type: Vec<ResourceType>,
+    #[setter]
     video_caption: Option<VideoCaption>,
-    video_category_id: Option<&'a str>,
+    #[setter]
+    video_category_id: Option<String>,
+    #[setter]
     video_definition: Option<VideoDefinition>,
+    #[setter]
     video_dimension: Option<VideoDimension>,
+    #[setter]
     video_duration: Option<VideoDuration>,
+    #[setter]
     video_embeddable: Option<VideoEmbeddable>,
+    #[setter]
     video_license: Option<VideoLicense>,
+    #[setter]
     video_paid_product_placement: Option<VideoPaidProductPlacement>,
+    #[setter]
     video_syndicated: Option<VideoSyndicated>,
+    #[setter]
     video_type: Option<VideoType>,
+
+    /// Drops the results already returned by a previous page from the pages of
+    /// [pages](PagedListApi::pages), and so from `items` and `collect_all`: the api often repeats results across
+    /// pages. The `page_info` of a page still holds the counts reported by the api.
+    #[setter]
+    dedupe: bool,
 }
 
-impl RequestBase for SearchList<'_> {
+impl<T> RequestBase for SearchList<'_, T> {
     fn api_path(&self) -> &str {
         "search"
     }
 }
 
-#[async_trait]
-impl YouTubeDataApi for SearchList<'_> {}
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl<T> YouTubeDataApi for SearchList<'_, T> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+
+    fn request_timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl<T: SearchItem> ListApi<ListResponse<T>> for SearchList<'_, T> {
+    async fn request(&self) -> Result<ListResponse<T>> {
+        self.request_page(None).await
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl<T: SearchItem> PagedListApi<T> for SearchList<'_, T> {
+    async fn request_page(&self, page_token: Option<&str>) -> Result<ListResponse<T>> {
+        let youtube = &self.service.youtube;
+        let params = self.params(page_token)?;
+
+        let request = self.optional_bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .get(self.url(&youtube.inner().base_path))
+                .query(&params),
+        )?;
+        let response = self.send(youtube, request).await?;
+        let page: SearchListResponse = list_json(response).await?;
+        Ok(ListResponse {
+            kind: page.kind,
+            etag: page.etag,
+            next_page_token: page.next_page_token,
+            prev_page_token: page.prev_page_token,
+            region_code: page.region_code,
+            page_info: page.page_info,
+            items: page
+                .items
+                .into_iter()
+                .filter_map(T::from_resource)
+                .collect(),
+        })
+    }
+
+    fn cursor(&self, page_token: Option<&str>) -> Result<PageCursor> {
+        Ok(PageCursor::new(self.api_path(), self.params(page_token)?))
+    }
+
+    fn pages(&self) -> BoxStream<'_, Result<ListResponse<T>>>
+    where
+        Self: Sync,
+    {
+        let pages = follow_pages(self, Paginated::next_token);
+        if !self.dedupe {
+            return pages;
+        }
+        let mut seen = HashSet::new();
+        Box::pin(pages.map_ok(move |mut page| {
+            page.items.retain(|item| seen.insert(item.key()));
+            page
+        }))
+    }
+}
 
-#[async_trait]
-impl ListApi<SearchListResponse> for SearchList<'_> {
-    async fn request(&self) -> Result<SearchListResponse> {
+impl<T> SearchList<'_, T> {
+    /// The query parameters of the page of `page_token`, or of the `page_token` of the builder for `None`.
+    fn params(&self, page_token: Option<&str>) -> Result<HashMap<String, String>> {
         let youtube = &self.service.youtube;
+        let page_token = page_token.or(self.page_token.as_deref());
 
         // createquery parameter map
         let mut params = HashMap::<String, String>::new();
 
-        // key
-        self.insert_query_parameter(&mut params, "key", Some(&youtube.api_key));
-
-        // required parameters
-        self.insert_query_parameters(&mut params, "part", Some(&self.part));
+        self.insert_query(youtube, &mut params)?;
+        self.insert_query_parameters(&mut params, "type", Some(&self.resource_type));
         let has_video_type_only =
             self.resource_type.len() == 1 && self.resource_type[0] == ResourceType::Video;
 
@@ -125,62 +276,40 @@ impl ListApi<SearchListResponse> for SearchList<'_> {
             )));
         }
         if count == 1 {
-            // TODO: check if the user is authenticated
-            if let Some(_for_content_owner) = self.for_content_owner {
-                return Err(Error::authorization_required(
-                    "The request uses the `for_content_owner` parameter but is not properly authorized",
-                ));
-                // // required parameter.
-                // if self.on_behalf_of_content_owner.is_none() {
-                //     let additional_message =
-                //         "parameter `on_behalf_of_content_owner` is required when using filter `for_content_owner`";
-                //     return Err(Error::invalid_parameter(format!(
-                //         "Request contains an invalid argument: {}",
-                //         additional_message
-                //     )));
-                // }
-
-                // // must be set to video.
-                // if !has_video_type_only {
-                //     return Err(type_must_set_be_video("video_duration"));
-                // }
-
-                // // must be using an account linked to the specified content owner.
-
-                // // the following parameters are not available: video_definition, video_dimension, video_duration,
-                // // video_embeddable, video_license, video_syndicated, video_type.
-
-                // self.insert_query_parameter(
-                //     &mut params,
-                //     "forContentOwner",
-                //     Some(for_content_owner),
-                // );
+            if let Some(for_content_owner) = self.for_content_owner {
+                self.require_authorization(youtube, "for_content_owner")?;
+                if self.on_behalf_of_content_owner.is_none() {
+                    return Err(Error::missing_required_parameter(
+                        "The `for_content_owner` parameter requires `on_behalf_of_content_owner`",
+                    ));
+                }
+                // must be set to video.
+                if !has_video_type_only {
+                    return Err(type_must_set_be_video("for_content_owner"));
+                }
+                self.restricted_video_parameters("for_content_owner")?;
+                self.insert_query_parameter(
+                    &mut params,
+                    "forContentOwner",
+                    Some(for_content_owner),
+                );
             }
-            if let Some(_for_developer) = self.for_developer {
-                return Err(Error::authorization_required(
-                    "The request uses the `for_developer` parameter but is not properly authorized",
-                ));
-                // self.insert_query_parameter(&mut params, "forDeveloper", Some(for_developer));
+            if let Some(for_developer) = self.for_developer {
+                self.require_authorization(youtube, "for_developer")?;
+                self.insert_query_parameter(&mut params, "forDeveloper", Some(for_developer));
             }
-            if let Some(_for_mine) = self.for_mine {
-                return Err(Error::authorization_required(
-                    "The request uses the `for_mine` parameter but is not properly authorized",
-                ));
-                // // must be set to video.
-                // if !has_video_type_only {
-                //     return Err(type_must_set_be_video("for_mine"));
-                // }
-                //
-                // // the following parameters are not available: video_definition, video_dimension, video_duration,
-                // // video_embeddable, video_license, video_syndicated, video_type.
-                //
-                // self.insert_query_parameter(&mut params, "forMine", Some(for_mine));
+            if let Some(for_mine) = self.for_mine {
+                self.require_authorization(youtube, "for_mine")?;
+                // must be set to video.
+                if !has_video_type_only {
+                    return Err(type_must_set_be_video("for_mine"));
+                }
+                self.restricted_video_parameters("for_mine")?;
+                self.insert_query_parameter(&mut params, "forMine", Some(for_mine));
             }
         }
 
         // optional parameters
-        self.insert_query_parameter(&mut params, "channelId", self.channel_id);
-        self.insert_query_parameter(&mut params, "channelType", self.channel_type.as_ref());
         if self.event_type.is_some() {
             // must be set to video.
             if !has_video_type_only {
@@ -194,7 +323,7 @@ impl ListApi<SearchListResponse> for SearchList<'_> {
                 return Err(type_must_set_be_video("location"));
             }
             // must also set the locationRadius parameter's value.
-            if !self.location_radius.is_none() {
+            if self.location_radius.is_none() {
                 let additional_message =
                     "parameter `location_radius` must be specified when using `location`";
                 return Err(Error::invalid_parameter(format!(
@@ -202,23 +331,36 @@ impl ListApi<SearchListResponse> for SearchList<'_> {
                     additional_message
                 )));
             }
-            self.insert_query_parameter(&mut params, "location", self.location);
         }
-        self.insert_query_parameter(&mut params, "maxResults", self.max_results);
-        self.insert_query_parameter(&mut params, "order", self.order.as_ref());
-        self.insert_query_parameter(&mut params, "pageToken", self.page_token);
+        if self.location_radius.is_some() && self.location.is_none() {
+            let additional_message =
+                "parameter `location` must be specified when using `location_radius`";
+            return Err(Error::invalid_parameter(format!(
+                "Request contains an invalid argument: {}",
+                additional_message
+            )));
+        }
+        if let Some(location) = &self.location {
+            location.validate()?;
+            self.insert_query_parameter(&mut params, "location", Some(location));
+        }
+        if let Some(location_radius) = &self.location_radius {
+            location_radius.validate()?;
+            self.insert_query_parameter(&mut params, "locationRadius", Some(location_radius));
+        }
+        self.insert_content_owner_parameters(
+            youtube,
+            &mut params,
+            self.on_behalf_of_content_owner.as_deref(),
+            None,
+        )?;
+        self.insert_query_parameter(&mut params, "pageToken", page_token);
         self.insert_date_time_query_parameter(&mut params, "publishedAfter", self.published_after);
         self.insert_date_time_query_parameter(
             &mut params,
             "publishedBefore",
             self.published_before,
         );
-        self.insert_query_parameter(&mut params, "q", self.q);
-        self.insert_query_parameter(&mut params, "regionCode", self.region_code);
-        self.insert_query_parameter(&mut params, "relevanceLanguage", self.relevance_language);
-        self.insert_query_parameter(&mut params, "safeSearch", self.safe_search.as_ref());
-        self.insert_query_parameter(&mut params, "topicId", self.topic_id);
-        self.insert_query_parameters(&mut params, "type", Some(&self.resource_type));
         if self.video_caption.is_some() {
             // must be set to video.
             if !has_video_type_only {
@@ -232,7 +374,11 @@ impl ListApi<SearchListResponse> for SearchList<'_> {
             if !has_video_type_only {
                 return Err(type_must_set_be_video("video_category_id"));
             }
-            self.insert_query_parameter(&mut params, "videoCategoryId", self.video_category_id);
+            self.insert_query_parameter(
+                &mut params,
+                "videoCategoryId",
+                self.video_category_id.as_deref(),
+            );
         }
         if self.video_definition.is_some() {
             // must be set to video.
@@ -311,20 +457,50 @@ impl ListApi<SearchListResponse> for SearchList<'_> {
             self.insert_query_parameter(&mut params, "videoType", self.video_type.as_ref());
         }
 
-        let response = self
-            .send(
-                youtube
-                    .client
-                    .get(self.url(&youtube.base_path))
-                    .query(&params),
-            )
-            .await?;
-        Ok(response.json().await?)
+        Ok(params)
     }
 }
 
-impl<'a> SearchList<'a> {
-    pub fn new(service: &'a SearchService, part: Vec<Part>) -> Self {
+impl SearchList<'_> {
+    /// The types of the resources to search for. Defaults to channels, playlists and videos.
+    pub fn resource_type(&mut self, resource_type: Vec<ResourceType>) -> &mut Self {
+        self.resource_type = resource_type;
+        self
+    }
+}
+
+impl<T> SearchList<'_, T> {
+    /// The `for_content_owner` and `for_mine` filters can not be combined with the video parameters below.
+    fn restricted_video_parameters(&self, filter: &str) -> Result<()> {
+        let restricted = vec![
+            ("video_definition", self.video_definition.is_some()),
+            ("video_dimension", self.video_dimension.is_some()),
+            ("video_duration", self.video_duration.is_some()),
+            ("video_embeddable", self.video_embeddable.is_some()),
+            ("video_license", self.video_license.is_some()),
+            ("video_syndicated", self.video_syndicated.is_some()),
+            ("video_type", self.video_type.is_some()),
+        ]
+        .into_iter()
+        .filter(|(_, v)| *v)
+        .map(|(name, _)| name)
+        .collect::<Vec<&str>>();
+
+        if restricted.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::incompatible_parameters(format!(
+                "Incompatible parameters specified in the request: {}, {}",
+                filter,
+                restricted.join(", "),
+            )))
+        }
+    }
+}
+
+impl<'a, T> SearchList<'a, T> {
+    pub(crate) fn new(service: SearchService<'a>, part: impl IntoIterator<Item = Part>) -> Self {
+        let part = crate::dedupe(part);
         let part = if part.is_empty() {
             vec![Part::Snippet]
         } else {
@@ -332,10 +508,14 @@ impl<'a> SearchList<'a> {
         };
         Self {
             service,
+            results: PhantomData,
+            authorizer: None,
             part,
             for_content_owner: None,
             for_developer: None,
             for_mine: None,
+            fields: None,
+            timeout: None,
             channel_id: None,
             channel_type: None,
             event_type: None,
@@ -367,203 +547,180 @@ impl<'a> SearchList<'a> {
             video_paid_product_placement: None,
             video_syndicated: None,
             video_type: None,
+            dedupe: false,
         }
     }
 
-    pub fn part(&mut self, part: Vec<Part>) -> &mut Self {
-        self.part = part;
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
         self
     }
 
-    pub fn for_content_owner(&mut self, for_content_owner: bool) -> &mut Self {
-        self.for_content_owner = Some(for_content_owner);
+    /// Only returns the resources created within `duration` before now, e.g. the last 6 hours: sets
+    /// `published_after` to now minus `duration`, computed when this setter is called and at the earliest 1970,
+    /// and clears `published_before`.
+    pub fn published_within(&mut self, duration: Duration) -> &mut Self {
+        let now = Utc::now();
+        let after = chrono::Duration::from_std(duration)
+            .ok()
+            .and_then(|duration| now.checked_sub_signed(duration))
+            .unwrap_or(DateTime::UNIX_EPOCH);
+        self.published_after = Some(after);
+        self.published_before = None;
         self
     }
 
-    pub fn for_developer(&mut self, for_developer: bool) -> &mut Self {
-        self.for_developer = Some(for_developer);
-        self
-    }
-
-    pub fn for_mine(&mut self, for_mine: bool) -> &mut Self {
-        self.for_mine = Some(for_mine);
-        self
-    }
-
-    pub fn channel_id(&mut self, channel_id: &'a str) -> &mut Self {
-        self.channel_id = Some(channel_id);
-        self
-    }
-
-    pub fn channel_type(&mut self, channel_type: ChannelType) -> &mut Self {
-        self.channel_type = Some(channel_type);
-        self
-    }
-
-    pub fn event_type(&mut self, event_type: EventType) -> &mut Self {
-        self.event_type = Some(event_type);
-        self
-    }
-
-    pub fn location(&mut self, location: &'a str) -> &mut Self {
-        self.location = Some(location);
-        self
-    }
-
-    pub fn location_radius(&mut self, location_radius: &'a str) -> &mut Self {
-        self.location_radius = Some(location_radius);
-        self
-    }
-
-    pub fn max_results(&mut self, max_results: u32) -> &mut Self {
-        let max_results = if max_results > 50 { 50 } else { max_results };
-        self.max_results = Some(max_results);
-        self
+    /// Only returns the resources created within the last `days` days, see
+    /// [published_within](Self::published_within).
+    pub fn published_last_days(&mut self, days: u32) -> &mut Self {
+        self.published_within(Duration::from_secs(u64::from(days) * 24 * 60 * 60))
     }
+}
 
-    pub fn on_behalf_of_content_owner(&mut self, on_behalf_of_content_owner: &'a str) -> &mut Self {
-        self.on_behalf_of_content_owner = Some(on_behalf_of_content_owner);
-        self
-    }
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    Id,
+    Snippet,
+}
 
-    pub fn order(&mut self, order: Order) -> &mut Self {
-        self.order = Some(order);
-        self
+impl Part {
+    /// Every part of the resource, to request all of its properties.
+    pub fn all_public() -> Vec<Part> {
+        vec![Part::Id, Part::Snippet]
     }
+}
 
-    pub fn page_token(&mut self, page_token: &'a str) -> &mut Self {
-        self.page_token = Some(page_token);
-        self
+impl std::fmt::Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            Part::Id => "id",
+            Part::Snippet => "snippet",
+        }
+        .to_string();
+        write!(f, "{}", str)
     }
+}
 
-    pub fn published_after(&mut self, published_after: DateTime<Utc>) -> &mut Self {
-        self.published_after = Some(published_after);
-        self
-    }
+/// The channelType parameter lets you restrict a search to a particular type of channel.
+#[derive(Clone)]
+pub enum ChannelType {
+    /// Return all channels.
+    Any,
 
-    pub fn published_before(&mut self, published_before: DateTime<Utc>) -> &mut Self {
-        self.published_before = Some(published_before);
-        self
-    }
+    /// Only retrieve shows.
+    Show,
+}
 
-    pub fn q(&mut self, q: &'a str) -> &mut Self {
-        self.q = Some(q);
-        self
+impl std::fmt::Display for ChannelType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            ChannelType::Any => "any",
+            ChannelType::Show => "show",
+        }
+        .to_string();
+        write!(f, "{}", str)
     }
+}
 
-    pub fn region_code(&mut self, region_code: &'a str) -> &mut Self {
-        self.region_code = Some(region_code);
-        self
-    }
+/// A point of the earth in degrees, the center of the area of a location search.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeoPoint {
+    /// The latitude, from -90 to 90.
+    pub lat: f64,
 
-    pub fn relevance_language(&mut self, relevance_language: &'a str) -> &mut Self {
-        self.relevance_language = Some(relevance_language);
-        self
-    }
+    /// The longitude, from -180 to 180.
+    pub lon: f64,
+}
 
-    pub fn safe_search(&mut self, safe_search: SafeSearch) -> &mut Self {
-        self.safe_search = Some(safe_search);
-        self
+impl GeoPoint {
+    pub fn new(lat: f64, lon: f64) -> GeoPoint {
+        GeoPoint { lat, lon }
     }
 
-    pub fn topic_id(&mut self, topic_id: &'a str) -> &mut Self {
-        self.topic_id = Some(topic_id);
-        self
+    fn validate(&self) -> Result<()> {
+        if (-90.0..=90.0).contains(&self.lat) && (-180.0..=180.0).contains(&self.lon) {
+            Ok(())
+        } else {
+            Err(Error::invalid_parameter(format!(
+                "Request contains an invalid argument: `{}` is not a valid location",
+                self
+            )))
+        }
     }
+}
 
-    pub fn resource_type(&mut self, resource_type: Vec<ResourceType>) -> &mut Self {
-        self.resource_type = resource_type;
-        self
+impl std::fmt::Display for GeoPoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.lat, self.lon)
     }
+}
 
-    pub fn video_caption(&mut self, video_caption: VideoCaption) -> &mut Self {
-        self.video_caption = Some(video_caption);
-        self
-    }
+/// The radius of the area of a location search, in one of the units accepted by the api.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Radius {
+    Meters(f64),
 
-    pub fn video_category_id(&mut self, video_category_id: &'a str) -> &mut Self {
-        self.video_category_id = Some(video_category_id);
-        self
-    }
+    Kilometers(f64),
 
-    pub fn video_definition(&mut self, video_definition: VideoDefinition) -> &mut Self {
-        self.video_definition = Some(video_definition);
-        self
-    }
+    Feet(f64),
 
-    pub fn video_dimension(&mut self, video_dimension: VideoDimension) -> &mut Self {
-        self.video_dimension = Some(video_dimension);
-        self
-    }
+    Miles(f64),
+}
 
-    pub fn video_duration(&mut self, video_duration: VideoDuration) -> &mut Self {
-        self.video_duration = Some(video_duration);
-        self
-    }
+impl Radius {
+    /// The largest radius accepted by the api, in meters.
+    const MAX_METERS: f64 = 1_000_000.0;
 
-    pub fn video_embeddable(&mut self, video_embeddable: VideoEmbeddable) -> &mut Self {
-        self.video_embeddable = Some(video_embeddable);
-        self
+    pub fn meters(meters: f64) -> Radius {
+        Radius::Meters(meters)
     }
 
-    pub fn video_license(&mut self, video_license: VideoLicense) -> &mut Self {
-        self.video_license = Some(video_license);
-        self
+    pub fn km(kilometers: f64) -> Radius {
+        Radius::Kilometers(kilometers)
     }
 
-    pub fn video_paid_product_placement(
-        &mut self,
-        video_paid_product_placement: VideoPaidProductPlacement,
-    ) -> &mut Self {
-        self.video_paid_product_placement = Some(video_paid_product_placement);
-        self
+    pub fn ft(feet: f64) -> Radius {
+        Radius::Feet(feet)
     }
 
-    pub fn video_syndicated(&mut self, video_syndicated: VideoSyndicated) -> &mut Self {
-        self.video_syndicated = Some(video_syndicated);
-        self
+    pub fn mi(miles: f64) -> Radius {
+        Radius::Miles(miles)
     }
 
-    pub fn video_type(&mut self, video_type: VideoType) -> &mut Self {
-        self.video_type = Some(video_type);
-        self
+    /// The radius in meters.
+    pub fn to_meters(&self) -> f64 {
+        match *self {
+            Radius::Meters(meters) => meters,
+            Radius::Kilometers(kilometers) => kilometers * 1000.0,
+            Radius::Feet(feet) => feet * 0.3048,
+            Radius::Miles(miles) => miles * 1609.344,
+        }
     }
-}
-
-pub enum Part {
-    Id,
-    Snippet,
-}
 
-impl std::fmt::Display for Part {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let str = match self {
-            Part::Id => "id",
-            Part::Snippet => "snippet",
+    fn validate(&self) -> Result<()> {
+        let meters = self.to_meters();
+        if meters > 0.0 && meters <= Radius::MAX_METERS {
+            Ok(())
+        } else {
+            Err(Error::invalid_parameter(format!(
+                "Request contains an invalid argument: the location radius `{}` must be greater than 0 and at most \
+                 1000km",
+                self
+            )))
         }
-        .to_string();
-        write!(f, "{}", str)
     }
 }
 
-/// The channelType parameter lets you restrict a search to a particular type of channel.
-#[derive(Clone)]
-pub enum ChannelType {
-    /// Return all channels.
-    Any,
-
-    /// Only retrieve shows.
-    Show,
-}
-
-impl std::fmt::Display for ChannelType {
+impl std::fmt::Display for Radius {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let str = match self {
-            ChannelType::Any => "any",
-            ChannelType::Show => "show",
+        match self {
+            Radius::Meters(meters) => write!(f, "{}m", meters),
+            Radius::Kilometers(kilometers) => write!(f, "{}km", kilometers),
+            Radius::Feet(feet) => write!(f, "{}ft", feet),
+            Radius::Miles(miles) => write!(f, "{}mi", miles),
         }
-        .to_string();
-        write!(f, "{}", str)
     }
 }
 
@@ -628,6 +785,7 @@ impl std::fmt::Display for Order {
     }
 }
 
+#[derive(Clone)]
 pub enum SafeSearch {
     /// YouTube will filter some content from search results and, at the least, will filter content that is restricted
     /// in your locale. Based on their content, search results could be removed from search results or demoted in
@@ -878,54 +1036,194 @@ impl std::fmt::Display for VideoType {
 }
 
 /// Structure shows the format of a search result.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SearchListResource {
     /// Identifies the API resource's type. The value will be `youtube#searchResult`.
+    #[serde(default)]
     pub kind: String,
 
     /// The ETag of the response.
+    #[serde(default)]
     pub etag: String,
 
+    #[serde(default)]
     pub id: ResourceId,
 
     pub snippet: Option<SearchSnippet>,
+
+    /// The fields of the resource that this version of the crate does not know, e.g. added to the api after its
+    /// release. They are serialized back with the resource.
+    #[serde(flatten, deserialize_with = "crate::serde_helpers::extra_fields")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl SearchListResource {
+    /// The resource that the search result identifies, see [ResourceId::result].
+    pub fn result(&self) -> Option<SearchResult> {
+        self.id.result()
+    }
 }
 
 /// The id object contains information that can be used to uniquely identify the resource that matches the search
 /// request.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ResourceId {
     /// The type of the API resource.
+    #[serde(default)]
     pub kind: String,
 
     /// If the `id.type` property's value is `youtube#video`, then this property will be present and its value will
     /// contain the ID that YouTube uses to uniquely identify a video that matches the search query.
-    #[serde(alias = "videoId")]
     pub video_id: Option<String>,
 
     /// If the id.type property's value is youtube#channel, then this property will be present and its value will
     /// contain the ID that YouTube uses to uniquely identify a channel that matches the search query.
-    #[serde(alias = "channelId")]
     pub channel_id: Option<String>,
 
     /// If the id.type property's value is youtube#playlist, then this property will be present and its value will
     /// contain the ID that YouTube uses to uniquely identify a playlist that matches the search query.
-    #[serde(alias = "playlistId")]
     pub playlist_id: Option<String>,
 }
 
+impl ResourceId {
+    /// The resource that the id identifies, with the id of its type, or `None` when the type of the resource is
+    /// unknown or its id is missing, e.g. when the request `fields` did not include it.
+    pub fn result(&self) -> Option<SearchResult> {
+        match self.kind.as_str() {
+            "youtube#video" => self.video_id.clone().map(SearchResult::Video),
+            "youtube#channel" => self.channel_id.clone().map(SearchResult::Channel),
+            "youtube#playlist" => self.playlist_id.clone().map(SearchResult::Playlist),
+            _ => None,
+        }
+    }
+}
+
+/// The resource that a search result identifies, e.g. to request the details of the videos of a search.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SearchResult {
+    /// The id of a video.
+    Video(String),
+
+    /// The id of a channel.
+    Channel(String),
+
+    /// The id of a playlist.
+    Playlist(String),
+}
+
+impl From<SearchResult> for crate::url::YouTubeLink {
+    fn from(result: SearchResult) -> Self {
+        match result {
+            SearchResult::Video(id) => crate::url::YouTubeLink::Video(id),
+            SearchResult::Channel(id) => crate::url::YouTubeLink::Channel(id),
+            SearchResult::Playlist(id) => crate::url::YouTubeLink::Playlist(id),
+        }
+    }
+}
+
+/// The results of a [SearchList]: a [SearchListResource], or the result of one type of resource.
+pub trait SearchItem: Serialize + Send + Sized + 'static {
+    /// The value by which [SearchList::dedupe] recognizes repeated results.
+    type Key: Hash + Eq + Send;
+
+    /// The result of `resource`, `None` when it is not a result of this type or its id is missing.
+    fn from_resource(resource: SearchListResource) -> Option<Self>;
+
+    fn key(&self) -> Self::Key;
+
+    /// The id of the video of the result, `None` when it is not a video, to fetch it with `videos().hydrate`.
+    fn video_id(&self) -> Option<&str>;
+}
+
+impl SearchItem for SearchListResource {
+    type Key = ResourceId;
+
+    fn from_resource(resource: SearchListResource) -> Option<Self> {
+        Some(resource)
+    }
+
+    fn key(&self) -> Self::Key {
+        self.id.clone()
+    }
+
+    fn video_id(&self) -> Option<&str> {
+        match self.id.kind.as_str() {
+            "youtube#video" => self.id.video_id.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// A video of [SearchService::videos].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VideoSearchResult {
+    /// The ID that YouTube uses to uniquely identify the video.
+    pub id: String,
+
+    pub snippet: Option<SearchSnippet>,
+}
+
+/// A channel of [SearchService::channels].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChannelSearchResult {
+    /// The ID that YouTube uses to uniquely identify the channel.
+    pub id: String,
+
+    pub snippet: Option<SearchSnippet>,
+}
+
+/// A playlist of [SearchService::playlists].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlaylistSearchResult {
+    /// The ID that YouTube uses to uniquely identify the playlist.
+    pub id: String,
+
+    pub snippet: Option<SearchSnippet>,
+}
+
+macro_rules! search_item {
+    ($result:ident, $variant:ident, $video:literal) => {
+        impl SearchItem for $result {
+            type Key = String;
+
+            fn from_resource(resource: SearchListResource) -> Option<Self> {
+                match resource.result()? {
+                    SearchResult::$variant(id) => Some(Self {
+                        id,
+                        snippet: resource.snippet,
+                    }),
+                    _ => None,
+                }
+            }
+
+            fn key(&self) -> Self::Key {
+                self.id.clone()
+            }
+
+            fn video_id(&self) -> Option<&str> {
+                Some(self.id.as_str()).filter(|_| $video)
+            }
+        }
+    };
+}
+
+search_item!(VideoSearchResult, Video, true);
+search_item!(ChannelSearchResult, Channel, false);
+search_item!(PlaylistSearchResult, Playlist, false);
+
 /// The snippet object contains basic details about a search result, such as its title or description.
 /// For example, if the search result is a video, then the title will be the video's title and the description
 /// will be the video's description.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct SearchSnippet {
     /// The creation date and time of the resource that the search result identifies. The value is specified in
     /// [ISO 8601](https://www.w3.org/TR/NOTE-datetime) format.
-    #[serde(alias = "publishedAt")]
-    pub published_at: DateTime<Utc>,
+    pub published_at: Option<DateTime<Utc>>,
 
     /// The value that YouTube uses to uniquely identify the channel that published the resource that the search result identifies.
-    #[serde(alias = "channelId")]
+    #[serde(default)]
     pub channel_id: String,
 
     /// The title of the search result.
@@ -938,10 +1236,11 @@ pub struct SearchSnippet {
 
     /// A map of thumbnail images associated with the search result. For each object in the map, the key is the name of the
     /// thumbnail image, and the value is an object that contains other information about the thumbnail.
-    pub thumbnails: HashMap<ThumbnailKind, Thumbnail>,
+    #[serde(default)]
+    pub thumbnails: Thumbnails,
 
     /// The title of the channel that published the resource that the search result identifies.
-    #[serde(alias = "channelTitle")]
+    #[serde(default)]
     pub channel_title: String,
 
     /// An indication of whether a video or channel resource has live broadcast content. Valid property values are upcoming, live, and none.
@@ -950,8 +1249,7 @@ pub struct SearchSnippet {
     /// while a value of live indicates that the video is an active live broadcast. For a channel resource, a value of upcoming
     /// indicates that the channel has a scheduled broadcast that has not yet started, while a value of live indicates that the channel
     /// has an active live broadcast.
-    #[serde(alias = "liveBroadcastContent")]
-    pub live_broadcast_content: Option<String>,
+    pub live_broadcast_content: Option<LiveBroadcastContent>,
 }
 
 fn type_must_set_be_video(parameter: &str) -> Error {
@@ -968,24 +1266,286 @@ fn type_must_set_be_video(parameter: &str) -> Error {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::get_youtube_without_user_agent;
+    use crate::transport::{json_response, HttpTransport, MockTransport};
+    use crate::{get_youtube_with_transport, get_youtube_without_user_agent};
+
+    /// Serves two pages of search results, the second one repeating a video of the first one.
+    struct RepeatingResults;
+
+    #[async_trait]
+    impl HttpTransport for RepeatingResults {
+        async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+            let second = request.url().query().unwrap().contains("pageToken=page-1");
+            let body = if second {
+                r#"{"items": [
+                    { "id": { "kind": "youtube#video", "videoId": "video-2" } },
+                    { "id": { "kind": "youtube#video", "videoId": "video-3" } }
+                ]}"#
+            } else {
+                r#"{"nextPageToken": "page-1", "items": [
+                    { "id": { "kind": "youtube#video", "videoId": "video-1" } },
+                    { "id": { "kind": "youtube#video", "videoId": "video-2" } }
+                ]}"#
+            };
+            json_response(200, body)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dedupe_pages() {
+        let youtube = get_youtube_with_transport(RepeatingResults);
+        let mut list = youtube.search().list(vec![Part::Snippet]);
+        list.q("rust");
+
+        let video_ids = |items: Vec<SearchListResource>| {
+            items
+                .into_iter()
+                .map(|item| item.id.video_id.unwrap())
+                .collect::<Vec<String>>()
+        };
+        let items = list.items(None).try_collect::<Vec<_>>().await.unwrap();
+        assert_eq!(
+            video_ids(items),
+            vec!["video-1", "video-2", "video-2", "video-3"]
+        );
+
+        list.dedupe(true);
+        let items = list.items(None).try_collect::<Vec<_>>().await.unwrap();
+        assert_eq!(video_ids(items), vec!["video-1", "video-2", "video-3"]);
+    }
+
+    /// Answers every search with a video, a channel and a video without its id, whatever the `type` of the request.
+    struct MixedResults;
+
+    #[async_trait]
+    impl HttpTransport for MixedResults {
+        async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+            let query = request.url().query().unwrap();
+            assert!(query.contains("q=rust"));
+            assert!(query.contains("type=video") || query.contains("type=channel"));
+            json_response(
+                200,
+                r#"{"items": [
+                    { "id": { "kind": "youtube#video", "videoId": "video-1" }, "snippet": { "title": "Rust" } },
+                    { "id": { "kind": "youtube#channel", "channelId": "channel-1" } },
+                    { "id": { "kind": "youtube#video" } }
+                ]}"#,
+            )
+        }
+    }
+
+    #[tokio::test]
+    async fn test_typed_search() {
+        let youtube = get_youtube_with_transport(MixedResults);
+        let videos = youtube.search().videos("rust").request().await.unwrap();
+        assert_eq!(videos.items.len(), 1);
+        assert_eq!(videos.items[0].id, "video-1");
+        assert_eq!(
+            videos.items[0].snippet.as_ref().unwrap().title.as_deref(),
+            Some("Rust")
+        );
+
+        let channels = youtube
+            .search()
+            .channels("rust")
+            .items(None)
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        assert_eq!(
+            channels,
+            vec![ChannelSearchResult {
+                id: "channel-1".to_string(),
+                snippet: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_resource_type() {
+        let youtube = get_youtube_with_transport(MixedResults);
+        let resource_type = |params: HashMap<String, String>| params["type"].clone();
+
+        let videos = youtube.search().videos("rust");
+        assert_eq!(resource_type(videos.params(None).unwrap()), "video");
+        let channels = youtube.search().channels("rust");
+        assert_eq!(resource_type(channels.params(None).unwrap()), "channel");
+        let playlists = youtube.search().playlists("rust");
+        assert_eq!(resource_type(playlists.params(None).unwrap()), "playlist");
+
+        let mut list = youtube.search().list(vec![Part::Snippet]);
+        assert_eq!(
+            resource_type(list.params(None).unwrap()),
+            "channel,playlist,video"
+        );
+        list.resource_type(vec![ResourceType::Video]);
+        assert_eq!(resource_type(list.params(None).unwrap()), "video");
+    }
+
+    #[test]
+    fn test_published_within() {
+        let youtube = get_youtube_with_transport(MixedResults);
+        let mut list = youtube.search().videos("rust");
+        list.published_before(Utc::now()).published_last_days(2);
+        let params = list.params(None).unwrap();
+        let after = DateTime::parse_from_rfc3339(&params["publishedAfter"]).unwrap();
+        let elapsed = Utc::now() - after.with_timezone(&Utc);
+        assert!(elapsed >= chrono::Duration::days(2));
+        assert!(elapsed < chrono::Duration::days(2) + chrono::Duration::minutes(1));
+        assert!(!params.contains_key("publishedBefore"));
+
+        list.published_within(Duration::MAX);
+        let params = list.params(None).unwrap();
+        assert!(params.contains_key("publishedAfter"));
+    }
+
+    #[tokio::test]
+    async fn test_request_with_location() {
+        let youtube = get_youtube_with_transport(MixedResults);
+        let mut list = youtube.search().videos("rust");
+        list.location(GeoPoint::new(37.42307, -122.08427));
+        let err = list.request().await.unwrap_err();
+        assert_eq!(
+            "builder error: \"Request contains an invalid argument: parameter `location_radius` must be specified when using `location`\"",
+            format!("{}", err)
+        );
+
+        list.location_radius(Radius::km(5.0));
+        let params = list.params(None).unwrap();
+        assert_eq!(params["location"], "37.42307,-122.08427");
+        assert_eq!(params["locationRadius"], "5km");
+
+        list.location_radius(Radius::mi(700.0));
+        let err = list.request().await.unwrap_err();
+        assert_eq!(
+            "builder error: \"Request contains an invalid argument: the location radius `700mi` must be greater than 0 and at most 1000km\"",
+            format!("{}", err)
+        );
+
+        list.location_radius(Radius::meters(1500.0))
+            .location(GeoPoint::new(91.0, 0.0));
+        let err = list.request().await.unwrap_err();
+        assert_eq!(
+            "builder error: \"Request contains an invalid argument: `91,0` is not a valid location\"",
+            format!("{}", err)
+        );
+
+        let mut list = youtube.search().channels("rust");
+        list.location_radius(Radius::ft(1000.0));
+        assert!(list.request().await.is_err());
+    }
+
+    #[test]
+    fn test_search_result() {
+        let items: Vec<SearchListResource> = serde_json::from_str(
+            r#"[
+                { "id": { "kind": "youtube#video", "videoId": "Ks-_Mh1QhMc" } },
+                { "id": { "kind": "youtube#channel", "channelId": "UCBR8-60-B28hp2BmDPdntcQ" } },
+                { "id": { "kind": "youtube#playlist", "playlistId": "PLOU2XLYxmsIIM9h1Ybw2DuRw6o2fkNMeR" } },
+                { "id": { "kind": "youtube#video" } },
+                { "id": { "kind": "youtube#short", "videoId": "Ks-_Mh1QhMc" } }
+            ]"#,
+        )
+        .unwrap();
+        let results = items
+            .iter()
+            .map(SearchListResource::result)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Some(SearchResult::Video("Ks-_Mh1QhMc".to_string())),
+                Some(SearchResult::Channel(
+                    "UCBR8-60-B28hp2BmDPdntcQ".to_string()
+                )),
+                Some(SearchResult::Playlist(
+                    "PLOU2XLYxmsIIM9h1Ybw2DuRw6o2fkNMeR".to_string()
+                )),
+                None,
+                None,
+            ]
+        );
+        let link = crate::url::YouTubeLink::from(results[0].clone().unwrap());
+        assert_eq!(
+            link.to_string(),
+            "https://www.youtube.com/watch?v=Ks-_Mh1QhMc"
+        );
+    }
 
     #[tokio::test]
     async fn test_get_by_keyword() {
-        let response = get_youtube_without_user_agent()
+        let transport = Arc::new(MockTransport::new().respond(
+            reqwest::Method::GET,
+            "search",
+            200,
+            r#"{
+                "kind": "youtube#searchListResponse",
+                "nextPageToken": "CAEQAA",
+                "regionCode": "US",
+                "pageInfo": { "totalResults": 1000000, "resultsPerPage": 1 },
+                "items": [{
+                    "kind": "youtube#searchResult",
+                    "id": { "kind": "youtube#video", "videoId": "video-id" },
+                    "snippet": {
+                        "publishedAt": "2023-05-01T12:00:00Z",
+                        "channelId": "UCa9Y57gfeY0Zro_noHRVrnw",
+                        "title": "Surfing the biggest waves",
+                        "description": "",
+                        "thumbnails": {},
+                        "channelTitle": "A channel",
+                        "liveBroadcastContent": "none"
+                    }
+                }]
+            }"#,
+        ));
+        let response = get_youtube_with_transport(transport.clone())
             .search()
             .list(vec![Part::Snippet])
             .q("surfing")
             .max_results(1)
             .request()
-            .await;
-        assert_eq!(true, response.is_ok());
-        assert_eq!(response.unwrap().items.len(), 1);
+            .await
+            .unwrap();
+        assert_eq!(response.items.len(), 1);
+        assert_eq!(response.region_code.as_deref(), Some("US"));
+        let result = &response.items[0];
+        assert_eq!(
+            result.result(),
+            Some(SearchResult::Video("video-id".to_string()))
+        );
+        let snippet = result.snippet.as_ref().unwrap();
+        assert_eq!(snippet.title.as_deref(), Some("Surfing the biggest waves"));
+        assert_eq!(snippet.channel_title, "A channel");
+        assert_eq!(
+            snippet.live_broadcast_content,
+            Some(LiveBroadcastContent::None)
+        );
+        let query = transport.requests()[0].1.query().unwrap().to_string();
+        assert!(query.contains("q=surfing"));
+        assert!(query.contains("maxResults=1"));
     }
 
     #[tokio::test]
     async fn test_get_by_live_events() {
-        let response = get_youtube_without_user_agent()
+        let transport = Arc::new(MockTransport::new().respond(
+            reqwest::Method::GET,
+            "search",
+            200,
+            r#"{
+                "kind": "youtube#searchListResponse",
+                "items": [{
+                    "kind": "youtube#searchResult",
+                    "id": { "kind": "youtube#video", "videoId": "live-video-id" },
+                    "snippet": {
+                        "channelId": "UCa9Y57gfeY0Zro_noHRVrnw",
+                        "title": "Live news",
+                        "channelTitle": "A news channel",
+                        "liveBroadcastContent": "live"
+                    }
+                }]
+            }"#,
+        ));
+        let response = get_youtube_with_transport(transport.clone())
             .search()
             .list(vec![Part::Snippet])
             .event_type(EventType::Live)
@@ -993,22 +1553,62 @@ mod tests {
             .q("news")
             .max_results(1)
             .request()
-            .await;
-        assert_eq!(true, response.is_ok());
-        assert_eq!(response.unwrap().items.len(), 1);
+            .await
+            .unwrap();
+        assert_eq!(response.items.len(), 1);
+        assert_eq!(
+            response.items[0]
+                .snippet
+                .as_ref()
+                .unwrap()
+                .live_broadcast_content,
+            Some(LiveBroadcastContent::Live)
+        );
+        let query = transport.requests()[0].1.query().unwrap().to_string();
+        assert!(query.contains("eventType=live"));
+        assert!(query.contains("type=video"));
     }
 
     #[tokio::test]
     async fn test_get_channels_by_q() {
-        let response = get_youtube_without_user_agent()
+        let transport = Arc::new(MockTransport::new().respond(
+            reqwest::Method::GET,
+            "search",
+            200,
+            r#"{
+                "kind": "youtube#searchListResponse",
+                "items": [
+                    { "kind": "youtube#searchResult", "id": { "kind": "youtube#channel", "channelId": "UCBR8-60-B28hp2BmDPdntcQ" } },
+                    { "kind": "youtube#searchResult", "id": { "kind": "youtube#channel", "channelId": "UC4R8DWoMoI7CAwX8_LjQHig" } }
+                ]
+            }"#,
+        ));
+        let response = get_youtube_with_transport(transport.clone())
             .search()
             .list(vec![Part::Id])
             .resource_type(vec![ResourceType::Channel])
             .q("@YouTube")
             .request()
-            .await;
-        assert_eq!(true, response.is_ok());
-        assert_ne!(response.unwrap().items.len(), 0);
+            .await
+            .unwrap();
+        assert_eq!(
+            response
+                .items
+                .iter()
+                .map(SearchListResource::result)
+                .collect::<Vec<_>>(),
+            vec![
+                Some(SearchResult::Channel(
+                    "UCBR8-60-B28hp2BmDPdntcQ".to_string()
+                )),
+                Some(SearchResult::Channel(
+                    "UC4R8DWoMoI7CAwX8_LjQHig".to_string()
+                )),
+            ]
+        );
+        let query = transport.requests()[0].1.query().unwrap().to_string();
+        assert!(query.contains("type=channel"));
+        assert!(query.contains("q=%40YouTube"));
     }
 
     #[tokio::test]
@@ -1021,7 +1621,7 @@ mod tests {
             .for_mine(true)
             .request()
             .await;
-        assert_eq!(true, multiple_filters.is_err());
+        assert!(multiple_filters.is_err());
         let err = multiple_filters.unwrap_err();
         assert_eq!(
             "builder error: \"Incompatible parameters specified in the request: for_content_owner, for_developer, for_mine\"",
@@ -1038,7 +1638,7 @@ mod tests {
             .for_mine(true)
             .request()
             .await;
-        assert_eq!(true, without_auth.is_err());
+        assert!(without_auth.is_err());
         let err = without_auth.unwrap_err();
         assert_eq!(
             "builder error: \"The request uses the `for_mine` parameter but is not properly authorized\"",
@@ -1046,15 +1646,73 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_request_for_mine_with_restricted_parameters() {
+        let youtube = get_youtube_without_user_agent().with_access_token("access-token");
+        let not_video = youtube.search().list(vec![]).for_mine(true).request().await;
+        assert!(not_video.is_err());
+        let err = not_video.unwrap_err();
+        assert_eq!(
+            "builder error: \"Request contains an invalid argument: parameter `type` must be set to `video` when using `for_mine`\"",
+            format!("{}", err)
+        );
+
+        let restricted = youtube
+            .search()
+            .list(vec![])
+            .for_mine(true)
+            .resource_type(vec![ResourceType::Video])
+            .video_duration(VideoDuration::Short)
+            .request()
+            .await;
+        assert!(restricted.is_err());
+        let err = restricted.unwrap_err();
+        assert_eq!(
+            "builder error: \"Incompatible parameters specified in the request: for_mine, video_duration\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_request_for_mine_as_user() {
+        let as_user = get_youtube_without_user_agent()
+            .search()
+            .list(vec![])
+            .for_mine(true)
+            .as_user("access-token")
+            .request()
+            .await;
+        assert!(as_user.is_err());
+        let err = as_user.unwrap_err();
+        assert_eq!(
+            "builder error: \"Request contains an invalid argument: parameter `type` must be set to `video` when using `for_mine`\"",
+            format!("{}", err)
+        );
+    }
+
     #[tokio::test]
     async fn test_request_with_invalid_channel_id() {
-        let invalid_id = get_youtube_without_user_agent()
+        let transport = Arc::new(MockTransport::new().respond(
+            reqwest::Method::GET,
+            "search",
+            400,
+            r#"{
+                "error": {
+                    "code": 400,
+                    "message": "Request contains an invalid argument.",
+                    "errors": [
+                        { "message": "Request contains an invalid argument.", "domain": "global", "reason": "badRequest" }
+                    ],
+                    "status": "INVALID_ARGUMENT"
+                }
+            }"#,
+        ));
+        let invalid_id = get_youtube_with_transport(transport)
             .search()
             .list(vec![])
             .channel_id("dasvvdasvrgegrebr232")
             .request()
             .await;
-        assert_eq!(true, invalid_id.is_err());
         let err = invalid_id.unwrap_err();
         let assert_message = concat!("client error for url (\"/youtube/v3/search?channelId=dasvvdasvrgegrebr232&key=[API_KEY]&part=snippet&type=channel,playlist,video\"): ",
         "400 Bad Request status: \"INVALID_ARGUMENT\" ",
@@ -0,0 +1,197 @@
+//! Deserializers shared by the resources of the services.
+
+use serde::{de::Error, Deserialize, Deserializer};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// A number, or a number sent as a string: the api sends its 64-bit integers as strings (e.g.
+/// `"viewCount": "12345"`) since JavaScript cannot represent all of them.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StringOrNumber<T> {
+    Number(T),
+    String(String),
+}
+
+impl<T> StringOrNumber<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    fn parse<E: Error>(self) -> Result<T, E> {
+        match self {
+            StringOrNumber::Number(number) => Ok(number),
+            StringOrNumber::String(value) => value
+                .parse()
+                .map_err(|e| E::custom(format!("invalid number {:?}: {}", value, e))),
+        }
+    }
+}
+
+/// Deserializes a number that the api sends either as a JSON number or as a string, e.g.
+/// `#[serde(deserialize_with = "string_or_number")]`.
+pub(crate) fn string_or_number<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr + Deserialize<'de>,
+    T::Err: Display,
+{
+    StringOrNumber::deserialize(deserializer)?.parse()
+}
+
+/// [string_or_number] for an optional number; the field also needs `#[serde(default)]` to be omitted.
+pub(crate) fn option_string_or_number<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr + Deserialize<'de>,
+    T::Err: Display,
+{
+    Option::<StringOrNumber<T>>::deserialize(deserializer)?
+        .map(StringOrNumber::parse)
+        .transpose()
+}
+
+/// Deserializes the flattened `extra` fields of a resource, the fields unknown to the model. They fail the
+/// deserialization with the `strict` feature.
+pub(crate) fn extra_fields<'de, D>(
+    deserializer: D,
+) -> Result<serde_json::Map<String, serde_json::Value>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let extra = serde_json::Map::deserialize(deserializer)?;
+    #[cfg(feature = "strict")]
+    if let Some(field) = extra.keys().next() {
+        return Err(D::Error::custom(format!("unknown field `{}`", field)));
+    }
+    Ok(extra)
+}
+
+/// Declares an enum of the values of a string field, with an `Unknown(String)` variant keeping the values added to
+/// the api after this version of the crate, so that a new value does not fail the deserialization of the response.
+/// The enum (de)serializes as the api value of its variant, e.g.
+///
+/// ```ignore
+/// string_enum! {
+///     /// The video's license.
+///     pub enum License {
+///         CreativeCommon => "creativeCommon",
+///         Youtube => "youtube",
+///     }
+/// }
+/// ```
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident => $value:literal,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($(#[$variant_meta])* $variant,)*
+            /// A value unknown to this version of the crate.
+            Unknown(String),
+        }
+
+        impl $name {
+            /// The value of the variant in the api.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $value,)*
+                    $name::Unknown(value) => value,
+                }
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> $name {
+                match value {
+                    $($value => $name::$variant,)*
+                    _ => $name::Unknown(value.to_string()),
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<$name, D::Error> {
+                let value = std::borrow::Cow::<str>::deserialize(deserializer)?;
+                Ok($name::from(value.as_ref()))
+            }
+        }
+    };
+}
+
+pub(crate) use string_enum;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Deserialize)]
+    struct Counts {
+        #[serde(deserialize_with = "string_or_number")]
+        count: u64,
+
+        #[serde(default, deserialize_with = "option_string_or_number")]
+        optional: Option<u64>,
+    }
+
+    #[test]
+    fn test_string_or_number() {
+        let counts: Counts = serde_json::from_str(r#"{"count": "12345", "optional": 7}"#).unwrap();
+        assert_eq!(counts.count, 12345);
+        assert_eq!(counts.optional, Some(7));
+
+        let counts: Counts = serde_json::from_str(r#"{"count": 12345}"#).unwrap();
+        assert_eq!(counts.count, 12345);
+        assert_eq!(counts.optional, None);
+
+        let counts: Counts =
+            serde_json::from_str(r#"{"count": "18446744073709551615", "optional": null}"#).unwrap();
+        assert_eq!(counts.count, u64::MAX);
+        assert_eq!(counts.optional, None);
+
+        assert!(serde_json::from_str::<Counts>(r#"{"count": "many"}"#).is_err());
+        assert!(serde_json::from_str::<Counts>(r#"{"count": "-1"}"#).is_err());
+    }
+
+    string_enum! {
+        pub enum Shape {
+            Circle => "circle",
+            RoundedSquare => "roundedSquare",
+        }
+    }
+
+    #[test]
+    fn test_string_enum() {
+        let shapes: Vec<Shape> =
+            serde_json::from_str(r#"["circle", "roundedSquare", "hexagon"]"#).unwrap();
+        assert_eq!(
+            shapes,
+            vec![
+                Shape::Circle,
+                Shape::RoundedSquare,
+                Shape::Unknown("hexagon".to_string())
+            ]
+        );
+        assert_eq!(
+            serde_json::to_string(&shapes).unwrap(),
+            r#"["circle","roundedSquare","hexagon"]"#
+        );
+        assert_eq!(Shape::RoundedSquare.to_string(), "roundedSquare");
+    }
+}
@@ -0,0 +1,522 @@
+use crate::{
+    auth::{Authorizer, OAuthTokenAuth},
+    cursor::PageCursor,
+    error::Result,
+    list_json,
+    search::ResourceId,
+    ListApi, ListResponse, PagedListApi, RequestBase, Thumbnails, YouTube, YouTubeDataApi,
+};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use youtube_data_derive::QueryBuilder;
+
+pub type SubscriptionListResponse = ListResponse<SubscriptionListResource>;
+
+#[derive(Clone, Copy)]
+pub struct SubscriptionsService<'a> {
+    youtube: &'a YouTube,
+}
+
+impl<'a> SubscriptionsService<'a> {
+    pub(crate) fn new(youtube: &'a YouTube) -> Self {
+        Self { youtube }
+    }
+
+    pub fn list(self, part: impl IntoIterator<Item = Part>) -> SubscriptionList<'a> {
+        SubscriptionList::new(self, part)
+    }
+}
+
+/// Parameters for the `list` method of the `subscriptions` api. details:
+/// [link](https://developers.google.com/youtube/v3/docs/subscriptions/list)
+#[derive(QueryBuilder)]
+pub struct SubscriptionList<'a> {
+    service: SubscriptionsService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    #[query(unique)]
+    part: Vec<Part>,
+
+    // filters (specify exactly one of the following parameters)
+    #[query(filter)]
+    channel_id: Option<String>,
+    #[query(filter)]
+    id: Option<Vec<String>>,
+    #[query(filter, authorized)]
+    mine: Option<bool>,
+    #[query(filter, authorized)]
+    my_recent_subscribers: Option<bool>,
+    #[query(filter, authorized)]
+    my_subscribers: Option<bool>,
+
+    // optional parameters
+    /// Selects the fields of the response, e.g. `items(id,snippet/title)`, so only the needed part of every
+    /// resource is returned. details: [link](https://developers.google.com/youtube/v3/getting-started#fields)
+    #[query]
+    fields: Option<String>,
+    /// Fails the request with a timeout error when it does not complete in time, instead of the timeout of the
+    /// `YouTube` handle.
+    #[setter]
+    timeout: Option<Duration>,
+    #[query]
+    for_channel_id: Option<Vec<String>>,
+    #[query(max = 50)]
+    max_results: Option<u32>,
+    #[setter]
+    on_behalf_of_content_owner: Option<String>,
+    #[setter]
+    on_behalf_of_content_owner_channel: Option<String>,
+    #[query]
+    order: Option<Order>,
+    #[setter]
+    page_token: Option<String>,
+}
+
+impl RequestBase for SubscriptionList<'_> {
+    fn api_path(&self) -> &str {
+        "subscriptions"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for SubscriptionList<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+
+    fn request_timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl ListApi<SubscriptionListResponse> for SubscriptionList<'_> {
+    async fn request(&self) -> Result<SubscriptionListResponse> {
+        self.request_page(None).await
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl PagedListApi<SubscriptionListResource> for SubscriptionList<'_> {
+    async fn request_page(&self, page_token: Option<&str>) -> Result<SubscriptionListResponse> {
+        let youtube = &self.service.youtube;
+        let params = self.params(page_token)?;
+
+        let request = self.optional_bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .get(self.url(&youtube.inner().base_path))
+                .query(&params),
+        )?;
+        let response = self.send(youtube, request).await?;
+        list_json(response).await
+    }
+
+    fn cursor(&self, page_token: Option<&str>) -> Result<PageCursor> {
+        Ok(PageCursor::new(self.api_path(), self.params(page_token)?))
+    }
+}
+
+impl SubscriptionList<'_> {
+    /// The query parameters of the page of `page_token`, or of the `page_token` of the builder for `None`.
+    fn params(&self, page_token: Option<&str>) -> Result<HashMap<String, String>> {
+        let youtube = &self.service.youtube;
+        let page_token = page_token.or(self.page_token.as_deref());
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        self.insert_query(youtube, &mut params)?;
+        self.insert_content_owner_parameters(
+            youtube,
+            &mut params,
+            self.on_behalf_of_content_owner.as_deref(),
+            self.on_behalf_of_content_owner_channel.as_deref(),
+        )?;
+        self.insert_query_parameter(&mut params, "pageToken", page_token);
+
+        Ok(params)
+    }
+}
+
+impl<'a> SubscriptionList<'a> {
+    pub(crate) fn new(
+        service: SubscriptionsService<'a>,
+        part: impl IntoIterator<Item = Part>,
+    ) -> Self {
+        let part = crate::dedupe(part);
+        let part = if part.is_empty() {
+            vec![Part::Id]
+        } else {
+            part
+        };
+        Self {
+            service,
+            authorizer: None,
+            part,
+            channel_id: None,
+            id: None,
+            mine: None,
+            my_recent_subscribers: None,
+            my_subscribers: None,
+            fields: None,
+            timeout: None,
+            for_channel_id: None,
+            max_results: None,
+            on_behalf_of_content_owner: None,
+            on_behalf_of_content_owner_channel: None,
+            order: None,
+            page_token: None,
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    ContentDetails,
+    Id,
+    Snippet,
+    SubscriberSnippet,
+}
+
+impl Part {
+    /// Every part of the resource, to request all of its properties.
+    pub fn all_public() -> Vec<Part> {
+        vec![
+            Part::ContentDetails,
+            Part::Id,
+            Part::Snippet,
+            Part::SubscriberSnippet,
+        ]
+    }
+}
+
+impl std::fmt::Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            Part::ContentDetails => "contentDetails",
+            Part::Id => "id",
+            Part::Snippet => "snippet",
+            Part::SubscriberSnippet => "subscriberSnippet",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+/// The order parameter specifies the method that will be used to sort resources in the API response.
+pub enum Order {
+    /// Sort alphabetically.
+    Alphabetical,
+
+    /// Sort by relevance. This is the default value.
+    Relevance,
+
+    /// Sort by order of activity.
+    Unread,
+}
+
+impl std::fmt::Display for Order {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            Order::Alphabetical => "alphabetical",
+            Order::Relevance => "relevance",
+            Order::Unread => "unread",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscriptionListResource {
+    /// Identifies the API resource's type. The value will be `youtube#subscription`.
+    #[serde(default)]
+    pub kind: String,
+
+    /// The ETag of the response.
+    #[serde(default)]
+    pub etag: String,
+
+    /// The ID that YouTube uses to uniquely identify the subscription.
+    #[serde(default)]
+    pub id: String,
+
+    pub snippet: Option<SubscriptionSnippet>,
+
+    pub content_details: Option<SubscriptionContentDetails>,
+
+    pub subscriber_snippet: Option<SubscriptionSubscriberSnippet>,
+
+    /// The fields of the resource that this version of the crate does not know, e.g. added to the api after its
+    /// release. They are serialized back with the resource.
+    #[serde(flatten, deserialize_with = "crate::serde_helpers::extra_fields")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// The snippet object contains basic details about the subscription, including its title and the channel that the
+/// user subscribed to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscriptionSnippet {
+    /// The date and time that the subscription was created. The value is specified in
+    /// [ISO 8601](https://www.w3.org/TR/NOTE-datetime) format.
+    pub published_at: Option<DateTime<Utc>>,
+
+    /// The title of the channel that the subscription belongs to.
+    pub channel_title: Option<String>,
+
+    /// The subscription's title.
+    #[serde(default)]
+    pub title: String,
+
+    /// The subscription's details.
+    #[serde(default)]
+    pub description: String,
+
+    /// The id object contains information about the channel that the user subscribed to.
+    #[serde(default)]
+    pub resource_id: ResourceId,
+
+    /// The ID that YouTube uses to uniquely identify the subscriber's channel.
+    #[serde(default)]
+    pub channel_id: String,
+
+    /// A map of thumbnail images associated with the subscription. For each object in the map, the key is the name
+    /// of the thumbnail image, and the value is an object that contains other information about the thumbnail.
+    #[serde(default)]
+    pub thumbnails: Thumbnails,
+}
+
+/// The contentDetails object contains basic statistics about the subscription.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscriptionContentDetails {
+    /// The approximate number of items that the subscription points to.
+    #[serde(default)]
+    pub total_item_count: u32,
+
+    /// The number of new items in the subscription since its content was last read.
+    #[serde(default)]
+    pub new_item_count: u32,
+
+    /// The type of activity this subscription is for (only uploads, everything).
+    ///
+    /// Valid values for this property are:
+    ///
+    /// * all
+    /// * uploads
+    pub activity_type: Option<String>,
+}
+
+/// The subscriberSnippet object contains basic details about the subscriber.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscriptionSubscriberSnippet {
+    /// The title of the subscriber's channel.
+    #[serde(default)]
+    pub title: String,
+
+    /// The description of the subscriber's channel.
+    #[serde(default)]
+    pub description: String,
+
+    /// The ID that YouTube assigns to uniquely identify the subscriber's channel.
+    #[serde(default)]
+    pub channel_id: String,
+
+    /// Thumbnail images for the subscriber's channel.
+    #[serde(default)]
+    pub thumbnails: Thumbnails,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::MockTransport;
+    use crate::{
+        auth::Authorizer, error::Error, get_youtube_with_transport, get_youtube_without_user_agent,
+    };
+
+    struct TokenBroker;
+
+    #[async_trait]
+    impl Authorizer for TokenBroker {
+        async fn authorize(&self, _request: &mut reqwest::Request) -> Result<()> {
+            Err(Error::authorizer_error("the token broker is unavailable"))
+        }
+
+        fn authorizes_user(&self) -> bool {
+            true
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_by_channel_id() {
+        let transport = Arc::new(MockTransport::new().respond(
+            reqwest::Method::GET,
+            "subscriptions",
+            200,
+            r#"{
+                "kind": "youtube#subscriptionListResponse",
+                "pageInfo": { "totalResults": 1, "resultsPerPage": 5 },
+                "items": [{
+                    "kind": "youtube#subscription",
+                    "id": "subscription-id",
+                    "snippet": {
+                        "publishedAt": "2015-09-02T18:41:55Z",
+                        "title": "Android Developers",
+                        "description": "",
+                        "resourceId": { "kind": "youtube#channel", "channelId": "UCVHFbqXqoYvEWM1Ddxl0QDg" },
+                        "channelId": "UC_x5XG1OV2P6uZZ5FSM9Ttw",
+                        "thumbnails": {}
+                    }
+                }]
+            }"#,
+        ));
+        let response = get_youtube_with_transport(transport.clone())
+            .subscriptions()
+            .list(vec![Part::Snippet])
+            .channel_id("UC_x5XG1OV2P6uZZ5FSM9Ttw")
+            .max_results(5)
+            .request()
+            .await
+            .unwrap();
+        assert_eq!(response.items.len(), 1);
+        let snippet = response.items[0].snippet.as_ref().unwrap();
+        assert_eq!(snippet.channel_id, "UC_x5XG1OV2P6uZZ5FSM9Ttw");
+        assert_eq!(snippet.title, "Android Developers");
+        assert_eq!(snippet.resource_id.kind, "youtube#channel");
+        assert_eq!(
+            snippet.resource_id.channel_id.as_deref(),
+            Some("UCVHFbqXqoYvEWM1Ddxl0QDg")
+        );
+        assert!(snippet.published_at.is_some());
+        let query = transport.requests()[0].1.query().unwrap().to_string();
+        assert!(query.contains("channelId=UC_x5XG1OV2P6uZZ5FSM9Ttw"));
+        assert!(query.contains("maxResults=5"));
+    }
+
+    #[tokio::test]
+    async fn test_request_multiple_filters() {
+        let multiple_filters = get_youtube_without_user_agent()
+            .subscriptions()
+            .list(vec![])
+            .channel_id("something")
+            .mine(true)
+            .request()
+            .await;
+        assert!(multiple_filters.is_err());
+        let err = multiple_filters.unwrap_err();
+        assert_eq!(
+            "builder error: \"Incompatible parameters specified in the request: channel_id, mine\"",
+            format!("{}", err)
+        );
+    }
+
+    #[test]
+    fn test_params() {
+        let youtube = get_youtube_without_user_agent();
+        let mut list = youtube.subscriptions().list(vec![Part::Snippet]);
+        list.channel_id("UC_x5XG1OV2P6uZZ5FSM9Ttw")
+            .for_channel_id(vec!["UCBR8-60-B28hp2BmDPdntcQ", ""])
+            .max_results(100)
+            .order(Order::Alphabetical);
+        let params = list.params(Some("page")).unwrap();
+        assert_eq!(params.len(), 6);
+        assert_eq!(params["part"], "snippet");
+        assert_eq!(params["channelId"], "UC_x5XG1OV2P6uZZ5FSM9Ttw");
+        assert_eq!(params["forChannelId"], "UCBR8-60-B28hp2BmDPdntcQ");
+        assert_eq!(params["maxResults"], "50");
+        assert_eq!(params["order"], "alphabetical");
+        assert_eq!(params["pageToken"], "page");
+
+        let err = list.id(Vec::<String>::new()).params(None).unwrap_err();
+        assert_eq!(
+            "builder error: \"Incompatible parameters specified in the request: channel_id, id\"",
+            format!("{}", err)
+        );
+    }
+
+    #[test]
+    fn test_params_owned() {
+        let youtube = get_youtube_without_user_agent();
+        let mut list = youtube.subscriptions().list(vec![Part::Snippet]);
+        for channel in ["UC_x5XG1OV2P6uZZ5FSM9Ttw", "UCBR8-60-B28hp2BmDPdntcQ"] {
+            list.channel_id(channel.to_string());
+        }
+        list.for_channel_id(["a", "b"].map(|id| format!("UC{}", id)));
+        let params = list.params(None).unwrap();
+        assert_eq!(params["channelId"], "UCBR8-60-B28hp2BmDPdntcQ");
+        assert_eq!(params["forChannelId"], "UCa,UCb");
+    }
+
+    /// test use filters that require authentication wihtout authentication
+    #[tokio::test]
+    async fn test_request_without_auth() {
+        let without_auth = get_youtube_without_user_agent()
+            .subscriptions()
+            .list(vec![])
+            .my_subscribers(true)
+            .request()
+            .await;
+        assert!(without_auth.is_err());
+        let err = without_auth.unwrap_err();
+        assert_eq!(
+            "builder error: \"The request uses the `my_subscribers` parameter but is not properly authorized\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_request_content_owner_channel_without_content_owner() {
+        let without_content_owner = get_youtube_without_user_agent()
+            .with_access_token("access-token")
+            .subscriptions()
+            .list(vec![])
+            .mine(true)
+            .on_behalf_of_content_owner_channel("UC_x5XG1OV2P6uZZ5FSM9Ttw")
+            .request()
+            .await;
+        assert!(without_content_owner.is_err());
+        let err = without_content_owner.unwrap_err();
+        assert_eq!(
+            "builder error: \"The `on_behalf_of_content_owner_channel` parameter requires `on_behalf_of_content_owner`\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_request_with_custom_authorizer() {
+        let unavailable = get_youtube_without_user_agent()
+            .with_authorizer(TokenBroker)
+            .subscriptions()
+            .list(vec![])
+            .mine(true)
+            .request()
+            .await;
+        assert!(unavailable.is_err());
+        let err = unavailable.unwrap_err();
+        assert_eq!(
+            "authorizer error: the token broker is unavailable",
+            format!("{}", err)
+        );
+    }
+}
@@ -0,0 +1,349 @@
+use crate::{
+    auth::{Authorizer, OAuthTokenAuth},
+    cursor::PageCursor,
+    error::Result,
+    list_json, ListApi, ListResponse, PagedListApi, RequestBase, YouTube, YouTubeDataApi,
+};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use youtube_data_derive::QueryBuilder;
+
+pub type SuperChatEventListResponse = ListResponse<SuperChatEventListResource>;
+
+#[derive(Clone, Copy)]
+pub struct SuperChatEventsService<'a> {
+    youtube: &'a YouTube,
+}
+
+impl<'a> SuperChatEventsService<'a> {
+    pub(crate) fn new(youtube: &'a YouTube) -> Self {
+        Self { youtube }
+    }
+
+    pub fn list(self, part: impl IntoIterator<Item = Part>) -> SuperChatEventList<'a> {
+        SuperChatEventList::new(self, part)
+    }
+}
+
+/// Parameters for the `list` method of the `superChatEvents` api. details:
+/// [link](https://developers.google.com/youtube/v3/live/docs/superChatEvents/list)
+#[derive(QueryBuilder)]
+pub struct SuperChatEventList<'a> {
+    service: SuperChatEventsService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    #[query(unique)]
+    part: Vec<Part>,
+
+    // optional parameters
+    /// Selects the fields of the response, e.g. `items(id,snippet/title)`, so only the needed part of every
+    /// resource is returned. details: [link](https://developers.google.com/youtube/v3/getting-started#fields)
+    #[query]
+    fields: Option<String>,
+    /// Fails the request with a timeout error when it does not complete in time, instead of the timeout of the
+    /// `YouTube` handle.
+    #[setter]
+    timeout: Option<Duration>,
+    /// The language used for the `snippet.display_string` of the amount.
+    #[query(default)]
+    hl: Option<String>,
+    #[query(min = 1, max = 50)]
+    max_results: Option<u32>,
+    #[setter]
+    page_token: Option<String>,
+}
+
+impl RequestBase for SuperChatEventList<'_> {
+    fn api_path(&self) -> &str {
+        "superChatEvents"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for SuperChatEventList<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+
+    fn request_timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl ListApi<SuperChatEventListResponse> for SuperChatEventList<'_> {
+    async fn request(&self) -> Result<SuperChatEventListResponse> {
+        self.request_page(None).await
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl PagedListApi<SuperChatEventListResource> for SuperChatEventList<'_> {
+    async fn request_page(&self, page_token: Option<&str>) -> Result<SuperChatEventListResponse> {
+        let youtube = &self.service.youtube;
+        let params = self.params(page_token)?;
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .get(self.url(&youtube.inner().base_path))
+                .query(&params),
+        )?;
+        let response = self.send(youtube, request).await?;
+        list_json(response).await
+    }
+
+    fn cursor(&self, page_token: Option<&str>) -> Result<PageCursor> {
+        Ok(PageCursor::new(self.api_path(), self.params(page_token)?))
+    }
+}
+
+impl SuperChatEventList<'_> {
+    /// The query parameters of the page of `page_token`, or of the `page_token` of the builder for `None`.
+    fn params(&self, page_token: Option<&str>) -> Result<HashMap<String, String>> {
+        let page_token = page_token.or(self.page_token.as_deref());
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        self.insert_query(self.service.youtube, &mut params)?;
+        self.insert_query_parameter(&mut params, "pageToken", page_token);
+
+        Ok(params)
+    }
+}
+
+impl<'a> SuperChatEventList<'a> {
+    pub(crate) fn new(
+        service: SuperChatEventsService<'a>,
+        part: impl IntoIterator<Item = Part>,
+    ) -> Self {
+        let part = crate::dedupe(part);
+        let part = if part.is_empty() {
+            vec![Part::Snippet]
+        } else {
+            part
+        };
+        Self {
+            service,
+            authorizer: None,
+            part,
+            fields: None,
+            timeout: None,
+            hl: None,
+            max_results: None,
+            page_token: None,
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    Id,
+    Snippet,
+}
+
+impl Part {
+    /// Every part of the resource, to request all of its properties.
+    pub fn all_public() -> Vec<Part> {
+        vec![Part::Id, Part::Snippet]
+    }
+}
+
+impl std::fmt::Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            Part::Id => "id",
+            Part::Snippet => "snippet",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SuperChatEventListResource {
+    /// Identifies the API resource's type. The value will be `youtube#superChatEvent`.
+    #[serde(default)]
+    pub kind: String,
+
+    /// The ETag of the response.
+    #[serde(default)]
+    pub etag: String,
+
+    /// The ID that YouTube assigns to uniquely identify the Super Chat event.
+    #[serde(default)]
+    pub id: String,
+
+    pub snippet: Option<SuperChatEventSnippet>,
+
+    /// The fields of the resource that this version of the crate does not know, e.g. added to the api after its
+    /// release. They are serialized back with the resource.
+    #[serde(flatten, deserialize_with = "crate::serde_helpers::extra_fields")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// The snippet object contains basic details about the Super Chat event.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuperChatEventSnippet {
+    /// The ID of the channel that owns the live chat in which the Super Chat was sent.
+    #[serde(default)]
+    pub channel_id: String,
+
+    /// Details about the supporter who purchased the Super Chat.
+    #[serde(default)]
+    pub supporter_details: SupporterDetails,
+
+    /// The text of the Super Chat message. This property is only present for Super Chats, not Super Stickers.
+    pub comment_text: Option<String>,
+
+    /// The date and time when the event occurred. The value is specified in
+    /// [ISO 8601](https://www.w3.org/TR/NOTE-datetime) format.
+    pub created_at: Option<DateTime<Utc>>,
+
+    /// The purchase amount, in micros of the purchase currency. For example, if the purchase amount is $1.00, the
+    /// value is `1000000`.
+    #[serde(default)]
+    pub amount_micros: String,
+
+    /// The currency in which the purchase was made. The value is an [ISO 4217](https://en.wikipedia.org/wiki/ISO_4217)
+    /// currency code.
+    #[serde(default)]
+    pub currency: String,
+
+    /// A string that contains the purchase amount and currency (for example, "$1.00"). The string is localized for
+    /// the language specified by the `hl` parameter.
+    #[serde(default)]
+    pub display_string: String,
+
+    /// The tier for the paid message, which is based on the amount of money spent to purchase the message. The tier
+    /// also determines the color of the message and how long it stays pinned at the top of the live chat.
+    #[serde(default)]
+    pub message_type: u32,
+
+    /// Indicates whether the event is a Super Sticker event.
+    pub is_super_sticker_event: Option<bool>,
+
+    /// Information about the Super Sticker. This property is only present if `is_super_sticker_event` is true.
+    pub super_sticker_metadata: Option<SuperStickerMetadata>,
+}
+
+impl SuperChatEventSnippet {
+    /// The purchase amount in units of the purchase currency, or `None` if `amount_micros` is not a number.
+    pub fn amount(&self) -> Option<f64> {
+        self.amount_micros
+            .parse::<u64>()
+            .ok()
+            .map(|micros| micros as f64 / 1_000_000.0)
+    }
+}
+
+/// The supporterDetails object contains information about the supporter who purchased the Super Chat.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SupporterDetails {
+    /// The supporter's YouTube channel ID.
+    #[serde(default)]
+    pub channel_id: String,
+
+    /// The supporter's YouTube channel URL.
+    #[serde(default)]
+    pub channel_url: String,
+
+    /// The supporter's channel name.
+    #[serde(default)]
+    pub display_name: String,
+
+    /// The supporter's avatar URL.
+    #[serde(default)]
+    pub profile_image_url: String,
+}
+
+/// The superStickerMetadata object contains information about the Super Sticker.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuperStickerMetadata {
+    /// Unique identifier of the Super Sticker.
+    #[serde(default)]
+    pub sticker_id: String,
+
+    /// Internationalized alt text that describes the sticker image.
+    #[serde(default)]
+    pub alt_text: String,
+
+    /// Specifies the localization language in which the alt text is returned.
+    #[serde(default)]
+    pub alt_text_language: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_youtube_without_user_agent;
+
+    #[tokio::test]
+    async fn test_request_without_auth() {
+        let without_auth = get_youtube_without_user_agent()
+            .super_chat_events()
+            .list(vec![Part::Snippet])
+            .max_results(10)
+            .request()
+            .await;
+        assert!(without_auth.is_err());
+        let err = without_auth.unwrap_err();
+        assert_eq!(
+            "builder error: \"The request to `superChatEvents` requires OAuth 2.0 authorization\"",
+            format!("{}", err)
+        );
+    }
+
+    #[test]
+    fn test_deserialize_super_chat_event() {
+        let event: SuperChatEventListResource = serde_json::from_str(
+            r#"{
+                "kind": "youtube#superChatEvent",
+                "etag": "etag",
+                "id": "event-id",
+                "snippet": {
+                    "channelId": "UC_x5XG1OV2P6uZZ5FSM9Ttw",
+                    "supporterDetails": {
+                        "channelId": "UCsupporter",
+                        "channelUrl": "http://www.youtube.com/channel/UCsupporter",
+                        "displayName": "Supporter",
+                        "profileImageUrl": "https://yt3.ggpht.com/photo.jpg"
+                    },
+                    "commentText": "Great stream!",
+                    "createdAt": "2023-01-01T00:00:00Z",
+                    "amountMicros": "5000000",
+                    "currency": "USD",
+                    "displayString": "$5.00",
+                    "messageType": 2,
+                    "isSuperStickerEvent": false
+                }
+            }"#,
+        )
+        .unwrap();
+        let snippet = event.snippet.unwrap();
+        assert_eq!(snippet.amount(), Some(5.0));
+        assert_eq!(snippet.message_type, 2);
+        assert_eq!(snippet.supporter_details.display_name, "Supporter");
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{
+    auth::{Authorizer, OAuthTokenAuth},
+    error::{Error, Result},
+    json, ImageMimeType, RequestBase, Thumbnails, YouTube, YouTubeDataApi,
+};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// The maximum file size of a custom thumbnail image (2MB).
+const MAX_THUMBNAIL_SIZE: usize = 2 * 1024 * 1024;
+
+#[derive(Clone, Copy)]
+pub struct ThumbnailsService<'a> {
+    youtube: &'a YouTube,
+}
+
+impl<'a> ThumbnailsService<'a> {
+    pub(crate) fn new(youtube: &'a YouTube) -> Self {
+        Self { youtube }
+    }
+
+    pub fn set(
+        self,
+        video_id: impl Into<String>,
+        image: Vec<u8>,
+        mime_type: ImageMimeType,
+    ) -> ThumbnailSet<'a> {
+        ThumbnailSet::new(self, video_id, image, mime_type)
+    }
+}
+
+/// Parameters for the `set` method of the `thumbnails` api. details:
+/// [link](https://developers.google.com/youtube/v3/docs/thumbnails/set)
+pub struct ThumbnailSet<'a> {
+    service: ThumbnailsService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    video_id: String,
+
+    // optional parameters
+    on_behalf_of_content_owner: Option<String>,
+
+    // media
+    image: Vec<u8>,
+    mime_type: ImageMimeType,
+}
+
+impl RequestBase for ThumbnailSet<'_> {
+    fn api_path(&self) -> &str {
+        "thumbnails/set"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for ThumbnailSet<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> ThumbnailSet<'a> {
+    pub(crate) fn new(
+        service: ThumbnailsService<'a>,
+        video_id: impl Into<String>,
+        image: Vec<u8>,
+        mime_type: ImageMimeType,
+    ) -> Self {
+        Self {
+            service,
+            authorizer: None,
+            video_id: video_id.into(),
+            on_behalf_of_content_owner: None,
+            image,
+            mime_type,
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+
+    pub fn on_behalf_of_content_owner(
+        &mut self,
+        on_behalf_of_content_owner: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+        self
+    }
+
+    /// Uploads a custom video thumbnail to YouTube and sets it for a video. The image is sent as a media upload
+    /// to the upload endpoint of the api.
+    pub async fn request(&self) -> Result<ThumbnailSetResponse> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        self.insert_query_parameter(&mut params, "uploadType", Some("media"));
+
+        // required parameters
+        if self.video_id.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required parameter `video_id` is empty",
+            ));
+        }
+        self.insert_query_parameter(&mut params, "videoId", Some(&self.video_id));
+
+        // optional parameters
+        self.insert_content_owner_parameters(
+            youtube,
+            &mut params,
+            self.on_behalf_of_content_owner.as_deref(),
+            None,
+        )?;
+
+        // media
+        if self.image.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required media body `image` is empty",
+            ));
+        }
+        if self.image.len() > MAX_THUMBNAIL_SIZE {
+            return Err(Error::invalid_parameter(
+                "The thumbnail image exceeds the maximum file size of 2MB",
+            ));
+        }
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .post(self.url(&youtube.inner().upload_base_path))
+                .header(reqwest::header::CONTENT_TYPE, self.mime_type.to_string())
+                .query(&params)
+                .body(self.image.clone()),
+        )?;
+        let response = self.send(youtube, request).await?;
+        json(response).await
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ThumbnailSetResponse {
+    /// Identifies the API resource's type. The value will be `youtube#thumbnailSetResponse`.
+    #[serde(default)]
+    pub kind: String,
+
+    /// The ETag of the response.
+    #[serde(default)]
+    pub etag: String,
+
+    /// A list of thumbnails. Each item is a map of the thumbnail images that were generated from the uploaded image.
+    #[serde(default)]
+    pub items: Vec<Thumbnails>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_youtube_without_user_agent;
+
+    #[tokio::test]
+    async fn test_set_without_auth() {
+        let without_auth = get_youtube_without_user_agent()
+            .thumbnails()
+            .set("Ks-_Mh1QhMc", vec![0xff, 0xd8, 0xff], ImageMimeType::Jpeg)
+            .request()
+            .await;
+        assert!(without_auth.is_err());
+        let err = without_auth.unwrap_err();
+        assert_eq!(
+            "builder error: \"The request to `thumbnails/set` requires OAuth 2.0 authorization\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_too_large_image() {
+        let too_large = get_youtube_without_user_agent()
+            .thumbnails()
+            .set(
+                "Ks-_Mh1QhMc",
+                vec![0; MAX_THUMBNAIL_SIZE + 1],
+                ImageMimeType::Png,
+            )
+            .request()
+            .await;
+        assert!(too_large.is_err());
+        let err = too_large.unwrap_err();
+        assert_eq!(
+            "builder error: \"The thumbnail image exceeds the maximum file size of 2MB\"",
+            format!("{}", err)
+        );
+    }
+}
@@ -0,0 +1,39 @@
+//! The clock and the timers of the requests: tokio's on native targets, the ones of the JavaScript host on wasm32,
+//! where tokio has no timer and `std::time::Instant` panics.
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use tokio::time::{sleep, Instant};
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) use wasm::{sleep, timeout, Instant};
+
+#[cfg(target_arch = "wasm32")]
+mod wasm {
+    use crate::error::{Error, Result};
+
+    use futures_util::future::{select, Either};
+    use std::future::Future;
+    use std::time::Duration;
+
+    pub(crate) use web_time::Instant;
+
+    pub(crate) async fn sleep(duration: Duration) {
+        gloo_timers::future::sleep(duration).await
+    }
+
+    /// Fails with a timeout error unless `future` completes within `timeout`. The wasm32 backend of reqwest can not
+    /// time out a request itself.
+    pub(crate) async fn timeout<T>(
+        timeout: Option<Duration>,
+        future: impl Future<Output = Result<T>>,
+    ) -> Result<T> {
+        let Some(timeout) = timeout else {
+            return future.await;
+        };
+        futures_util::pin_mut!(future);
+        match select(future, Box::pin(sleep(timeout))).await {
+            Either::Left((output, _)) => output,
+            Either::Right(_) => Err(Error::timed_out()),
+        }
+    }
+}
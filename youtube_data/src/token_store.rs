@@ -0,0 +1,167 @@
+//! Storage of the [TokenSet] issued by the OAuth 2.0 flows, so the refresh token can be reused between runs.
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::error::Error;
+use crate::{error::Result, oauth::TokenSet};
+
+use async_trait::async_trait;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+/// A storage of tokens, used by [OAuthClient::load_token](crate::oauth::OAuthClient::load_token). Implement it
+/// to keep the tokens in a keyring, a database, etc.
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait TokenStore: Send + Sync {
+    /// Returns the stored tokens, or `None` if nothing was saved yet.
+    async fn load(&self) -> Result<Option<TokenSet>>;
+
+    /// Stores the tokens, replacing the stored ones.
+    async fn save(&self, token: &TokenSet) -> Result<()>;
+
+    /// Called with the new tokens after the access token was refreshed. Saves them by default.
+    async fn on_refresh(&self, token: &TokenSet) -> Result<()> {
+        self.save(token).await
+    }
+}
+
+/// Keeps the tokens in memory for the lifetime of the process.
+#[derive(Default)]
+pub struct MemoryTokenStore {
+    token: Mutex<Option<TokenSet>>,
+}
+
+impl MemoryTokenStore {
+    pub fn new() -> MemoryTokenStore {
+        MemoryTokenStore::default()
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl TokenStore for MemoryTokenStore {
+    async fn load(&self) -> Result<Option<TokenSet>> {
+        Ok(self.token.lock().unwrap().clone())
+    }
+
+    async fn save(&self, token: &TokenSet) -> Result<()> {
+        *self.token.lock().unwrap() = Some(token.clone());
+        Ok(())
+    }
+}
+
+/// Keeps the tokens in a JSON file. On unix, the file is only readable by its owner. Not available on wasm32.
+#[cfg(not(target_arch = "wasm32"))]
+pub struct FileTokenStore {
+    path: PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileTokenStore {
+    pub fn new(path: impl Into<PathBuf>) -> FileTokenStore {
+        FileTokenStore { path: path.into() }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[async_trait]
+impl TokenStore for FileTokenStore {
+    async fn load(&self) -> Result<Option<TokenSet>> {
+        let json = match tokio::fs::read(&self.path).await {
+            Ok(json) => json,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(Error::token_store_error(e)),
+        };
+        serde_json::from_slice(&json)
+            .map(Some)
+            .map_err(Error::token_store_error)
+    }
+
+    async fn save(&self, token: &TokenSet) -> Result<()> {
+        let json = serde_json::to_vec_pretty(token).map_err(Error::token_store_error)?;
+        if let Some(parent) = self.path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(Error::token_store_error)?;
+        }
+
+        let mut options = tokio::fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        options.mode(0o600);
+        let mut file = options
+            .open(&self.path)
+            .await
+            .map_err(Error::token_store_error)?;
+        tokio::io::AsyncWriteExt::write_all(&mut file, &json)
+            .await
+            .map_err(Error::token_store_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use chrono::{Duration, Utc};
+
+    fn token_set() -> TokenSet {
+        TokenSet {
+            access_token: "access-token".to_string(),
+            refresh_token: Some("refresh-token".to_string()),
+            expires_at: Utc::now() + Duration::hours(1),
+            scope: Some("https://www.googleapis.com/auth/youtube".to_string()),
+            token_type: "Bearer".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_memory_token_store() {
+        let store = MemoryTokenStore::new();
+        assert_eq!(store.load().await.unwrap(), None);
+
+        let token = token_set();
+        store.save(&token).await.unwrap();
+        assert_eq!(store.load().await.unwrap(), Some(token));
+    }
+
+    #[tokio::test]
+    async fn test_file_token_store() {
+        let path = std::env::temp_dir()
+            .join(format!("youtube_data_token_store_{}", std::process::id()))
+            .join("token.json");
+        let store = FileTokenStore::new(&path);
+        assert_eq!(store.load().await.unwrap(), None);
+
+        let token = token_set();
+        store.save(&token).await.unwrap();
+        assert_eq!(store.load().await.unwrap(), Some(token));
+
+        tokio::fs::remove_dir_all(path.parent().unwrap())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_load_expired_token_without_refresh_token() {
+        let store = MemoryTokenStore::new();
+        store
+            .save(&TokenSet {
+                refresh_token: None,
+                expires_at: Utc::now() - Duration::hours(1),
+                ..token_set()
+            })
+            .await
+            .unwrap();
+        let expired = crate::oauth::OAuthClient::new("client-id", None)
+            .load_token(&store)
+            .await;
+        assert!(expired.is_err());
+        let err = expired.unwrap_err();
+        assert_eq!(
+            "oauth error: \"invalid_grant\" description: \"The access token expired and there is no refresh token\"",
+            format!("{}", err)
+        );
+    }
+}
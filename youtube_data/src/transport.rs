@@ -0,0 +1,512 @@
+//! The HTTP layer of the [YouTube](crate::YouTube) handle. Every request of every service is executed by an
+//! [HttpTransport], by default a reqwest client; [MockTransport] serves canned JSON instead, so tests run without
+//! network access or api key. [RecordingTransport] saves the real responses as fixtures, which [ReplayTransport]
+//! serves back for deterministic test suites. A [CaptureSink] receives the raw request and response bodies, to debug
+//! responses that fail to deserialize.
+//!
+//! On wasm32 only the reqwest client and custom transports are available: a response can not be built there, and
+//! there is no file system for the fixtures.
+
+use crate::error::Result;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::error::{replace_sensitive_query_params, Error};
+
+use async_trait::async_trait;
+use reqwest::Client;
+#[cfg(not(target_arch = "wasm32"))]
+use reqwest::{header::HeaderMap, Method, ResponseBuilderExt, StatusCode, Url};
+#[cfg(not(target_arch = "wasm32"))]
+use serde::{Deserialize, Serialize};
+#[cfg(not(target_arch = "wasm32"))]
+use sha2::{Digest, Sha256};
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::PathBuf;
+use std::sync::Arc;
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::Mutex;
+
+/// Executes the requests built by the services, once the credentials and the client-wide configuration are attached.
+/// Retries, rate limits and the status code handling are applied around the transport.
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait HttpTransport: Send + Sync {
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response>;
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl<T> HttpTransport for Arc<T>
+where
+    T: HttpTransport + ?Sized,
+{
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+        (**self).execute(request).await
+    }
+}
+
+/// Sends the requests with a reqwest client. The default transport.
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    client: Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(client: Client) -> ReqwestTransport {
+        ReqwestTransport { client }
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl HttpTransport for ReqwestTransport {
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+        Ok(self.client.execute(request).await?)
+    }
+}
+
+/// Answers the requests with canned responses instead of calling the api. A request is answered by the first
+/// response registered for its method and api path, or with a `404 Not Found` error of the api.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Default)]
+pub struct MockTransport {
+    responses: Vec<MockResponse>,
+    requests: Mutex<Vec<(Method, Url)>>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug)]
+struct MockResponse {
+    method: Method,
+    api_path: String,
+    status: u16,
+    body: String,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl MockTransport {
+    pub fn new() -> MockTransport {
+        MockTransport::default()
+    }
+
+    /// Answers the requests to `api_path` (e.g. `videos` or `videos/rate`) with `status` and the JSON `body`.
+    pub fn respond(
+        mut self,
+        method: Method,
+        api_path: impl Into<String>,
+        status: u16,
+        body: impl Into<String>,
+    ) -> MockTransport {
+        self.responses.push(MockResponse {
+            method,
+            api_path: api_path.into(),
+            status,
+            body: body.into(),
+        });
+        self
+    }
+
+    /// The method and URL of every request received so far, including the query parameters.
+    pub fn requests(&self) -> Vec<(Method, Url)> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[async_trait]
+impl HttpTransport for MockTransport {
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+        self.requests
+            .lock()
+            .unwrap()
+            .push((request.method().clone(), request.url().clone()));
+
+        let path = request.url().path();
+        let response = self.responses.iter().find(|response| {
+            response.method == request.method()
+                && path
+                    .strip_suffix(response.api_path.as_str())
+                    .is_some_and(|rest| rest.ends_with('/'))
+        });
+        let (status, body) = match response {
+            Some(response) => (response.status, response.body.clone()),
+            None => (
+                404,
+                format!(
+                    r#"{{"error":{{"code":404,"message":"No mock response for {} {}","errors":[]}}}}"#,
+                    request.method(),
+                    path
+                ),
+            ),
+        };
+        build_json_response(
+            http02::Response::builder().url(request.url().clone()),
+            status,
+            body,
+        )
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+/// Executes the requests with another transport and saves every response as a JSON fixture in a directory, one file
+/// per method and URL. The api key is not part of the fixtures, neither are the headers of the request, e.g. the
+/// access token.
+pub struct RecordingTransport {
+    transport: Box<dyn HttpTransport>,
+    directory: PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl RecordingTransport {
+    pub fn new(
+        transport: impl HttpTransport + 'static,
+        directory: impl Into<PathBuf>,
+    ) -> RecordingTransport {
+        RecordingTransport {
+            transport: Box::new(transport),
+            directory: directory.into(),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[async_trait]
+impl HttpTransport for RecordingTransport {
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+        let method = request.method().clone();
+        let url = request.url().clone();
+        let response = self.transport.execute(request).await?;
+        let status = response.status().as_u16();
+        let body = response.bytes().await?;
+
+        let fixture = Fixture::new(&method, &url, status, &body);
+        let path = self.directory.join(Fixture::file_name(&method, &url));
+        let json = serde_json::to_string_pretty(&fixture).map_err(|e| {
+            Error::invalid_parameter(format!("The fixture could not be serialized: {}", e))
+        })?;
+        let written = async {
+            tokio::fs::create_dir_all(&self.directory).await?;
+            tokio::fs::write(&path, json).await
+        };
+        written.await.map_err(|e| {
+            Error::invalid_parameter(format!(
+                "The fixture `{}` could not be written: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        json_response(status, body.to_vec())
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+/// Answers the requests with the fixtures saved by a [RecordingTransport], or with a `404 Not Found` error of the
+/// api when no fixture was recorded for the method and URL.
+#[derive(Debug, Clone)]
+pub struct ReplayTransport {
+    directory: PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl ReplayTransport {
+    pub fn new(directory: impl Into<PathBuf>) -> ReplayTransport {
+        ReplayTransport {
+            directory: directory.into(),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[async_trait]
+impl HttpTransport for ReplayTransport {
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+        let path = self
+            .directory
+            .join(Fixture::file_name(request.method(), request.url()));
+        let fixture = match tokio::fs::read_to_string(&path).await {
+            Ok(json) => serde_json::from_str::<Fixture>(&json).map_err(|e| {
+                Error::invalid_parameter(format!(
+                    "The fixture `{}` is not valid: {}",
+                    path.display(),
+                    e
+                ))
+            })?,
+            Err(_) => {
+                return json_response(
+                    404,
+                    format!(
+                        r#"{{"error":{{"code":404,"message":"No fixture for {} {}","errors":[]}}}}"#,
+                        request.method(),
+                        request.url().path()
+                    ),
+                );
+            }
+        };
+        let body = match fixture.body {
+            serde_json::Value::String(text) => text,
+            json => json.to_string(),
+        };
+        build_json_response(
+            http02::Response::builder().url(request.url().clone()),
+            fixture.status,
+            body,
+        )
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+/// A recorded response. JSON bodies are saved as JSON to keep the fixtures readable, other bodies as text.
+#[derive(Debug, Serialize, Deserialize)]
+struct Fixture {
+    method: String,
+    /// The path and the sorted query parameters, with the api key replaced.
+    url: String,
+    status: u16,
+    body: serde_json::Value,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Fixture {
+    fn new(method: &Method, url: &Url, status: u16, body: &[u8]) -> Fixture {
+        Fixture {
+            method: method.to_string(),
+            url: Fixture::redacted_url(url),
+            status,
+            body: serde_json::from_slice(body).unwrap_or_else(|_| {
+                serde_json::Value::String(String::from_utf8_lossy(body).into_owned())
+            }),
+        }
+    }
+
+    fn redacted_url(url: &Url) -> String {
+        replace_sensitive_query_params(Some(url.clone())).unwrap_or_default()
+    }
+
+    /// `<method>-<last path segment>-<hash of the method and redacted URL>.json`, e.g. `GET-videos-1f0c….json`.
+    fn file_name(method: &Method, url: &Url) -> String {
+        let hash = Sha256::digest(format!("{} {}", method, Fixture::redacted_url(url)));
+        let hash = hash
+            .iter()
+            .take(8)
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<String>();
+        let resource = url.path().rsplit('/').next().unwrap_or_default();
+        format!("{}-{}-{}.json", method, resource, hash)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+/// One request and its response, as received by a [CaptureSink].
+#[derive(Debug, Clone, Serialize)]
+pub struct CapturedExchange {
+    pub method: String,
+
+    /// The path and the sorted query parameters, with the api key replaced.
+    pub url: String,
+
+    /// The body of the request, `None` when the request has no body or streams it, e.g. an upload.
+    pub request_body: Option<String>,
+
+    pub status: u16,
+
+    pub response_body: String,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+/// Receives the raw bodies of every request of a handle, see
+/// [YouTubeBuilder::capture](crate::client::YouTubeBuilder::capture). Closures taking a [CapturedExchange] are sinks,
+/// e.g. `|exchange: &CapturedExchange| eprintln!("{}", serde_json::to_string(exchange).unwrap())`.
+pub trait CaptureSink: Send + Sync {
+    fn capture(&self, exchange: &CapturedExchange);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<F> CaptureSink for F
+where
+    F: Fn(&CapturedExchange) + Send + Sync,
+{
+    fn capture(&self, exchange: &CapturedExchange) {
+        self(exchange)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+/// Passes every request and response through to a [CaptureSink] before the response is returned.
+pub(crate) struct CapturingTransport {
+    transport: Arc<dyn HttpTransport>,
+    sink: Arc<dyn CaptureSink>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl CapturingTransport {
+    pub(crate) fn new(
+        transport: Arc<dyn HttpTransport>,
+        sink: Arc<dyn CaptureSink>,
+    ) -> CapturingTransport {
+        CapturingTransport { transport, sink }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[async_trait]
+impl HttpTransport for CapturingTransport {
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+        let method = request.method().to_string();
+        let url = request.url().clone();
+        let request_body = request
+            .body()
+            .and_then(|body| body.as_bytes())
+            .map(|body| String::from_utf8_lossy(body).into_owned());
+        let response = self.transport.execute(request).await?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let response_url = response.url().clone();
+        let body = response.bytes().await?;
+
+        self.sink.capture(&CapturedExchange {
+            method,
+            url: Fixture::redacted_url(&url),
+            request_body,
+            status: status.as_u16(),
+            response_body: String::from_utf8_lossy(&body).into_owned(),
+        });
+        rebuild_response(status, headers, response_url, body.to_vec())
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+/// A response whose body was read, with the status code, the headers and the URL of the original response.
+fn rebuild_response(
+    status: StatusCode,
+    headers: HeaderMap,
+    url: Url,
+    body: Vec<u8>,
+) -> Result<reqwest::Response> {
+    let mut response = http02::Response::builder()
+        .status(status)
+        .url(url)
+        .body(reqwest::Body::from(body))
+        .map_err(|_| {
+            Error::invalid_parameter(format!("`{}` is not a valid status code", status))
+        })?;
+    *response.headers_mut() = headers;
+    Ok(reqwest::Response::from(response))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+/// A response with a JSON body, e.g. for a custom [HttpTransport].
+pub fn json_response(status: u16, body: impl Into<reqwest::Body>) -> Result<reqwest::Response> {
+    build_json_response(http02::Response::builder(), status, body)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+/// A response with a JSON body from `builder`, e.g. one with the URL of the request, which the error messages show.
+fn build_json_response(
+    builder: http02::response::Builder,
+    status: u16,
+    body: impl Into<reqwest::Body>,
+) -> Result<reqwest::Response> {
+    let response = builder
+        .status(status)
+        .header("content-type", "application/json; charset=UTF-8")
+        .body(body.into())
+        .map_err(|_| {
+            Error::invalid_parameter(format!("`{}` is not a valid status code", status))
+        })?;
+    Ok(reqwest::Response::from(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_record_and_replay() {
+        let directory =
+            std::env::temp_dir().join(format!("youtube_data-{}", rand::random::<u64>()));
+        let request = || {
+            Client::new()
+                .get("https://www.googleapis.com/youtube/v3/videos?part=id&id=video-id&key=api-key")
+                .build()
+                .unwrap()
+        };
+
+        let recording = RecordingTransport::new(
+            MockTransport::new().respond(
+                Method::GET,
+                "videos",
+                200,
+                r#"{"items":[{"id":"video-id"}]}"#,
+            ),
+            &directory,
+        );
+        let recorded = recording.execute(request()).await.unwrap();
+        assert_eq!(
+            recorded.text().await.unwrap(),
+            r#"{"items":[{"id":"video-id"}]}"#
+        );
+
+        let mut fixtures = std::fs::read_dir(&directory).unwrap();
+        let fixture = std::fs::read_to_string(fixtures.next().unwrap().unwrap().path()).unwrap();
+        assert!(fixtures.next().is_none());
+        assert!(!fixture.contains("api-key"));
+
+        let replay = ReplayTransport::new(&directory);
+        let replayed = replay.execute(request()).await.unwrap();
+        assert_eq!(replayed.status(), 200);
+        assert_eq!(
+            replayed.text().await.unwrap(),
+            r#"{"items":[{"id":"video-id"}]}"#
+        );
+
+        let missing = Client::new()
+            .get("https://www.googleapis.com/youtube/v3/channels?part=id")
+            .build()
+            .unwrap();
+        assert_eq!(replay.execute(missing).await.unwrap().status(), 404);
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_capture() {
+        let captured = Arc::new(Mutex::new(Vec::new()));
+        let sink = {
+            let captured = captured.clone();
+            move |exchange: &CapturedExchange| captured.lock().unwrap().push(exchange.clone())
+        };
+        let transport = CapturingTransport::new(
+            Arc::new(MockTransport::new().respond(
+                Method::POST,
+                "videos/rate",
+                400,
+                r#"{"error":{"code":400,"message":"Invalid rating","errors":[]}}"#,
+            )),
+            Arc::new(sink),
+        );
+        let request = Client::new()
+            .post("https://www.googleapis.com/youtube/v3/videos/rate?id=video-id&key=api-key")
+            .body(r#"{"rating":"awesome"}"#)
+            .build()
+            .unwrap();
+
+        let response = transport.execute(request).await.unwrap();
+        assert_eq!(response.status(), 400);
+        assert_eq!(
+            response.text().await.unwrap(),
+            r#"{"error":{"code":400,"message":"Invalid rating","errors":[]}}"#
+        );
+
+        let captured = captured.lock().unwrap();
+        assert_eq!(captured.len(), 1);
+        assert_eq!(captured[0].method, "POST");
+        assert_eq!(
+            captured[0].url,
+            "/youtube/v3/videos/rate?id=video-id&key=[API_KEY]"
+        );
+        assert_eq!(
+            captured[0].request_body.as_deref(),
+            Some(r#"{"rating":"awesome"}"#)
+        );
+        assert!(captured[0].response_body.contains("Invalid rating"));
+    }
+}
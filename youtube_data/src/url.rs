@@ -0,0 +1,232 @@
+//! Extracts the ids of videos, channels and playlists from the links of YouTube, e.g. the links pasted by users:
+//!
+//! ```ignore
+//! match "https://youtu.be/Ks-_Mh1QhMc?t=42".parse::<YouTubeLink>()? {
+//!     YouTubeLink::Video(id) => youtube.videos().get(id).await?,
+//!     ...
+//! }
+//! ```
+
+use crate::error::{Error, Result};
+
+use reqwest::Url;
+use std::fmt::Display;
+
+/// The resource that a link of YouTube points to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum YouTubeLink {
+    /// The id of a video, from `watch?v=`, `youtu.be/`, `/shorts/`, `/live/`, `/embed/` or `/v/` links. A video
+    /// watched in a playlist (`watch?v=...&list=...`) is a video.
+    Video(String),
+
+    /// The id of a channel, from `/channel/UC...` links.
+    Channel(String),
+
+    /// The handle of a channel, from `/@handle` links, with its `@`. The `for_handle` filter of `channels.list` finds
+    /// the channel.
+    Handle(String),
+
+    /// The legacy username of a channel, from `/user/name` links. The `for_username` filter of `channels.list` finds
+    /// the channel.
+    User(String),
+
+    /// The custom URL of a channel, from `/c/name` links. The api has no filter for custom URLs, see
+    /// [ChannelsService::by_url](crate::channels::ChannelsService::by_url).
+    Custom(String),
+
+    /// The id of a playlist, from `playlist?list=` links.
+    Playlist(String),
+}
+
+impl YouTubeLink {
+    /// Parses a link of youtube.com (including the `www.`, `m.` and `music.` hosts), youtube-nocookie.com or
+    /// youtu.be. The scheme can be omitted, e.g. `youtu.be/Ks-_Mh1QhMc`.
+    pub fn parse(link: &str) -> Result<YouTubeLink> {
+        let link = link.trim();
+        let url = match Url::parse(link) {
+            Ok(url) if url.has_host() => url,
+            _ => Url::parse(&format!("https://{}", link)).map_err(|_| invalid_link(link))?,
+        };
+        let host = url.host_str().unwrap_or_default().to_ascii_lowercase();
+        let host = host.strip_prefix("www.").unwrap_or(&host);
+        let segments = url
+            .path_segments()
+            .map(|segments| segments.filter(|s| !s.is_empty()).collect::<Vec<_>>())
+            .unwrap_or_default();
+        let query = |name: &str| {
+            url.query_pairs()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.into_owned())
+        };
+
+        let resource = match (host, segments.as_slice()) {
+            ("youtu.be", [id]) => Some(YouTubeLink::Video(id.to_string())),
+            ("youtube.com" | "m.youtube.com" | "music.youtube.com", segments) => match segments {
+                ["watch"] => query("v").map(YouTubeLink::Video),
+                ["playlist"] => query("list").map(YouTubeLink::Playlist),
+                ["shorts" | "live" | "embed" | "v", id] => Some(YouTubeLink::Video(id.to_string())),
+                ["channel", id, ..] => Some(YouTubeLink::Channel(id.to_string())),
+                ["user", name, ..] => Some(YouTubeLink::User(name.to_string())),
+                ["c", name, ..] => Some(YouTubeLink::Custom(name.to_string())),
+                [handle, ..] if handle.starts_with('@') => {
+                    Some(YouTubeLink::Handle(handle.to_string()))
+                }
+                _ => None,
+            },
+            ("youtube-nocookie.com", ["embed", id]) => Some(YouTubeLink::Video(id.to_string())),
+            _ => None,
+        };
+        resource
+            .filter(YouTubeLink::is_valid)
+            .ok_or_else(|| invalid_link(link))
+    }
+
+    /// The id of the video, the channel or the playlist, or the handle, username or custom URL of the channel.
+    pub fn id(&self) -> &str {
+        match self {
+            YouTubeLink::Video(id)
+            | YouTubeLink::Channel(id)
+            | YouTubeLink::Handle(id)
+            | YouTubeLink::User(id)
+            | YouTubeLink::Custom(id)
+            | YouTubeLink::Playlist(id) => id,
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        match self {
+            YouTubeLink::Video(id) => id.len() == 11 && is_id(id),
+            YouTubeLink::Channel(id) => is_channel_id(id),
+            YouTubeLink::Handle(handle) => handle.len() > 1 && !handle.contains('/'),
+            YouTubeLink::User(name) | YouTubeLink::Custom(name) => !name.contains('/'),
+            YouTubeLink::Playlist(id) => is_id(id),
+        }
+    }
+}
+
+impl std::str::FromStr for YouTubeLink {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        YouTubeLink::parse(s)
+    }
+}
+
+impl Display for YouTubeLink {
+    /// The canonical link of the resource.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            YouTubeLink::Video(id) => write!(f, "https://www.youtube.com/watch?v={}", id),
+            YouTubeLink::Channel(id) => write!(f, "https://www.youtube.com/channel/{}", id),
+            YouTubeLink::Handle(handle) => write!(f, "https://www.youtube.com/{}", handle),
+            YouTubeLink::User(name) => write!(f, "https://www.youtube.com/user/{}", name),
+            YouTubeLink::Custom(name) => write!(f, "https://www.youtube.com/c/{}", name),
+            YouTubeLink::Playlist(id) => write!(f, "https://www.youtube.com/playlist?list={}", id),
+        }
+    }
+}
+
+/// Whether `id` has the form of a channel id, e.g. `UC_x5XG1OV2P6uZZ5FSM9Ttw`.
+pub(crate) fn is_channel_id(id: &str) -> bool {
+    id.len() == 24 && id.starts_with("UC") && is_id(id)
+}
+
+fn is_id(id: &str) -> bool {
+    !id.is_empty()
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+fn invalid_link(link: &str) -> Error {
+    Error::invalid_parameter(format!("`{}` is not a link to a YouTube resource", link))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::{BuilderErrorKind, ErrorKind};
+
+    #[test]
+    fn test_parse() {
+        let video = YouTubeLink::Video("Ks-_Mh1QhMc".to_string());
+        for link in [
+            "https://www.youtube.com/watch?v=Ks-_Mh1QhMc",
+            "https://www.youtube.com/watch?feature=share&v=Ks-_Mh1QhMc&list=PLOU2XLYxmsIIM9h1Ybw2DuRw6o2fkNMeR",
+            "http://m.youtube.com/watch?v=Ks-_Mh1QhMc",
+            "https://music.youtube.com/watch?v=Ks-_Mh1QhMc",
+            "youtube.com/watch?v=Ks-_Mh1QhMc",
+            "https://youtu.be/Ks-_Mh1QhMc?t=42",
+            "youtu.be/Ks-_Mh1QhMc",
+            "https://www.youtube.com/shorts/Ks-_Mh1QhMc",
+            "https://www.youtube.com/live/Ks-_Mh1QhMc?si=share",
+            "https://www.youtube.com/embed/Ks-_Mh1QhMc",
+            "https://www.youtube-nocookie.com/embed/Ks-_Mh1QhMc",
+            " https://www.youtube.com/watch?v=Ks-_Mh1QhMc\n",
+        ] {
+            assert_eq!(YouTubeLink::parse(link).unwrap(), video, "{}", link);
+        }
+
+        assert_eq!(
+            "https://www.youtube.com/channel/UCBR8-60-B28hp2BmDPdntcQ/videos"
+                .parse::<YouTubeLink>()
+                .unwrap(),
+            YouTubeLink::Channel("UCBR8-60-B28hp2BmDPdntcQ".to_string())
+        );
+        assert_eq!(
+            YouTubeLink::parse("https://www.youtube.com/@YouTube/featured").unwrap(),
+            YouTubeLink::Handle("@YouTube".to_string())
+        );
+        assert_eq!(
+            YouTubeLink::parse("https://www.youtube.com/user/GoogleDevelopers").unwrap(),
+            YouTubeLink::User("GoogleDevelopers".to_string())
+        );
+        assert_eq!(
+            YouTubeLink::parse("youtube.com/c/GoogleDevelopers/videos").unwrap(),
+            YouTubeLink::Custom("GoogleDevelopers".to_string())
+        );
+        assert_eq!(
+            YouTubeLink::parse(
+                "https://www.youtube.com/playlist?list=PLOU2XLYxmsIIM9h1Ybw2DuRw6o2fkNMeR"
+            )
+            .unwrap()
+            .id(),
+            "PLOU2XLYxmsIIM9h1Ybw2DuRw6o2fkNMeR"
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for link in [
+            "",
+            "https://www.youtube.com/",
+            "https://www.youtube.com/watch",
+            "https://www.youtube.com/watch?v=too-short",
+            "https://www.youtube.com/channel/not-a-channel",
+            "https://www.youtube.com/feed/subscriptions",
+            "https://example.com/watch?v=Ks-_Mh1QhMc",
+            "https://youtu.be/",
+            "https://www.youtube.com/user/",
+        ] {
+            let error = YouTubeLink::parse(link).unwrap_err();
+            assert!(
+                matches!(
+                    error.kind(),
+                    ErrorKind::BuilderError(BuilderErrorKind::InvalidParameter { .. })
+                ),
+                "{}",
+                link
+            );
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let link = YouTubeLink::parse("youtu.be/Ks-_Mh1QhMc").unwrap();
+        assert_eq!(
+            link.to_string(),
+            "https://www.youtube.com/watch?v=Ks-_Mh1QhMc"
+        );
+        assert_eq!(YouTubeLink::parse(&link.to_string()).unwrap(), link);
+    }
+}
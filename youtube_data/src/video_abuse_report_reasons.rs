@@ -0,0 +1,228 @@
+use crate::{
+    auth::{Authorizer, OAuthTokenAuth},
+    error::Result,
+    list_json, ListApi, RequestBase, YouTube, YouTubeDataApi,
+};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use youtube_data_derive::QueryBuilder;
+
+#[derive(Clone, Copy)]
+pub struct VideoAbuseReportReasonsService<'a> {
+    youtube: &'a YouTube,
+}
+
+impl<'a> VideoAbuseReportReasonsService<'a> {
+    pub(crate) fn new(youtube: &'a YouTube) -> Self {
+        Self { youtube }
+    }
+
+    pub fn list(self, part: impl IntoIterator<Item = Part>) -> VideoAbuseReportReasonList<'a> {
+        VideoAbuseReportReasonList::new(self, part)
+    }
+}
+
+/// Parameters for the `list` method of the `videoAbuseReportReasons` api. details:
+/// [link](https://developers.google.com/youtube/v3/docs/videoAbuseReportReasons/list)
+#[derive(QueryBuilder)]
+pub struct VideoAbuseReportReasonList<'a> {
+    service: VideoAbuseReportReasonsService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    #[query(unique)]
+    part: Vec<Part>,
+
+    // optional parameters
+    /// Selects the fields of the response, e.g. `items(id,snippet/title)`, so only the needed part of every
+    /// resource is returned. details: [link](https://developers.google.com/youtube/v3/getting-started#fields)
+    #[query]
+    fields: Option<String>,
+    /// Fails the request with a timeout error when it does not complete in time, instead of the timeout of the
+    /// `YouTube` handle.
+    #[setter]
+    timeout: Option<Duration>,
+    #[query(default)]
+    hl: Option<String>,
+}
+
+impl RequestBase for VideoAbuseReportReasonList<'_> {
+    fn api_path(&self) -> &str {
+        "videoAbuseReportReasons"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for VideoAbuseReportReasonList<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+
+    fn request_timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl ListApi<VideoAbuseReportReasonListResponse> for VideoAbuseReportReasonList<'_> {
+    async fn request(&self) -> Result<VideoAbuseReportReasonListResponse> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        self.insert_query(youtube, &mut params)?;
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .get(self.url(&youtube.inner().base_path))
+                .query(&params),
+        )?;
+        let response = self.send(youtube, request).await?;
+        list_json(response).await
+    }
+}
+
+impl<'a> VideoAbuseReportReasonList<'a> {
+    pub(crate) fn new(
+        service: VideoAbuseReportReasonsService<'a>,
+        part: impl IntoIterator<Item = Part>,
+    ) -> Self {
+        let part = crate::dedupe(part);
+        let part = if part.is_empty() {
+            vec![Part::Snippet]
+        } else {
+            part
+        };
+        Self {
+            service,
+            authorizer: None,
+            part,
+            fields: None,
+            timeout: None,
+            hl: None,
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    Id,
+    Snippet,
+}
+
+impl Part {
+    /// Every part of the resource, to request all of its properties.
+    pub fn all_public() -> Vec<Part> {
+        vec![Part::Id, Part::Snippet]
+    }
+}
+
+impl std::fmt::Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            Part::Id => "id",
+            Part::Snippet => "snippet",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VideoAbuseReportReasonListResponse {
+    /// Identifies the API resource's type. The value will be `youtube#videoAbuseReportReasonListResponse`.
+    #[serde(default)]
+    pub kind: String,
+
+    /// The ETag of the response.
+    #[serde(default)]
+    pub etag: String,
+
+    /// A list of valid abuse reasons that are used with `video.ReportAbuse`.
+    #[serde(default)]
+    pub items: Vec<VideoAbuseReportReasonListResource>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VideoAbuseReportReasonListResource {
+    /// Identifies the API resource's type. The value will be `youtube#videoAbuseReportReason`.
+    #[serde(default)]
+    pub kind: String,
+
+    /// The ETag of the response.
+    #[serde(default)]
+    pub etag: String,
+
+    /// The ID that YouTube uses to identify the reason. When calling the `videos.reportAbuse` method, your request
+    /// uses this value to identify the reason.
+    #[serde(default)]
+    pub id: String,
+
+    pub snippet: Option<VideoAbuseReportReasonSnippet>,
+
+    /// The fields of the resource that this version of the crate does not know, e.g. added to the api after its
+    /// release. They are serialized back with the resource.
+    #[serde(flatten, deserialize_with = "crate::serde_helpers::extra_fields")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// The snippet object contains basic details about the abuse report reason.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoAbuseReportReasonSnippet {
+    /// The localized label text for the abuse report reason.
+    #[serde(default)]
+    pub label: String,
+
+    /// A list of secondary reasons associated with the reason, if any are available. (There might be 0 or more.)
+    pub secondary_reasons: Option<Vec<VideoAbuseReportSecondaryReason>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VideoAbuseReportSecondaryReason {
+    /// The ID that YouTube uses to identify the secondary reason. When calling the `videos.reportAbuse` method,
+    /// your request uses this value to identify the secondary reason.
+    #[serde(default)]
+    pub id: String,
+
+    /// The localized label text for the secondary reason.
+    #[serde(default)]
+    pub label: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_youtube_without_user_agent;
+
+    #[tokio::test]
+    async fn test_request_without_auth() {
+        let without_auth = get_youtube_without_user_agent()
+            .video_abuse_report_reasons()
+            .list(vec![Part::Snippet])
+            .request()
+            .await;
+        assert!(without_auth.is_err());
+        let err = without_auth.unwrap_err();
+        assert_eq!(
+            "builder error: \"The request to `videoAbuseReportReasons` requires OAuth 2.0 authorization\"",
+            format!("{}", err)
+        );
+    }
+}
@@ -0,0 +1,458 @@
+use crate::{
+    auth::{Authorizer, OAuthTokenAuth},
+    error::Result,
+    list_json, ListApi, RequestBase, YouTube, YouTubeDataApi,
+};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use youtube_data_derive::QueryBuilder;
+
+#[derive(Clone, Copy)]
+pub struct VideoCategoriesService<'a> {
+    youtube: &'a YouTube,
+}
+
+impl<'a> VideoCategoriesService<'a> {
+    pub(crate) fn new(youtube: &'a YouTube) -> Self {
+        Self { youtube }
+    }
+
+    pub fn list(self, part: impl IntoIterator<Item = Part>) -> VideoCategoryList<'a> {
+        VideoCategoryList::new(self, part)
+    }
+}
+
+/// Parameters for the `list` method of the `videoCategories` api. details:
+/// [link](https://developers.google.com/youtube/v3/docs/videoCategories/list)
+#[derive(QueryBuilder)]
+pub struct VideoCategoryList<'a> {
+    service: VideoCategoriesService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    #[query(unique)]
+    part: Vec<Part>,
+
+    // filters (specify exactly one of the following parameters)
+    #[query(filter)]
+    id: Option<Vec<String>>,
+    #[query(filter)]
+    region_code: Option<String>,
+
+    // optional parameters
+    /// Selects the fields of the response, e.g. `items(id,snippet/title)`, so only the needed part of every
+    /// resource is returned. details: [link](https://developers.google.com/youtube/v3/getting-started#fields)
+    #[query]
+    fields: Option<String>,
+    /// Fails the request with a timeout error when it does not complete in time, instead of the timeout of the
+    /// `YouTube` handle.
+    #[setter]
+    timeout: Option<Duration>,
+    #[query(default)]
+    hl: Option<String>,
+}
+
+impl RequestBase for VideoCategoryList<'_> {
+    fn api_path(&self) -> &str {
+        "videoCategories"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for VideoCategoryList<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+
+    fn request_timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl ListApi<VideoCategoryListResponse> for VideoCategoryList<'_> {
+    async fn request(&self) -> Result<VideoCategoryListResponse> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        self.insert_query(youtube, &mut params)?;
+
+        let response = self
+            .send(
+                youtube,
+                youtube
+                    .inner()
+                    .client
+                    .get(self.url(&youtube.inner().base_path))
+                    .query(&params),
+            )
+            .await?;
+        list_json(response).await
+    }
+}
+
+impl<'a> VideoCategoryList<'a> {
+    pub(crate) fn new(
+        service: VideoCategoriesService<'a>,
+        part: impl IntoIterator<Item = Part>,
+    ) -> Self {
+        let part = crate::dedupe(part);
+        let part = if part.is_empty() {
+            vec![Part::Snippet]
+        } else {
+            part
+        };
+        Self {
+            service,
+            authorizer: None,
+            part,
+            id: None,
+            region_code: None,
+            fields: None,
+            timeout: None,
+            hl: None,
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    Snippet,
+}
+
+impl Part {
+    /// Every part of the resource, to request all of its properties.
+    pub fn all_public() -> Vec<Part> {
+        vec![Part::Snippet]
+    }
+}
+
+impl std::fmt::Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            Part::Snippet => "snippet",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+/// The `videoCategories.list` response is not paginated in practice, so it does not share the
+/// [ListResponse](crate::ListResponse) layout.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VideoCategoryListResponse {
+    /// Identifies the API resource's type. The value will be `youtube#videoCategoryListResponse`.
+    #[serde(default)]
+    pub kind: String,
+
+    /// The ETag of the response.
+    #[serde(default)]
+    pub etag: String,
+
+    /// A list of video categories that can be associated with YouTube videos.
+    #[serde(default)]
+    pub items: Vec<VideoCategoryListResource>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VideoCategoryListResource {
+    /// Identifies the API resource's type. The value will be `youtube#videoCategory`.
+    #[serde(default)]
+    pub kind: String,
+
+    /// The ETag of the response.
+    #[serde(default)]
+    pub etag: String,
+
+    /// The ID that YouTube uses to uniquely identify the video category.
+    #[serde(default)]
+    pub id: String,
+
+    pub snippet: Option<VideoCategorySnippet>,
+
+    /// The fields of the resource that this version of the crate does not know, e.g. added to the api after its
+    /// release. They are serialized back with the resource.
+    #[serde(flatten, deserialize_with = "crate::serde_helpers::extra_fields")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// The snippet object contains basic details about the video category, including its title.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoCategorySnippet {
+    /// The YouTube channel that created the video category.
+    #[serde(default)]
+    pub channel_id: String,
+
+    /// The video category's title.
+    #[serde(default)]
+    pub title: String,
+
+    /// Indicates whether videos can be associated with the category.
+    #[serde(default)]
+    pub assignable: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorKind;
+    use crate::transport::MockTransport;
+    use crate::{get_youtube_with_transport, get_youtube_without_user_agent};
+    use reqwest::Method;
+
+    #[tokio::test]
+    async fn test_get_by_region_code() {
+        let transport = Arc::new(MockTransport::new().respond(
+            Method::GET,
+            "videoCategories",
+            200,
+            r#"{
+                "kind": "youtube#videoCategoryListResponse",
+                "items": [
+                    {
+                        "kind": "youtube#videoCategory",
+                        "id": "1",
+                        "snippet": { "title": "Film & Animation", "assignable": true, "channelId": "UCBR8-60-B28hp2BmDPdntcQ" }
+                    },
+                    {
+                        "kind": "youtube#videoCategory",
+                        "id": "18",
+                        "snippet": { "title": "Short Movies", "assignable": false, "channelId": "UCBR8-60-B28hp2BmDPdntcQ" }
+                    }
+                ]
+            }"#,
+        ));
+        let response = get_youtube_with_transport(transport.clone())
+            .video_categories()
+            .list(vec![Part::Snippet])
+            .region_code("US")
+            .request()
+            .await
+            .unwrap();
+        assert_eq!(
+            response
+                .items
+                .iter()
+                .map(|item| {
+                    let snippet = item.snippet.as_ref().unwrap();
+                    (item.id.as_str(), snippet.title.as_str(), snippet.assignable)
+                })
+                .collect::<Vec<_>>(),
+            vec![
+                ("1", "Film & Animation", true),
+                ("18", "Short Movies", false)
+            ]
+        );
+        assert!(transport.requests()[0]
+            .1
+            .query()
+            .unwrap()
+            .contains("regionCode=US"));
+    }
+
+    #[tokio::test]
+    async fn test_get_by_id() {
+        let transport = Arc::new(MockTransport::new().respond(
+            Method::GET,
+            "videoCategories",
+            200,
+            r#"{
+                "kind": "youtube#videoCategoryListResponse",
+                "items": [
+                    {
+                        "kind": "youtube#videoCategory",
+                        "id": "10",
+                        "snippet": { "title": "Music", "assignable": true, "channelId": "UCBR8-60-B28hp2BmDPdntcQ" }
+                    }
+                ]
+            }"#,
+        ));
+        let response = get_youtube_with_transport(transport.clone())
+            .video_categories()
+            .list(vec![Part::Snippet])
+            .id(vec!["10"])
+            .hl("en_US")
+            .request()
+            .await
+            .unwrap();
+        assert_eq!(response.items.len(), 1);
+        let snippet = response.items[0].snippet.as_ref().unwrap();
+        assert_eq!(snippet.title, "Music");
+        assert_eq!(snippet.channel_id, "UCBR8-60-B28hp2BmDPdntcQ");
+        assert!(snippet.assignable);
+        let query = transport.requests()[0].1.query().unwrap().to_string();
+        assert!(query.contains("id=10"));
+        assert!(query.contains("hl=en_US"));
+    }
+
+    #[tokio::test]
+    async fn test_get_by_id_with_mock_transport() {
+        let transport = Arc::new(MockTransport::new().respond(
+            Method::GET,
+            "videoCategories",
+            200,
+            r#"{
+                "kind": "youtube#videoCategoryListResponse",
+                "etag": "etag",
+                "items": [
+                    {
+                        "kind": "youtube#videoCategory",
+                        "etag": "etag",
+                        "id": "10",
+                        "snippet": { "title": "Music", "assignable": true, "channelId": "UCBR8-60-B28hp2BmDPdntcQ" }
+                    }
+                ]
+            }"#,
+        ));
+        let response = get_youtube_with_transport(transport.clone())
+            .video_categories()
+            .list(vec![Part::Snippet])
+            .id(vec!["10"])
+            .request()
+            .await
+            .unwrap();
+        assert_eq!(response.items[0].snippet.as_ref().unwrap().title, "Music");
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 1);
+        let query = requests[0].1.query().unwrap();
+        assert!(query.contains("id=10"));
+        assert!(query.contains("key=api-key"));
+    }
+
+    #[tokio::test]
+    async fn test_list_quota_exceeded() {
+        let transport = MockTransport::new().respond(
+            Method::GET,
+            "videoCategories",
+            403,
+            r#"{
+                "error": {
+                    "code": 403,
+                    "message": "The request cannot be completed because you have exceeded your quota.",
+                    "errors": [
+                        { "message": "The request cannot be completed because you have exceeded your quota.", "domain": "youtube.quota", "reason": "quotaExceeded" }
+                    ]
+                }
+            }"#,
+        );
+        let error = get_youtube_with_transport(transport)
+            .video_categories()
+            .list(vec![Part::Snippet])
+            .id(vec!["10"])
+            .request()
+            .await
+            .unwrap_err();
+        assert!(error.is_quota_exceeded());
+        assert!(error.is_forbidden());
+        assert!(!error.is_rate_limited());
+        assert!(!error.is_not_found());
+        assert_eq!(error.retry_after(), None);
+    }
+
+    #[tokio::test]
+    async fn test_list_server_error() {
+        let transport = MockTransport::new().respond(
+            Method::GET,
+            "videoCategories",
+            502,
+            "<html><body>Bad Gateway</body></html>",
+        );
+        let error = get_youtube_with_transport(transport)
+            .video_categories()
+            .list(vec![Part::Snippet])
+            .id(vec!["10"])
+            .request()
+            .await
+            .unwrap_err();
+        assert_eq!(error.kind(), &ErrorKind::ServerError);
+        assert_eq!(error.status(), Some(http::StatusCode::BAD_GATEWAY));
+        assert!(error.to_string().contains("Bad Gateway"));
+
+        let transport = MockTransport::new().respond(Method::GET, "videoCategories", 302, "");
+        let error = get_youtube_with_transport(transport)
+            .video_categories()
+            .list(vec![Part::Snippet])
+            .id(vec!["10"])
+            .request()
+            .await
+            .unwrap_err();
+        assert_eq!(error.status(), Some(http::StatusCode::FOUND));
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_get_by_id_blocking() {
+        let transport = MockTransport::new().respond(
+            Method::GET,
+            "videoCategories",
+            200,
+            r#"{"items": [{ "id": "10", "snippet": { "title": "Music" } }]}"#,
+        );
+        let youtube = crate::blocking::YouTube::builder("api-key")
+            .configure(|builder| builder.transport(transport))
+            .build()
+            .unwrap();
+        let mut list = youtube.video_categories().list(vec![Part::Snippet]);
+        list.id(vec!["10"]);
+        let response = list.request().unwrap();
+        assert_eq!(response.items[0].snippet.as_ref().unwrap().title, "Music");
+    }
+
+    #[tokio::test]
+    async fn test_list_batch() {
+        let batch = get_youtube_with_transport(crate::batch::tests::EchoBatchTransport)
+            .batch()
+            .unwrap();
+        let mut music = batch.youtube().video_categories().list(vec![Part::Snippet]);
+        music.id(vec!["10"]);
+        let mut sports = batch.youtube().video_categories().list(vec![Part::Snippet]);
+        sports.id(vec!["17"]);
+        let no_filter = batch.youtube().video_categories().list(vec![Part::Snippet]);
+
+        let results = batch
+            .run([music.request(), sports.request(), no_filter.request()])
+            .await;
+        assert_eq!(results.len(), 3);
+        let music = results[0].as_ref().unwrap();
+        assert!(music.items[0]
+            .id
+            .starts_with("GET /youtube/v3/videoCategories?"));
+        assert!(music.items[0].id.contains("id=10"));
+        let sports = results[1].as_ref().unwrap();
+        assert!(sports.items[0].id.contains("id=17"));
+        assert!(results[2].is_err());
+    }
+
+    #[tokio::test]
+    async fn test_request_multiple_filters() {
+        let multiple_filters = get_youtube_without_user_agent()
+            .video_categories()
+            .list(vec![])
+            .id(vec!["10"])
+            .region_code("US")
+            .request()
+            .await;
+        assert!(multiple_filters.is_err());
+        let err = multiple_filters.unwrap_err();
+        assert_eq!(
+            "builder error: \"Incompatible parameters specified in the request: id, region_code\"",
+            format!("{}", err)
+        );
+    }
+}
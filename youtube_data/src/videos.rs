@@ -1,51 +1,166 @@
 use crate::{
+    auth::{Authorizer, OAuthTokenAuth},
+    cursor::PageCursor,
     error::{Error, Result},
-    ListApi, ListResponse, Localization, RequestBase, Thumbnail, ThumbnailKind, YouTube,
-    YouTubeDataApi,
+    i18n_regions::RegionCode,
+    json, list_json, poll_pages,
+    search::SearchItem,
+    serde_helpers::{option_string_or_number, string_enum, string_or_number},
+    timer,
+    video_abuse_report_reasons::VideoAbuseReportReasonListResponse,
+    BoxStream, ListApi, ListResponse, LiveBroadcastContent, Localization, PageInfo, PagedListApi,
+    PrivacyStatus, RequestBase, Thumbnails, YouTube, YouTubeDataApi, IF_NONE_MATCH,
 };
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use futures_util::{future::join_all, stream};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use youtube_data_derive::QueryBuilder;
 
 pub type VideoListResponse = ListResponse<VideoListResource>;
 
-#[derive(Clone)]
-pub(crate) struct VideosService {
-    youtube: Box<YouTube>,
+/// The most ids accepted by one `videos.list` request.
+pub(crate) const MAX_IDS_PER_REQUEST: usize = 50;
+
+#[derive(Clone, Copy)]
+pub struct VideosService<'a> {
+    youtube: &'a YouTube,
 }
 
-impl VideosService {
-    pub fn new(youtube: Box<YouTube>) -> Self {
+impl<'a> VideosService<'a> {
+    pub(crate) fn new(youtube: &'a YouTube) -> Self {
         Self { youtube }
     }
 
-    pub fn list(&self, part: Vec<Part>) -> VideoList {
-        VideoList::new(&self, part)
+    pub fn list(self, part: impl IntoIterator<Item = Part>, filter: VideoFilter) -> VideoList<'a> {
+        VideoList::new(self, part, filter)
+    }
+
+    /// Fetches one video with its `snippet`, `statistics` and `contentDetails` parts, or `None` when no video has
+    /// the id, e.g. because it was deleted or is private.
+    pub async fn get(self, id: impl Into<String>) -> Result<Option<VideoListResource>> {
+        let response = self
+            .list(
+                [Part::Snippet, Part::Statistics, Part::ContentDetails],
+                VideoFilter::id([id]),
+            )
+            .request()
+            .await?;
+        Ok(response.items.into_iter().next())
+    }
+
+    /// The most popular videos of the region of `region_code`, and of the video category of `video_category_id` or
+    /// of every category for `None`, with their `snippet`, `statistics` and `contentDetails` parts. The pages of 50
+    /// videos are followed by [items](PagedListApi::items).
+    pub fn most_popular(
+        self,
+        region_code: RegionCode,
+        video_category_id: Option<String>,
+    ) -> VideoList<'a> {
+        let mut list = self.list(
+            [Part::Snippet, Part::Statistics, Part::ContentDetails],
+            VideoFilter::Chart(Chart::MostPopular),
+        );
+        list.region_code(region_code).max_results(50);
+        if let Some(video_category_id) = video_category_id {
+            list.video_category_id(video_category_id);
+        }
+        list
+    }
+
+    /// Fetches the videos of search results with their `snippet`, `statistics` and `contentDetails` parts, e.g. the
+    /// duration and view count that search results lack, in the order of the results. The results that are not
+    /// videos are skipped, as are the videos that no longer exist. The ids are requested in batches of 50, a video of
+    /// several results only once, and is returned for each of its results.
+    pub async fn hydrate<T: SearchItem>(self, results: &[T]) -> Result<Vec<VideoListResource>> {
+        let id = results
+            .iter()
+            .filter_map(SearchItem::video_id)
+            .collect::<Vec<&str>>();
+        if id.is_empty() {
+            return Ok(Vec::new());
+        }
+        let videos = self
+            .list(
+                [Part::Snippet, Part::Statistics, Part::ContentDetails],
+                VideoFilter::id(crate::dedupe(id.iter().copied())),
+            )
+            .concurrent_chunks(true)
+            .request()
+            .await?
+            .items
+            .into_iter()
+            .map(|video| (video.id.clone(), video))
+            .collect::<HashMap<String, VideoListResource>>();
+        Ok(id
+            .into_iter()
+            .filter_map(|id| videos.get(id).cloned())
+            .collect())
+    }
+
+    pub fn get_rating(self, id: impl IntoIterator<Item = impl Into<String>>) -> VideoGetRating<'a> {
+        VideoGetRating::new(self, id)
+    }
+
+    pub fn rate(self, id: impl Into<String>, rating: Rating) -> VideoRate<'a> {
+        VideoRate::new(self, id, rating)
+    }
+
+    pub fn report_abuse(
+        self,
+        video_id: impl Into<String>,
+        reason_id: impl Into<String>,
+    ) -> VideoReportAbuse<'a> {
+        VideoReportAbuse::new(self, video_id, reason_id)
     }
 }
 
-struct VideoList<'a> {
-    service: &'a VideosService,
+/// Parameters for the `list` method of the `videos` api. details:
+/// [link](https://developers.google.com/youtube/v3/docs/videos/list)
+#[derive(QueryBuilder)]
+pub struct VideoList<'a> {
+    service: VideosService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
 
     // required parameters
+    #[query(unique)]
     part: Vec<Part>,
 
-    // filters (specify exactly one of the following parameters)
-    chart: Option<Chart>,
-    id: Option<Vec<&'a str>>,
-    my_rating: Option<MyRating>,
+    // the filter, of which the api requires exactly one
+    filter: VideoFilter,
 
     // optional parameters
-    hl: Option<&'a str>,
+    /// Selects the fields of the response, e.g. `items(id,snippet/title)`, so only the needed part of every
+    /// resource is returned. details: [link](https://developers.google.com/youtube/v3/getting-started#fields)
+    #[query]
+    fields: Option<String>,
+    /// Fails the request with a timeout error when it does not complete in time, instead of the timeout of the
+    /// `YouTube` handle.
+    #[setter]
+    timeout: Option<Duration>,
+    #[query(default)]
+    hl: Option<String>,
+    #[query(min = 72, max = 4320)]
     max_height: Option<u32>,
+    #[query(max = 50)]
     max_results: Option<u32>,
+    #[query(min = 72, max = 8192)]
     max_width: Option<u32>,
-    on_behalf_of_content_owner: Option<&'a str>,
-    page_token: Option<&'a str>,
-    region_code: Option<&'a str>,
-    video_category_id: Option<&'a str>,
+    #[setter]
+    on_behalf_of_content_owner: Option<String>,
+    #[setter]
+    page_token: Option<String>,
+    #[setter]
+    region_code: Option<String>,
+    #[setter]
+    video_category_id: Option<String>,
+    /// Sends the chunks of more than 50 ids concurrently instead of one after another.
+    #[setter]
+    concurrent_chunks: bool,
 }
 
 impl RequestBase for VideoList<'_> {
@@ -54,105 +169,309 @@ impl RequestBase for VideoList<'_> {
     }
 }
 
-#[async_trait]
-impl YouTubeDataApi for VideoList<'_> {}
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for VideoList<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+
+    fn request_timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+}
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl ListApi<VideoListResponse> for VideoList<'_> {
     async fn request(&self) -> Result<VideoListResponse> {
+        self.request_page(None).await
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl PagedListApi<VideoListResource> for VideoList<'_> {
+    async fn request_page(&self, page_token: Option<&str>) -> Result<VideoListResponse> {
+        let chunks = self.chunks(page_token)?;
+        if let [params] = chunks.as_slice() {
+            return self.request_chunk(params).await;
+        }
+
+        // the api returns at most 50 videos: request the ids in chunks and merge the responses
+        let pages = chunks.iter().map(|params| self.request_chunk(params));
+        let pages = if self.concurrent_chunks {
+            join_all(pages)
+                .await
+                .into_iter()
+                .collect::<Result<Vec<VideoListResponse>>>()?
+        } else {
+            let mut responses = Vec::new();
+            for page in pages {
+                responses.push(page.await?);
+            }
+            responses
+        };
+        Ok(self.merge(pages))
+    }
+
+    fn cursor(&self, page_token: Option<&str>) -> Result<PageCursor> {
+        Ok(PageCursor::new(self.api_path(), self.params(page_token)?))
+    }
+
+    fn poll(&self, interval: Duration) -> BoxStream<'_, Result<VideoListResponse>>
+    where
+        Self: Sync,
+    {
+        poll_list(self, interval)
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl ChunkedList<VideoListResource> for VideoList<'_> {
+    fn chunks(&self, page_token: Option<&str>) -> Result<Vec<Vec<(String, String)>>> {
+        let mut params = self.params(page_token)?;
+        let id = match &self.filter {
+            VideoFilter::Id(id) if id.len() > MAX_IDS_PER_REQUEST => id,
+            _ => return Ok(vec![params.into_iter().collect()]),
+        };
+        params.remove("id");
+        Ok(id
+            .chunks(MAX_IDS_PER_REQUEST)
+            .map(|chunk| {
+                let mut params = params.clone();
+                self.insert_query_parameters(&mut params, "id", Some(&chunk.to_vec()));
+                params.into_iter().collect()
+            })
+            .collect())
+    }
+
+    async fn request_chunk(&self, params: &[(String, String)]) -> Result<VideoListResponse> {
         let youtube = &self.service.youtube;
+        let request = self.optional_bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .get(self.url(&youtube.inner().base_path))
+                .query(params),
+        )?;
+        let response = self.send(youtube, request).await?;
+        list_json(response).await
+    }
 
-        // createquery parameter map
-        let mut params = HashMap::<String, String>::new();
+    fn merge(&self, pages: Vec<VideoListResponse>) -> VideoListResponse {
+        match &self.filter {
+            VideoFilter::Id(id) => merge_pages(id, pages),
+            _ => merge_chunks(pages),
+        }
+    }
+}
 
-        // key
-        self.insert_query_parameter(&mut params, "key", Some(&youtube.api_key));
+impl VideoList<'_> {
+    /// The query parameters of the page of `page_token`, or of the `page_token` of the builder for `None`.
+    fn params(&self, page_token: Option<&str>) -> Result<HashMap<String, String>> {
+        let youtube = &self.service.youtube;
+        let page_token = page_token.or(self.page_token.as_deref());
 
-        // required parameters
-        self.insert_query_parameters(&mut params, "part", Some(&self.part));
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
 
-        // filter
-        let filters = vec![
-            self.chart.is_some(),
-            self.id.is_some(),
-            self.my_rating.is_some(),
-        ]
-        .into_iter()
-        .filter(|&x| x);
-        let count = filters.clone().count();
-        // filter must be specified exactly one
-        if count == 1 {
-            if let Some(chart) = &self.chart {
+        self.insert_query(youtube, &mut params)?;
+        match &self.filter {
+            VideoFilter::Chart(chart) => {
                 self.insert_query_parameter(&mut params, "chart", Some(chart));
-                self.insert_query_parameter(&mut params, "videoCategoryId", self.video_category_id);
             }
-            if let Some(id) = &self.id {
+            VideoFilter::Id(id) => {
                 if id.is_empty() {
                     return Err(Error::missing_required_parameter(
-                        "No filter selected. Expected one of: chart, id, my_rating",
+                        "The `id` filter requires at least one id",
                     ));
                 }
-                self.insert_query_parameters(&mut params, "id", Some(&id));
+                self.insert_query_parameters(&mut params, "id", Some(id));
             }
-            // TODO: check if the user is authenticated
-            if let Some(_my_rating) = &self.my_rating {
-                return Err(Error::authorization_required(
-                    "The request uses the `my_rating` parameter but is not properly authorized",
-                ));
-                // self.insert_query_parameter(&mut params, "myRating", Some(my_rating));
+            VideoFilter::MyRating(my_rating) => {
+                self.require_authorization(youtube, "my_rating")?;
+                self.insert_query_parameter(&mut params, "myRating", Some(my_rating));
             }
-        } else {
-            return if count > 1 {
-                let imcompatible_params = filters
-                    .into_iter()
-                    .enumerate()
-                    .filter(|(_, v)| *v)
-                    .map(|(i, _)| match i {
-                        0 => "chart",
-                        1 => "id",
-                        2 => "my_rating",
-                        _ => "",
-                    })
-                    .collect::<Vec<&str>>()
-                    .join(", ");
-                Err(Error::incompatible_parameters(format!(
-                    "Incompatible parameters specified in the request: {}",
-                    imcompatible_params,
-                )))
-            } else {
-                Err(Error::missing_required_parameter(
-                    "No filter selected. Expected one of: chart, id, my_rating",
-                ))
-            };
         }
-
-        // optional parameters
-        self.insert_query_parameter(&mut params, "hl", self.hl);
-        self.insert_query_parameter(&mut params, "maxHeight", self.max_height);
-        self.insert_query_parameter(&mut params, "maxResults", self.max_results);
-        self.insert_query_parameter(&mut params, "maxWidth", self.max_width);
-        if self.on_behalf_of_content_owner.is_some() {
-            return Err(Error::authorization_required(
-                "The request uses the `on_behalf_of_content_owner` parameter but is not properly authorized",
+        let chart = matches!(self.filter, VideoFilter::Chart(_));
+        // the owner parts are not returned for the videos of a chart
+        let owner_parts = self
+            .part
+            .iter()
+            .filter(|part| part.is_owner_only())
+            .collect::<Vec<&Part>>();
+        if chart && !owner_parts.is_empty() {
+            let parts = owner_parts
+                .iter()
+                .map(|part| part.to_string())
+                .collect::<Vec<String>>();
+            return Err(Error::incompatible_parameters(format!(
+                "Incompatible parameters specified in the request: chart, {}",
+                parts.join(", "),
+            )));
+        }
+        self.require_part_authorization(youtube, owner_parts)?;
+        // `videoCategoryId` only applies to the `chart` filter
+        if self.video_category_id.is_some() && !chart {
+            return Err(Error::invalid_parameter(
+                "Request contains an invalid argument: parameter `video_category_id` can only be used with `chart`",
             ));
         }
-        self.insert_query_parameter(&mut params, "pageToken", self.page_token);
-        self.insert_query_parameter(&mut params, "regionCode", self.region_code);
+        self.insert_query_parameter(
+            &mut params,
+            "videoCategoryId",
+            self.video_category_id.as_deref(),
+        );
+        // the default `regionCode` of the handle only applies to the charts
+        let region_code = if chart {
+            self.region_code
+                .as_ref()
+                .or(youtube.inner().region_code.as_ref())
+        } else {
+            self.region_code.as_ref()
+        };
+        self.insert_query_parameter(&mut params, "regionCode", region_code);
+        self.insert_content_owner_parameters(
+            youtube,
+            &mut params,
+            self.on_behalf_of_content_owner.as_deref(),
+            None,
+        )?;
+        self.insert_query_parameter(&mut params, "pageToken", page_token);
+
+        Ok(params)
+    }
+}
 
-        let response = self
-            .send(
-                youtube
-                    .client
-                    .get(self.url(&youtube.base_path))
-                    .query(&params),
-            )
-            .await?;
-        Ok(response.json().await?)
+/// Merges the responses of the chunks of `id` into one response, with the videos in the order of `id`.
+fn merge_pages(id: &[String], pages: Vec<VideoListResponse>) -> VideoListResponse {
+    let mut merged = merge_chunks(pages);
+    merged.items.sort_by_key(|item| {
+        id.iter()
+            .position(|id| *id == item.id)
+            .unwrap_or(usize::MAX)
+    });
+    merged
+}
+
+/// Merges the responses of the chunks of an id list into one response, with the items in the order of the chunks.
+pub(crate) fn merge_chunks<T>(pages: Vec<ListResponse<T>>) -> ListResponse<T> {
+    let mut pages = pages.into_iter();
+    let mut merged = pages.next().unwrap_or_else(|| ListResponse {
+        kind: String::new(),
+        etag: String::new(),
+        next_page_token: None,
+        prev_page_token: None,
+        region_code: None,
+        page_info: PageInfo::default(),
+        items: Vec::new(),
+    });
+    for page in pages {
+        merged.items.extend(page.items);
+    }
+    merged.page_info.total_results = merged.items.len() as i32;
+    merged.page_info.results_per_page = merged.items.len() as i32;
+    merged
+}
+
+/// A list whose ids are requested in chunks of at most 50 that are merged into one response: a [VideoList] of more
+/// than 50 ids, or its resumed [CursorList](crate::cursor::CursorList).
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub(crate) trait ChunkedList<T> {
+    /// The query parameters of every chunk of the page of `page_token`, a single one when the ids fit in one request.
+    fn chunks(&self, page_token: Option<&str>) -> Result<Vec<Vec<(String, String)>>>;
+
+    async fn request_chunk(&self, params: &[(String, String)]) -> Result<ListResponse<T>>;
+
+    /// Merges the responses of the chunks, given in the order of [chunks](ChunkedList::chunks).
+    fn merge(&self, pages: Vec<ListResponse<T>>) -> ListResponse<T>;
+}
+
+/// [PagedListApi::poll] of a chunked list: a list of one chunk is polled as any other list, the chunks of a longer
+/// one with [poll_chunks].
+pub(crate) fn poll_list<'a, T, L>(
+    list: &'a L,
+    interval: Duration,
+) -> BoxStream<'a, Result<ListResponse<T>>>
+where
+    T: Serialize + Clone + Send + 'static,
+    L: ChunkedList<T> + PagedListApi<T> + Sync,
+{
+    match list.chunks(None) {
+        Ok(chunks) if chunks.len() > 1 => poll_chunks(list, chunks, interval),
+        Ok(_) => poll_pages(list, interval),
+        Err(e) => Box::pin(stream::once(async { Err(e) })),
     }
 }
 
+/// Polls the chunks of a list with the ETag of every chunk: a chunk is requested with its own `If-None-Match`, and
+/// keeps its last response when it is answered with `304 Not Modified` or with the same ETag. The merged response
+/// is yielded on the first request and whenever one of the chunks changed.
+fn poll_chunks<'a, T, L>(
+    list: &'a L,
+    chunks: Vec<Vec<(String, String)>>,
+    interval: Duration,
+) -> BoxStream<'a, Result<ListResponse<T>>>
+where
+    T: Clone + Send + 'static,
+    L: ChunkedList<T> + Sync,
+{
+    // the state is the last response of every chunk, `None` before the first request
+    Box::pin(stream::try_unfold(
+        (chunks, None::<Vec<ListResponse<T>>>),
+        move |(chunks, mut last)| async move {
+            loop {
+                if last.is_some() {
+                    timer::sleep(interval).await;
+                }
+                let mut pages = Vec::with_capacity(chunks.len());
+                let mut changed = last.is_none();
+                for (i, params) in chunks.iter().enumerate() {
+                    let etag = last
+                        .as_ref()
+                        .map(|last| last[i].etag.clone())
+                        .filter(|etag| !etag.is_empty());
+                    let response = match etag {
+                        Some(etag) => IF_NONE_MATCH.scope(etag, list.request_chunk(params)).await,
+                        None => list.request_chunk(params).await,
+                    };
+                    let previous = last.as_ref().map(|last| &last[i]);
+                    match (response, previous) {
+                        (Ok(page), Some(previous))
+                            if !page.etag.is_empty() && page.etag == previous.etag =>
+                        {
+                            pages.push(page)
+                        }
+                        (Ok(page), _) => {
+                            changed = true;
+                            pages.push(page);
+                        }
+                        (Err(e), Some(previous)) if e.is_not_modified() => {
+                            pages.push(previous.clone())
+                        }
+                        (Err(e), _) => return Err(e),
+                    }
+                }
+                if changed {
+                    return Ok(Some((list.merge(pages.clone()), (chunks, Some(pages)))));
+                }
+                last = Some(pages);
+            }
+        },
+    ))
+}
+
 impl<'a> VideoList<'a> {
-    pub fn new(service: &'a VideosService, part: Vec<Part>) -> Self {
+    pub(crate) fn new(
+        service: VideosService<'a>,
+        part: impl IntoIterator<Item = Part>,
+        filter: VideoFilter,
+    ) -> Self {
+        let part = crate::dedupe(part);
         let part = if part.is_empty() {
             vec![Part::Id]
         } else {
@@ -160,10 +479,11 @@ impl<'a> VideoList<'a> {
         };
         Self {
             service,
+            authorizer: None,
             part,
-            chart: None,
-            id: None,
-            my_rating: None,
+            filter,
+            fields: None,
+            timeout: None,
             hl: None,
             max_height: None,
             max_results: None,
@@ -172,85 +492,352 @@ impl<'a> VideoList<'a> {
             page_token: None,
             region_code: None,
             video_category_id: None,
+            concurrent_chunks: false,
         }
     }
 
-    pub fn part(&mut self, part: Vec<Part>) -> &mut Self {
-        self.part = part;
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
         self
     }
+}
 
-    pub fn chart(&mut self, chart: Chart) -> &mut Self {
-        self.chart = Some(chart);
-        self
+/// Parameters for the `getRating` method of the `videos` api. details:
+/// [link](https://developers.google.com/youtube/v3/docs/videos/getRating)
+pub struct VideoGetRating<'a> {
+    service: VideosService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    id: Vec<String>,
+}
+
+impl RequestBase for VideoGetRating<'_> {
+    fn api_path(&self) -> &str {
+        "videos/getRating"
     }
+}
 
-    pub fn id(&mut self, id: Vec<&'a str>) -> &mut Self {
-        self.id = Some(id);
-        self
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for VideoGetRating<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> VideoGetRating<'a> {
+    pub(crate) fn new(
+        service: VideosService<'a>,
+        id: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        Self {
+            service,
+            authorizer: None,
+            id: id.into_iter().map(Into::into).collect(),
+        }
     }
 
-    pub fn my_rating(&mut self, my_rating: MyRating) -> &mut Self {
-        self.my_rating = Some(my_rating);
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
         self
     }
 
-    pub fn hl(&mut self, hl: &'a str) -> &mut Self {
-        self.hl = Some(hl);
+    pub fn id(&mut self, id: impl IntoIterator<Item = impl Into<String>>) -> &mut Self {
+        self.id = id.into_iter().map(Into::into).collect();
         self
     }
 
-    pub fn max_height(&mut self, max_height: u32) -> &mut Self {
-        let max_height = if max_height > 4320 {
-            4320
-        } else if max_height < 72 {
-            72
-        } else {
-            max_height
-        };
-        self.max_height = Some(max_height);
+    /// Retrieves the ratings that the authorized user gave to a list of specified videos.
+    pub async fn request(&self) -> Result<VideoGetRatingResponse> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        // required parameters
+        if self.id.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required parameter `id` is empty",
+            ));
+        }
+        self.insert_query_parameters(&mut params, "id", Some(&self.id));
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .get(self.url(&youtube.inner().base_path))
+                .query(&params),
+        )?;
+        let response = self.send(youtube, request).await?;
+        json(response).await
+    }
+}
+
+/// Parameters for the `rate` method of the `videos` api. details:
+/// [link](https://developers.google.com/youtube/v3/docs/videos/rate)
+pub struct VideoRate<'a> {
+    service: VideosService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    id: String,
+    rating: Rating,
+}
+
+impl RequestBase for VideoRate<'_> {
+    fn api_path(&self) -> &str {
+        "videos/rate"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for VideoRate<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> VideoRate<'a> {
+    pub(crate) fn new(service: VideosService<'a>, id: impl Into<String>, rating: Rating) -> Self {
+        Self {
+            service,
+            authorizer: None,
+            id: id.into(),
+            rating,
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
         self
     }
 
-    pub fn max_results(&mut self, max_results: u32) -> &mut Self {
-        let max_results = if max_results > 50 { 50 } else { max_results };
-        self.max_results = Some(max_results);
+    /// Adds a like or dislike rating to a video or removes a rating from a video. The api responds with an
+    /// empty body on success.
+    pub async fn request(&self) -> Result<()> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        // required parameters
+        if self.id.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required parameter `id` is empty",
+            ));
+        }
+        self.insert_query_parameter(&mut params, "id", Some(&self.id));
+        if self.rating == Rating::Unspecified {
+            return Err(Error::invalid_parameter(
+                "Request contains an invalid argument: parameter `rating` must be one of: dislike, like, none",
+            ));
+        }
+        self.insert_query_parameter(&mut params, "rating", Some(&self.rating));
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .post(self.url(&youtube.inner().base_path))
+                .query(&params),
+        )?;
+        self.send_without_content(youtube, request).await
+    }
+}
+
+/// Parameters for the `reportAbuse` method of the `videos` api. details:
+/// [link](https://developers.google.com/youtube/v3/docs/videos/reportAbuse)
+pub struct VideoReportAbuse<'a> {
+    service: VideosService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // optional parameters
+    on_behalf_of_content_owner: Option<String>,
+
+    // request body
+    body: VideoAbuseReport,
+
+    /// The reasons returned by `video_abuse_report_reasons().list()`, used to validate the reason ids.
+    reasons: Option<&'a VideoAbuseReportReasonListResponse>,
+}
+
+impl RequestBase for VideoReportAbuse<'_> {
+    fn api_path(&self) -> &str {
+        "videos/reportAbuse"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for VideoReportAbuse<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> VideoReportAbuse<'a> {
+    pub(crate) fn new(
+        service: VideosService<'a>,
+        video_id: impl Into<String>,
+        reason_id: impl Into<String>,
+    ) -> Self {
+        Self {
+            service,
+            authorizer: None,
+            on_behalf_of_content_owner: None,
+            body: VideoAbuseReport {
+                video_id: video_id.into(),
+                reason_id: reason_id.into(),
+                secondary_reason_id: None,
+                comments: None,
+                language: None,
+            },
+            reasons: None,
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
         self
     }
 
-    pub fn max_width(&mut self, max_width: u32) -> &mut Self {
-        let max_width = if max_width > 8192 {
-            8192
-        } else if max_width < 72 {
-            72
-        } else {
-            max_width
-        };
-        self.max_width = Some(max_width);
+    pub fn secondary_reason_id(&mut self, secondary_reason_id: impl Into<String>) -> &mut Self {
+        self.body.secondary_reason_id = Some(secondary_reason_id.into());
         self
     }
 
-    pub fn on_behalf_of_content_owner(&mut self, on_behalf_of_content_owner: &'a str) -> &mut Self {
-        self.on_behalf_of_content_owner = Some(on_behalf_of_content_owner);
+    /// Additional information about the abuse report.
+    pub fn comments(&mut self, comments: impl Into<String>) -> &mut Self {
+        self.body.comments = Some(comments.into());
         self
     }
 
-    pub fn page_token(&mut self, page_token: &'a str) -> &mut Self {
-        self.page_token = Some(page_token);
+    /// The language that the reporter speaks.
+    pub fn language(&mut self, language: impl Into<String>) -> &mut Self {
+        self.body.language = Some(language.into());
         self
     }
 
-    pub fn region_code(&mut self, region_code: &'a str) -> &mut Self {
-        self.region_code = Some(region_code);
+    pub fn on_behalf_of_content_owner(
+        &mut self,
+        on_behalf_of_content_owner: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
         self
     }
 
-    pub fn video_category_id(&mut self, video_category_id: &'a str) -> &mut Self {
-        self.video_category_id = Some(video_category_id);
+    /// Checks `reason_id` and `secondary_reason_id` against the reasons returned by
+    /// `video_abuse_report_reasons().list()` before the report is sent.
+    pub fn reasons(&mut self, reasons: &'a VideoAbuseReportReasonListResponse) -> &mut Self {
+        self.reasons = Some(reasons);
         self
     }
+
+    /// Reports a video for containing abusive content. The api responds with an empty body on success.
+    pub async fn request(&self) -> Result<()> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        // optional parameters
+        self.insert_content_owner_parameters(
+            youtube,
+            &mut params,
+            self.on_behalf_of_content_owner.as_deref(),
+            None,
+        )?;
+
+        // request body
+        if self.body.video_id.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required property `video_id` is empty",
+            ));
+        }
+        if self.body.reason_id.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required property `reason_id` is empty",
+            ));
+        }
+        if let Some(reasons) = self.reasons {
+            self.validate_reason(reasons)?;
+        }
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .post(self.url(&youtube.inner().base_path))
+                .query(&params)
+                .json(&self.body),
+        )?;
+        self.send_without_content(youtube, request).await
+    }
+
+    fn validate_reason(&self, reasons: &VideoAbuseReportReasonListResponse) -> Result<()> {
+        let reason = match reasons.items.iter().find(|r| r.id == self.body.reason_id) {
+            Some(reason) => reason,
+            None => {
+                return Err(Error::invalid_parameter(format!(
+                    "The abuse report reason `{}` does not exist",
+                    self.body.reason_id
+                )))
+            }
+        };
+        if let Some(secondary_reason_id) = &self.body.secondary_reason_id {
+            let exists = reason
+                .snippet
+                .as_ref()
+                .and_then(|snippet| snippet.secondary_reasons.as_ref())
+                .is_some_and(|secondary_reasons| {
+                    secondary_reasons
+                        .iter()
+                        .any(|r| &r.id == secondary_reason_id)
+                });
+            if !exists {
+                return Err(Error::invalid_parameter(format!(
+                    "The secondary reason `{}` does not belong to the abuse report reason `{}`",
+                    secondary_reason_id, self.body.reason_id
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The request body of `videos.reportAbuse`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct VideoAbuseReport {
+    video_id: String,
+
+    reason_id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    secondary_reason_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    comments: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Part {
     ContentDetails,
 
@@ -279,6 +866,32 @@ pub enum Part {
     TopicDetails,
 }
 
+impl Part {
+    /// The parts that are returned without being the owner of the video, to request everything about a video.
+    pub fn all_public() -> Vec<Part> {
+        vec![
+            Part::ContentDetails,
+            Part::Id,
+            Part::LiveStreamingDetails,
+            Part::Localizations,
+            Part::Player,
+            Part::RecordingDetails,
+            Part::Snippet,
+            Part::Statistics,
+            Part::Status,
+            Part::TopicDetails,
+        ]
+    }
+
+    /// Whether the part is only returned to the owner of the video, so it requires an authorized request.
+    pub fn is_owner_only(&self) -> bool {
+        matches!(
+            self,
+            Part::FileDetails | Part::ProcessingDetails | Part::Suggestions
+        )
+    }
+}
+
 impl std::fmt::Display for Part {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let part = match self {
@@ -300,6 +913,26 @@ impl std::fmt::Display for Part {
     }
 }
 
+/// The filter of a [VideoList], which selects the videos of the response.
+pub enum VideoFilter {
+    /// The videos of a chart.
+    Chart(Chart),
+
+    /// The videos of the ids. The api accepts at most 50 ids per request; more ids are requested in chunks of 50
+    /// and merged into one response, in the order of the ids.
+    Id(Vec<String>),
+
+    /// The videos rated by the authorized user.
+    MyRating(MyRating),
+}
+
+impl VideoFilter {
+    /// The videos of the ids, see [VideoFilter::Id].
+    pub fn id(id: impl IntoIterator<Item = impl Into<String>>) -> VideoFilter {
+        VideoFilter::Id(id.into_iter().map(Into::into).collect())
+    }
+}
+
 pub enum Chart {
     /// Returns the most popular videos for the specified content region and video category.
     MostPopular,
@@ -332,25 +965,110 @@ impl std::fmt::Display for MyRating {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// The rating that the authorized user gave to a video.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Rating {
+    /// The user disliked the video.
+    Dislike,
+
+    /// The user liked the video.
+    Like,
+
+    /// The user has not rated the video. Passing it to `rate` removes any rating that the user had previously set.
+    None,
+
+    /// The rating is unknown. This value is only returned by `getRating` and can not be used to rate a video.
+    Unspecified,
+}
+
+impl std::fmt::Display for Rating {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rating = match self {
+            Rating::Dislike => "dislike",
+            Rating::Like => "like",
+            Rating::None => "none",
+            Rating::Unspecified => "unspecified",
+        };
+        write!(f, "{}", rating)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VideoGetRatingResponse {
+    /// Identifies the API resource's type. The value will be `youtube#videoGetRatingResponse`.
+    #[serde(default)]
+    pub kind: String,
+
+    /// The ETag of the response.
+    #[serde(default)]
+    pub etag: String,
+
+    /// A list of ratings that match the request criteria.
+    #[serde(default)]
+    pub items: Vec<VideoRating>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoRating {
+    /// The ID that YouTube uses to uniquely identify the video.
+    #[serde(default)]
+    pub video_id: String,
+
+    /// The rating that the authorized user gave to the video.
+    pub rating: Rating,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct VideoListResource {
     /// Identifies the API resource's type. The value will be `youtube#video`.
+    #[serde(default)]
     pub kind: String,
 
     /// The ETag of the response.
+    #[serde(default)]
     pub etag: String,
 
     /// The ID that YouTube uses to uniquely identify the video.
+    #[serde(default)]
     pub id: String,
 
     pub snippet: Option<VideoSnippet>,
 
-    #[serde(alias = "liveStreamingDetails")]
+    pub content_details: Option<VideoContentDetail>,
+
+    pub status: Option<VideoStatus>,
+
+    pub statistics: Option<VideoStatistics>,
+
+    pub player: Option<VideoPlayer>,
+
+    pub topic_details: Option<VideoTopicDetails>,
+
+    pub recording_details: Option<VideoRecordingDetails>,
+
+    pub file_details: Option<VideoFileDetails>,
+
+    pub processing_details: Option<VideoProcessingDetails>,
+
+    pub suggestions: Option<VideoSuggestions>,
+
     pub live_streaming_details: Option<VideoLiveStreamingDetails>,
+
+    /// The translations of the video's metadata, keyed by the language of the translation, e.g. `de_DE`.
+    pub localizations: Option<HashMap<String, Localization>>,
+
+    /// The fields of the resource that this version of the crate does not know, e.g. added to the api after its
+    /// release. They are serialized back with the resource.
+    #[serde(flatten, deserialize_with = "crate::serde_helpers::extra_fields")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The snippet object contains basic details about the video, such as its title, description, and category.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct VideoSnippet {
     /// The date and time that the video was published. Note that this time might be different than the time that
     /// the video was uploaded. For example, if a video is uploaded as a private video and then made public at a
@@ -359,16 +1077,15 @@ pub struct VideoSnippet {
     /// There are a couple of special cases:
     ///
     /// * If a video is uploaded as a private video and the video metadata is retrieved by the channel owner,
-    /// then the property value specifies the date and time that the video was uploaded.
+    ///   then the property value specifies the date and time that the video was uploaded.
     /// * If a video is uploaded as an unlisted video, the property value also specifies the date and time that
-    /// the video was uploaded. In this case, anyone who knows the video's unique video ID can retrieve the video metadata.
+    ///   the video was uploaded. In this case, anyone who knows the video's unique video ID can retrieve the video metadata.
     ///
     /// The value is specified in [ISO 8601](https://www.w3.org/TR/NOTE-datetime) format.
-    #[serde(alias = "publishedAt")]
-    pub published_at: DateTime<Utc>,
+    pub published_at: Option<DateTime<Utc>>,
 
     /// The ID that YouTube uses to uniquely identify the channel that the video was uploaded to.
-    #[serde(alias = "channelId")]
+    #[serde(default)]
     pub channel_id: String,
 
     /// The video's title. The property value has a maximum length of 100 characters and may contain all valid
@@ -384,10 +1101,11 @@ pub struct VideoSnippet {
 
     /// A map of thumbnail images associated with the video. For each object in the map, the key is the name
     /// of the thumbnail image, and the value is an object that contains other information about the thumbnail.
-    pub thumbnails: HashMap<ThumbnailKind, Thumbnail>,
+    #[serde(default)]
+    pub thumbnails: Thumbnails,
 
     /// Channel title for the channel that the video belongs to.
-    #[serde(alias = "channelTitle")]
+    #[serde(default)]
     pub channel_title: String,
 
     /// A list of keyword tags associated with the video. Tags may contain spaces. The property value has a maximum
@@ -395,63 +1113,160 @@ pub struct VideoSnippet {
     ///
     /// * The property value is a list, and commas between items in the list count toward the limit.
     /// * If a tag contains a space, the API server handles the tag value as though it were wrapped in quotation marks,
-    /// and the quotation marks count toward the character limit. So, for the purposes of character limits,
-    /// the tag Foo-Baz contains seven characters, but the tag Foo Baz contains nine characters.
+    ///   and the quotation marks count toward the character limit. So, for the purposes of character limits,
+    ///   the tag Foo-Baz contains seven characters, but the tag Foo Baz contains nine characters.
     pub tags: Option<Vec<String>>,
 
     /// The YouTube video category associated with the video. You must set a value for this property if you call
     /// the `videos.update` method and are updating the [snippet][VideoSnippet] part of a video resource.
-    #[serde(alias = "categoryId")]
     pub category_id: Option<String>,
 
     /// Indicates if the video is an upcoming/active live broadcast. Or it's "none" if the video is not an
     /// upcoming/active live broadcast.
-    ///
-    /// Valid values for this property are:
-    ///
-    /// * live
-    /// * none
-    /// * upcoming
-    #[serde(alias = "liveBroadcastContent")]
-    pub live_broadcast_content: Option<String>,
+    pub live_broadcast_content: Option<LiveBroadcastContent>,
 
     /// The language of the text in the channel resource's `snippet.title` and `snippet.description` properties.
-    #[serde(alias = "defaultLanguage")]
     pub default_language: Option<String>,
 
     /// The snippet.localized object contains either a localized title and description for the video or the title in
     /// the `default_language` for the video's metadata.
     ///
     /// * Localized text is returned in the resource snippet if the videos.list request used the hl parameter to specify
-    /// a language for which localized text should be returned and localized text is available in that language.
+    ///   a language for which localized text should be returned and localized text is available in that language.
     /// * Metadata for the default language is returned if an hl parameter value is not specified or a value is specified
-    /// but localized metadata is not available for the specified language.
+    ///   but localized metadata is not available for the specified language.
     pub localized: Option<Localization>,
 
     /// The property contains a read-only value. Use the `localizations` object to add, update, or delete localized titles.
-    #[serde(alias = "defaultAudioLanguage")]
     pub default_audio_language: Option<String>,
 }
 
 /// The contentDetails object contains information about the video content, including the length of the video and
 /// an indication of whether captions are available for the video.
-#[derive(Debug, Serialize, Deserialize)]
-pub struct VideoContentDetail {}
-
-/// The status object contains information about the video's uploading, processing, and privacy statuses.
-#[derive(Debug, Serialize, Deserialize)]
-pub struct VideoStatus {
-    /// The status of the uploaded video.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoContentDetail {
+    /// The length of the video, sent by the api as an [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601#Durations)
+    /// duration such as `PT15M33S`. `None` when the api omits the duration, e.g. for an upcoming live broadcast.
+    #[serde(with = "iso8601_duration", default)]
+    pub duration: Option<Duration>,
+
+    /// Indicates whether the video is available in 3D or in 2D.
     ///
     /// Valid values for this property are:
     ///
-    /// * deleted
-    /// * failed
-    /// * processed
-    /// * rejected
-    /// * uploaded
-    #[serde(alias = "uploadStatus")]
-    pub upload_status: String,
+    /// * 2d
+    /// * 3d
+    pub dimension: Option<String>,
+
+    /// Indicates whether the video is available in high definition (HD) or only in standard definition.
+    ///
+    /// Valid values for this property are:
+    ///
+    /// * hd
+    /// * sd
+    pub definition: Option<String>,
+
+    /// Indicates whether captions are available for the video.
+    ///
+    /// Valid values for this property are:
+    ///
+    /// * false
+    /// * true
+    pub caption: Option<String>,
+
+    /// Indicates whether the video represents licensed content, which means that the content was uploaded to a channel
+    /// linked to a YouTube content partner and then claimed by that partner.
+    #[serde(default)]
+    pub licensed_content: bool,
+
+    /// Contains information about the countries where a video is (or is not) viewable.
+    pub region_restriction: Option<RegionRestriction>,
+
+    /// Specifies the ratings that the video received under various rating schemes.
+    pub content_rating: Option<ContentRating>,
+
+    /// Specifies the projection format of the video.
+    ///
+    /// Valid values for this property are:
+    ///
+    /// * 360
+    /// * rectangular
+    pub projection: Option<String>,
+
+    /// Indicates whether the video uploader has provided a custom thumbnail image for the video. This property is only
+    /// visible to the video uploader.
+    pub has_custom_thumbnail: Option<bool>,
+}
+
+/// The ratings that a video received under various rating schemes. Only YouTube's own age restriction and the
+/// rating of the Motion Picture Association of America are typed; the ratings of the dozens of regional schemes
+/// (e.g. `fskRating`, `bbfcRating`, `eirinRating`) are kept in `other`, keyed by the name of the scheme.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentRating {
+    /// A rating that YouTube uses to identify age-restricted content. The only valid value is `ytAgeRestricted`.
+    pub yt_rating: Option<String>,
+
+    /// The video's Motion Picture Association of America (MPAA) rating, e.g. `mpaaPg13` or `mpaaR`.
+    pub mpaa_rating: Option<String>,
+
+    /// The ratings of the other schemes, keyed by the name of the scheme as sent by the api. The values are strings
+    /// such as `fsk16`, except for the lists of reasons such as `djctqRatingReasons`.
+    #[serde(flatten)]
+    pub other: HashMap<String, serde_json::Value>,
+}
+
+impl ContentRating {
+    /// Whether YouTube restricts the video to viewers that are signed in and 18 or older.
+    pub fn is_age_restricted(&self) -> bool {
+        self.yt_rating.as_deref() == Some("ytAgeRestricted")
+    }
+
+    /// The rating of the video under `scheme`, e.g. `rating("fskRating")`, or `None` when the video was not rated
+    /// under the scheme.
+    pub fn rating(&self, scheme: &str) -> Option<&str> {
+        match scheme {
+            "ytRating" => self.yt_rating.as_deref(),
+            "mpaaRating" => self.mpaa_rating.as_deref(),
+            _ => self.other.get(scheme).and_then(serde_json::Value::as_str),
+        }
+    }
+}
+
+/// The countries where a video is (or is not) viewable, identified by their ISO 3166-1 alpha-2 codes. The object
+/// contains either the `allowed` or the `blocked` list.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RegionRestriction {
+    /// The countries where the video is viewable. If this property is present and a country is not listed in its
+    /// value, then the video is blocked from appearing in that country. If this property is present and contains an
+    /// empty list, the video is blocked in all countries.
+    pub allowed: Option<Vec<String>>,
+
+    /// The countries where the video is blocked. If this property is present and a country is not listed in its
+    /// value, then the video is viewable in that country. If this property is present and contains an empty list,
+    /// the video is viewable in all countries.
+    pub blocked: Option<Vec<String>>,
+}
+
+impl RegionRestriction {
+    /// Whether the video is viewable in the country identified by its ISO 3166-1 alpha-2 `region` code, e.g. `DE`.
+    pub fn is_viewable_in(&self, region: &str) -> bool {
+        let listed = |regions: &Vec<String>| regions.iter().any(|r| r.eq_ignore_ascii_case(region));
+        match (&self.allowed, &self.blocked) {
+            (Some(allowed), _) => listed(allowed),
+            (None, Some(blocked)) => !listed(blocked),
+            (None, None) => true,
+        }
+    }
+}
+
+/// The status object contains information about the video's uploading, processing, and privacy statuses.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoStatus {
+    /// The status of the uploaded video.
+    pub upload_status: Option<UploadStatus>,
 
     /// This value explains why a video failed to upload. This property is only present if the uploadStatus
     /// property indicates that the upload failed.
@@ -464,7 +1279,6 @@ pub struct VideoStatus {
     /// * invalidFile
     /// * tooSmall
     /// * uploadAborted
-    #[serde(alias = "failureReason")]
     pub failure_reason: Option<String>,
 
     /// This value explains why YouTube rejected an uploaded video. This property is only present if the uploadStatus
@@ -482,136 +1296,392 @@ pub struct VideoStatus {
     /// * trademark
     /// * uploaderAccountClosed
     /// * uploaderAccountSuspended
-    #[serde(alias = "rejectionReason")]
     pub rejection_reason: Option<String>,
 
     /// The video's privacy status.
-    ///
-    /// Valid values for this property are:
-    ///
-    /// * private
-    /// * public
-    /// * unlisted
-    #[serde(alias = "privacyStatus")]
-    pub privacy_status: Option<String>,
+    pub privacy_status: Option<PrivacyStatus>,
 
     /// The date and time when the video is scheduled to publish. It can be set only if the privacy status of the video
     /// is private. The value is specified in ISO 8601 format. Note the following two additional points about this
     /// property's behavior:
     ///
     /// * If you set this property's value when calling the videos.update method, you must also set the status.privacyStatus
-    /// property value to private even if the video is already private.
+    ///   property value to private even if the video is already private.
     /// * If your request schedules a video to be published at some time in the past, the video will be published right away.
-    /// As such, the effect of setting the status. `publishAt` property to a past date and time is the same as of changing
-    /// the video's `privacyStatus` from private to public.
-    #[serde(alias = "publishAt")]
+    ///   As such, the effect of setting the status. `publishAt` property to a past date and time is the same as of changing
+    ///   the video's `privacyStatus` from private to public.
     pub publish_at: Option<DateTime<Utc>>,
 
     /// The video's license.
-    ///
-    /// Valid values for this property are:
-    ///
-    /// * creativeCommon
-    /// * youtube
-    pub license: Option<String>,
+    pub license: Option<License>,
 
     /// This value indicates whether the video can be embedded on another website.
+    #[serde(default)]
     pub embeddable: bool,
 
     /// This value indicates whether the extended video statistics on the video's watch page are publicly viewable.
     /// By default, those statistics are viewable, and statistics like a video's view count and ratings will still be
     /// publicly visible even if this property's value is set to false.
-    #[serde(alias = "publicStatsViewable")]
+    #[serde(default)]
     pub public_stats_viewable: bool,
 
     /// This value indicates whether the video is designated as child-directed, and it contains the current "made for kids"
     /// status of the video. For example, the status might be determined based on the value of the selfDeclaredMadeForKids property.
     /// See the YouTube Help Center for more information about setting the audience for your channel, videos, or broadcasts.
-    #[serde(alias = "madeForKids")]
+    #[serde(default)]
     pub made_for_kids: bool,
 
     /// In a videos.insert or videos.update request, this property allows the channel owner to designate the video as being child-directed.
     /// In a videos.list request, the property value is only returned if the channel owner authorized the API request.
-    #[serde(alias = "selfDeclaredMadeForKids")]
     pub self_declared_made_for_kids: Option<bool>,
 }
 
+string_enum! {
+    /// The status of an uploaded video.
+    pub enum UploadStatus {
+        Deleted => "deleted",
+        Failed => "failed",
+        Processed => "processed",
+        Rejected => "rejected",
+        Uploaded => "uploaded",
+    }
+}
+
+string_enum! {
+    /// The license of a video.
+    pub enum License {
+        /// The [Creative Commons Attribution license](https://support.google.com/youtube/answer/2797468).
+        CreativeCommon => "creativeCommon",
+        /// The standard YouTube license.
+        Youtube => "youtube",
+    }
+}
+
 /// The statistics object contains statistics about the video.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct VideoStatistics {
     /// The number of times the video has been viewed.
-    #[serde(alias = "viewCount")]
+    #[serde(default, deserialize_with = "option_string_or_number")]
     pub view_count: Option<u64>,
 
     /// The number of users who have indicated that they liked the video.
-    #[serde(alias = "likeCount")]
+    #[serde(default, deserialize_with = "option_string_or_number")]
     pub like_count: Option<u64>,
 
     /// The number of users who have indicated that they disliked the video.
     ///
     /// Note: The statistics.dislikeCount property was made private as of December 13, 2021. This means that the property is included
     /// in an API response only if the API request was authenticated by the video owner. See the revision history for more information.
-    #[serde(alias = "dislikeCount")]
+    #[serde(default, deserialize_with = "option_string_or_number")]
     pub dislike_count: Option<u64>,
 
     /// Note: This property has been deprecated. The deprecation is effective as of August 28, 2015. The property's value is now always set to 0.
-    #[serde(alias = "favoriteCount")]
+    #[serde(default, deserialize_with = "string_or_number")]
     pub favorite_count: u64,
 
     /// The number of comments for the video.
-    #[serde(alias = "commentCount")]
+    #[serde(default, deserialize_with = "option_string_or_number")]
     pub comment_count: Option<u64>,
 }
 
 /// The player object contains information that you would use to play the video in an embedded player.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct VideoPlayer {
-    #[serde(alias = "embedHtml")]
     pub embed_html: Option<String>,
 
-    #[serde(alias = "embedHeight")]
     pub embed_height: Option<i64>,
 
-    #[serde(alias = "embedWidth")]
     pub embed_width: Option<i64>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct VideoTopicDetails {}
+/// The topicDetails object encapsulates information about topics associated with the video.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoTopicDetails {
+    /// A list of topic IDs associated with the video. The IDs are
+    /// [Freebase topic IDs](https://developers.google.com/youtube/v3/docs/videos#topicDetails.topicIds).
+    #[serde(default)]
+    pub topic_ids: Vec<String>,
+
+    /// A list of topic IDs that are relevant to the video, including the topics that are not the video's main
+    /// topic.
+    #[serde(default)]
+    pub relevant_topic_ids: Vec<String>,
+
+    /// A list of Wikipedia URLs that provide a high-level description of the video's content.
+    #[serde(default)]
+    pub topic_categories: Vec<String>,
+}
+
+/// The recordingDetails object encapsulates information about the location, date and address where the video was
+/// recorded.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoRecordingDetails {
+    /// The date and time when the video was recorded. The value is specified in ISO 8601 format.
+    pub recording_date: Option<DateTime<Utc>>,
+
+    /// The geolocation information associated with the video.
+    pub location: Option<VideoLocation>,
+
+    /// The text description of the location where the video was recorded.
+    pub location_description: Option<String>,
+}
+
+/// A position in the WGS 84 reference system.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VideoLocation {
+    /// Latitude in degrees.
+    pub latitude: Option<f64>,
+
+    /// Longitude in degrees.
+    pub longitude: Option<f64>,
+
+    /// Altitude above the reference ellipsoid, in meters.
+    pub altitude: Option<f64>,
+}
+
+/// The fileDetails object encapsulates information about the video file that was uploaded to YouTube, including the
+/// file's resolution, duration, audio and video codecs, stream bitrates, and more. This data can only be retrieved by
+/// the video owner, and only once the video processing made them available (see
+/// [VideoProcessingDetails::file_details_availability]).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoFileDetails {
+    /// The uploaded file's name.
+    pub file_name: Option<String>,
+
+    /// The uploaded file's size in bytes.
+    #[serde(default, deserialize_with = "option_string_or_number")]
+    pub file_size: Option<u64>,
+
+    /// The uploaded file's type as detected by YouTube's video processing engine.
+    ///
+    /// Valid values for this property are:
+    ///
+    /// * archive
+    /// * audio
+    /// * document
+    /// * image
+    /// * other
+    /// * project
+    /// * video
+    pub file_type: Option<String>,
+
+    /// The uploaded video file's container format.
+    pub container: Option<String>,
+
+    /// A list of video streams contained in the uploaded video file.
+    #[serde(default)]
+    pub video_streams: Vec<VideoFileVideoStream>,
+
+    /// A list of audio streams contained in the uploaded video file.
+    #[serde(default)]
+    pub audio_streams: Vec<VideoFileAudioStream>,
+
+    /// The length of the uploaded video in milliseconds.
+    #[serde(default, deserialize_with = "option_string_or_number")]
+    pub duration_ms: Option<u64>,
+
+    /// The uploaded video file's combined (video and audio) bitrate in bits per second.
+    #[serde(default, deserialize_with = "option_string_or_number")]
+    pub bitrate_bps: Option<u64>,
+
+    /// The date and time when the uploaded video file was created, in one of the ISO 8601 formats supported by the
+    /// api, which might not include a time zone.
+    pub creation_time: Option<String>,
+}
+
+/// A video stream of an uploaded file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoFileVideoStream {
+    /// The encoded video content's width in pixels.
+    pub width_pixels: Option<u32>,
+
+    /// The encoded video content's height in pixels.
+    pub height_pixels: Option<u32>,
+
+    /// The video stream's frame rate, in frames per second.
+    pub frame_rate_fps: Option<f64>,
+
+    /// The video content's display aspect ratio, which specifies the aspect ratio in which the video should be
+    /// displayed.
+    pub aspect_ratio: Option<f64>,
+
+    /// The video codec that the stream uses.
+    pub codec: Option<String>,
+
+    /// The video stream's bitrate, in bits per second.
+    #[serde(default, deserialize_with = "option_string_or_number")]
+    pub bitrate_bps: Option<u64>,
+
+    /// The amount that YouTube needs to rotate the original source content to properly display the video.
+    ///
+    /// Valid values for this property are:
+    ///
+    /// * clockwise
+    /// * counterClockwise
+    /// * none
+    /// * other
+    /// * upsideDown
+    pub rotation: Option<String>,
+
+    /// A value that uniquely identifies a video vendor. Typically, the value is a four-letter vendor code.
+    pub vendor: Option<String>,
+}
+
+/// An audio stream of an uploaded file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoFileAudioStream {
+    /// The number of audio channels that the stream contains.
+    pub channel_count: Option<u32>,
+
+    /// The audio codec that the stream uses.
+    pub codec: Option<String>,
+
+    /// The audio stream's bitrate, in bits per second.
+    #[serde(default, deserialize_with = "option_string_or_number")]
+    pub bitrate_bps: Option<u64>,
+
+    /// A value that uniquely identifies a video vendor. Typically, the value is a four-letter vendor code.
+    pub vendor: Option<String>,
+}
+
+/// The processingDetails object encapsulates information about YouTube's progress in processing the uploaded video
+/// file. This data can only be retrieved by the video owner.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoProcessingDetails {
+    /// The video's processing status. This value indicates whether YouTube was able to process the video or if the
+    /// video is still being processed.
+    ///
+    /// Valid values for this property are:
+    ///
+    /// * failed
+    /// * processing
+    /// * succeeded
+    /// * terminated
+    pub processing_status: Option<String>,
+
+    /// The information about the progress YouTube has made in processing the video. The values are only relevant if
+    /// the video's processing status is `processing`.
+    pub processing_progress: Option<VideoProcessingProgress>,
+
+    /// The reason that YouTube failed to process the video. This property will only have a value if the
+    /// `processing_status` is `failed`.
+    ///
+    /// Valid values for this property are:
+    ///
+    /// * other
+    /// * streamingFailed
+    /// * transcodeFailed
+    /// * uploadFailed
+    pub processing_failure_reason: Option<String>,
+
+    /// This value indicates whether file details are available for the uploaded video.
+    pub file_details_availability: Option<String>,
+
+    /// This value indicates whether the video processing engine has generated suggestions that might improve
+    /// YouTube's ability to process the video, warnings that explain video processing problems, or errors that cause
+    /// video processing problems.
+    pub processing_issues_availability: Option<String>,
+
+    /// This value indicates whether keyword (tag) suggestions are available for the video.
+    pub tag_suggestions_availability: Option<String>,
+
+    /// This value indicates whether video editing suggestions, which might improve video quality or the playback
+    /// experience, are available for the video.
+    pub editor_suggestions_availability: Option<String>,
+
+    /// This value indicates whether thumbnail images have been generated for the video.
+    pub thumbnails_availability: Option<String>,
+}
+
+/// The progress of the processing of an uploaded video.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoProcessingProgress {
+    /// An estimate of the total number of parts that need to be processed for the video.
+    #[serde(default, deserialize_with = "option_string_or_number")]
+    pub parts_total: Option<u64>,
+
+    /// The number of parts of the video that YouTube has already processed.
+    #[serde(default, deserialize_with = "option_string_or_number")]
+    pub parts_processed: Option<u64>,
+
+    /// An estimate of the amount of time, in milliseconds, that YouTube needs to finish processing the video.
+    #[serde(default, deserialize_with = "option_string_or_number")]
+    pub time_left_ms: Option<u64>,
+}
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct VideoRecordingDetails {}
+/// The suggestions object encapsulates suggestions that identify opportunities to improve the video quality or the
+/// metadata for the uploaded video. This data can only be retrieved by the video owner.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoSuggestions {
+    /// A list of errors that will prevent YouTube from successfully processing the uploaded video, e.g.
+    /// `notAVideoFile` or `unsupportedSpatialAudioLayout`.
+    #[serde(default)]
+    pub processing_errors: Vec<String>,
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct VideoFileDetails {}
+    /// A list of reasons why YouTube may have difficulty transcoding the uploaded video or that might result in an
+    /// erroneous transcoding, e.g. `hasEditlist` or `unknownContainer`.
+    #[serde(default)]
+    pub processing_warnings: Vec<String>,
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct VideoProcessingDetails {}
+    /// A list of suggestions that may improve YouTube's ability to process the video, e.g. `nonStreamableMov` or
+    /// `sendBestQualityVideo`.
+    #[serde(default)]
+    pub processing_hints: Vec<String>,
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct VideoSuggestions {}
+    /// A list of keyword tags that could be added to the video's metadata to increase the likelihood that users will
+    /// locate your video when searching or browsing on YouTube.
+    #[serde(default)]
+    pub tag_suggestions: Vec<VideoTagSuggestion>,
+
+    /// A list of video editing operations that might improve the video quality or playback experience of the
+    /// uploaded video, e.g. `videoAutoLevels` or `videoStabilize`.
+    #[serde(default)]
+    pub editor_suggestions: Vec<String>,
+}
+
+/// A keyword tag suggested for a video.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoTagSuggestion {
+    /// The keyword tag suggested for the video.
+    #[serde(default)]
+    pub tag: String,
+
+    /// A set of video categories for which the tag is relevant. You can use this information to display appropriate
+    /// tag suggestions based on the video category that the video uploader associates with the video. By default,
+    /// tag suggestions are relevant for all categories if there are no restricts defined for the keyword.
+    #[serde(default)]
+    pub category_restricts: Vec<String>,
+}
 
 /// The object contains metadata about a live video broadcast. The object will only be present in a
 /// video resource if the video is an upcoming, live, or completed live broadcast.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct VideoLiveStreamingDetails {
     /// The time that the broadcast actually started. The value is specified in ISO 8601 format.
     /// This value will not be available until the broadcast begins.
-    #[serde(alias = "actualStartTime")]
     pub actual_start_time: Option<DateTime<Utc>>,
 
     /// The time that the broadcast actually ended. The value is specified in ISO 8601 format.
     /// This value will not be available until the broadcast is over.
-    #[serde(alias = "actualEndTime")]
     pub actual_end_time: Option<DateTime<Utc>>,
 
     /// The time that the broadcast is scheduled to begin. The value is specified in ISO 8601 format.
-    #[serde(alias = "scheduledStartTime")]
-    pub scheduled_start_time: DateTime<Utc>,
+    pub scheduled_start_time: Option<DateTime<Utc>>,
 
     /// The time that the broadcast is scheduled to end. The value is specified in ISO 8601 format.
     /// If the value is empty or the property is not present, then the broadcast is scheduled to continue indefinitely.
-    #[serde(alias = "scheduledEndTime")]
     pub scheduled_end_time: Option<DateTime<Utc>>,
 
     /// The number of viewers currently watching the broadcast. The property and its value will be present if the broadcast
@@ -621,109 +1691,973 @@ pub struct VideoLiveStreamingDetails {
     ///
     /// The concurrent viewer counts that the YouTube Data API returns might differ from the processed, despammed concurrent viewer
     /// counts available through YouTube Analytics. Learn more about live streaming metrics in the YouTube Help Center.
-    #[serde(alias = "concurrentViewers")]
+    #[serde(default, deserialize_with = "option_string_or_number")]
     pub concurrent_viewers: Option<u64>,
 
     /// The ID of the currently active live chat attached to this video. This field is filled only if the video is a currently
     /// live broadcast that has live chat. Once the broadcast transitions to complete this field will be removed and the
     /// live chat closed down. For persistent broadcasts that live chat id will no longer be tied to this video
     /// but rather to the new video being displayed at the persistent page.
-    #[serde(alias = "activeLiveChatId")]
     pub active_live_chat_id: Option<String>,
 }
 
+/// (De)serializes an [ISO 8601 duration](https://en.wikipedia.org/wiki/ISO_8601#Durations), e.g. `PT1H2M3S` or
+/// `P1DT2H`. The years and months of a calendar duration have no fixed length and are rejected; the api only uses
+/// weeks, days, hours, minutes and seconds.
+mod iso8601_duration {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub(super) fn deserialize<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Option::<String>::deserialize(deserializer)? {
+            Some(value) => parse(&value)
+                .map(Some)
+                .ok_or_else(|| D::Error::custom(format!("invalid ISO 8601 duration: {}", value))),
+            None => Ok(None),
+        }
+    }
+
+    pub(super) fn serialize<S>(
+        duration: &Option<Duration>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match duration {
+            Some(duration) => serializer.serialize_str(&format(duration)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub(super) fn parse(value: &str) -> Option<Duration> {
+        let value = value.strip_prefix('P')?;
+        let (date, time) = match value.split_once('T') {
+            Some((date, time)) => (date, Some(time)),
+            None => (value, None),
+        };
+        if date.is_empty() && time.is_none_or(str::is_empty) {
+            return None;
+        }
+        let mut seconds = components(date, &[('W', 604_800.0), ('D', 86_400.0)])?;
+        if let Some(time) = time {
+            seconds += components(time, &[('H', 3_600.0), ('M', 60.0), ('S', 1.0)])?;
+        }
+        Some(Duration::from_secs_f64(seconds))
+    }
+
+    /// The seconds of the components of `value`, which must be numbers followed by one of `units`, in the order of
+    /// `units`.
+    fn components(value: &str, units: &[(char, f64)]) -> Option<f64> {
+        let mut seconds = 0.0;
+        let mut units = units.iter();
+        let mut number = String::new();
+        for c in value.chars() {
+            if c.is_ascii_digit() || c == '.' || c == ',' {
+                number.push(if c == ',' { '.' } else { c });
+                continue;
+            }
+            let (_, unit_seconds) = units.by_ref().find(|(unit, _)| *unit == c)?;
+            seconds += number.parse::<f64>().ok()? * unit_seconds;
+            number.clear();
+        }
+        number.is_empty().then_some(seconds)
+    }
+
+    pub(super) fn format(duration: &Duration) -> String {
+        let seconds = duration.as_secs();
+        let (days, hours, minutes) = (seconds / 86_400, seconds / 3_600 % 24, seconds / 60 % 60);
+        let mut value = String::from("P");
+        if days > 0 {
+            value.push_str(&format!("{}D", days));
+        }
+        let seconds = seconds % 60;
+        let nanos = duration.subsec_nanos();
+        if hours == 0 && minutes == 0 && seconds == 0 && nanos == 0 && days > 0 {
+            return value;
+        }
+        value.push('T');
+        if hours > 0 {
+            value.push_str(&format!("{}H", hours));
+        }
+        if minutes > 0 {
+            value.push_str(&format!("{}M", minutes));
+        }
+        if seconds > 0 || nanos > 0 || (hours == 0 && minutes == 0) {
+            value.push_str(&seconds.to_string());
+            if nanos > 0 {
+                let fraction = format!("{:09}", nanos);
+                value.push('.');
+                value.push_str(fraction.trim_end_matches('0'));
+            }
+            value.push('S');
+        }
+        value
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::get_youtube_without_user_agent;
+    use crate::transport::{json_response, HttpTransport, MockTransport};
+    use crate::{get_youtube_with_transport, get_youtube_without_user_agent};
+    use futures_util::{StreamExt, TryStreamExt};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Answers `videos.list` with the requested ids in reverse order, counting the requests.
+    #[derive(Default)]
+    struct ReversedVideos(AtomicUsize);
+
+    #[async_trait]
+    impl HttpTransport for ReversedVideos {
+        async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+            self.0.fetch_add(1, Ordering::Relaxed);
+            let (_, id) = request
+                .url()
+                .query_pairs()
+                .find(|(key, _)| key == "id")
+                .unwrap();
+            let items = id
+                .split(',')
+                .rev()
+                .map(|id| format!(r#"{{"id":"{}"}}"#, id))
+                .collect::<Vec<String>>();
+            json_response(200, format!(r#"{{"items":[{}]}}"#, items.join(",")))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_hydrate() {
+        let results: Vec<crate::search::SearchListResource> =
+            serde_json::from_value(serde_json::json!([
+                { "id": { "kind": "youtube#video", "videoId": "video-1" } },
+                { "id": { "kind": "youtube#channel", "channelId": "UCBR8-60-B28hp2BmDPdntcQ" } },
+                { "id": { "kind": "youtube#video", "videoId": "video-2" } },
+                { "id": { "kind": "youtube#video", "videoId": "video-3" } }
+            ]))
+            .unwrap();
+        let transport = Arc::new(ReversedVideos::default());
+        let youtube = get_youtube_with_transport(transport.clone());
+        let videos = youtube.videos().hydrate(&results).await.unwrap();
+        assert_eq!(
+            videos
+                .iter()
+                .map(|video| video.id.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["video-1", "video-2", "video-3"]
+        );
+        assert_eq!(transport.0.load(Ordering::Relaxed), 1);
+
+        let channels = [crate::search::ChannelSearchResult {
+            id: "UCBR8-60-B28hp2BmDPdntcQ".to_string(),
+            snippet: None,
+        }];
+        assert!(youtube
+            .videos()
+            .hydrate(&channels)
+            .await
+            .unwrap()
+            .is_empty());
+        assert_eq!(transport.0.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn test_hydrate_repeated_videos() {
+        // 60 results of 50 videos fit in one request
+        let results = (0..50)
+            .chain(0..10)
+            .map(|i| crate::search::VideoSearchResult {
+                id: format!("video-{}", i),
+                snippet: None,
+            })
+            .collect::<Vec<_>>();
+        let transport = Arc::new(ReversedVideos::default());
+        let videos = get_youtube_with_transport(transport.clone())
+            .videos()
+            .hydrate(&results)
+            .await
+            .unwrap();
+        assert_eq!(transport.0.load(Ordering::Relaxed), 1);
+        assert_eq!(
+            videos
+                .iter()
+                .map(|video| video.id.as_str())
+                .collect::<Vec<&str>>(),
+            results
+                .iter()
+                .map(|result| result.id.as_str())
+                .collect::<Vec<&str>>()
+        );
+    }
 
     #[tokio::test]
-    async fn test_request_without_filters() {
-        let without_filters = get_youtube_without_user_agent()
+    async fn test_list_more_than_50_ids() {
+        let ids = (0..120)
+            .map(|i| format!("video-{}", i))
+            .collect::<Vec<String>>();
+        let transport = Arc::new(ReversedVideos::default());
+        let response = get_youtube_with_transport(transport.clone())
             .videos()
-            .list(vec![])
+            .list(vec![Part::Id], VideoFilter::id(&ids))
+            .concurrent_chunks(true)
             .request()
-            .await;
-        assert!(without_filters.is_err());
-        let err = without_filters.unwrap_err();
+            .await
+            .unwrap();
+        assert_eq!(transport.0.load(Ordering::Relaxed), 3);
+        assert_eq!(
+            response
+                .items
+                .iter()
+                .map(|item| item.id.clone())
+                .collect::<Vec<String>>(),
+            ids
+        );
+        assert_eq!(response.page_info.total_results, 120);
+    }
+
+    #[tokio::test]
+    async fn test_resume_more_than_50_ids() {
+        let ids = (0..51)
+            .map(|i| format!("video-{}", i))
+            .collect::<Vec<String>>();
+        let transport = Arc::new(ReversedVideos::default());
+        let youtube = get_youtube_with_transport(transport.clone());
+        let cursor = youtube
+            .videos()
+            .list(vec![Part::Id], VideoFilter::id(&ids))
+            .cursor(None)
+            .unwrap();
+
+        let response = youtube
+            .resume::<VideoListResource>(cursor)
+            .request()
+            .await
+            .unwrap();
+        assert_eq!(transport.0.load(Ordering::Relaxed), 2);
+        let mut resumed = response
+            .items
+            .into_iter()
+            .map(|item| item.id)
+            .collect::<Vec<String>>();
+        resumed.sort_by_key(|id| ids.iter().position(|other| other == id));
+        assert_eq!(resumed, ids);
+        assert_eq!(response.page_info.total_results, 51);
+    }
+
+    /// Serves the chunks of `video-0` to `video-59`: the first chunk never changes, the second one on every request.
+    /// A request with the current ETag of its chunk is answered with `304 Not Modified`. Records the first id and the
+    /// status of every request.
+    #[derive(Default)]
+    struct ChangingSecondChunk {
+        second_version: AtomicUsize,
+        requests: std::sync::Mutex<Vec<(String, u16)>>,
+    }
+
+    #[async_trait]
+    impl HttpTransport for ChangingSecondChunk {
+        async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+            let (_, id) = request
+                .url()
+                .query_pairs()
+                .find(|(key, _)| key == "id")
+                .unwrap();
+            let id = id.split(',').map(ToString::to_string).collect::<Vec<_>>();
+            let (etag, version) = if id[0] == "video-0" {
+                ("first-0".to_string(), 0)
+            } else {
+                let version = self.second_version.fetch_add(1, Ordering::SeqCst);
+                (format!("second-{}", version), version)
+            };
+            let if_none_match = request.headers().get(reqwest::header::IF_NONE_MATCH);
+            let status =
+                if if_none_match.is_some_and(|if_none_match| if_none_match == etag.as_str()) {
+                    304
+                } else {
+                    200
+                };
+            self.requests.lock().unwrap().push((id[0].clone(), status));
+            if status == 304 {
+                return json_response(304, "");
+            }
+            let items = id
+                .iter()
+                .map(|id| serde_json::json!({ "id": id, "etag": format!("{}-{}", id, version) }))
+                .collect::<Vec<_>>();
+            let body = serde_json::json!({ "etag": etag, "items": items });
+            json_response(200, body.to_string())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_poll_more_than_50_ids() {
+        let ids = (0..60)
+            .map(|i| format!("video-{}", i))
+            .collect::<Vec<String>>();
+        let transport = Arc::new(ChangingSecondChunk::default());
+        let youtube = get_youtube_with_transport(transport.clone());
+        let list = youtube.videos().list(vec![Part::Id], VideoFilter::id(&ids));
+
+        let changes = list
+            .poll(Duration::from_millis(1))
+            .take(2)
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        for change in &changes {
+            assert_eq!(
+                change
+                    .items
+                    .iter()
+                    .map(|item| item.id.clone())
+                    .collect::<Vec<String>>(),
+                ids
+            );
+        }
+        assert_eq!(changes[0].items[0].etag, "video-0-0");
+        assert_eq!(changes[0].items[59].etag, "video-59-0");
+        // the first chunk is kept from its `304 Not Modified`, the second one is replaced
+        assert_eq!(changes[1].items[0].etag, "video-0-0");
+        assert_eq!(changes[1].items[59].etag, "video-59-1");
+        assert_eq!(
+            *transport.requests.lock().unwrap(),
+            vec![
+                ("video-0".to_string(), 200),
+                ("video-50".to_string(), 200),
+                ("video-0".to_string(), 304),
+                ("video-50".to_string(), 200),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get() {
+        let transport = Arc::new(ReversedVideos::default());
+        let youtube = get_youtube_with_transport(transport.clone());
+        let video = youtube.videos().get("Ks-_Mh1QhMc").await.unwrap();
+        assert_eq!(video.unwrap().id, "Ks-_Mh1QhMc");
+
+        let transport = Arc::new(MockTransport::new().respond(
+            reqwest::Method::GET,
+            "videos",
+            200,
+            r#"{"items": []}"#,
+        ));
+        let youtube = get_youtube_with_transport(transport.clone());
+        assert!(youtube.videos().get("deleted").await.unwrap().is_none());
+        let requests = transport.requests();
+        let query = requests[0].1.query().unwrap();
+        assert!(query.contains("part=snippet%2Cstatistics%2CcontentDetails"));
+        assert!(query.contains("id=deleted"));
+    }
+
+    #[tokio::test]
+    async fn test_most_popular() {
+        let transport = Arc::new(MockTransport::new().respond(
+            reqwest::Method::GET,
+            "videos",
+            200,
+            r#"{"items": [{"id": "Ks-_Mh1QhMc"}]}"#,
+        ));
+        let youtube = get_youtube_with_transport(transport.clone());
+        let videos = youtube
+            .videos()
+            .most_popular(RegionCode::new("KR").unwrap(), Some("10".to_string()))
+            .items(None)
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        assert_eq!(videos.len(), 1);
+        let requests = transport.requests();
+        let query = requests[0].1.query().unwrap();
+        assert!(query.contains("chart=mostPopular"));
+        assert!(query.contains("regionCode=KR"));
+        assert!(query.contains("videoCategoryId=10"));
+        assert!(query.contains("maxResults=50"));
+
+        let err = youtube
+            .videos()
+            .list(vec![Part::Id], VideoFilter::id(["Ks-_Mh1QhMc"]))
+            .video_category_id("10")
+            .request()
+            .await
+            .unwrap_err();
         assert_eq!(
-            "builder error: \"No filter selected. Expected one of: chart, id, my_rating\"",
+            "builder error: \"Request contains an invalid argument: parameter `video_category_id` can only be used with `chart`\"",
             format!("{}", err)
         );
     }
 
+    /// the response of `fields("items(id,snippet/title)")` omits the other fields of the resources
+    #[test]
+    fn test_deserialize_partial_response() {
+        let partial: VideoListResponse = serde_json::from_str(
+            r#"{
+                "items": [
+                    {
+                        "id": "Ks-_Mh1QhMc",
+                        "snippet": {
+                            "title": "Your body language may shape who you are | Amy Cuddy"
+                        }
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(partial.items[0].id, "Ks-_Mh1QhMc");
+        assert_eq!(
+            partial.items[0].snippet.as_ref().unwrap().title,
+            "Your body language may shape who you are | Amy Cuddy"
+        );
+        assert!(partial.items[0]
+            .snippet
+            .as_ref()
+            .unwrap()
+            .published_at
+            .is_none());
+        assert!(partial.next_page_token.is_none());
+    }
+
+    #[test]
+    fn test_iso8601_duration() {
+        let parse = iso8601_duration::parse;
+        assert_eq!(parse("PT15M33S"), Some(Duration::from_secs(933)));
+        assert_eq!(parse("PT1H2M3S"), Some(Duration::from_secs(3723)));
+        assert_eq!(parse("P1DT2H"), Some(Duration::from_secs(93_600)));
+        assert_eq!(parse("P2W"), Some(Duration::from_secs(1_209_600)));
+        assert_eq!(parse("PT0S"), Some(Duration::ZERO));
+        assert_eq!(parse("P0D"), Some(Duration::ZERO));
+        assert_eq!(parse("PT1.5S"), Some(Duration::from_millis(1500)));
+        assert_eq!(parse("P1Y"), None);
+        assert_eq!(parse("PT"), None);
+        assert_eq!(parse("PT3S15M"), None);
+        assert_eq!(parse("15:33"), None);
+
+        let format = iso8601_duration::format;
+        assert_eq!(format(&Duration::from_secs(933)), "PT15M33S");
+        assert_eq!(format(&Duration::from_secs(93_600)), "P1DT2H");
+        assert_eq!(format(&Duration::from_secs(86_400)), "P1D");
+        assert_eq!(format(&Duration::ZERO), "PT0S");
+        assert_eq!(format(&Duration::from_millis(1500)), "PT1.5S");
+    }
+
+    #[test]
+    fn test_deserialize_content_details() {
+        let content_details: VideoContentDetail = serde_json::from_str(
+            r#"{
+                "duration": "PT20M4S",
+                "dimension": "2d",
+                "definition": "hd",
+                "caption": "true",
+                "licensedContent": true,
+                "regionRestriction": { "blocked": ["DE"] },
+                "contentRating": { "ytRating": "ytAgeRestricted" },
+                "projection": "rectangular"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(content_details.duration, Some(Duration::from_secs(1204)));
+        assert_eq!(content_details.definition.as_deref(), Some("hd"));
+        assert!(content_details.licensed_content);
+        assert_eq!(
+            content_details.region_restriction.unwrap().blocked,
+            Some(vec!["DE".to_string()])
+        );
+        assert!(content_details.content_rating.unwrap().is_age_restricted());
+        assert_eq!(
+            serde_json::to_value(&VideoContentDetail {
+                duration: Some(Duration::from_secs(1204)),
+                ..serde_json::from_str("{}").unwrap()
+            })
+            .unwrap()["duration"],
+            "PT20M4S"
+        );
+
+        let upcoming: VideoContentDetail = serde_json::from_str(r#"{"duration": "P0D"}"#).unwrap();
+        assert_eq!(upcoming.duration, Some(Duration::ZERO));
+        assert!(serde_json::from_str::<VideoContentDetail>(r#"{"duration": "1:02"}"#).is_err());
+    }
+
+    #[test]
+    fn test_content_rating() {
+        let content_rating: ContentRating = serde_json::from_str(
+            r#"{
+                "mpaaRating": "mpaaPg13",
+                "fskRating": "fsk12",
+                "djctqRatingReasons": ["djctqViolence"]
+            }"#,
+        )
+        .unwrap();
+        assert!(!content_rating.is_age_restricted());
+        assert_eq!(content_rating.rating("mpaaRating"), Some("mpaaPg13"));
+        assert_eq!(content_rating.rating("fskRating"), Some("fsk12"));
+        assert_eq!(content_rating.rating("djctqRatingReasons"), None);
+        assert_eq!(content_rating.rating("bbfcRating"), None);
+        assert_eq!(
+            serde_json::to_value(&content_rating).unwrap()["djctqRatingReasons"][0],
+            "djctqViolence"
+        );
+    }
+
+    #[test]
+    fn test_region_restriction() {
+        let allowed: RegionRestriction =
+            serde_json::from_str(r#"{"allowed": ["US", "CA"]}"#).unwrap();
+        assert!(allowed.is_viewable_in("CA"));
+        assert!(allowed.is_viewable_in("us"));
+        assert!(!allowed.is_viewable_in("DE"));
+
+        let blocked: RegionRestriction = serde_json::from_str(r#"{"blocked": ["DE"]}"#).unwrap();
+        assert!(!blocked.is_viewable_in("DE"));
+        assert!(blocked.is_viewable_in("KR"));
+
+        let blocked_everywhere: RegionRestriction =
+            serde_json::from_str(r#"{"allowed": []}"#).unwrap();
+        assert!(!blocked_everywhere.is_viewable_in("US"));
+    }
+
+    #[test]
+    fn test_deserialize_parts() {
+        let response: VideoListResponse = serde_json::from_str(
+            r#"{
+                "items": [
+                    {
+                        "id": "Ks-_Mh1QhMc",
+                        "contentDetails": { "duration": "PT21M3S", "definition": "hd" },
+                        "status": { "uploadStatus": "processed", "privacyStatus": "public", "embeddable": true },
+                        "player": { "embedHtml": "<iframe></iframe>" },
+                        "topicDetails": { "topicCategories": ["https://en.wikipedia.org/wiki/Knowledge"] },
+                        "recordingDetails": {},
+                        "processingDetails": { "processingStatus": "succeeded" },
+                        "suggestions": { "editorSuggestions": ["videoStabilize"] }
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+        let video = &response.items[0];
+        assert_eq!(
+            video.content_details.as_ref().unwrap().duration,
+            Some(Duration::from_secs(1263))
+        );
+        assert_eq!(
+            video.status.as_ref().unwrap().privacy_status,
+            Some(PrivacyStatus::Public)
+        );
+        assert_eq!(
+            video.player.as_ref().unwrap().embed_html.as_deref(),
+            Some("<iframe></iframe>")
+        );
+        assert_eq!(
+            video.topic_details.as_ref().unwrap().topic_categories.len(),
+            1
+        );
+        assert!(video.recording_details.is_some());
+        assert!(video.file_details.is_none());
+        assert!(video.statistics.is_none());
+        assert_eq!(
+            video.suggestions.as_ref().unwrap().editor_suggestions,
+            vec!["videoStabilize"]
+        );
+    }
+
+    /// the api sends the counts as strings
+    #[test]
+    fn test_deserialize_statistics() {
+        let video: VideoListResource = serde_json::from_str(
+            r#"{
+                "id": "Ks-_Mh1QhMc",
+                "statistics": {
+                    "viewCount": "23949683",
+                    "likeCount": "421056",
+                    "favoriteCount": "0",
+                    "commentCount": "8652"
+                },
+                "liveStreamingDetails": {
+                    "scheduledStartTime": "2024-01-01T00:00:00Z",
+                    "concurrentViewers": "1520"
+                }
+            }"#,
+        )
+        .unwrap();
+        let statistics = video.statistics.unwrap();
+        assert_eq!(statistics.view_count, Some(23_949_683));
+        assert_eq!(statistics.like_count, Some(421_056));
+        assert_eq!(statistics.dislike_count, None);
+        assert_eq!(statistics.favorite_count, 0);
+        assert_eq!(statistics.comment_count, Some(8652));
+        assert_eq!(
+            video.live_streaming_details.unwrap().concurrent_viewers,
+            Some(1520)
+        );
+    }
+
+    /// a serialized response uses the keys of the api, so that it deserializes back
+    #[test]
+    fn test_round_trip() {
+        let json: serde_json::Value = serde_json::from_str(
+            r#"{
+                "kind": "youtube#videoListResponse",
+                "etag": "etag",
+                "nextPageToken": "page-2",
+                "prevPageToken": null,
+                "regionCode": null,
+                "pageInfo": { "totalResults": 1, "resultsPerPage": 5 },
+                "items": [
+                    {
+                        "kind": "youtube#video",
+                        "etag": "etag",
+                        "id": "Ks-_Mh1QhMc",
+                        "snippet": null,
+                        "contentDetails": {
+                            "duration": "PT21M3S",
+                            "dimension": null,
+                            "definition": "hd",
+                            "caption": null,
+                            "licensedContent": false,
+                            "regionRestriction": null,
+                            "contentRating": null,
+                            "projection": null,
+                            "hasCustomThumbnail": null
+                        },
+                        "status": null,
+                        "statistics": {
+                            "viewCount": 23949683,
+                            "likeCount": null,
+                            "dislikeCount": null,
+                            "favoriteCount": 0,
+                            "commentCount": 8652
+                        },
+                        "player": null,
+                        "topicDetails": null,
+                        "recordingDetails": null,
+                        "fileDetails": null,
+                        "processingDetails": null,
+                        "suggestions": null,
+                        "liveStreamingDetails": null,
+                        "localizations": null
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+        let response: VideoListResponse = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&response).unwrap(), json);
+
+        let mut modified = response.clone();
+        assert_eq!(modified, response);
+        modified.items[0].statistics.as_mut().unwrap().comment_count = Some(8653);
+        assert_ne!(modified, response);
+    }
+
+    #[test]
+    fn test_deserialize_owner_details() {
+        let recording_details: VideoRecordingDetails = serde_json::from_str(
+            r#"{
+                "recordingDate": "2012-06-26T00:00:00Z",
+                "location": { "latitude": 37.42, "longitude": -122.08, "altitude": 0 }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            recording_details.recording_date.unwrap().to_rfc3339(),
+            "2012-06-26T00:00:00+00:00"
+        );
+        assert_eq!(recording_details.location.unwrap().latitude, Some(37.42));
+
+        let file_details: VideoFileDetails = serde_json::from_str(
+            r#"{
+                "fileName": "talk.mp4",
+                "fileSize": "734003200",
+                "fileType": "video",
+                "container": "mov",
+                "videoStreams": [
+                    {
+                        "widthPixels": 1920,
+                        "heightPixels": 1080,
+                        "frameRateFps": 29.97,
+                        "aspectRatio": 1.777,
+                        "codec": "h264",
+                        "bitrateBps": "8000000",
+                        "rotation": "none"
+                    }
+                ],
+                "audioStreams": [{ "channelCount": 2, "codec": "aac", "bitrateBps": "128000" }],
+                "durationMs": "1204000",
+                "bitrateBps": "8128000"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(file_details.file_size, Some(734_003_200));
+        assert_eq!(file_details.video_streams[0].height_pixels, Some(1080));
+        assert_eq!(file_details.audio_streams[0].channel_count, Some(2));
+        assert_eq!(file_details.duration_ms, Some(1_204_000));
+        assert_eq!(file_details.video_streams[0].bitrate_bps, Some(8_000_000));
+
+        let processing_details: VideoProcessingDetails = serde_json::from_str(
+            r#"{
+                "processingStatus": "processing",
+                "processingProgress": { "partsTotal": "1000", "partsProcessed": "250", "timeLeftMs": "60000" },
+                "fileDetailsAvailability": "inProgress",
+                "thumbnailsAvailability": "available"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            processing_details.processing_status.as_deref(),
+            Some("processing")
+        );
+        let progress = processing_details.processing_progress.unwrap();
+        assert_eq!(progress.parts_processed, Some(250));
+        assert!(processing_details.processing_failure_reason.is_none());
+
+        let suggestions: VideoSuggestions = serde_json::from_str(
+            r#"{
+                "processingHints": ["sendBestQualityVideo"],
+                "tagSuggestions": [{ "tag": "body language", "categoryRestricts": ["27"] }],
+                "editorSuggestions": ["videoAutoLevels"]
+            }"#,
+        )
+        .unwrap();
+        assert!(suggestions.processing_errors.is_empty());
+        assert_eq!(suggestions.tag_suggestions[0].tag, "body language");
+        assert_eq!(suggestions.editor_suggestions, vec!["videoAutoLevels"]);
+
+        let topic_details: VideoTopicDetails = serde_json::from_str(
+            r#"{ "topicCategories": ["https://en.wikipedia.org/wiki/Knowledge"] }"#,
+        )
+        .unwrap();
+        assert!(topic_details.topic_ids.is_empty());
+        assert_eq!(topic_details.topic_categories.len(), 1);
+    }
+
+    #[test]
+    fn test_deserialize_localizations() {
+        let response: VideoListResponse = serde_json::from_str(
+            r#"{
+                "items": [
+                    {
+                        "id": "Ks-_Mh1QhMc",
+                        "localizations": {
+                            "es": { "title": "El lenguaje corporal moldea nuestra identidad", "description": "" }
+                        }
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+        let localizations = response.items[0].localizations.as_ref().unwrap();
+        assert_eq!(
+            localizations["es"].title,
+            "El lenguaje corporal moldea nuestra identidad"
+        );
+        assert!(response.items[0].snippet.is_none());
+    }
+
     #[tokio::test]
-    async fn test_request_multiple_filters() {
-        let multiple_filters = get_youtube_without_user_agent()
+    async fn test_request_without_ids() {
+        let without_ids = get_youtube_without_user_agent()
             .videos()
-            .list(vec![Part::Snippet])
-            .chart(Chart::MostPopular)
-            .id(vec!["Ks-_Mh1QhMc", "c0KYU2j0TM4", "eIho2S0ZahI"])
+            .list(vec![], VideoFilter::id(Vec::<String>::new()))
             .request()
             .await;
-        assert!(multiple_filters.is_err());
-        let err = multiple_filters.unwrap_err();
+        assert!(without_ids.is_err());
+        let err = without_ids.unwrap_err();
         assert_eq!(
-            "builder error: \"Incompatible parameters specified in the request: chart, id\"",
+            "builder error: \"The `id` filter requires at least one id\"",
             format!("{}", err)
         );
     }
 
     #[tokio::test]
     async fn test_get_by_id() {
-        let response = get_youtube_without_user_agent()
+        let transport = Arc::new(MockTransport::new().respond(
+            reqwest::Method::GET,
+            "videos",
+            200,
+            r#"{
+                "kind": "youtube#videoListResponse",
+                "items": [{
+                    "kind": "youtube#video",
+                    "id": "Ks-_Mh1QhMc",
+                    "snippet": {
+                        "publishedAt": "2012-10-01T15:27:35Z",
+                        "channelId": "UCAuUUnT6oDeKwE6v1NGQxug",
+                        "title": "Your body language may shape who you are",
+                        "description": "",
+                        "thumbnails": {},
+                        "channelTitle": "TED",
+                        "tags": ["body language", "psychology"],
+                        "categoryId": "22",
+                        "liveBroadcastContent": "none"
+                    },
+                    "contentDetails": { "duration": "PT21M3S", "definition": "hd", "licensedContent": true },
+                    "statistics": { "viewCount": "24000000", "likeCount": "420000", "favoriteCount": "0" }
+                }]
+            }"#,
+        ));
+        let response = get_youtube_with_transport(transport.clone())
             .videos()
-            .list(vec![Part::Snippet])
-            .id(vec!["Ks-_Mh1QhMc"])
+            .list(
+                vec![Part::Snippet, Part::ContentDetails, Part::Statistics],
+                VideoFilter::id(vec!["Ks-_Mh1QhMc"]),
+            )
             .request()
             .await
             .unwrap();
         assert_eq!(response.items.len(), 1);
-        assert_eq!(response.items[0].id, "Ks-_Mh1QhMc");
+        let video = &response.items[0];
+        assert_eq!(video.id, "Ks-_Mh1QhMc");
+        let snippet = video.snippet.as_ref().unwrap();
+        assert_eq!(snippet.title, "Your body language may shape who you are");
+        assert_eq!(snippet.channel_title, "TED");
+        assert_eq!(snippet.category_id.as_deref(), Some("22"));
+        assert_eq!(
+            snippet.tags,
+            Some(vec!["body language".to_string(), "psychology".to_string()])
+        );
+        let content_details = video.content_details.as_ref().unwrap();
+        assert_eq!(content_details.duration, Some(Duration::from_secs(1263)));
+        assert!(content_details.licensed_content);
+        let statistics = video.statistics.as_ref().unwrap();
+        assert_eq!(statistics.view_count, Some(24000000));
+        assert_eq!(statistics.like_count, Some(420000));
+        assert_eq!(statistics.comment_count, None);
+        assert!(transport.requests()[0]
+            .1
+            .query()
+            .unwrap()
+            .contains("id=Ks-_Mh1QhMc"));
     }
 
     #[tokio::test]
     async fn test_get_by_multiple_ids() {
-        let response = get_youtube_without_user_agent()
+        let transport = Arc::new(MockTransport::new().respond(
+            reqwest::Method::GET,
+            "videos",
+            200,
+            r#"{
+                "kind": "youtube#videoListResponse",
+                "items": [
+                    { "kind": "youtube#video", "id": "Ks-_Mh1QhMc", "snippet": { "title": "The first video" } },
+                    { "kind": "youtube#video", "id": "c0KYU2j0TM4", "snippet": { "title": "The second video" } },
+                    { "kind": "youtube#video", "id": "eIho2S0ZahI", "snippet": { "title": "The third video" } }
+                ]
+            }"#,
+        ));
+        let response = get_youtube_with_transport(transport.clone())
             .videos()
-            .list(vec![Part::Snippet])
-            .id(vec!["Ks-_Mh1QhMc", "c0KYU2j0TM4", "eIho2S0ZahI"])
+            .list(
+                vec![Part::Snippet],
+                VideoFilter::id(vec!["Ks-_Mh1QhMc", "c0KYU2j0TM4", "eIho2S0ZahI"]),
+            )
             .request()
             .await
             .unwrap();
-        assert_eq!(response.items.len(), 3);
-        assert_eq!(response.items[0].id, "Ks-_Mh1QhMc");
-        assert_eq!(response.items[1].id, "c0KYU2j0TM4");
-        assert_eq!(response.items[2].id, "eIho2S0ZahI");
+        assert_eq!(
+            response
+                .items
+                .iter()
+                .map(|item| (
+                    item.id.as_str(),
+                    item.snippet.as_ref().unwrap().title.as_str()
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                ("Ks-_Mh1QhMc", "The first video"),
+                ("c0KYU2j0TM4", "The second video"),
+                ("eIho2S0ZahI", "The third video"),
+            ]
+        );
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0]
+            .1
+            .query()
+            .unwrap()
+            .contains("id=Ks-_Mh1QhMc%2Cc0KYU2j0TM4%2CeIho2S0ZahI"));
     }
 
     #[tokio::test]
     async fn test_get_most_popular_videos() {
-        let response = get_youtube_without_user_agent()
+        let items = (0..10)
+            .map(|i| serde_json::json!({ "kind": "youtube#video", "id": format!("video-{}", i) }))
+            .collect::<Vec<_>>();
+        let body = serde_json::json!({
+            "kind": "youtube#videoListResponse",
+            "nextPageToken": "CAoQAA",
+            "pageInfo": { "totalResults": 200, "resultsPerPage": 10 },
+            "items": items,
+        });
+        let transport = Arc::new(MockTransport::new().respond(
+            reqwest::Method::GET,
+            "videos",
+            200,
+            body.to_string(),
+        ));
+        let response = get_youtube_with_transport(transport.clone())
             .videos()
-            .list(vec![Part::Snippet])
-            .chart(Chart::MostPopular)
+            .list(vec![Part::Snippet], VideoFilter::Chart(Chart::MostPopular))
             .max_results(10)
             .request()
             .await
             .unwrap();
-        println!("{:#?}", response);
         assert_eq!(response.items.len(), 10);
+        assert_eq!(response.items[9].id, "video-9");
+        assert_eq!(response.next_page_token.as_deref(), Some("CAoQAA"));
+        assert_eq!(response.page_info.total_results, 200);
+        let query = transport.requests()[0].1.query().unwrap().to_string();
+        assert!(query.contains("chart=mostPopular"));
+        assert!(query.contains("maxResults=10"));
     }
 
-    /// this test is ignored because live videos might be not "upcoming" or "active" at the time of testing
-    #[ignore]
     #[tokio::test]
     async fn test_get_upcoming_live_videos() {
-        let response = get_youtube_without_user_agent()
+        let transport = Arc::new(MockTransport::new().respond(
+            reqwest::Method::GET,
+            "videos",
+            200,
+            r#"{
+                "kind": "youtube#videoListResponse",
+                "items": [{
+                    "kind": "youtube#video",
+                    "id": "wPXfKeWU2YE",
+                    "snippet": { "title": "An upcoming stream", "liveBroadcastContent": "upcoming" },
+                    "liveStreamingDetails": {
+                        "scheduledStartTime": "2030-01-01T12:00:00Z",
+                        "activeLiveChatId": "live-chat-id"
+                    }
+                }]
+            }"#,
+        ));
+        let response = get_youtube_with_transport(transport)
             .videos()
-            .list(vec![Part::Snippet, Part::LiveStreamingDetails])
-            .id(vec!["wPXfKeWU2YE"])
+            .list(
+                vec![Part::Snippet, Part::LiveStreamingDetails],
+                VideoFilter::id(vec!["wPXfKeWU2YE"]),
+            )
             .request()
             .await
             .unwrap();
         assert_eq!(response.items.len(), 1);
-        assert_eq!(response.items[0].id, "wPXfKeWU2YE");
+        let video = &response.items[0];
+        assert_eq!(video.id, "wPXfKeWU2YE");
+        assert_eq!(
+            video.snippet.as_ref().unwrap().live_broadcast_content,
+            Some(LiveBroadcastContent::Upcoming)
+        );
+        let live_streaming_details = video.live_streaming_details.as_ref().unwrap();
+        assert_eq!(
+            live_streaming_details.scheduled_start_time,
+            Some("2030-01-01T12:00:00Z".parse().unwrap())
+        );
+        assert_eq!(live_streaming_details.actual_start_time, None);
+        assert_eq!(
+            live_streaming_details.active_live_chat_id.as_deref(),
+            Some("live-chat-id")
+        );
     }
 
     /// test use filters that require authentication wihtout authentication
@@ -731,15 +2665,164 @@ mod tests {
     async fn test_request_without_auth() {
         let without_auth = get_youtube_without_user_agent()
             .videos()
-            .list(vec![])
-            .my_rating(MyRating::Like)
+            .list(vec![], VideoFilter::MyRating(MyRating::Like))
             .request()
             .await;
-        assert_eq!(true, without_auth.is_err());
+        assert!(without_auth.is_err());
         let err = without_auth.unwrap_err();
         assert_eq!(
             "builder error: \"The request uses the `my_rating` parameter but is not properly authorized\"",
             format!("{}", err)
         );
     }
+
+    #[test]
+    fn test_dedupe_parts() {
+        let youtube = get_youtube_without_user_agent().with_access_token("access-token");
+        let mut list = youtube.videos().list(
+            Part::all_public().into_iter().chain([
+                Part::Snippet,
+                Part::FileDetails,
+                Part::FileDetails,
+            ]),
+            VideoFilter::id(["Ks-_Mh1QhMc"]),
+        );
+        assert_eq!(
+            list.params(None).unwrap()["part"],
+            "contentDetails,id,liveStreamingDetails,localizations,player,recordingDetails,snippet,statistics,\
+             status,topicDetails,fileDetails"
+        );
+
+        list.part([Part::Id, Part::Snippet, Part::Id]);
+        assert_eq!(list.params(None).unwrap()["part"], "id,snippet");
+    }
+
+    #[tokio::test]
+    async fn test_request_owner_parts() {
+        let youtube = get_youtube_without_user_agent();
+        let without_auth = youtube
+            .videos()
+            .list(
+                vec![Part::Snippet, Part::FileDetails, Part::Suggestions],
+                VideoFilter::id(vec!["Ks-_Mh1QhMc"]),
+            )
+            .request()
+            .await;
+        assert_eq!(
+            "builder error: \"The request uses the parts only returned to the owner of the resource but is not \
+             properly authorized: fileDetails, suggestions\"",
+            format!("{}", without_auth.unwrap_err())
+        );
+
+        let chart = youtube
+            .with_access_token("access-token")
+            .videos()
+            .list(
+                vec![Part::ProcessingDetails],
+                VideoFilter::Chart(Chart::MostPopular),
+            )
+            .request()
+            .await;
+        assert_eq!(
+            "builder error: \"Incompatible parameters specified in the request: chart, processingDetails\"",
+            format!("{}", chart.unwrap_err())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_rating_without_auth() {
+        let without_auth = get_youtube_without_user_agent()
+            .videos()
+            .get_rating(vec!["Ks-_Mh1QhMc"])
+            .request()
+            .await;
+        assert!(without_auth.is_err());
+        let err = without_auth.unwrap_err();
+        assert_eq!(
+            "builder error: \"The request to `videos/getRating` requires OAuth 2.0 authorization\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rate_with_unspecified_rating() {
+        let unspecified = get_youtube_without_user_agent()
+            .videos()
+            .rate("Ks-_Mh1QhMc", Rating::Unspecified)
+            .request()
+            .await;
+        assert!(unspecified.is_err());
+        let err = unspecified.unwrap_err();
+        assert_eq!(
+            "builder error: \"Request contains an invalid argument: parameter `rating` must be one of: dislike, like, none\"",
+            format!("{}", err)
+        );
+    }
+
+    #[test]
+    fn test_deserialize_get_rating_response() {
+        let response: VideoGetRatingResponse = serde_json::from_str(
+            r#"{
+                "kind": "youtube#videoGetRatingResponse",
+                "etag": "etag",
+                "items": [
+                    { "videoId": "Ks-_Mh1QhMc", "rating": "like" },
+                    { "videoId": "c0KYU2j0TM4", "rating": "none" }
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(response.items[0].rating, Rating::Like);
+        assert_eq!(response.items[1].rating, Rating::None);
+    }
+
+    #[tokio::test]
+    async fn test_report_abuse_without_auth() {
+        let without_auth = get_youtube_without_user_agent()
+            .videos()
+            .report_abuse("Ks-_Mh1QhMc", "S")
+            .secondary_reason_id("27")
+            .comments("spam links in the description")
+            .request()
+            .await;
+        assert!(without_auth.is_err());
+        let err = without_auth.unwrap_err();
+        assert_eq!(
+            "builder error: \"The request to `videos/reportAbuse` requires OAuth 2.0 authorization\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_report_abuse_with_unknown_secondary_reason() {
+        let reasons: VideoAbuseReportReasonListResponse = serde_json::from_str(
+            r#"{
+                "kind": "youtube#videoAbuseReportReasonListResponse",
+                "etag": "etag",
+                "items": [{
+                    "kind": "youtube#videoAbuseReportReason",
+                    "etag": "etag",
+                    "id": "S",
+                    "snippet": {
+                        "label": "Spam or misleading",
+                        "secondaryReasons": [{ "id": "27", "label": "Mass advertising" }]
+                    }
+                }]
+            }"#,
+        )
+        .unwrap();
+        let unknown = get_youtube_without_user_agent()
+            .videos()
+            .report_abuse("Ks-_Mh1QhMc", "S")
+            .secondary_reason_id("28")
+            .reasons(&reasons)
+            .request()
+            .await;
+        assert!(unknown.is_err());
+        let err = unknown.unwrap_err();
+        assert_eq!(
+            "builder error: \"The secondary reason `28` does not belong to the abuse report reason `S`\"",
+            format!("{}", err)
+        );
+    }
 }
@@ -0,0 +1,415 @@
+use crate::{
+    auth::{Authorizer, OAuthTokenAuth},
+    error::{Error, Result},
+    multipart_related_body, ImageMimeType, RequestBase, YouTube, YouTubeDataApi,
+};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// The maximum file size of a watermark image (10MB).
+const MAX_WATERMARK_SIZE: usize = 10 * 1024 * 1024;
+
+#[derive(Clone, Copy)]
+pub struct WatermarksService<'a> {
+    youtube: &'a YouTube,
+}
+
+impl<'a> WatermarksService<'a> {
+    pub(crate) fn new(youtube: &'a YouTube) -> Self {
+        Self { youtube }
+    }
+
+    pub fn set(
+        self,
+        channel_id: impl Into<String>,
+        image: Vec<u8>,
+        mime_type: ImageMimeType,
+        timing: WatermarkTiming,
+    ) -> WatermarkSet<'a> {
+        WatermarkSet::new(self, channel_id, image, mime_type, timing)
+    }
+
+    pub fn unset(self, channel_id: impl Into<String>) -> WatermarkUnset<'a> {
+        WatermarkUnset::new(self, channel_id)
+    }
+}
+
+/// Parameters for the `set` method of the `watermarks` api. details:
+/// [link](https://developers.google.com/youtube/v3/docs/watermarks/set)
+pub struct WatermarkSet<'a> {
+    service: WatermarksService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    channel_id: String,
+
+    // optional parameters
+    on_behalf_of_content_owner: Option<String>,
+
+    // request body
+    timing: WatermarkTiming,
+    position: WatermarkCornerPosition,
+    target_channel_id: Option<String>,
+
+    // media
+    image: Vec<u8>,
+    mime_type: ImageMimeType,
+}
+
+impl RequestBase for WatermarkSet<'_> {
+    fn api_path(&self) -> &str {
+        "watermarks/set"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for WatermarkSet<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> WatermarkSet<'a> {
+    pub(crate) fn new(
+        service: WatermarksService<'a>,
+        channel_id: impl Into<String>,
+        image: Vec<u8>,
+        mime_type: ImageMimeType,
+        timing: WatermarkTiming,
+    ) -> Self {
+        Self {
+            service,
+            authorizer: None,
+            channel_id: channel_id.into(),
+            on_behalf_of_content_owner: None,
+            timing,
+            position: WatermarkCornerPosition::TopRight,
+            target_channel_id: None,
+            image,
+            mime_type,
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+
+    pub fn on_behalf_of_content_owner(
+        &mut self,
+        on_behalf_of_content_owner: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+        self
+    }
+
+    /// The corner of the player where the watermark appears. The default value is `TopRight`.
+    pub fn position(&mut self, position: WatermarkCornerPosition) -> &mut Self {
+        self.position = position;
+        self
+    }
+
+    /// The channel to which the watermark links. The default is the channel the watermark is set for.
+    pub fn target_channel_id(&mut self, target_channel_id: impl Into<String>) -> &mut Self {
+        self.target_channel_id = Some(target_channel_id.into());
+        self
+    }
+
+    /// Uploads a watermark image to YouTube and sets it for a channel. The api responds with an empty body on success.
+    pub async fn request(&self) -> Result<()> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        self.insert_query_parameter(&mut params, "uploadType", Some("multipart"));
+
+        // required parameters
+        if self.channel_id.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required parameter `channel_id` is empty",
+            ));
+        }
+        self.insert_query_parameter(&mut params, "channelId", Some(&self.channel_id));
+
+        // optional parameters
+        self.insert_content_owner_parameters(
+            youtube,
+            &mut params,
+            self.on_behalf_of_content_owner.as_deref(),
+            None,
+        )?;
+
+        // media
+        if self.image.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required media body `image` is empty",
+            ));
+        }
+        if self.image.len() > MAX_WATERMARK_SIZE {
+            return Err(Error::invalid_parameter(
+                "The watermark image exceeds the maximum file size of 10MB",
+            ));
+        }
+        let metadata = InvideoBranding {
+            timing: &self.timing,
+            position: InvideoPosition {
+                position_type: "corner",
+                corner_position: &self.position,
+            },
+            target_channel_id: self.target_channel_id.as_deref(),
+        };
+        let (content_type, body) = multipart_related_body(&metadata, &self.image, self.mime_type)?;
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .post(self.url(&youtube.inner().upload_base_path))
+                .header(reqwest::header::CONTENT_TYPE, content_type)
+                .query(&params)
+                .body(body),
+        )?;
+        self.send_without_content(youtube, request).await
+    }
+}
+
+/// Parameters for the `unset` method of the `watermarks` api. details:
+/// [link](https://developers.google.com/youtube/v3/docs/watermarks/unset)
+pub struct WatermarkUnset<'a> {
+    service: WatermarksService<'a>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+
+    // required parameters
+    channel_id: String,
+
+    // optional parameters
+    on_behalf_of_content_owner: Option<String>,
+}
+
+impl RequestBase for WatermarkUnset<'_> {
+    fn api_path(&self) -> &str {
+        "watermarks/unset"
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl YouTubeDataApi for WatermarkUnset<'_> {
+    fn authorizer(&self) -> Option<&Arc<dyn Authorizer>> {
+        self.authorizer.as_ref()
+    }
+}
+
+impl<'a> WatermarkUnset<'a> {
+    pub(crate) fn new(service: WatermarksService<'a>, channel_id: impl Into<String>) -> Self {
+        Self {
+            service,
+            authorizer: None,
+            channel_id: channel_id.into(),
+            on_behalf_of_content_owner: None,
+        }
+    }
+
+    /// Sends this request with the access token of another user instead of the credentials of the `YouTube`
+    /// handle, so one handle and its connection pool can serve many users.
+    pub fn as_user(&mut self, access_token: impl Into<String>) -> &mut Self {
+        self.authorizer = Some(Arc::new(OAuthTokenAuth::new(access_token)));
+        self
+    }
+
+    pub fn on_behalf_of_content_owner(
+        &mut self,
+        on_behalf_of_content_owner: impl Into<String>,
+    ) -> &mut Self {
+        self.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+        self
+    }
+
+    /// Deletes the watermark image of a channel. The api responds with an empty body on success.
+    pub async fn request(&self) -> Result<()> {
+        let youtube = &self.service.youtube;
+
+        // createquery parameter map
+        let mut params = HashMap::<String, String>::new();
+
+        // required parameters
+        if self.channel_id.is_empty() {
+            return Err(Error::missing_required_parameter(
+                "Required parameter `channel_id` is empty",
+            ));
+        }
+        self.insert_query_parameter(&mut params, "channelId", Some(&self.channel_id));
+
+        // optional parameters
+        self.insert_content_owner_parameters(
+            youtube,
+            &mut params,
+            self.on_behalf_of_content_owner.as_deref(),
+            None,
+        )?;
+
+        let request = self.bearer_auth(
+            youtube,
+            youtube
+                .inner()
+                .client
+                .post(self.url(&youtube.inner().base_path))
+                .query(&params),
+        )?;
+        self.send_without_content(youtube, request).await
+    }
+}
+
+/// The metadata of a watermark, sent as the `invideoBranding` resource.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct InvideoBranding<'a> {
+    timing: &'a WatermarkTiming,
+    position: InvideoPosition<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target_channel_id: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct InvideoPosition<'a> {
+    #[serde(rename = "type")]
+    position_type: &'a str,
+    corner_position: &'a WatermarkCornerPosition,
+}
+
+/// The timing object specifies when the watermark is displayed during video playback.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WatermarkTiming {
+    /// Defines how the `offset_ms` value is measured.
+    #[serde(rename = "type")]
+    pub timing_type: WatermarkTimingType,
+
+    /// The time offset, in milliseconds, that determines when the watermark appears during video playback.
+    #[serde(rename = "offsetMs", default)]
+    pub offset_ms: u64,
+
+    /// The length of time, in milliseconds, that the watermark displays. If unset, the watermark is displayed until
+    /// the end of the video.
+    #[serde(rename = "durationMs", skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<u64>,
+}
+
+impl WatermarkTiming {
+    /// Displays the watermark `offset_ms` milliseconds after the video starts.
+    pub fn offset_from_start(offset_ms: u64) -> Self {
+        Self {
+            timing_type: WatermarkTimingType::OffsetFromStart,
+            offset_ms,
+            duration_ms: None,
+        }
+    }
+
+    /// Displays the watermark `offset_ms` milliseconds before the video ends.
+    pub fn offset_from_end(offset_ms: u64) -> Self {
+        Self {
+            timing_type: WatermarkTimingType::OffsetFromEnd,
+            offset_ms,
+            duration_ms: None,
+        }
+    }
+
+    /// Limits how long the watermark displays.
+    pub fn duration_ms(mut self, duration_ms: u64) -> Self {
+        self.duration_ms = Some(duration_ms);
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WatermarkTimingType {
+    /// The offset is measured from the start of the video.
+    #[serde(rename = "offsetFromStart")]
+    OffsetFromStart,
+
+    /// The offset is measured from the end of the video.
+    #[serde(rename = "offsetFromEnd")]
+    OffsetFromEnd,
+}
+
+/// The corner of the player where the watermark appears.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WatermarkCornerPosition {
+    #[serde(rename = "bottomLeft")]
+    BottomLeft,
+
+    #[serde(rename = "bottomRight")]
+    BottomRight,
+
+    #[serde(rename = "topLeft")]
+    TopLeft,
+
+    #[serde(rename = "topRight")]
+    TopRight,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_youtube_without_user_agent;
+
+    #[tokio::test]
+    async fn test_set_without_auth() {
+        let without_auth = get_youtube_without_user_agent()
+            .watermarks()
+            .set(
+                "UC_x5XG1OV2P6uZZ5FSM9Ttw",
+                vec![0x89, 0x50, 0x4e, 0x47],
+                ImageMimeType::Png,
+                WatermarkTiming::offset_from_start(15000),
+            )
+            .request()
+            .await;
+        assert!(without_auth.is_err());
+        let err = without_auth.unwrap_err();
+        assert_eq!(
+            "builder error: \"The request to `watermarks/set` requires OAuth 2.0 authorization\"",
+            format!("{}", err)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unset_without_auth() {
+        let without_auth = get_youtube_without_user_agent()
+            .watermarks()
+            .unset("UC_x5XG1OV2P6uZZ5FSM9Ttw")
+            .request()
+            .await;
+        assert!(without_auth.is_err());
+        let err = without_auth.unwrap_err();
+        assert_eq!(
+            "builder error: \"The request to `watermarks/unset` requires OAuth 2.0 authorization\"",
+            format!("{}", err)
+        );
+    }
+
+    #[test]
+    fn test_serialize_invideo_branding() {
+        let timing = WatermarkTiming::offset_from_end(5000).duration_ms(3000);
+        let metadata = InvideoBranding {
+            timing: &timing,
+            position: InvideoPosition {
+                position_type: "corner",
+                corner_position: &WatermarkCornerPosition::BottomLeft,
+            },
+            target_channel_id: None,
+        };
+        assert_eq!(
+            r#"{"timing":{"type":"offsetFromEnd","offsetMs":5000,"durationMs":3000},"position":{"type":"corner","cornerPosition":"bottomLeft"}}"#,
+            serde_json::to_string(&metadata).unwrap()
+        );
+    }
+}
@@ -0,0 +1,76 @@
+//! Uses the services, the request builders and the resources through the re-exports of the crate root, as a
+//! dependent crate does.
+
+use std::sync::Arc;
+use youtube_data::transport::MockTransport;
+use youtube_data::videos::Part;
+use youtube_data::{
+    ChannelFilter, ChannelList, ChannelListResource, ChannelsService, ListApi, SearchList,
+    SearchService, VideoFilter, VideoList, VideoListResource, VideoListResponse, VideoRate,
+    VideoSearchResult, VideosService, YouTube,
+};
+
+/// A configured request kept in a struct and sent later.
+struct Watch<'a> {
+    videos: VideoList<'a>,
+    channels: ChannelList<'a>,
+}
+
+fn watch<'a>(videos: VideosService<'a>, channels: ChannelsService<'a>) -> Watch<'a> {
+    Watch {
+        videos: videos.list([Part::Id], VideoFilter::id(["Ks-_Mh1QhMc"])),
+        channels: channels.list(
+            [youtube_data::channels::Part::Id],
+            ChannelFilter::id(["UCa9Y57gfeY0Zro_noHRVrnw"]),
+        ),
+    }
+}
+
+fn search(service: SearchService<'_>) -> SearchList<'_, VideoSearchResult> {
+    service.videos("rust")
+}
+
+#[tokio::test]
+async fn test_public_api() {
+    let transport = Arc::new(
+        MockTransport::new()
+            .respond(
+                reqwest::Method::GET,
+                "videos",
+                200,
+                r#"{"items": [{"id": "Ks-_Mh1QhMc"}]}"#,
+            )
+            .respond(
+                reqwest::Method::GET,
+                "channels",
+                200,
+                r#"{"items": [{"id": "UCa9Y57gfeY0Zro_noHRVrnw"}]}"#,
+            )
+            .respond(
+                reqwest::Method::GET,
+                "search",
+                200,
+                r#"{"items": [{"id": {"kind": "youtube#video", "videoId": "Ks-_Mh1QhMc"}}]}"#,
+            ),
+    );
+    let youtube = YouTube::builder("api-key")
+        .transport(transport.clone())
+        .build()
+        .unwrap();
+
+    let watch = watch(youtube.videos(), youtube.channels());
+    let videos: VideoListResponse = watch.videos.request().await.unwrap();
+    let video: &VideoListResource = &videos.items[0];
+    assert_eq!(video.id, "Ks-_Mh1QhMc");
+    let channels = watch.channels.request().await.unwrap();
+    let channel: &ChannelListResource = &channels.items[0];
+    assert_eq!(channel.id, "UCa9Y57gfeY0Zro_noHRVrnw");
+
+    let results = search(youtube.search()).request().await.unwrap();
+    assert_eq!(results.items[0].id, "Ks-_Mh1QhMc");
+
+    let _rate: VideoRate<'_> = youtube
+        .videos()
+        .rate("Ks-_Mh1QhMc", youtube_data::videos::Rating::Like);
+    assert_eq!(transport.requests().len(), 3);
+}
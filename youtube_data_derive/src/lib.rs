@@ -0,0 +1,596 @@
+//! The derive macro of the request builders of `youtube_data`.
+//!
+//! `#[derive(QueryBuilder)]` generates the setters of the annotated fields of a builder and an `insert_query`
+//! method that inserts them into the query parameter map:
+//!
+//! ```ignore
+//! #[derive(QueryBuilder)]
+//! struct SubscriptionList<'a> {
+//!     service: SubscriptionsService<'a>,
+//!
+//!     #[query]
+//!     part: Vec<Part>,
+//!     #[query(filter)]
+//!     channel_id: Option<String>,
+//!     #[query(filter, authorized)]
+//!     mine: Option<bool>,
+//!     #[query(max = 50)]
+//!     max_results: Option<u32>,
+//!     #[setter]
+//!     page_token: Option<String>,
+//! }
+//! ```
+//!
+//! - `#[query]` generates the setter and inserts the field as the camelCase parameter, or as
+//!   `#[query(rename = "type")]`.
+//! - `#[query(filter)]` makes the field one of the filters of which exactly one must be specified; `insert_query`
+//!   fails with a builder error otherwise. `authorized` filters require an authorized request.
+//! - `#[query(max = 50)]` and `#[query(min = 72)]` bound the value, which `insert_query` clamps or rejects as the
+//!   `OutOfRange` policy of the `YouTube` handle selects.
+//! - `#[query(default)]` falls back to the field of the same name of the `YouTube` handle, e.g. its default `hl`,
+//!   when the setter was not called.
+//! - `#[query(unique)]` on a `Vec<T>` field makes the setter take any `IntoIterator<Item = T>` and drop the repeated
+//!   values.
+//! - `#[setter]` only generates the setter, for the parameters that the builder validates or inserts itself.
+//!
+//! The setter of an `Option<T>` field takes a `T`, the setter of a `String` field any `impl Into<String>` and the
+//! setter of a `Vec<String>` field any iterator of them. The doc comments of the field become the doc of the setter.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse_macro_input, Attribute, Data, DeriveInput, Field, Fields, GenericArgument, Ident, LitInt,
+    LitStr, Meta, PathArguments, Type,
+};
+
+#[proc_macro_derive(QueryBuilder, attributes(query, setter))]
+pub fn derive_query_builder(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// How the value of a field reaches the query.
+#[derive(PartialEq)]
+enum Kind {
+    /// Inserted by `insert_query`.
+    Parameter,
+    /// One of the filters of which exactly one must be specified.
+    Filter,
+    /// Only the setter is generated.
+    Setter,
+}
+
+struct QueryField<'a> {
+    ident: &'a Ident,
+    ty: &'a Type,
+    docs: Vec<&'a Attribute>,
+    kind: Kind,
+    name: String,
+    authorized: bool,
+    unique: bool,
+    default: bool,
+    min: Option<LitInt>,
+    max: Option<LitInt>,
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "QueryBuilder requires a struct with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "QueryBuilder requires a struct",
+            ))
+        }
+    };
+    let mut query_fields = Vec::new();
+    for field in fields {
+        if let Some(query_field) = query_field(field)? {
+            query_fields.push(query_field);
+        }
+    }
+
+    let setters = query_fields.iter().map(setter);
+    let filter_check = filter_check(&query_fields);
+    let insertions = query_fields
+        .iter()
+        .filter(|field| field.kind != Kind::Setter)
+        .map(insertion);
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#setters)*
+
+            /// Inserts the query parameters of the `#[query]` fields, failing unless exactly one filter is
+            /// specified.
+            fn insert_query(
+                &self,
+                youtube: &crate::YouTube,
+                params: &mut ::std::collections::HashMap<String, String>,
+            ) -> crate::error::Result<()> {
+                let _ = youtube;
+                #filter_check
+                #(#insertions)*
+                Ok(())
+            }
+        }
+    })
+}
+
+fn query_field(field: &Field) -> syn::Result<Option<QueryField<'_>>> {
+    let ident = field.ident.as_ref().expect("named field");
+    let mut query_field = QueryField {
+        ident,
+        ty: &field.ty,
+        docs: field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("doc"))
+            .collect(),
+        kind: Kind::Parameter,
+        name: camel_case(&ident.to_string()),
+        authorized: false,
+        unique: false,
+        default: false,
+        min: None,
+        max: None,
+    };
+    let mut annotated = false;
+    for attr in &field.attrs {
+        if attr.path().is_ident("setter") {
+            annotated = true;
+            query_field.kind = Kind::Setter;
+        } else if attr.path().is_ident("query") {
+            annotated = true;
+            if let Meta::Path(_) = attr.meta {
+                continue;
+            }
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    query_field.name = meta.value()?.parse::<LitStr>()?.value();
+                } else if meta.path.is_ident("filter") {
+                    query_field.kind = Kind::Filter;
+                } else if meta.path.is_ident("authorized") {
+                    query_field.authorized = true;
+                } else if meta.path.is_ident("unique") {
+                    query_field.unique = true;
+                } else if meta.path.is_ident("default") {
+                    query_field.default = true;
+                } else if meta.path.is_ident("min") {
+                    query_field.min = Some(meta.value()?.parse::<LitInt>()?);
+                } else if meta.path.is_ident("max") {
+                    query_field.max = Some(meta.value()?.parse::<LitInt>()?);
+                } else {
+                    return Err(meta.error(
+                        "expected `rename`, `filter`, `authorized`, `unique`, `default`, `min` or `max`",
+                    ));
+                }
+                Ok(())
+            })?;
+        }
+    }
+    Ok(annotated.then_some(query_field))
+}
+
+fn setter(field: &QueryField) -> TokenStream2 {
+    let QueryField {
+        ident, ty, docs, ..
+    } = field;
+    if field.unique {
+        let Some(item) = generic_argument(ty, "Vec") else {
+            return syn::Error::new_spanned(ty, "`unique` requires a `Vec`").into_compile_error();
+        };
+        return quote! {
+            #(#docs)*
+            pub fn #ident(&mut self, #ident: impl IntoIterator<Item = #item>) -> &mut Self {
+                self.#ident = crate::dedupe(#ident);
+                self
+            }
+        };
+    }
+    let inner = generic_argument(ty, "Option");
+    let value = inner.unwrap_or(ty);
+    let (argument, conversion) = if is_string(value) {
+        (
+            quote! { impl Into<String> },
+            quote! { let #ident: String = #ident.into(); },
+        )
+    } else if generic_argument(value, "Vec").is_some_and(is_string) {
+        (
+            quote! { impl IntoIterator<Item = impl Into<String>> },
+            quote! { let #ident = #ident.into_iter().map(Into::into).collect::<Vec<String>>(); },
+        )
+    } else {
+        (quote! { #value }, TokenStream2::new())
+    };
+    let assignment = match inner {
+        Some(_) => quote! { self.#ident = Some(#ident); },
+        None => quote! { self.#ident = #ident; },
+    };
+    quote! {
+        #(#docs)*
+        pub fn #ident(&mut self, #ident: #argument) -> &mut Self {
+            #conversion
+            #assignment
+            self
+        }
+    }
+}
+
+/// Fails unless exactly one of the filters is specified, with the same messages as the api.
+fn filter_check(fields: &[QueryField]) -> TokenStream2 {
+    let filters = fields
+        .iter()
+        .filter(|field| field.kind == Kind::Filter)
+        .collect::<Vec<_>>();
+    if filters.is_empty() {
+        return TokenStream2::new();
+    }
+    let names = filters.iter().map(|field| field.ident.to_string());
+    let idents = filters.iter().map(|field| field.ident);
+    let missing = format!(
+        "No filter selected. Expected one of: {}",
+        filters
+            .iter()
+            .map(|field| field.ident.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    let empty_checks = filters
+        .iter()
+        .filter(|field| {
+            generic_argument(field.ty, "Option")
+                .is_some_and(|inner| generic_argument(inner, "Vec").is_some())
+        })
+        .map(|field| {
+            let ident = field.ident;
+            quote! {
+                if self.#ident.as_ref().is_some_and(|value| value.is_empty()) {
+                    return Err(crate::error::Error::missing_required_parameter(#missing));
+                }
+            }
+        });
+    let authorizations = filters
+        .iter()
+        .filter(|field| field.authorized)
+        .map(|field| {
+            let ident = field.ident;
+            let name = ident.to_string();
+            quote! {
+                if self.#ident.is_some() {
+                    crate::YouTubeDataApi::require_authorization(self, youtube, #name)?;
+                }
+            }
+        });
+    quote! {
+        let filters = [#((#names, self.#idents.is_some())),*]
+            .into_iter()
+            .filter(|(_, specified)| *specified)
+            .map(|(name, _)| name)
+            .collect::<Vec<&str>>();
+        if filters.len() > 1 {
+            return Err(crate::error::Error::incompatible_parameters(format!(
+                "Incompatible parameters specified in the request: {}",
+                filters.join(", "),
+            )));
+        }
+        if filters.is_empty() {
+            return Err(crate::error::Error::missing_required_parameter(#missing));
+        }
+        #(#empty_checks)*
+        #(#authorizations)*
+    }
+}
+
+fn insertion(field: &QueryField) -> TokenStream2 {
+    let ident = field.ident;
+    let name = &field.name;
+    if field.min.is_some() || field.max.is_some() {
+        let parameter = ident.to_string();
+        let min = bound(&field.min);
+        let max = bound(&field.max);
+        return quote! {
+            let value = match self.#ident {
+                Some(value) => Some(youtube.inner().out_of_range.apply(#parameter, value, #min, #max)?),
+                None => None,
+            };
+            crate::RequestBase::insert_query_parameter(self, params, #name, value.as_ref());
+        };
+    }
+    match generic_argument(field.ty, "Option") {
+        Some(_) if field.default => quote! {
+            crate::RequestBase::insert_query_parameter(
+                self,
+                params,
+                #name,
+                self.#ident.as_ref().or(youtube.inner().#ident.as_ref()),
+            );
+        },
+        Some(inner) if generic_argument(inner, "Vec").is_some() => quote! {
+            crate::RequestBase::insert_query_parameters(self, params, #name, self.#ident.as_ref());
+        },
+        Some(_) => quote! {
+            crate::RequestBase::insert_query_parameter(self, params, #name, self.#ident.as_ref());
+        },
+        None if generic_argument(field.ty, "Vec").is_some() => quote! {
+            crate::RequestBase::insert_query_parameters(self, params, #name, Some(&self.#ident));
+        },
+        None => quote! {
+            crate::RequestBase::insert_query_parameter(self, params, #name, Some(&self.#ident));
+        },
+    }
+}
+
+/// `Some(bound)` or `None`, as an argument of `OutOfRange::apply`.
+fn bound(bound: &Option<LitInt>) -> TokenStream2 {
+    match bound {
+        Some(bound) => quote! { Some(#bound) },
+        None => quote! { None },
+    }
+}
+
+/// The type argument of `ty` if it is the generic type `wrapper`, e.g. `T` of `Option<T>`.
+fn generic_argument<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != wrapper {
+        return None;
+    }
+    match &segment.arguments {
+        PathArguments::AngleBracketed(arguments) => match arguments.args.first()? {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Whether `ty` is `String`.
+fn is_string(ty: &Type) -> bool {
+    matches!(ty, Type::Path(path) if path.path.is_ident("String"))
+}
+
+/// `max_results` → `maxResults`
+fn camel_case(snake_case: &str) -> String {
+    let mut camel_case = String::with_capacity(snake_case.len());
+    let mut upper = false;
+    for c in snake_case.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            camel_case.push(c.to_ascii_uppercase());
+            upper = false;
+        } else {
+            camel_case.push(c);
+        }
+    }
+    camel_case
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    /// The generated code, or the message of the error.
+    fn expanded(input: DeriveInput) -> Result<String, String> {
+        expand(input)
+            .map(|tokens| tokens.to_string())
+            .map_err(|err| err.to_string())
+    }
+
+    fn contains(expanded: &str, tokens: TokenStream2) -> bool {
+        expanded.contains(&tokens.to_string())
+    }
+
+    #[test]
+    fn test_camel_case() {
+        assert_eq!(camel_case("max_results"), "maxResults");
+        assert_eq!(
+            camel_case("on_behalf_of_content_owner"),
+            "onBehalfOfContentOwner"
+        );
+        assert_eq!(camel_case("id"), "id");
+    }
+
+    #[test]
+    fn test_rename() {
+        let expanded = expanded(parse_quote! {
+            struct List {
+                #[query]
+                max_results: Option<u32>,
+                #[query(rename = "type")]
+                kind: Option<String>,
+            }
+        })
+        .unwrap();
+        assert!(contains(
+            &expanded,
+            quote! { insert_query_parameter(self, params, "maxResults", self.max_results.as_ref()); }
+        ));
+        assert!(contains(
+            &expanded,
+            quote! { insert_query_parameter(self, params, "type", self.kind.as_ref()); }
+        ));
+        assert!(!expanded.contains("\"kind\""));
+    }
+
+    #[test]
+    fn test_optional_and_required() {
+        let expanded = expanded(parse_quote! {
+            struct List {
+                #[query]
+                part: Vec<Part>,
+                #[query]
+                id: String,
+                #[query]
+                hl: Option<String>,
+                #[query]
+                mine: Option<bool>,
+                #[query]
+                video_id: Option<Vec<String>>,
+            }
+        })
+        .unwrap();
+
+        // the setter of an `Option<T>` takes a `T`, and of a `String` any `impl Into<String>`
+        assert!(contains(
+            &expanded,
+            quote! { pub fn mine(&mut self, mine: bool) -> &mut Self { self.mine = Some(mine); self } }
+        ));
+        assert!(contains(
+            &expanded,
+            quote! {
+                pub fn hl(&mut self, hl: impl Into<String>) -> &mut Self {
+                    let hl: String = hl.into();
+                    self.hl = Some(hl);
+                    self
+                }
+            }
+        ));
+        assert!(contains(
+            &expanded,
+            quote! {
+                pub fn id(&mut self, id: impl Into<String>) -> &mut Self {
+                    let id: String = id.into();
+                    self.id = id;
+                    self
+                }
+            }
+        ));
+        assert!(contains(
+            &expanded,
+            quote! { pub fn video_id(&mut self, video_id: impl IntoIterator<Item = impl Into<String>>) -> &mut Self }
+        ));
+
+        // a required field is always inserted, an optional one when it is set
+        assert!(contains(
+            &expanded,
+            quote! { insert_query_parameters(self, params, "part", Some(&self.part)); }
+        ));
+        assert!(contains(
+            &expanded,
+            quote! { insert_query_parameter(self, params, "id", Some(&self.id)); }
+        ));
+        assert!(contains(
+            &expanded,
+            quote! { insert_query_parameter(self, params, "hl", self.hl.as_ref()); }
+        ));
+        assert!(contains(
+            &expanded,
+            quote! { insert_query_parameters(self, params, "videoId", self.video_id.as_ref()); }
+        ));
+    }
+
+    #[test]
+    fn test_setter_and_unannotated_fields() {
+        let expanded = expanded(parse_quote! {
+            struct List<'a> {
+                service: Service<'a>,
+                #[setter]
+                page_token: Option<String>,
+            }
+        })
+        .unwrap();
+        assert!(contains(&expanded, quote! { impl<'a> List<'a> }));
+        assert!(contains(
+            &expanded,
+            quote! { pub fn page_token(&mut self, page_token: impl Into<String>) -> &mut Self }
+        ));
+        assert!(!expanded.contains("pageToken"));
+        assert!(!expanded.contains("fn service"));
+    }
+
+    #[test]
+    fn test_filters() {
+        let expanded = expanded(parse_quote! {
+            struct List {
+                #[query(filter)]
+                id: Option<Vec<String>>,
+                #[query(filter, authorized)]
+                mine: Option<bool>,
+            }
+        })
+        .unwrap();
+        assert!(expanded.contains("\"No filter selected. Expected one of: id, mine\""));
+        assert!(contains(
+            &expanded,
+            quote! { crate::YouTubeDataApi::require_authorization(self, youtube, "mine")?; }
+        ));
+        assert!(!contains(
+            &expanded,
+            quote! { crate::YouTubeDataApi::require_authorization(self, youtube, "id")?; }
+        ));
+    }
+
+    #[test]
+    fn test_bounds_and_defaults() {
+        let expanded = expanded(parse_quote! {
+            struct List {
+                #[query(max = 50)]
+                max_results: Option<u32>,
+                #[query(default)]
+                region_code: Option<String>,
+            }
+        })
+        .unwrap();
+        assert!(contains(
+            &expanded,
+            quote! { youtube.inner().out_of_range.apply("max_results", value, None, Some(50))? }
+        ));
+        assert!(contains(
+            &expanded,
+            quote! { self.region_code.as_ref().or(youtube.inner().region_code.as_ref()) }
+        ));
+    }
+
+    #[test]
+    fn test_unsupported_input() {
+        assert_eq!(
+            expanded(parse_quote! { struct List(u32); }).unwrap_err(),
+            "QueryBuilder requires a struct with named fields"
+        );
+        assert_eq!(
+            expanded(parse_quote! { enum List { A } }).unwrap_err(),
+            "QueryBuilder requires a struct"
+        );
+        assert_eq!(
+            expanded(parse_quote! {
+                struct List {
+                    #[query(flter)]
+                    id: Option<String>,
+                }
+            })
+            .unwrap_err(),
+            "expected `rename`, `filter`, `authorized`, `unique`, `default`, `min` or `max`"
+        );
+
+        // `unique` on a field that is not a `Vec` fails at the field
+        let expanded = expanded(parse_quote! {
+            struct List {
+                #[query(unique)]
+                part: Option<Part>,
+            }
+        })
+        .unwrap();
+        assert!(contains(
+            &expanded,
+            quote! { ::core::compile_error! { "`unique` requires a `Vec`" } }
+        ));
+    }
+}